@@ -4,15 +4,15 @@
 //! and resources in a separate window with a graphical interface.
 
 use bevy::prelude::*;
-use feathers_inspector::{entity_name_resolution::NameResolutionPlugin, InspectorWindowPlugin};
+use feathers_inspector::{InspectorWindowPlugin, entity_name_resolution::NameResolutionPlugin};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         // NOTE: will not be required once this crate is upstreamed
         .add_plugins(NameResolutionPlugin)
-        // Add the inspector window plugin
-        .add_plugins(InspectorWindowPlugin)
+        // Add the inspector window plugin, observing component additions for the Timeline tab
+        .add_plugins(InspectorWindowPlugin::default().with_component_lifecycles())
         .add_systems(Startup, setup)
         .run();
 }
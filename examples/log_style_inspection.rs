@@ -13,7 +13,7 @@ use feathers_inspector::{
         CommandsExtensionTrait, EntityCommandsInspectionTrait, WorldInspectionExtensionTrait,
     },
     resource_inspection::ResourceInspectionSettings,
-    summary::{CommandsSummaryExt, SummarySettings},
+    summary::{AuditSettings, CommandsSummaryExt, SummarySettings},
 };
 
 fn main() {
@@ -32,6 +32,7 @@ fn main() {
                 inspect_specific_component_when_c_pressed,
                 inspect_all_entities_when_space_pressed,
                 summarize_when_s_pressed,
+                audit_when_u_pressed,
             ),
         )
         .run();
@@ -53,7 +54,8 @@ Press 'A' to inspect all resources
 Press 'C' to inspect the Sprite component on all Sprite entities
 Press `Space` to inspect all entities
 Press 'M' to inspect the Sprite component type metadata
-Press 'S' to obtain summary statistics"
+Press 'S' to obtain summary statistics
+Press 'U' to audit the world for common problems"
         .to_string();
 
     commands.spawn((
@@ -149,6 +151,12 @@ fn summarize_when_s_pressed(keyboard_input: Res<ButtonInput<KeyCode>>, mut comma
     }
 }
 
+fn audit_when_u_pressed(keyboard_input: Res<ButtonInput<KeyCode>>, mut commands: Commands) {
+    if keyboard_input.just_pressed(KeyCode::KeyU) {
+        commands.audit(AuditSettings::default());
+    }
+}
+
 fn inspect_sprite_component_type_when_m_pressed(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
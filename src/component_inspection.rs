@@ -22,14 +22,17 @@ use crate::memory_size::MemorySize;
 ///
 /// To inspect a component type itself, see [`ComponentTypeInspection`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentInspection {
     /// The entity that owns the component.
     pub entity: Entity,
     /// The [`ComponentId`] of the component.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_adapters::component_id"))]
     pub component_id: ComponentId,
     /// The type name of the component.
     ///
     /// This is duplicated from the metadata for convenience and [`Display`] printing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_adapters::debug_name"))]
     pub name: DebugName,
     /// The size, in bytes, of the component value.
     ///
@@ -81,18 +84,24 @@ impl Display for ComponentInspection {
 /// directly, as that type is not `Send + Sync` and cannot be stored in many contexts.
 /// Instead, this type extracts all relevant information that can be stored and used later.
 ///
+/// With the `serde` feature, the [`TypeId`] and [`TypeRegistration`] are not serialized,
+/// as they are only meaningful within the running app.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentTypeMetadata {
     /// The [`ComponentId`] of the component type.
     ///
     /// This is generally stored as a key in [`ComponentMetadataMap`],
     /// but is duplicated here for convenience.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_adapters::component_id"))]
     pub component_id: ComponentId,
     /// The type name of the component.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_adapters::debug_name"))]
     pub name: DebugName,
     /// The [`TypeId`] of the component type.
     ///
     /// Note that dynamic types will not have a [`TypeId`].
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub type_id: Option<TypeId>,
     /// The minimum size in bytes of the component type.
     ///
@@ -106,11 +115,16 @@ pub struct ComponentTypeMetadata {
     /// Returns true if the component type is mutable while in the ECS.
     pub mutable: bool,
     /// The storage type of this component.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_adapters::storage_type"))]
     pub storage_type: StorageType,
     /// Returns true if the underlying component type can freely be shared across threads.
     pub is_send_and_sync: bool,
     /// The list of components required by this component,
     /// which will automatically be added when this component is added to an entity.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_adapters::component_ids")
+    )]
     pub required_components: Vec<ComponentId>,
     /// The type information of the component.
     ///
@@ -124,6 +138,7 @@ pub struct ComponentTypeMetadata {
     /// Note: this may be `None` if the type is not reflected and registered in the type registry.
     /// Currently, generic types need to be manually registered,
     /// and dynamically-typed components cannot be registered.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub type_registration: Option<TypeRegistration>,
 }
 
@@ -56,7 +56,7 @@ impl EntityGrouping {
 }
 
 /// Specifies what kind of grouping [`EntityGrouping::generate`] should make.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
 pub enum GroupingStrategy {
     /// Group based on parent-child relationships.
     #[default]
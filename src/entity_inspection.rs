@@ -28,6 +28,7 @@ use crate::{
 
 /// The result of inspecting an entity.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityInspection {
     /// The entity being inspected.
     pub entity: Entity,
@@ -45,7 +46,31 @@ pub struct EntityInspection {
     /// The components on the entity, in inspection form.
     pub components: Option<Vec<ComponentInspection>>,
     /// Information about how this entity was spawned.
-    pub spawn_details: SpawnDetails,
+    pub spawn_details: EntitySpawnDetails,
+}
+
+/// Information about how an entity was spawned, copied from its [`SpawnDetails`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntitySpawnDetails {
+    /// The source location of the code that spawned the entity.
+    ///
+    /// Only available with Bevy's `track_location` feature.
+    pub spawned_by: Option<String>,
+    /// The system tick at which the entity was spawned.
+    pub spawn_tick: u32,
+}
+
+impl From<SpawnDetails> for EntitySpawnDetails {
+    fn from(details: SpawnDetails) -> Self {
+        Self {
+            spawned_by: details
+                .spawned_by()
+                .into_option()
+                .map(|location| location.to_string()),
+            spawn_tick: details.spawn_tick().get(),
+        }
+    }
 }
 
 impl Display for EntityInspection {
@@ -61,13 +86,10 @@ impl Display for EntityInspection {
             display_str.push_str(&format!("\nMemory Size: {}", total_size));
         }
 
-        let maybe_location = &self.spawn_details.spawned_by();
-        let tick = &self.spawn_details.spawn_tick();
-
-        if let Some(location) = maybe_location.into_option() {
+        if let Some(location) = &self.spawn_details.spawned_by {
             display_str.push_str(&format!(
                 "\nSpawned by: {location} on system tick {}",
-                tick.get()
+                self.spawn_details.spawn_tick
             ));
         } else {
             warn_once!(
@@ -187,6 +209,155 @@ impl Default for MultipleEntityInspectionSettings {
     }
 }
 
+impl MultipleEntityInspectionSettings {
+    /// Adds the constraints of `query` to these settings.
+    ///
+    /// Component names are resolved against the components registered in `world`,
+    /// matching either their short name or their full type path, ignoring case.
+    /// The components are appended to [`Self::with_component_filter`]
+    /// and [`Self::without_component_filter`], and the name text, if any,
+    /// replaces [`Self::name_filter`].
+    pub fn apply_query(
+        &mut self,
+        world: &World,
+        query: &EntityQuery,
+    ) -> Result<(), EntityQueryError> {
+        for name in &query.with_components {
+            // Requiring every component sharing a name would match nothing,
+            // so a required name must be unique
+            match resolve_component_name(world, name)[..] {
+                [] => return Err(EntityQueryError::UnknownComponent(name.clone())),
+                [id] => self.with_component_filter.push(id),
+                _ => return Err(EntityQueryError::AmbiguousComponent(name.clone())),
+            }
+        }
+        for name in &query.without_components {
+            let ids = resolve_component_name(world, name);
+            if ids.is_empty() {
+                return Err(EntityQueryError::UnknownComponent(name.clone()));
+            }
+            self.without_component_filter.extend(ids);
+        }
+        if let Some(name) = &query.name {
+            self.name_filter = Some(NameFilter::from(name));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the registered components whose short name or full type path is `name`,
+/// ignoring case.
+fn resolve_component_name(world: &World, name: &str) -> Vec<ComponentId> {
+    world
+        .components()
+        .iter_registered()
+        .filter(|info| {
+            info.name()
+                .shortname()
+                .to_string()
+                .eq_ignore_ascii_case(name)
+                || info.name().to_string().eq_ignore_ascii_case(name)
+        })
+        .map(|info| info.id())
+        .collect()
+}
+
+/// A query-like entity filter, as typed into a search box,
+/// e.g. `has:Transform !has:Sprite name:enemy`.
+///
+/// Terms are separated by whitespace:
+/// - `has:<Component>` requires the component,
+/// - `!has:<Component>` excludes the component,
+/// - `name:<text>`, or any other term, is searched for within the entity name,
+///   so names such as `ui::root` or `Enemy:3` can be searched for.
+///
+/// Apply it with [`MultipleEntityInspectionSettings::apply_query`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntityQuery {
+    /// The text to search for within entity names, joining all name terms with spaces.
+    pub name: Option<String>,
+    /// The names of the components that must be present.
+    pub with_components: Vec<String>,
+    /// The names of the components that must not be present.
+    pub without_components: Vec<String>,
+}
+
+impl EntityQuery {
+    /// Parses an [`EntityQuery`] from `text`.
+    ///
+    /// Plain text without any keys parses to a name-only query,
+    /// so a search box can accept both.
+    pub fn parse(text: &str) -> Result<Self, EntityQueryError> {
+        let mut query = Self::default();
+        let mut name_terms = Vec::new();
+        for term in text.split_whitespace() {
+            let (key, value) = match term.split_once(':') {
+                Some((key @ ("has" | "!has" | "name"), value)) => (key, value),
+                _ => {
+                    name_terms.push(term);
+                    continue;
+                }
+            };
+            if value.is_empty() {
+                return Err(EntityQueryError::MissingValue(key.to_string()));
+            }
+            match key {
+                "has" => query.with_components.push(value.to_string()),
+                "!has" => query.without_components.push(value.to_string()),
+                _ => name_terms.push(value),
+            }
+        }
+        if !name_terms.is_empty() {
+            query.name = Some(name_terms.join(" "));
+        }
+        Ok(query)
+    }
+
+    /// Parses an [`EntityQuery`] whose name is a pattern, such as a regex, from `text`.
+    ///
+    /// Only the `has:` and `!has:` terms are taken out; the rest of the text is kept verbatim
+    /// as the name, including its whitespace and any colons.
+    pub fn parse_pattern(text: &str) -> Result<Self, EntityQueryError> {
+        let mut query = Self::default();
+        let mut name = String::new();
+        for piece in text.split_inclusive(char::is_whitespace) {
+            let (key, value) = match piece.trim_end().split_once(':') {
+                Some((key @ ("has" | "!has"), value)) => (key, value),
+                _ => {
+                    name.push_str(piece);
+                    continue;
+                }
+            };
+            if value.is_empty() {
+                return Err(EntityQueryError::MissingValue(key.to_string()));
+            }
+            match key {
+                "has" => query.with_components.push(value.to_string()),
+                _ => query.without_components.push(value.to_string()),
+            }
+        }
+        let name = name.trim();
+        if !name.is_empty() {
+            query.name = Some(name.to_string());
+        }
+        Ok(query)
+    }
+}
+
+/// An error that occurred when parsing or applying an [`EntityQuery`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum EntityQueryError {
+    /// A term has a key, but nothing after the colon.
+    #[error("Missing value after `{0}:`")]
+    MissingValue(String),
+    /// No registered component has the given name.
+    #[error("No component named `{0}`")]
+    UnknownComponent(String),
+    /// Several registered components share the given name, so it cannot be required.
+    #[error("Several components are named `{0}`, use the full type path")]
+    AmbiguousComponent(String),
+}
+
 /// A filter for named entities.
 ///
 /// For convenience, the [`From`] trait has been implemented
@@ -300,7 +471,12 @@ fn does_entity_match_inspection_filter(
 
 #[cfg(test)]
 mod tests {
-    use crate::entity_inspection::NameFilter;
+    use bevy::prelude::*;
+
+    use crate::entity_inspection::{
+        EntityQuery, EntityQueryError, MultipleEntityInspectionSettings, NameFilter,
+        filter_entity_list_for_inspection,
+    };
 
     #[test]
     fn case_insensitive() {
@@ -334,4 +510,62 @@ mod tests {
             assert_eq!(matches, expected)
         }
     }
+
+    #[test]
+    fn queries_filter_by_components_and_name() {
+        let query = EntityQuery::parse("has:Transform  !has:sprite name:big enemy").unwrap();
+        assert_eq!(
+            query,
+            EntityQuery {
+                name: Some("big enemy".to_string()),
+                with_components: vec!["Transform".to_string()],
+                without_components: vec!["sprite".to_string()],
+            }
+        );
+        assert_eq!(
+            EntityQuery::parse("has:"),
+            Err(EntityQueryError::MissingValue("has".to_string()))
+        );
+        assert_eq!(
+            EntityQuery::parse("ui::root Enemy:3")
+                .unwrap()
+                .name
+                .as_deref(),
+            Some("ui::root Enemy:3")
+        );
+
+        let pattern =
+            EntityQuery::parse_pattern("has:Transform  (?i:chunk)_[[:digit:]]  (?:a|b)").unwrap();
+        assert_eq!(pattern.with_components, ["Transform"]);
+        assert_eq!(
+            pattern.name.as_deref(),
+            Some("(?i:chunk)_[[:digit:]]  (?:a|b)")
+        );
+        assert_eq!(
+            EntityQuery::parse_pattern("^Big Enemy$ !has:").unwrap_err(),
+            EntityQueryError::MissingValue("!has".to_string())
+        );
+
+        let mut world = World::new();
+        let enemy = world
+            .spawn((Name::new("Big Enemy"), Transform::default()))
+            .id();
+        world.spawn((
+            Name::new("Big Enemy"),
+            Transform::default(),
+            Sprite::default(),
+        ));
+        world.spawn(Name::new("Big Enemy"));
+        let mut entities: Vec<Entity> = world.query::<Entity>().iter(&world).collect();
+        let mut settings = MultipleEntityInspectionSettings::default();
+        settings.apply_query(&world, &query).unwrap();
+        filter_entity_list_for_inspection(&world, &mut entities, &settings);
+        assert_eq!(entities, vec![enemy]);
+
+        let unknown = EntityQuery::parse("has:Missing").unwrap();
+        assert_eq!(
+            settings.apply_query(&world, &unknown),
+            Err(EntityQueryError::UnknownComponent("Missing".to_string()))
+        );
+    }
 }
@@ -18,6 +18,7 @@ use crate::component_inspection::{ComponentInspection, ComponentTypeMetadata};
 ///
 /// This data is produced by [`resolve_name`].
 #[derive(Clone, Debug, PartialEq, Eq, Deref, DerefMut)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityName {
     #[deref]
     pub name: Name,
@@ -49,6 +50,7 @@ impl EntityName {
 /// Identifies whether the inspected entity's name
 /// is manually assigned or automatically resolved.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NameOrigin {
     /// The entity name comes from the [`Name`] component.
     Custom,
@@ -80,37 +82,52 @@ pub fn resolve_name(
             return None;
         };
 
-        let mut name_resolution_priorities = component_data
-            .iter()
-            .filter_map(|comp_inspection| {
-                let name_definition_priority = metadata_map
-                    .get(&comp_inspection.component_id)?
-                    .name_definition_priority;
-                name_definition_priority
-                    .map(|priority| (comp_inspection.name.shortname().to_string(), priority))
-            })
-            .collect::<Vec<(String, i8)>>();
-
-        if name_resolution_priorities.is_empty() {
-            return None;
-        }
+        generated_name(
+            component_data
+                .iter()
+                .map(|comp_inspection| comp_inspection.component_id),
+            metadata_map,
+        )
+    }
+}
 
-        // Sort by priority (higher priority first), then by name alphabetically
-        name_resolution_priorities.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+/// Joins the names of the highest priority name-defining components among `component_ids`,
+/// the name [`resolve_name`] generates for an entity without a [`Name`].
+///
+/// Returns [`None`] if none of the components define names.
+pub(crate) fn generated_name(
+    component_ids: impl IntoIterator<Item = ComponentId>,
+    metadata_map: &HashMap<ComponentId, ComponentTypeMetadata>,
+) -> Option<EntityName> {
+    let mut name_resolution_priorities = component_ids
+        .into_iter()
+        .filter_map(|component_id| {
+            let metadata = metadata_map.get(&component_id)?;
+            metadata
+                .name_definition_priority
+                .map(|priority| (metadata.name.shortname().to_string(), priority))
+        })
+        .collect::<Vec<(String, i8)>>();
 
-        // Only include names with the highest priority
-        // PERF: we could do this more efficiently by combining the sort and filter steps
-        let highest_priority = name_resolution_priorities[0].1;
-        name_resolution_priorities.retain(|&(_, priority)| priority == highest_priority);
+    if name_resolution_priorities.is_empty() {
+        return None;
+    }
 
-        let resolved_name = name_resolution_priorities
-            .into_iter()
-            .map(|(name, _)| name)
-            .collect::<Vec<String>>()
-            .join(" | ");
+    // Sort by priority (higher priority first), then by name alphabetically
+    name_resolution_priorities.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
-        Some(EntityName::generated(&resolved_name))
-    }
+    // Only include names with the highest priority
+    // PERF: we could do this more efficiently by combining the sort and filter steps
+    let highest_priority = name_resolution_priorities[0].1;
+    name_resolution_priorities.retain(|&(_, priority)| priority == highest_priority);
+
+    let resolved_name = name_resolution_priorities
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<String>>()
+        .join(" | ");
+
+    Some(EntityName::generated(&resolved_name))
 }
 
 /// Stores the registered name-defining component types and their priorities.
@@ -81,3 +81,59 @@ pub fn fuzzy_resource_name_to_id(world: &World, fuzzy_name: &str) -> Option<Comp
 
     best_match.map(|(id, _)| id)
 }
+
+/// Scores how well `candidate` matches the fuzzy `pattern`, where lower scores are better.
+///
+/// Unlike the Levenshtein-based lookups above, this always returns `None`
+/// when the candidate does not contain every character of the pattern in order,
+/// which makes it suitable for filtering lists of names.
+///
+/// Matching is case-insensitive and ignores leading/trailing whitespace.
+/// Exact matches score `0`, prefix matches `1` and substring matches `2`.
+/// Other matches score `3` plus the number of characters skipped between matched characters.
+pub fn fuzzy_match_score(pattern: &str, candidate: &str) -> Option<usize> {
+    let pattern = pattern.trim().to_lowercase();
+    let candidate = candidate.trim().to_lowercase();
+
+    if pattern == candidate {
+        return Some(0);
+    }
+    if candidate.starts_with(&pattern) {
+        return Some(1);
+    }
+    if candidate.contains(&pattern) {
+        return Some(2);
+    }
+
+    let mut gaps = 0;
+    let mut candidate_chars = candidate.chars();
+    let mut started = false;
+    for pattern_char in pattern.chars() {
+        loop {
+            let candidate_char = candidate_chars.next()?;
+            if candidate_char == pattern_char {
+                started = true;
+                break;
+            }
+            if started {
+                gaps += 1;
+            }
+        }
+    }
+
+    Some(3 + gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_score_ordering() {
+        assert_eq!(fuzzy_match_score("player", "Player"), Some(0));
+        assert_eq!(fuzzy_match_score("player", "Player 2"), Some(1));
+        assert_eq!(fuzzy_match_score("player", "Main Player"), Some(2));
+        assert_eq!(fuzzy_match_score("plyr", "Player"), Some(5));
+        assert_eq!(fuzzy_match_score("player", "Enemy"), None);
+    }
+}
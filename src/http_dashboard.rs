@@ -0,0 +1,384 @@
+//! A read-only HTTP dashboard of the [`World`], for builds without the inspector window.
+//!
+//! [`HttpDashboardPlugin`] serves a snapshot of the entity list and the world summary,
+//! so world state can be checked from a browser on deployed or headless builds:
+//!
+//! - `/` is an HTML page listing the entities and their components,
+//! - `/entities.json` is the [`DashboardSnapshot`] as JSON.
+//!
+//! The dashboard starts listening once the app starts up, and answers requests
+//! from the last snapshot on a small pool of threads, so serving never blocks or reads the world.
+//! A new snapshot is only taken once a client asked for one, at most once per refresh interval,
+//! so an unwatched dashboard costs nothing.
+//! The inspector's own entities, and those hidden by [`EntityExclusions`], are left out.
+//! The dashboard has no authentication; keep its address local or behind a firewall.
+//!
+//! Requires the `http_dashboard` feature.
+
+use core::time::Duration;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TrySendError};
+use std::sync::{Arc, Mutex, RwLock};
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_real_timer;
+
+use crate::component_inspection::ComponentMetadataMap;
+use crate::entity_inspection::{EntityInspection, MultipleEntityInspectionSettings};
+use crate::extension_methods::WorldInspectionExtensionTrait;
+use crate::inspector::EntityExclusions;
+use crate::inspector::state::InspectorInternal;
+use crate::summary::{SummarySettings, WorldSummaryExt};
+
+/// A snapshot of the [`World`] served by the [`HttpDashboardPlugin`].
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct DashboardSnapshot {
+    /// Seconds since startup when the snapshot was taken.
+    pub elapsed_seconds: f64,
+    /// The world summary, as formatted by [`WorldSummary`](crate::summary::WorldSummary).
+    pub summary: String,
+    /// The entities, with the names of their components.
+    pub entities: Vec<EntityInspection>,
+}
+
+impl DashboardSnapshot {
+    /// Takes a snapshot of `world`, leaving out the entities hidden from the inspector.
+    pub fn capture(world: &mut World, metadata_map: &mut ComponentMetadataMap) -> Self {
+        let mut query = world.query::<EntityRef>();
+        let exclusions = world.get_resource::<EntityExclusions>();
+        let entities: Vec<Entity> = query
+            .iter(world)
+            .filter(|&entity| {
+                !exclusions.map_or(entity.contains::<InspectorInternal>(), |exclusions| {
+                    exclusions.is_excluded(entity)
+                })
+            })
+            .map(|entity| entity.id())
+            .collect();
+        Self {
+            elapsed_seconds: world
+                .get_resource::<Time<Real>>()
+                .map_or(0.0, |time| time.elapsed_secs_f64()),
+            summary: world.summarize(SummarySettings::default()).to_string(),
+            entities: world
+                .inspect_multiple(
+                    entities,
+                    MultipleEntityInspectionSettings::default(),
+                    metadata_map,
+                )
+                .into_iter()
+                .filter_map(Result::ok)
+                .collect(),
+        }
+    }
+
+    /// Formats the snapshot as an HTML page.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>World</title>\
+             <style>body{font-family:monospace}td{padding:2px 8px;vertical-align:top}</style>\
+             </head><body>",
+        );
+        html.push_str(&format!(
+            "<p>Snapshot at {:.1}s, <a href=\"/entities.json\">JSON</a></p><pre>{}</pre>",
+            self.elapsed_seconds,
+            escape_html(&self.summary)
+        ));
+        html.push_str(
+            "<table><tr><th>Entity</th><th>Name</th><th>Memory</th><th>Components</th></tr>",
+        );
+        for inspection in &self.entities {
+            let components = inspection
+                .components
+                .iter()
+                .flatten()
+                .map(|component| component.name.shortname().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                inspection.entity,
+                escape_html(inspection.name.as_ref().map_or("", |name| name.as_str())),
+                inspection
+                    .total_memory_size
+                    .map(|size| size.to_string())
+                    .unwrap_or_default(),
+                escape_html(&components)
+            ));
+        }
+        html.push_str("</table></body></html>");
+        html
+    }
+}
+
+/// Escapes the characters of `text` that have a meaning in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The last snapshot, formatted for each endpoint.
+///
+/// Cheap to clone, so that requests don't hold the lock while writing to slow clients.
+#[derive(Clone, Default, Debug)]
+struct RenderedSnapshot {
+    html: Arc<str>,
+    json: Arc<str>,
+}
+
+/// The snapshot shared between the app and the threads serving the dashboard.
+#[derive(Default, Debug)]
+struct SharedSnapshot {
+    rendered: RwLock<RenderedSnapshot>,
+    /// Whether a client asked for the snapshot since it was last taken.
+    requested: AtomicBool,
+}
+
+/// How many threads answer dashboard requests.
+const WORKER_THREADS: usize = 4;
+
+/// How many accepted connections may wait for a worker; further connections are dropped.
+const MAX_QUEUED_CONNECTIONS: usize = 16;
+
+/// The app's side of the dashboard.
+#[derive(Resource)]
+struct DashboardState {
+    address: SocketAddr,
+    shared: Arc<SharedSnapshot>,
+    metadata_map: ComponentMetadataMap,
+}
+
+/// Plugin that serves a read-only HTML and JSON snapshot of the [`World`] over HTTP,
+/// see the [module docs](self).
+///
+/// Like the rest of the inspection API, this requires the
+/// [`NameResolutionPlugin`](crate::entity_name_resolution::NameResolutionPlugin).
+#[derive(Clone, Debug)]
+pub struct HttpDashboardPlugin {
+    /// The address the dashboard listens on.
+    ///
+    /// Defaults to `127.0.0.1:7878`, only reachable from the same machine.
+    pub address: SocketAddr,
+    /// How often the snapshot is retaken while clients ask for it, in real time.
+    pub refresh_interval: Duration,
+}
+
+impl Default for HttpDashboardPlugin {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 7878)),
+            refresh_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Plugin for HttpDashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DashboardState {
+            address: self.address,
+            shared: Arc::default(),
+            metadata_map: ComponentMetadataMap::empty(),
+        })
+        .add_systems(Startup, start_dashboard_server)
+        .add_systems(
+            Last,
+            update_dashboard_snapshot.run_if(on_real_timer(self.refresh_interval)),
+        );
+    }
+}
+
+/// Starts listening on the dashboard's address, serving requests on background threads.
+fn start_dashboard_server(state: Res<DashboardState>) {
+    match TcpListener::bind(state.address) {
+        Ok(listener) => {
+            info!("Serving the world dashboard at http://{}", state.address);
+            let shared = state.shared.clone();
+            std::thread::spawn(move || serve_dashboard(listener, shared));
+        }
+        Err(error) => {
+            warn!(
+                "Failed to serve the world dashboard at {}: {}",
+                state.address, error
+            );
+        }
+    }
+}
+
+/// Takes a new [`DashboardSnapshot`] and formats it for the serving threads,
+/// if a client asked for one since the last.
+fn update_dashboard_snapshot(world: &mut World) {
+    world.resource_scope(|world, mut state: Mut<DashboardState>| {
+        if !state.shared.requested.swap(false, Ordering::Relaxed) {
+            return;
+        }
+        let snapshot = DashboardSnapshot::capture(world, &mut state.metadata_map);
+        let json = serde_json::to_string(&snapshot).unwrap_or_else(|error| {
+            warn!("Failed to serialize the world dashboard: {}", error);
+            "null".to_string()
+        });
+        let html = snapshot.to_html();
+        if let Ok(mut rendered) = state.shared.rendered.write() {
+            *rendered = RenderedSnapshot {
+                html: html.into(),
+                json: json.into(),
+            };
+        }
+    });
+}
+
+/// Accepts connections to `listener` and hands them to a pool of [`WORKER_THREADS`] threads
+/// answering them with the last snapshot, until the app exits.
+///
+/// Connections beyond [`MAX_QUEUED_CONNECTIONS`] waiting for a worker are dropped,
+/// so slow or numerous clients can't pile up threads or memory.
+fn serve_dashboard(listener: TcpListener, shared: Arc<SharedSnapshot>) {
+    let (sender, receiver) = mpsc::sync_channel(MAX_QUEUED_CONNECTIONS);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..WORKER_THREADS {
+        let receiver = receiver.clone();
+        let shared = shared.clone();
+        std::thread::spawn(move || answer_connections(&receiver, &shared));
+    }
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        match sender.try_send(stream) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => debug!("World dashboard busy, dropped a connection"),
+            Err(TrySendError::Disconnected(_)) => return,
+        }
+    }
+}
+
+/// Answers the connections sent to `receiver`, one at a time, until the sender is dropped.
+fn answer_connections(receiver: &Mutex<Receiver<TcpStream>>, shared: &SharedSnapshot) {
+    loop {
+        // Only one worker waits for a connection at a time, the others answer theirs
+        let next = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(stream) = next else {
+            return;
+        };
+        if let Err(error) = respond(stream, shared) {
+            debug!("World dashboard request failed: {}", error);
+        }
+    }
+}
+
+/// Reads the request line from `stream` and writes the matching response.
+fn respond(mut stream: TcpStream, shared: &SharedSnapshot) -> std::io::Result<()> {
+    // A slow client only holds up its worker for a few seconds
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(5)))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    let rendered = shared
+        .rendered
+        .read()
+        .map_err(|_| std::io::Error::other("dashboard snapshot poisoned"))?
+        .clone();
+    let (status, content_type, body) = match path {
+        "/" | "/index.html" | "/entities.json" => {
+            shared.requested.store(true, Ordering::Relaxed);
+            match path {
+                // Until the first snapshot is taken after the first request
+                _ if rendered.html.is_empty() => (
+                    "503 Service Unavailable",
+                    "text/plain",
+                    "Taking the first snapshot, reload in a moment",
+                ),
+                "/entities.json" => ("200 OK", "application/json", &*rendered.json),
+                _ => ("200 OK", "text/html; charset=utf-8", &*rendered.html),
+            }
+        }
+        _ => ("404 Not Found", "text/plain", "Not found"),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_name_resolution::NameResolutionRegistry;
+
+    #[test]
+    fn snapshots_list_entities_as_html_and_json() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.insert_resource(NameResolutionRegistry::new());
+        world.spawn((Name::new("<Player>"), Transform::default()));
+        world.spawn((Name::new("Inspector camera"), InspectorInternal));
+        let snapshot = DashboardSnapshot::capture(&mut world, &mut ComponentMetadataMap::empty());
+
+        assert_eq!(snapshot.entities.len(), 1);
+        let html = snapshot.to_html();
+        assert!(html.contains("&lt;Player&gt;"));
+        assert!(html.contains("Transform"));
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(json.contains("\"<Player>\""));
+    }
+
+    #[test]
+    fn requests_are_answered_from_the_last_snapshot() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let address = listener.local_addr().unwrap();
+        let shared = Arc::new(SharedSnapshot::default());
+        let serving = shared.clone();
+        std::thread::spawn(move || serve_dashboard(listener, serving));
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(stream, "GET {} HTTP/1.1\r\n\r\n", path).unwrap();
+            let mut response = String::new();
+            std::io::Read::read_to_string(&mut stream, &mut response).unwrap();
+            response
+        };
+
+        assert!(get("/missing").starts_with("HTTP/1.1 404"));
+        assert!(!shared.requested.load(Ordering::Relaxed));
+        assert!(get("/").starts_with("HTTP/1.1 503"));
+        assert!(shared.requested.load(Ordering::Relaxed));
+
+        shared.rendered.write().unwrap().json = "[]".into();
+        shared.rendered.write().unwrap().html = "<html></html>".into();
+        assert!(get("/entities.json").ends_with("\r\n\r\n[]"));
+    }
+
+    #[test]
+    fn snapshots_are_only_taken_once_requested() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.insert_resource(NameResolutionRegistry::new());
+        let shared = Arc::new(SharedSnapshot::default());
+        world.insert_resource(DashboardState {
+            address: HttpDashboardPlugin::default().address,
+            shared: shared.clone(),
+            metadata_map: ComponentMetadataMap::empty(),
+        });
+
+        update_dashboard_snapshot(&mut world);
+        assert!(shared.rendered.read().unwrap().html.is_empty());
+
+        shared.requested.store(true, Ordering::Relaxed);
+        update_dashboard_snapshot(&mut world);
+        assert!(!shared.rendered.read().unwrap().html.is_empty());
+        assert!(!shared.requested.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,160 @@
+//! Comparing an entity against a baseline captured earlier.
+//!
+//! Capturing a baseline copies the values of all reflected components of the selected entity.
+//! While comparing, the detail panel highlights the fields whose values changed since then,
+//! and lists the components added or removed in the meantime.
+
+use core::any::TypeId;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::inspector::widgets::drag_value::clone_reflected;
+use crate::reflection_tools::get_reflected_component_ref;
+
+/// The type ids and values of the reflected components of `entity`, if it exists.
+fn reflected_components(
+    world: &World,
+    entity: Entity,
+) -> Option<impl Iterator<Item = (TypeId, &dyn PartialReflect)>> {
+    let components = world.inspect_entity(entity).ok()?;
+    Some(components.filter_map(move |info| {
+        let type_id = info.type_id()?;
+        let value = get_reflected_component_ref(world, entity, type_id).ok()?;
+        Some((type_id, value))
+    }))
+}
+
+/// Copies of an entity's reflected components at the time the baseline was captured.
+#[derive(Debug)]
+pub struct EntityBaseline {
+    components: HashMap<TypeId, Box<dyn PartialReflect>>,
+}
+
+impl EntityBaseline {
+    /// Captures the current values of the reflected components of `entity`.
+    ///
+    /// Components that aren't registered for reflection are left out,
+    /// and are not reported as added later either.
+    pub fn capture(world: &World, entity: Entity) -> Option<Self> {
+        let components = reflected_components(world, entity)?
+            .map(|(type_id, value)| (type_id, clone_reflected(value)))
+            .collect();
+        Some(Self { components })
+    }
+
+    /// The value of the component `type_id` when the baseline was captured.
+    pub fn component(&self, type_id: TypeId) -> Option<&dyn PartialReflect> {
+        self.components.get(&type_id).map(|value| value.as_ref())
+    }
+
+    /// Compares the baseline with the current components of `entity`.
+    pub fn diff(&self, world: &World, entity: Entity) -> BaselineDiff {
+        let mut diff = BaselineDiff::default();
+        let Ok(entity_ref) = world.get_entity(entity) else {
+            diff.removed = self.components.keys().copied().collect();
+            return diff;
+        };
+
+        for &type_id in self.components.keys() {
+            if !entity_ref.contains_type_id(type_id) {
+                diff.removed.push(type_id);
+            }
+        }
+        for (type_id, current) in reflected_components(world, entity).into_iter().flatten() {
+            match self.component(type_id) {
+                None => diff.added.push(type_id),
+                Some(baseline) if current.reflect_partial_eq(baseline) != Some(true) => {
+                    diff.changed.push(type_id);
+                }
+                Some(_) => {}
+            }
+        }
+        diff
+    }
+}
+
+/// The components of an entity that differ from its [`EntityBaseline`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BaselineDiff {
+    /// Components whose value changed, or that can't be compared.
+    pub changed: Vec<TypeId>,
+    /// Components added since the baseline was captured.
+    pub added: Vec<TypeId>,
+    /// Components removed since the baseline was captured.
+    pub removed: Vec<TypeId>,
+}
+
+/// The captured baseline of each entity, see [`EntityBaseline`].
+#[derive(Resource, Default)]
+pub struct EntityBaselines {
+    baselines: EntityHashMap<EntityBaseline>,
+}
+
+impl EntityBaselines {
+    /// Captures a baseline of `entity`, replacing its previous baseline.
+    ///
+    /// Returns whether the entity exists.
+    pub fn capture(&mut self, world: &World, entity: Entity) -> bool {
+        let Some(baseline) = EntityBaseline::capture(world, entity) else {
+            return false;
+        };
+        self.baselines.insert(entity, baseline);
+        true
+    }
+
+    /// The baseline captured for `entity`, if any.
+    pub fn get(&self, entity: Entity) -> Option<&EntityBaseline> {
+        self.baselines.get(&entity)
+    }
+
+    /// Forgets the baseline of `entity`.
+    pub fn remove(&mut self, entity: Entity) -> Option<EntityBaseline> {
+        self.baselines.remove(&entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default, PartialEq)]
+    #[reflect(Component, PartialEq)]
+    struct Health(f32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Poisoned;
+
+    #[test]
+    fn diff_reports_changed_added_and_removed_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Poisoned>();
+            registry.register::<Name>();
+        }
+        let entity = world.spawn((Health(10.0), Name::new("Player"))).id();
+
+        let mut baselines = EntityBaselines::default();
+        assert!(baselines.capture(&world, entity));
+        let baseline = baselines.get(entity).unwrap();
+        assert_eq!(baseline.diff(&world, entity), BaselineDiff::default());
+
+        world
+            .entity_mut(entity)
+            .insert((Health(4.0), Poisoned))
+            .remove::<Name>();
+        assert_eq!(
+            baseline.diff(&world, entity),
+            BaselineDiff {
+                changed: vec![TypeId::of::<Health>()],
+                added: vec![TypeId::of::<Poisoned>()],
+                removed: vec![TypeId::of::<Name>()],
+            }
+        );
+    }
+}
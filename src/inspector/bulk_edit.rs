@@ -0,0 +1,226 @@
+//! Inserting or removing a component on many entities at once.
+//!
+//! Edits are queued in [`BulkComponentEdits`] and handed to [`PendingStructuralChanges`]
+//! in batches of [`BULK_EDIT_BATCH_SIZE`] entities per frame,
+//! so large sets don't stall a single frame and their progress can be reported.
+
+use core::any::TypeId;
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+
+use crate::fuzzy_name_mapping::fuzzy_match_score;
+use crate::inspector::state::InspectorCache;
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::structural_changes::{PendingStructuralChanges, StructuralChange};
+
+/// Number of entities edited per frame.
+pub const BULK_EDIT_BATCH_SIZE: usize = 256;
+
+/// What a [`BulkComponentEdit`] does to each entity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BulkComponentAction {
+    /// Inserts the component's default value, replacing existing values.
+    Insert,
+    /// Removes the component.
+    Remove,
+}
+
+/// A component insertion or removal on a set of entities.
+#[derive(Clone, Debug)]
+pub struct BulkComponentEdit {
+    /// The component type, which must register `ReflectComponent`,
+    /// and `ReflectDefault` to be inserted.
+    pub type_id: TypeId,
+    /// Whether the component is inserted or removed.
+    pub action: BulkComponentAction,
+    /// The entities not edited yet.
+    pub remaining: Vec<Entity>,
+    /// The number of entities to edit in total.
+    pub total: usize,
+}
+
+/// Queue of bulk component edits, processed in order.
+#[derive(Resource, Default)]
+pub struct BulkComponentEdits {
+    queue: VecDeque<BulkComponentEdit>,
+    /// Progress of the current edit, or the outcome of the last one.
+    status: Option<String>,
+}
+
+impl BulkComponentEdits {
+    /// Queues inserting (or removing) the component `type_id` on every entity in `entities`.
+    pub fn push(&mut self, type_id: TypeId, action: BulkComponentAction, entities: Vec<Entity>) {
+        self.queue.push_back(BulkComponentEdit {
+            type_id,
+            action,
+            total: entities.len(),
+            remaining: entities,
+        });
+    }
+
+    /// Returns whether any edits are still in progress.
+    pub fn is_busy(&self) -> bool {
+        !self.queue.is_empty()
+    }
+
+    /// Describes the progress of the current edit, or the outcome of the last one.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+}
+
+/// Returns the component type whose short name best matches the fuzzy `search`,
+/// among the types registering `ReflectComponent`, with its short name.
+///
+/// Ties are broken by name, so the result is stable.
+pub fn best_component_match(registry: &TypeRegistry, search: &str) -> Option<(TypeId, String)> {
+    if search.trim().is_empty() {
+        return None;
+    }
+    registry
+        .iter_with_data::<ReflectComponent>()
+        .filter_map(|(registration, _)| {
+            let name = ShortName(registration.type_info().type_path()).to_string();
+            let score = fuzzy_match_score(search, &name)?;
+            Some((score, name, registration.type_id()))
+        })
+        .min()
+        .map(|(_, name, type_id)| (type_id, name))
+}
+
+/// Queues up to [`BULK_EDIT_BATCH_SIZE`] entities of the queued [`BulkComponentEdits`]
+/// as [`PendingStructuralChanges`], and marks the entity list stale once an edit completes.
+pub fn process_bulk_component_edits(
+    mut edits: ResMut<BulkComponentEdits>,
+    registry: Res<AppTypeRegistry>,
+    strings: Res<InspectorStrings>,
+    mut cache: ResMut<InspectorCache>,
+    mut pending: ResMut<PendingStructuralChanges>,
+) {
+    let edits = &mut *edits;
+    let Some(edit) = edits.queue.front_mut() else {
+        return;
+    };
+    let registry = registry.read();
+    let Some(registration) = registry.get(edit.type_id) else {
+        edits.queue.pop_front();
+        return;
+    };
+    let name = ShortName(registration.type_info().type_path()).to_string();
+    if edit.action == BulkComponentAction::Insert && registration.data::<ReflectDefault>().is_none()
+    {
+        warn!(
+            "Cannot insert {} in bulk: it does not register `ReflectDefault`",
+            name
+        );
+        edits.status = Some(format!("{} {}", name, strings.no_default_value));
+        edits.queue.pop_front();
+        return;
+    }
+
+    let batch_start = edit.remaining.len().saturating_sub(BULK_EDIT_BATCH_SIZE);
+    let component_type_id = edit.type_id;
+    for entity in edit.remaining.drain(batch_start..) {
+        pending.push(match edit.action {
+            BulkComponentAction::Insert => StructuralChange::Insert {
+                entity,
+                component_type_id,
+            },
+            BulkComponentAction::Remove => StructuralChange::Remove {
+                entity,
+                component_type_id,
+            },
+        });
+    }
+
+    let done = edit.total - edit.remaining.len();
+    let status = match (edit.action, edit.remaining.is_empty()) {
+        (BulkComponentAction::Insert, true) => {
+            format!(
+                "{} {} ({} {})",
+                strings.bulk_added, name, done, strings.entities
+            )
+        }
+        (BulkComponentAction::Remove, true) => {
+            format!(
+                "{} {} ({} {})",
+                strings.bulk_removed, name, done, strings.entities
+            )
+        }
+        (BulkComponentAction::Insert, false) => {
+            format!("{} {}: {}/{}", strings.bulk_adding, name, done, edit.total)
+        }
+        (BulkComponentAction::Remove, false) => {
+            format!(
+                "{} {}: {}/{}",
+                strings.bulk_removing, name, done, edit.total
+            )
+        }
+    };
+    if edit.remaining.is_empty() {
+        edits.queue.pop_front();
+        cache.stale = true;
+    }
+    edits.status = Some(status);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::inspector::structural_changes::apply_pending_structural_changes;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Wireframe;
+
+    #[test]
+    fn edits_are_applied_in_batches() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorCache>();
+        world.init_resource::<InspectorStrings>();
+        world.init_resource::<BulkComponentEdits>();
+        world.init_resource::<PendingStructuralChanges>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Wireframe>();
+        let entities: Vec<Entity> = (0..BULK_EDIT_BATCH_SIZE + 1)
+            .map(|_| world.spawn_empty().id())
+            .collect();
+
+        let type_id = TypeId::of::<Wireframe>();
+        let (matched, name) =
+            best_component_match(&world.resource::<AppTypeRegistry>().read(), "wire").unwrap();
+        assert_eq!((matched, name.as_str()), (type_id, "Wireframe"));
+
+        world.resource_mut::<BulkComponentEdits>().push(
+            type_id,
+            BulkComponentAction::Insert,
+            entities.clone(),
+        );
+        world.run_system_once(process_bulk_component_edits).unwrap();
+        apply_pending_structural_changes(&mut world);
+        let edited = entities
+            .iter()
+            .filter(|&&e| world.get::<Wireframe>(e).is_some())
+            .count();
+        assert_eq!(edited, BULK_EDIT_BATCH_SIZE);
+        assert!(world.resource::<BulkComponentEdits>().is_busy());
+
+        world.run_system_once(process_bulk_component_edits).unwrap();
+        apply_pending_structural_changes(&mut world);
+        assert!(
+            entities
+                .iter()
+                .all(|&e| world.get::<Wireframe>(e).is_some())
+        );
+        let edits = world.resource::<BulkComponentEdits>();
+        assert!(!edits.is_busy());
+        assert_eq!(edits.status(), Some("Added Wireframe (257 entities)"));
+    }
+}
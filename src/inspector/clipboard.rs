@@ -0,0 +1,229 @@
+//! Copying displayed values out of the inspector.
+//!
+//! Values are copied by double-clicking them, or with the [`CopyButton`]s on field rows
+//! and component card headers.
+//! Bevy has no clipboard API, so copied text is kept in [`InspectorClipboard`]
+//! and announced with a [`ValueCopied`] event.
+//! Observe that event to forward copies to the system clipboard,
+//! e.g. with the `arboard` crate.
+
+use core::any::TypeId;
+use std::time::{Duration, Instant};
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::picking::events::{Click, Pointer};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::Tooltip;
+use crate::inspector::widgets::drag_value::DOUBLE_CLICK_THRESHOLD_MS;
+use crate::reflection_tools::get_reflected_component_ref;
+
+/// The text most recently copied in the inspector.
+#[derive(Resource, Default, Debug)]
+pub struct InspectorClipboard {
+    contents: Option<String>,
+}
+
+impl InspectorClipboard {
+    /// Returns the most recently copied text, if anything was copied yet.
+    pub fn contents(&self) -> Option<&str> {
+        self.contents.as_deref()
+    }
+}
+
+/// Event triggered whenever the inspector copies a value.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::ValueCopied;
+/// # let mut app = App::new();
+/// app.add_observer(|copied: On<ValueCopied>| {
+///     info!("Copied {}", copied.text);
+/// });
+/// ```
+#[derive(Event, Clone, Debug)]
+pub struct ValueCopied {
+    /// The copied text.
+    pub text: String,
+}
+
+/// Stores `text` in the [`InspectorClipboard`] and triggers [`ValueCopied`].
+pub fn copy_to_clipboard(world: &mut World, text: String) {
+    world.get_resource_or_init::<InspectorClipboard>().contents = Some(text.clone());
+    world.trigger(ValueCopied { text });
+}
+
+/// Copies the given text when the node is double-clicked.
+#[derive(Component, Clone, Debug)]
+pub struct CopyOnDoubleClick {
+    /// The text to copy.
+    pub text: String,
+    /// When the node was last clicked, for double-click detection.
+    last_click_time: Option<Instant>,
+}
+
+impl CopyOnDoubleClick {
+    /// Copies `text` on double-click.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            last_click_time: None,
+        }
+    }
+}
+
+/// Observer: copies the text of double-clicked [`CopyOnDoubleClick`] nodes.
+pub(crate) fn copy_on_double_click(
+    mut click: On<Pointer<Click>>,
+    mut copyable: Query<&mut CopyOnDoubleClick>,
+    mut commands: Commands,
+) {
+    let Ok(mut copyable) = copyable.get_mut(click.entity) else {
+        return;
+    };
+    click.propagate(false);
+
+    let now = Instant::now();
+    let threshold = Duration::from_millis(DOUBLE_CLICK_THRESHOLD_MS);
+    let is_double_click = copyable
+        .last_click_time
+        .is_some_and(|last| now.duration_since(last) < threshold);
+    if is_double_click {
+        copyable.last_click_time = None;
+        let text = copyable.text.clone();
+        commands.queue(move |world: &mut World| copy_to_clipboard(world, text));
+    } else {
+        copyable.last_click_time = Some(now);
+    }
+}
+
+/// Formats `value` as pretty-printed RON, without its type path,
+/// in the form accepted by [`validate_component_ron`](crate::inspector::validate_component_ron).
+///
+/// Returns `None` if the value's type cannot be serialized.
+pub fn reflect_to_ron(value: &dyn PartialReflect, registry: &TypeRegistry) -> Option<String> {
+    let serializer = TypedReflectSerializer::new(value, registry);
+    ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default()).ok()
+}
+
+/// A button copying a value when clicked.
+#[derive(Component, Clone, Debug)]
+pub enum CopyButton {
+    /// Copies the given text, e.g. a field's formatted value.
+    Text(String),
+    /// Copies a component's current value as RON, see [`reflect_to_ron`].
+    ComponentRon {
+        /// The entity the component belongs to.
+        entity: Entity,
+        /// The type of the component.
+        component_type_id: TypeId,
+    },
+}
+
+/// Observer: copies the value of a clicked [`CopyButton`].
+fn on_copy_button_click(
+    activate: On<Activate>,
+    buttons: Query<&CopyButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let button = button.clone();
+    commands.queue(move |world: &mut World| {
+        let text = match button {
+            CopyButton::Text(text) => text,
+            CopyButton::ComponentRon {
+                entity,
+                component_type_id,
+            } => {
+                let Ok(component) = get_reflected_component_ref(world, entity, component_type_id)
+                else {
+                    return;
+                };
+                let registry = world.resource::<AppTypeRegistry>().read();
+                let Some(ron) = reflect_to_ron(component, &registry) else {
+                    warn!("Failed to copy component of entity {} as RON", entity);
+                    return;
+                };
+                ron
+            }
+        };
+        copy_to_clipboard(world, text);
+    });
+}
+
+/// Spawns a small button copying `copy` when clicked.
+pub(crate) fn spawn_copy_button(
+    parent: &mut ChildSpawner,
+    copy: CopyButton,
+    tooltip: &str,
+    strings: &InspectorStrings,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(3.0)),
+                ..default()
+            },
+            bevy::ui_widgets::Button,
+            Tooltip(tooltip.to_string()),
+            copy,
+            observe(on_copy_button_click),
+        ))
+        .with_child((
+            Text::new(strings.copy.clone()),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(Color::srgba(0.5, 0.5, 0.5, 1.0)),
+        ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::scene_validation::validate_component_ron;
+
+    #[test]
+    fn copying_stores_text_and_triggers_event() {
+        #[derive(Resource, Default)]
+        struct Copied(Vec<String>);
+
+        let mut world = World::new();
+        world.init_resource::<Copied>();
+        world.add_observer(|copied: On<ValueCopied>, mut log: ResMut<Copied>| {
+            log.0.push(copied.text.clone());
+        });
+
+        copy_to_clipboard(&mut world, "Srgba".to_string());
+
+        assert_eq!(
+            world.resource::<InspectorClipboard>().contents(),
+            Some("Srgba")
+        );
+        assert_eq!(world.resource::<Copied>().0, ["Srgba"]);
+    }
+
+    #[test]
+    fn components_are_copied_as_ron_that_validates() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Transform>();
+
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let ron = reflect_to_ron(&transform, &registry).unwrap();
+        assert!(ron.contains("translation"));
+
+        let type_path = core::any::type_name::<Transform>();
+        let parsed = validate_component_ron(&registry, type_path, &ron).unwrap();
+        assert_eq!(Transform::from_reflect(&*parsed), Some(transform));
+    }
+}
@@ -0,0 +1,97 @@
+//! Per-component overrides for how component cards are built.
+//!
+//! Some components are rarely interesting in full (e.g. [`GlobalTransform`] duplicates
+//! [`Transform`]), while others reflect into large, noisy values.
+//! [`ComponentDisplayOverrides`] lets users tune how much of each component type is shown,
+//! and how edits to it are written.
+
+use core::any::TypeId;
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::component_inspection::ComponentDetailLevel;
+
+/// How a component card presents its fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComponentCardDisplay {
+    /// Every field is shown.
+    #[default]
+    Expanded,
+    /// Only the card header is shown until the card is expanded by clicking it.
+    Collapsed,
+    /// The whole value is shown on a single line.
+    Summary,
+}
+
+/// Inspection settings for a single component type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ComponentDisplaySettings {
+    /// How much information is gathered for the component.
+    ///
+    /// With [`ComponentDetailLevel::Names`], the component's value is never read,
+    /// and its card only shows the name and size.
+    pub detail_level: ComponentDetailLevel,
+    /// How the card presents the component's fields.
+    pub display: ComponentCardDisplay,
+    /// Write dragged values only when the drag ends, instead of on every pointer move.
+    ///
+    /// Useful for components whose changes are expensive to react to,
+    /// e.g. ones that trigger a mesh rebuild.
+    pub commit_on_release: bool,
+}
+
+/// Per-component-type overrides of [`ComponentDisplaySettings`],
+/// applied when building component cards.
+///
+/// Component types without an override use [`ComponentDisplaySettings::default`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use feathers_inspector::inspector::component_settings::{
+///     ComponentCardDisplay, ComponentDisplayOverrides, ComponentDisplaySettings,
+/// };
+///
+/// let mut overrides = ComponentDisplayOverrides::default();
+/// overrides
+///     .set::<GlobalTransform>(ComponentDisplaySettings {
+///         display: ComponentCardDisplay::Collapsed,
+///         ..default()
+///     })
+///     .set::<Mesh3d>(ComponentDisplaySettings {
+///         display: ComponentCardDisplay::Summary,
+///         ..default()
+///     });
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ComponentDisplayOverrides {
+    overrides: HashMap<TypeId, ComponentDisplaySettings>,
+}
+
+impl ComponentDisplayOverrides {
+    /// Overrides the settings for component type `C`.
+    pub fn set<C: Component>(&mut self, settings: ComponentDisplaySettings) -> &mut Self {
+        self.set_by_type_id(TypeId::of::<C>(), settings)
+    }
+
+    /// Overrides the settings for the component type with the given [`TypeId`].
+    pub fn set_by_type_id(
+        &mut self,
+        type_id: TypeId,
+        settings: ComponentDisplaySettings,
+    ) -> &mut Self {
+        self.overrides.insert(type_id, settings);
+        self
+    }
+
+    /// Removes the override for component type `C`, restoring the default settings.
+    pub fn remove<C: Component>(&mut self) -> &mut Self {
+        self.overrides.remove(&TypeId::of::<C>());
+        self
+    }
+
+    /// Returns the settings to use for the component type with the given [`TypeId`].
+    pub fn get(&self, type_id: TypeId) -> ComponentDisplaySettings {
+        self.overrides.get(&type_id).copied().unwrap_or_default()
+    }
+}
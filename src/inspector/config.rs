@@ -1,8 +1,14 @@
 //! Configuration constants for the inspector UI.
 
+use core::time::Duration;
+use std::path::PathBuf;
+
 use bevy::prelude::*;
 use bevy::ui::Val;
 
+use crate::inspector::despawn::DespawnProtection;
+use crate::inspector::state::EntityListColumn;
+
 /// Configuration for inspector UI layout and styling.
 #[derive(Resource, Clone)]
 pub struct InspectorConfig {
@@ -13,6 +19,19 @@ pub struct InspectorConfig {
     pub title_bar_height: Val,
     /// Height of the tab bar.
     pub tab_bar_height: Val,
+    /// Width of the scrollbars, in logical pixels.
+    pub scrollbar_width: f32,
+    /// Minimum height of entity list rows and component field rows, in logical pixels.
+    pub row_min_height: f32,
+    /// Minimum height of the draggable number fields, in logical pixels.
+    pub drag_value_min_height: f32,
+    /// Scale applied to the whole inspector UI on top of the window's DPI scale factor.
+    ///
+    /// All sizes are in logical pixels, which are converted using the inspector window's
+    /// scale factor as reported by the OS (and updated when the window moves between monitors).
+    /// Use this to make the inspector larger or smaller than that, e.g. `1.5` on a 4K display
+    /// whose OS scale factor is `1.0`.
+    pub ui_scale: f32,
 
     // Spacing
     /// Padding inside panels.
@@ -29,6 +48,13 @@ pub struct InspectorConfig {
     pub body_font_size: f32,
     /// Font size for small/secondary text.
     pub small_font_size: f32,
+    /// Font of the inspector's text, or `None` for Bevy's default font.
+    pub font: Option<Handle<Font>>,
+    /// Font of field values such as numbers, or `None` to use [`font`](Self::font).
+    ///
+    /// A monospace font keeps digits aligned across rows, e.g.
+    /// `asset_server.load("fonts/FiraMono-Medium.ttf")`.
+    pub value_font: Option<Handle<Font>>,
 
     // Colors (for non-themed elements)
     /// Border color.
@@ -37,6 +63,90 @@ pub struct InspectorConfig {
     pub muted_text_color: Color,
     /// Error text color.
     pub error_text_color: Color,
+    /// Background of entity list rows.
+    pub row_color: Color,
+    /// Background of every other entity list row (zebra striping).
+    pub row_stripe_color: Color,
+    /// Background of the entity list row under the pointer.
+    pub row_hover_color: Color,
+    /// Background of the selected entity list row.
+    pub row_selected_color: Color,
+    /// Background the selected entity list row flashes with after the selection jumped to it.
+    pub row_flash_color: Color,
+    /// Color of the marker shown next to fields whose value differs from the type's default.
+    pub modified_marker_color: Color,
+    /// Background of fields whose value changed since the entity's baseline was captured.
+    pub baseline_changed_color: Color,
+    /// Background of small buttons next to fields, such as the tween button.
+    pub control_background_color: Color,
+    /// Background of small text inputs next to fields, such as the tween input.
+    pub input_background_color: Color,
+    /// Label color of small buttons next to fields.
+    pub action_text_color: Color,
+
+    // Entity list
+    /// The columns of the entity list rows, in order.
+    /// Columns are aligned across rows, with the name taking up the remaining width.
+    pub entity_list_columns: Vec<EntityListColumn>,
+
+    // Selection
+    /// When the selected entity is despawned, re-select the entity with the same
+    /// [`Name`] path (see [`entity_name_path`](crate::inspector::selection::entity_name_path)).
+    ///
+    /// Keeps the inspector locked onto logical objects, such as pooled entities
+    /// that are despawned and respawned with a new generation.
+    pub reacquire_selection_by_path: bool,
+    /// Follow mode: when set, the detail panel always shows the entity whose [`Name`]
+    /// best matches this fuzzy pattern (e.g. `"player"`),
+    /// switching over automatically if that entity is replaced.
+    ///
+    /// See [`fuzzy_match_score`](crate::fuzzy_name_mapping::fuzzy_match_score) for the matching rules.
+    pub follow_name_pattern: Option<String>,
+    /// Select the newest entity spawned each frame, to watch the components of things
+    /// the game spawns at runtime. Toggled from the title bar.
+    ///
+    /// Only entities the entity list would show are selected.
+    pub auto_select_spawned: bool,
+    /// Show an arrow at the edge of the game viewport pointing toward the selected entity
+    /// while it is outside the main camera's view.
+    pub show_offscreen_indicator: bool,
+
+    // Refresh
+    /// How often the entity list is refreshed to pick up changes to the world,
+    /// or `None` to only refresh it when the inspector's filters change
+    /// or its refresh button is clicked.
+    pub entity_list_refresh_interval: Option<Duration>,
+
+    // Editing
+    /// Increment that numeric fields and vector axes snap to while Alt is held during a drag,
+    /// e.g. `0.5` to place things on a half-unit grid, or `None` to disable snapping.
+    ///
+    /// Quaternion components and matrix values never snap,
+    /// since rounding them separately doesn't give meaningful rotations.
+    pub drag_snap_increment: Option<f64>,
+    /// Increment in degrees that the Euler angles of rotations snap to
+    /// while Alt is held during a drag, e.g. `15.0`, or `None` to disable snapping.
+    pub drag_angle_snap_increment: Option<f64>,
+    /// Component types whose entities the inspector refuses to despawn,
+    /// or only despawns after a second confirmation.
+    pub despawn_protection: DespawnProtection,
+    /// How long entities despawned from the inspector are kept disabled in the
+    /// [`Graveyard`](crate::inspector::Graveyard), from where they can be restored,
+    /// or `None` to despawn them right away.
+    pub despawn_grace_period: Option<Duration>,
+
+    // Session
+    /// File the Notes tab is loaded from at startup and saved to after every edit,
+    /// or `None` to keep the notes in memory only.
+    pub notes_file: Option<PathBuf>,
+    /// File the [`InspectorProfiles`](crate::inspector::InspectorProfiles) are loaded from
+    /// at startup and saved to whenever a profile is saved, or `None` to keep them in memory only.
+    ///
+    /// Keep it inside the project, e.g. `inspector_profiles.ron`, and commit it
+    /// to share the profiles with the team.
+    pub profiles_file: Option<PathBuf>,
+    /// Name of the profile applied at startup, if any.
+    pub default_profile: Option<String>,
 }
 
 impl Default for InspectorConfig {
@@ -46,6 +156,10 @@ impl Default for InspectorConfig {
             left_panel_width: Val::Percent(30.0),
             title_bar_height: Val::Px(40.0),
             tab_bar_height: Val::Px(36.0),
+            scrollbar_width: 8.0,
+            row_min_height: 0.0,
+            drag_value_min_height: 0.0,
+            ui_scale: 1.0,
 
             // Spacing
             panel_padding: UiRect::all(Val::Px(8.0)),
@@ -56,11 +170,68 @@ impl Default for InspectorConfig {
             title_font_size: 16.0,
             body_font_size: 13.0,
             small_font_size: 11.0,
+            font: None,
+            value_font: None,
 
             // Colors
             border_color: Color::srgba(0.3, 0.3, 0.3, 1.0),
             muted_text_color: Color::srgba(0.6, 0.6, 0.6, 1.0),
             error_text_color: Color::srgba(0.8, 0.3, 0.3, 1.0),
+            row_color: Color::NONE,
+            row_stripe_color: Color::srgba(1.0, 1.0, 1.0, 0.03),
+            row_hover_color: Color::srgba(1.0, 1.0, 1.0, 0.08),
+            row_selected_color: Color::srgba(0.25, 0.45, 0.75, 0.6),
+            row_flash_color: Color::srgba(0.95, 0.85, 0.4, 0.8),
+            modified_marker_color: Color::srgba(0.95, 0.65, 0.25, 1.0),
+            baseline_changed_color: Color::srgba(0.95, 0.65, 0.25, 0.2),
+            control_background_color: Color::srgba(0.15, 0.15, 0.15, 1.0),
+            input_background_color: Color::srgba(0.1, 0.1, 0.1, 1.0),
+            action_text_color: Color::srgba(0.9, 0.9, 0.6, 1.0),
+
+            // Entity list
+            entity_list_columns: vec![
+                EntityListColumn::Name,
+                EntityListColumn::EntityId,
+                EntityListColumn::ComponentCount,
+                EntityListColumn::Memory,
+            ],
+
+            // Selection
+            reacquire_selection_by_path: false,
+            follow_name_pattern: None,
+            auto_select_spawned: false,
+            show_offscreen_indicator: false,
+
+            // Refresh
+            entity_list_refresh_interval: Some(Duration::from_millis(500)),
+
+            // Editing
+            drag_snap_increment: None,
+            drag_angle_snap_increment: None,
+            despawn_protection: DespawnProtection::default(),
+            despawn_grace_period: None,
+
+            // Session
+            notes_file: None,
+            profiles_file: None,
+            default_profile: None,
+        }
+    }
+}
+
+impl InspectorConfig {
+    /// Enlarges the scrollbars, rows and number fields so they can be hit with a finger or pen,
+    /// keeping the rest of the configuration.
+    ///
+    /// Unlike [`ui_scale`](Self::ui_scale), text and other non-interactive elements
+    /// keep their size.
+    pub fn touch_mode(self) -> Self {
+        Self {
+            scrollbar_width: 20.0,
+            row_min_height: 32.0,
+            drag_value_min_height: 28.0,
+            item_gap: Val::Px(6.0),
+            ..self
         }
     }
 }
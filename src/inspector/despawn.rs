@@ -0,0 +1,470 @@
+//! Despawning entities from the inspector, with protection for critical entities.
+//!
+//! Entities holding one of the components listed in [`DespawnProtection`]
+//! (by default windows and cameras) are either never despawned by the inspector,
+//! or only after a second, explicit confirmation, depending on [`DespawnProtectionMode`].
+//! This prevents accidentally closing the app's own window while clicking around.
+//!
+//! With [`InspectorConfig::despawn_grace_period`] set, despawned entities are soft-deleted first:
+//! their subtree is [`Disabled`] and kept in the [`Graveyard`], from where it can be restored
+//! until the grace period runs out.
+
+use core::any::{TypeId, type_name};
+use core::time::Duration;
+
+use bevy::ecs::entity_disabling::Disabled;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use thiserror::Error;
+
+use crate::inspector::config::InspectorConfig;
+
+/// How the inspector treats despawn requests for protected entities.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DespawnProtectionMode {
+    /// Protected entities can never be despawned from the inspector.
+    Refuse,
+    /// Protected entities are only despawned after a second confirmation.
+    #[default]
+    Confirm,
+}
+
+/// The component types whose entities are protected from being despawned by the inspector.
+#[derive(Clone, Debug)]
+pub struct DespawnProtection {
+    /// How despawn requests for protected entities are handled.
+    pub mode: DespawnProtectionMode,
+    /// Type ids and type names of the protected component types.
+    components: Vec<(TypeId, &'static str)>,
+}
+
+impl Default for DespawnProtection {
+    fn default() -> Self {
+        Self {
+            mode: DespawnProtectionMode::default(),
+            components: Vec::new(),
+        }
+        .protect::<PrimaryWindow>()
+        .protect::<Window>()
+        .protect::<Camera>()
+    }
+}
+
+impl DespawnProtection {
+    /// Protects entities with a `C` component.
+    pub fn protect<C: Component>(mut self) -> Self {
+        let type_id = TypeId::of::<C>();
+        if !self.components.iter().any(|(id, _)| *id == type_id) {
+            self.components.push((type_id, type_name::<C>()));
+        }
+        self
+    }
+
+    /// Stops protecting entities with a `C` component.
+    pub fn unprotect<C: Component>(mut self) -> Self {
+        let type_id = TypeId::of::<C>();
+        self.components.retain(|(id, _)| *id != type_id);
+        self
+    }
+
+    /// Returns the short name of the first protected component on `entity`, if any.
+    pub fn protecting_component(&self, entity: EntityRef) -> Option<String> {
+        self.components
+            .iter()
+            .find(|(type_id, _)| entity.contains_type_id(*type_id))
+            .map(|(_, name)| ShortName(name).to_string())
+    }
+}
+
+/// Reasons the inspector did not despawn an entity.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum DespawnError {
+    #[error("Entity not found: {0}")]
+    EntityNotFound(Entity),
+    #[error("{entity} is protected by {component} and can't be despawned from the inspector")]
+    Protected { entity: Entity, component: String },
+    #[error("{entity} is protected by {component}, confirm to despawn it anyway")]
+    ConfirmationRequired { entity: Entity, component: String },
+}
+
+/// Despawns `entity` (and its descendants), unless it or one of its descendants is protected
+/// by the [`DespawnProtection`] in [`InspectorConfig`].
+///
+/// If [`InspectorConfig::despawn_grace_period`] is set, the entity is moved to the [`Graveyard`]
+/// instead, and only despawned once the grace period runs out.
+///
+/// `confirmed` is the second confirmation required by [`DespawnProtectionMode::Confirm`].
+pub fn despawn_entity(
+    world: &mut World,
+    entity: Entity,
+    confirmed: bool,
+) -> Result<(), DespawnError> {
+    if !world.entities().contains(entity) {
+        return Err(DespawnError::EntityNotFound(entity));
+    }
+    // Soft-deleted entities are gone as far as the inspector is concerned, including roots
+    // that were already disabled, and so aren't among the entities disabled by burying them
+    if world
+        .get_resource::<Graveyard>()
+        .is_some_and(|graveyard| graveyard.contains(entity) || graveyard.is_buried(entity))
+    {
+        return Err(DespawnError::EntityNotFound(entity));
+    }
+
+    let protection = world
+        .get_resource::<InspectorConfig>()
+        .map(|config| config.despawn_protection.clone())
+        .unwrap_or_default();
+    // Despawning an entity despawns its descendants, so they are protected along with it
+    if let Some((entity, component)) = protected_in_subtree(world, entity, &protection) {
+        match protection.mode {
+            DespawnProtectionMode::Refuse => {
+                return Err(DespawnError::Protected { entity, component });
+            }
+            DespawnProtectionMode::Confirm if !confirmed => {
+                return Err(DespawnError::ConfirmationRequired { entity, component });
+            }
+            DespawnProtectionMode::Confirm => {}
+        }
+    }
+
+    let soft_delete = world
+        .get_resource::<InspectorConfig>()
+        .is_some_and(|config| config.despawn_grace_period.is_some());
+    if soft_delete && world.contains_resource::<Graveyard>() {
+        bury_entity(world, entity);
+    } else {
+        world.despawn(entity);
+    }
+    Ok(())
+}
+
+/// Returns the first entity of the subtree rooted at `entity` that is protected by `protection`,
+/// with the short name of its protected component.
+fn protected_in_subtree(
+    world: &World,
+    entity: Entity,
+    protection: &DespawnProtection,
+) -> Option<(Entity, String)> {
+    let mut stack = vec![entity];
+    while let Some(next) = stack.pop() {
+        let Ok(entity_ref) = world.get_entity(next) else {
+            continue;
+        };
+        if let Some(component) = protection.protecting_component(entity_ref) {
+            return Some((next, component));
+        }
+        if let Some(children) = entity_ref.get::<Children>() {
+            stack.extend(children.iter());
+        }
+    }
+    None
+}
+
+/// An entity soft-deleted by the inspector, see [`Graveyard`].
+#[derive(Clone, Debug)]
+pub struct GraveyardEntry {
+    /// The root of the soft-deleted subtree.
+    pub entity: Entity,
+    /// The name of the entity when it was soft-deleted.
+    pub name: String,
+    /// When the entity was soft-deleted, in real time since startup.
+    pub buried_at: Duration,
+    /// The entities of the subtree that were disabled by soft-deleting it,
+    /// leaving entities that were already disabled disabled on restore.
+    disabled: Vec<Entity>,
+}
+
+/// Entities despawned from the inspector that can still be restored,
+/// see [`InspectorConfig::despawn_grace_period`].
+///
+/// Their subtrees are kept [`Disabled`], so they are hidden from the app's queries
+/// and the entity list, and despawned for good once the grace period runs out.
+#[derive(Resource, Default)]
+pub struct Graveyard {
+    entries: Vec<GraveyardEntry>,
+}
+
+impl Graveyard {
+    /// Returns the soft-deleted entities, oldest first.
+    pub fn entries(&self) -> &[GraveyardEntry] {
+        &self.entries
+    }
+
+    /// Returns true if `entity` is the root of a soft-deleted subtree.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entries.iter().any(|entry| entry.entity == entity)
+    }
+
+    /// Returns true if `entity` was disabled as part of a soft-deleted subtree.
+    pub fn is_buried(&self, entity: Entity) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.disabled.contains(&entity))
+    }
+}
+
+/// Returns the real time since startup, or zero if there is no [`Time<Real>`].
+fn real_time_elapsed(world: &World) -> Duration {
+    world
+        .get_resource::<Time<Real>>()
+        .map_or(Duration::ZERO, |time| time.elapsed())
+}
+
+/// Disables `entity` and its descendants and records them in the [`Graveyard`].
+fn bury_entity(world: &mut World, entity: Entity) {
+    let name = world
+        .get::<Name>(entity)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("Entity {:?}", entity));
+    let mut disabled = Vec::new();
+    let mut stack = vec![entity];
+    while let Some(next) = stack.pop() {
+        let Ok(entity_ref) = world.get_entity(next) else {
+            continue;
+        };
+        if let Some(children) = entity_ref.get::<Children>() {
+            stack.extend(children.iter());
+        }
+        if !entity_ref.contains::<Disabled>() {
+            disabled.push(next);
+        }
+    }
+    for &entity in &disabled {
+        world.entity_mut(entity).insert(Disabled);
+    }
+
+    let buried_at = real_time_elapsed(world);
+    world
+        .resource_mut::<Graveyard>()
+        .entries
+        .push(GraveyardEntry {
+            entity,
+            name,
+            buried_at,
+            disabled,
+        });
+}
+
+/// Restores the soft-deleted `entity` and its descendants from the [`Graveyard`].
+/// Returns false if `entity` is not in the graveyard.
+pub fn restore_entity(world: &mut World, entity: Entity) -> bool {
+    let mut graveyard = world.resource_mut::<Graveyard>();
+    let Some(index) = graveyard
+        .entries
+        .iter()
+        .position(|entry| entry.entity == entity)
+    else {
+        return false;
+    };
+    let entry = graveyard.entries.remove(index);
+    for disabled in entry.disabled {
+        if let Ok(mut entity_mut) = world.get_entity_mut(disabled) {
+            entity_mut.remove::<Disabled>();
+        }
+    }
+    true
+}
+
+/// Despawns the soft-deleted `entity` and its descendants right away.
+/// Returns false if `entity` is not in the [`Graveyard`].
+pub fn purge_entity(world: &mut World, entity: Entity) -> bool {
+    let mut graveyard = world.resource_mut::<Graveyard>();
+    let Some(index) = graveyard
+        .entries
+        .iter()
+        .position(|entry| entry.entity == entity)
+    else {
+        return false;
+    };
+    graveyard.entries.remove(index);
+    if world.entities().contains(entity) {
+        world.despawn(entity);
+    }
+    true
+}
+
+/// Despawns the entities buried longer than `grace_period` before `now`.
+fn expire_buried(world: &mut World, now: Duration, grace_period: Duration) {
+    let expired: Vec<Entity> = world
+        .resource::<Graveyard>()
+        .entries
+        .iter()
+        .filter(|entry| now.saturating_sub(entry.buried_at) >= grace_period)
+        .map(|entry| entry.entity)
+        .collect();
+    for entity in expired {
+        purge_entity(world, entity);
+    }
+}
+
+/// Exclusive system that despawns the entities whose grace period in the [`Graveyard`] ran out.
+///
+/// Disabling soft-deletion also empties the graveyard.
+pub(crate) fn expire_graveyard(world: &mut World) {
+    if world.resource::<Graveyard>().entries.is_empty() {
+        return;
+    }
+    let grace_period = world
+        .resource::<InspectorConfig>()
+        .despawn_grace_period
+        .unwrap_or(Duration::ZERO);
+    let now = real_time_elapsed(world);
+    expire_buried(world, now, grace_period);
+}
+
+/// Outcome of [`despawn_entities`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BulkDespawnReport {
+    /// Number of entities despawned, not counting descendants despawned along with them.
+    pub despawned: usize,
+    /// Entities that were not despawned, because they are protected.
+    pub skipped: Vec<DespawnError>,
+}
+
+/// Despawns every entity in `entities` (and their descendants) with [`despawn_entity`].
+///
+/// Protected entities, and those with protected descendants, are always skipped,
+/// since confirming a bulk despawn should not implicitly confirm despawning them.
+/// Entities that no longer exist, e.g. because they were descendants of an earlier entity,
+/// are ignored.
+pub fn despawn_entities(world: &mut World, entities: &[Entity]) -> BulkDespawnReport {
+    let mut report = BulkDespawnReport::default();
+    for &entity in entities {
+        match despawn_entity(world, entity, false) {
+            Ok(()) => report.despawned += 1,
+            Err(DespawnError::EntityNotFound(_)) => {}
+            Err(error) => report.skipped.push(error),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_entities_need_confirmation() {
+        let mut world = World::new();
+        world.insert_resource(InspectorConfig::default());
+        let camera = world.spawn(Camera::default()).id();
+        let other = world.spawn_empty().id();
+
+        assert_eq!(
+            despawn_entity(&mut world, camera, false),
+            Err(DespawnError::ConfirmationRequired {
+                entity: camera,
+                component: "Camera".to_string()
+            })
+        );
+        assert!(world.entities().contains(camera));
+        assert_eq!(despawn_entity(&mut world, camera, true), Ok(()));
+        assert!(!world.entities().contains(camera));
+
+        assert_eq!(despawn_entity(&mut world, other, false), Ok(()));
+        assert_eq!(
+            despawn_entity(&mut world, other, false),
+            Err(DespawnError::EntityNotFound(other))
+        );
+    }
+
+    #[test]
+    fn protected_descendants_protect_their_ancestors() {
+        let mut world = World::new();
+        world.insert_resource(InspectorConfig::default());
+        let player = world.spawn_empty().id();
+        let camera = world.spawn((Camera::default(), ChildOf(player))).id();
+
+        assert_eq!(
+            despawn_entity(&mut world, player, false),
+            Err(DespawnError::ConfirmationRequired {
+                entity: camera,
+                component: "Camera".to_string()
+            })
+        );
+        assert!(world.entities().contains(player));
+        assert!(world.entities().contains(camera));
+        assert_eq!(despawn_entity(&mut world, player, true), Ok(()));
+        assert!(!world.entities().contains(camera));
+    }
+
+    #[test]
+    fn bulk_despawn_skips_protected_entities() {
+        let mut world = World::new();
+        world.insert_resource(InspectorConfig::default());
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(parent)).id();
+        let camera = world.spawn(Camera::default()).id();
+        let rig = world.spawn_empty().id();
+        let rig_camera = world.spawn((Camera::default(), ChildOf(rig))).id();
+
+        let report = despawn_entities(&mut world, &[parent, child, camera, rig]);
+        assert_eq!(report.despawned, 1);
+        assert_eq!(report.skipped.len(), 2);
+        assert!(!world.entities().contains(child));
+        assert!(world.entities().contains(camera));
+        assert!(world.entities().contains(rig));
+        assert!(world.entities().contains(rig_camera));
+    }
+
+    #[test]
+    fn soft_deleted_entities_can_be_restored_until_they_expire() {
+        let mut world = World::new();
+        world.insert_resource(InspectorConfig {
+            despawn_grace_period: Some(Duration::from_secs(5)),
+            ..default()
+        });
+        world.init_resource::<Graveyard>();
+        let parent = world.spawn(Name::new("Crate")).id();
+        let child = world.spawn(ChildOf(parent)).id();
+        let hidden = world.spawn((ChildOf(parent), Disabled)).id();
+
+        assert_eq!(despawn_entity(&mut world, parent, false), Ok(()));
+        assert!(world.resource::<Graveyard>().contains(parent));
+        assert_eq!(world.resource::<Graveyard>().entries()[0].name, "Crate");
+        assert!(world.get::<Disabled>(child).is_some());
+        assert_eq!(
+            despawn_entity(&mut world, child, false),
+            Err(DespawnError::EntityNotFound(child))
+        );
+
+        assert!(restore_entity(&mut world, parent));
+        assert!(world.get::<Disabled>(parent).is_none());
+        assert!(world.get::<Disabled>(child).is_none());
+        assert!(world.get::<Disabled>(hidden).is_some());
+        assert!(!restore_entity(&mut world, parent));
+
+        despawn_entity(&mut world, parent, false).unwrap();
+        expire_buried(&mut world, Duration::from_secs(4), Duration::from_secs(5));
+        assert!(world.entities().contains(parent));
+        expire_buried(&mut world, Duration::from_secs(5), Duration::from_secs(5));
+        assert!(!world.entities().contains(parent));
+        assert!(!world.entities().contains(child));
+        assert!(world.resource::<Graveyard>().entries().is_empty());
+
+        // Soft-deleting a disabled entity twice records it once
+        let disabled = world.spawn(Disabled).id();
+        despawn_entity(&mut world, disabled, false).unwrap();
+        assert_eq!(
+            despawn_entity(&mut world, disabled, false),
+            Err(DespawnError::EntityNotFound(disabled))
+        );
+        assert_eq!(world.resource::<Graveyard>().entries().len(), 1);
+    }
+
+    #[test]
+    fn refuse_mode_never_despawns_protected_entities() {
+        let mut world = World::new();
+        let mut config = InspectorConfig::default();
+        config.despawn_protection.mode = DespawnProtectionMode::Refuse;
+        world.insert_resource(config);
+        let window = world.spawn(Window::default()).id();
+
+        assert!(matches!(
+            despawn_entity(&mut world, window, true),
+            Err(DespawnError::Protected { .. })
+        ));
+        assert!(world.entities().contains(window));
+    }
+}
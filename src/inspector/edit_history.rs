@@ -0,0 +1,386 @@
+//! Undo and redo of field edits.
+//!
+//! Every edit applied by the inspector's write-back path is recorded in [`EditHistory`]
+//! together with the value it replaced. Pressing Ctrl+Z in the inspector window reverts
+//! the latest edit and Ctrl+Shift+Z reapplies it, both by queuing the recorded value
+//! into [`PendingValueChanges`] like any other edit.
+//!
+//! Consecutive edits of the same field made in quick succession, such as the writes of a
+//! single drag, are merged into one step, as are the edits of a field on every entity
+//! of a multi-entity selection and the edits of the axes of a linked vector.
+
+use std::time::{Duration, Instant};
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use crate::inspector::state::{InspectorCache, InspectorState, InspectorWindowState};
+use crate::inspector::widgets::drag_value::clone_reflected;
+use crate::inspector::widgets::{
+    DragValueDragState, FieldPath, FieldValue, InspectorFieldEdited, PendingValueChange,
+    PendingValueChanges, apply_pending_value_changes,
+};
+
+/// An edit of a single field, recorded in [`EditHistory`].
+#[derive(Debug)]
+pub struct EditRecord {
+    /// The edited field.
+    pub field_path: FieldPath,
+    /// The value of the field before the edit.
+    pub old_value: Box<dyn PartialReflect>,
+    /// The value of the field after the edit.
+    pub new_value: Box<dyn PartialReflect>,
+}
+
+/// Whether `a` and `b` are the same field, possibly on different entities.
+fn same_field(a: &FieldPath, b: &FieldPath) -> bool {
+    a.component_type_id == b.component_type_id && a.path == b.path
+}
+
+/// Edits undone and redone together: the edits of one field on one or more entities,
+/// or of the axes of one linked vector.
+#[derive(Debug)]
+pub struct EditStep {
+    /// The edits, in the order they were first applied.
+    pub records: Vec<EditRecord>,
+    /// When the step was last extended, for merging.
+    /// `None` once it was undone or redone, so later edits start a new step.
+    recorded_at: Option<Instant>,
+    /// The linked vector whose axes this step edits, if any.
+    linked_vector: Option<FieldPath>,
+}
+
+impl EditStep {
+    /// Whether `field_path` is a field this step edits, on any entity,
+    /// or an axis of the linked vector `linked_vector` it edits.
+    fn edits_field(&self, field_path: &FieldPath, linked_vector: Option<&FieldPath>) -> bool {
+        let same_vector = match (&self.linked_vector, linked_vector) {
+            (Some(a), Some(b)) => same_field(a, b),
+            _ => false,
+        };
+        same_vector
+            || self
+                .records
+                .iter()
+                .any(|record| same_field(&record.field_path, field_path))
+    }
+}
+
+/// The edits that can be undone and redone, most recent last.
+#[derive(Resource, Debug)]
+pub struct EditHistory {
+    /// The maximum number of steps kept for undoing; the oldest are forgotten first.
+    pub capacity: usize,
+    /// Edits of the same field, on any entity, less than this apart are merged into one step.
+    pub merge_window: Duration,
+    undo: Vec<EditStep>,
+    redo: Vec<EditStep>,
+    /// Whether an undo or redo is being applied, so it isn't recorded as a new edit.
+    replaying: bool,
+    /// Whether the edit being replayed was applied.
+    replayed: bool,
+}
+
+impl Default for EditHistory {
+    fn default() -> Self {
+        Self {
+            capacity: 100,
+            merge_window: Duration::from_millis(500),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            replaying: false,
+            replayed: false,
+        }
+    }
+}
+
+impl EditHistory {
+    /// Records an edit of `field_path`, discarding the edits that could be redone.
+    pub fn record(
+        &mut self,
+        field_path: FieldPath,
+        old_value: Box<dyn PartialReflect>,
+        new_value: Box<dyn PartialReflect>,
+    ) {
+        self.record_axis(field_path, old_value, new_value, None);
+    }
+
+    /// Records an edit of `field_path`, an axis of `linked_vector` if its axes are linked,
+    /// so the edits of its other axes are merged into the same step.
+    fn record_axis(
+        &mut self,
+        field_path: FieldPath,
+        old_value: Box<dyn PartialReflect>,
+        new_value: Box<dyn PartialReflect>,
+        linked_vector: Option<FieldPath>,
+    ) {
+        self.redo.clear();
+        let now = Instant::now();
+        if let Some(step) = self.undo.last_mut()
+            && step.edits_field(&field_path, linked_vector.as_ref())
+            && step
+                .recorded_at
+                .is_some_and(|recorded_at| now.duration_since(recorded_at) < self.merge_window)
+        {
+            step.recorded_at = Some(now);
+            match step
+                .records
+                .iter_mut()
+                .find(|record| record.field_path == field_path)
+            {
+                // Keep the value from before the first edit, so the whole gesture is undone at once
+                Some(record) => record.new_value = new_value,
+                None => step.records.push(EditRecord {
+                    field_path,
+                    old_value,
+                    new_value,
+                }),
+            }
+            return;
+        }
+
+        self.undo.push(EditStep {
+            records: vec![EditRecord {
+                field_path,
+                old_value,
+                new_value,
+            }],
+            recorded_at: Some(now),
+            linked_vector,
+        });
+        if self.undo.len() > self.capacity {
+            let excess = self.undo.len() - self.capacity;
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// The steps that can be undone, most recent last.
+    pub fn undo_stack(&self) -> &[EditStep] {
+        &self.undo
+    }
+
+    /// Forgets all recorded edits.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+/// Observer recording every applied field edit in [`EditHistory`].
+pub(crate) fn record_field_edits(
+    edit: On<InspectorFieldEdited>,
+    mut history: ResMut<EditHistory>,
+    state: Option<Res<InspectorState>>,
+) {
+    if history.replaying {
+        history.replayed = true;
+        return;
+    }
+    let field_path = FieldPath {
+        owner: edit.owner,
+        component_type_id: edit.component_type_id,
+        path: edit.path.clone(),
+    };
+    let linked_vector = state.and_then(|state| {
+        let (_, vector_path) = field_path.path.split_last()?;
+        let vector = FieldPath {
+            path: vector_path.to_vec(),
+            ..field_path.clone()
+        };
+        state.linked_vectors.contains(&vector).then_some(vector)
+    });
+    history.record_axis(
+        field_path,
+        clone_reflected(edit.old_value.as_ref()),
+        clone_reflected(edit.new_value.as_ref()),
+        linked_vector,
+    );
+}
+
+/// Writes the values of `step` chosen by `undo` back to their fields.
+/// Returns whether any write succeeded.
+fn replay(world: &mut World, step: &EditStep, undo: bool) -> bool {
+    let mut pending = world.resource_mut::<PendingValueChanges>();
+    for record in &step.records {
+        let value = if undo {
+            &record.old_value
+        } else {
+            &record.new_value
+        };
+        pending.push(PendingValueChange {
+            field_path: record.field_path.clone(),
+            value: FieldValue::Restore(clone_reflected(value.as_ref())),
+        });
+    }
+
+    let mut history = world.resource_mut::<EditHistory>();
+    history.replaying = true;
+    history.replayed = false;
+    apply_pending_value_changes(world);
+    let mut history = world.resource_mut::<EditHistory>();
+    history.replaying = false;
+    let replayed = history.replayed;
+
+    if let Some(mut cache) = world.get_resource_mut::<InspectorCache>() {
+        cache.detail_stale = true;
+    }
+    replayed
+}
+
+/// Reverts the most recent step in [`EditHistory`]. Returns whether a step was reverted.
+///
+/// Edits still waiting in [`PendingValueChanges`] are applied first, so they can be undone too.
+/// A step that can't be reverted at all, e.g. because its entity was despawned, is dropped.
+pub fn undo_edit(world: &mut World) -> bool {
+    apply_pending_value_changes(world);
+    let Some(mut step) = world.resource_mut::<EditHistory>().undo.pop() else {
+        return false;
+    };
+    if !replay(world, &step, true) {
+        return false;
+    }
+    step.recorded_at = None;
+    world.resource_mut::<EditHistory>().redo.push(step);
+    true
+}
+
+/// Reapplies the most recently undone step in [`EditHistory`].
+/// Returns whether a step was reapplied.
+pub fn redo_edit(world: &mut World) -> bool {
+    apply_pending_value_changes(world);
+    let Some(mut step) = world.resource_mut::<EditHistory>().redo.pop() else {
+        return false;
+    };
+    if !replay(world, &step, false) {
+        return false;
+    }
+    step.recorded_at = None;
+    world.resource_mut::<EditHistory>().undo.push(step);
+    true
+}
+
+/// Undoes edits on Ctrl+Z and redoes them on Ctrl+Shift+Z in the inspector window.
+///
+/// Ignored while typing into a text field, including a drag value in text edit mode.
+pub(crate) fn handle_edit_history_shortcuts(
+    mut keyboard: MessageReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    window_state: Res<InspectorWindowState>,
+    input_focus: Res<InputFocus>,
+    drag_states: Query<&DragValueDragState>,
+    mut commands: Commands,
+) {
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed
+            || input.key_code != KeyCode::KeyZ
+            || Some(input.window) != window_state.window_entity
+            || !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        {
+            continue;
+        }
+        // Drag values keep the focus after a click, but only take text while editing
+        if let Some(focused) = input_focus.get()
+            && drag_states.get(focused).map_or(true, |state| state.editing)
+        {
+            continue;
+        }
+
+        if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            commands.queue(|world: &mut World| {
+                redo_edit(world);
+            });
+        } else {
+            commands.queue(|world: &mut World| {
+                undo_edit(world);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::widgets::FieldPathSegment;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Inventory {
+        gold: u32,
+        items: Vec<u32>,
+    }
+
+    fn set(world: &mut World, field_path: &FieldPath, value: impl PartialReflect) {
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push(PendingValueChange {
+                field_path: field_path.clone(),
+                value: FieldValue::Reflected(Box::new(value)),
+            });
+        apply_pending_value_changes(world);
+    }
+
+    #[test]
+    fn edits_are_undone_and_redone() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Inventory>();
+        world.init_resource::<PendingValueChanges>();
+        world.init_resource::<EditHistory>();
+        world.add_observer(record_field_edits);
+        let entity = world.spawn(Inventory::default()).id();
+        let path = |field: &str| {
+            FieldPath::new::<Inventory>(entity, [FieldPathSegment::Named(field.to_string())])
+        };
+
+        // Rapid edits of one field are a single step
+        set(&mut world, &path("gold"), 5_u32);
+        set(&mut world, &path("gold"), 7_u32);
+        set(&mut world, &path("items"), vec![1_u32, 2]);
+        assert_eq!(world.resource::<EditHistory>().undo_stack().len(), 2);
+
+        assert!(undo_edit(&mut world));
+        assert!(world.get::<Inventory>(entity).unwrap().items.is_empty());
+        assert!(undo_edit(&mut world));
+        assert_eq!(world.get::<Inventory>(entity).unwrap().gold, 0);
+        assert!(!undo_edit(&mut world));
+
+        assert!(redo_edit(&mut world));
+        assert_eq!(world.get::<Inventory>(entity).unwrap().gold, 7);
+        // A new edit discards what could be redone
+        set(&mut world, &path("gold"), 1_u32);
+        assert!(!world.resource::<EditHistory>().can_redo());
+        assert_eq!(world.resource::<EditHistory>().undo_stack().len(), 2);
+
+        // Edits of a field on every selected entity are undone together
+        let other = world.spawn(Inventory::default()).id();
+        let items = PendingValueChange {
+            field_path: path("items"),
+            value: FieldValue::Reflected(Box::new(vec![3_u32])),
+        };
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push_batched(items, &[entity, other]);
+        apply_pending_value_changes(&mut world);
+        assert_eq!(world.resource::<EditHistory>().undo_stack().len(), 3);
+        assert_eq!(world.get::<Inventory>(other).unwrap().items, [3]);
+        assert!(undo_edit(&mut world));
+        for inventory in [entity, other] {
+            assert!(world.get::<Inventory>(inventory).unwrap().items.is_empty());
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Programmatic editing of component fields.
+//!
+//! Edits made through [`InspectorEdits`] are queued into [`PendingValueChanges`]
+//! and applied by the same write-back system as edits made in the inspector UI,
+//! so they are validated the same way and trigger [`InspectorFieldEdited`] on success.
+//!
+//! [`InspectorFieldEdited`]: crate::inspector::InspectorFieldEdited
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::inspector::widgets::{FieldPath, FieldValue, PendingValueChange, PendingValueChanges};
+
+/// System parameter for editing component fields through the inspector's write-back path.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::edits::InspectorEdits;
+/// # use feathers_inspector::inspector::{FieldPath, FieldPathSegment};
+/// fn move_to_origin(mut edits: InspectorEdits, query: Query<Entity, With<Transform>>) {
+///     for entity in &query {
+///         let path = FieldPath::new::<Transform>(
+///             entity,
+///             [FieldPathSegment::Named("translation".to_string())],
+///         );
+///         edits.set_field(path, Vec3::ZERO);
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(move_to_origin);
+/// ```
+#[derive(SystemParam)]
+pub struct InspectorEdits<'w> {
+    pending: ResMut<'w, PendingValueChanges>,
+}
+
+impl InspectorEdits<'_> {
+    /// Queues `value` to be written to the field at `field_path`.
+    ///
+    /// The value is applied with [`PartialReflect::try_apply`],
+    /// so it must have the same type (or a compatible dynamic representation) as the field.
+    /// Failed writes are logged and skipped.
+    pub fn set_field(&mut self, field_path: FieldPath, value: impl PartialReflect) {
+        self.pending.push(PendingValueChange {
+            field_path,
+            value: FieldValue::Reflected(Box::new(value)),
+        });
+    }
+
+    /// Queues a numeric `value` to be written to the field at `field_path`,
+    /// converting it to the field's numeric type in the same way as the drag value widget.
+    pub fn set_numeric_field(&mut self, field_path: FieldPath, value: f64) {
+        self.pending.push(PendingValueChange {
+            field_path,
+            value: FieldValue::Numeric(value),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::inspector::widgets::{
+        FieldPathSegment, InspectorFieldEdited, apply_pending_value_changes,
+    };
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+    }
+
+    #[derive(Resource, Default)]
+    struct EditCount(usize);
+
+    #[test]
+    fn queued_edits_are_applied_and_reported() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        world.init_resource::<PendingValueChanges>();
+        world.init_resource::<EditCount>();
+        world.add_observer(
+            |edit: On<InspectorFieldEdited>, mut count: ResMut<EditCount>| {
+                assert_eq!(edit.old_value.try_downcast_ref::<f32>(), Some(&10.0));
+                assert_eq!(edit.new_value.try_downcast_ref::<f32>(), Some(&5.0));
+                count.0 += 1;
+            },
+        );
+        let entity = world.spawn(Health { current: 10.0 }).id();
+
+        world
+            .run_system_once(move |mut edits: InspectorEdits| {
+                let path = |field: &str| {
+                    FieldPath::new::<Health>(entity, [FieldPathSegment::Named(field.to_string())])
+                };
+                edits.set_field(path("current"), 5.0_f32);
+                // Mismatched types and missing fields are rejected
+                edits.set_field(path("current"), 5.0_f64);
+                edits.set_field(path("missing"), 5.0_f32);
+            })
+            .unwrap();
+        apply_pending_value_changes(&mut world);
+
+        assert_eq!(world.get::<Health>(entity).unwrap().current, 5.0);
+        assert_eq!(world.resource::<EditCount>().0, 1);
+    }
+}
@@ -0,0 +1,191 @@
+//! Which entities the inspector hides.
+//!
+//! By default, UI nodes and windows are hidden from the entity list and lifecycle log.
+//! Apps can hide more entities, such as particles or pooled entities, with marker components
+//! or arbitrary predicates through [`InspectorExclusionAppExt`].
+//! The inspector's own entities ([`InspectorInternal`]) are hidden too.
+//! Each kind of [`HiddenEntityKind`] can be shown again from the checkboxes of the entity list.
+
+use core::any::TypeId;
+
+use bevy::prelude::*;
+
+use crate::inspector::state::InspectorInternal;
+use crate::inspector::strings::InspectorStrings;
+
+/// A predicate deciding whether an entity is hidden from the inspector.
+pub type ExclusionPredicate = Box<dyn Fn(EntityRef) -> bool + Send + Sync>;
+
+/// The marker components and predicates that hide entities from the inspector.
+#[derive(Resource)]
+pub struct EntityExclusions {
+    components: Vec<TypeId>,
+    predicates: Vec<ExclusionPredicate>,
+}
+
+impl Default for EntityExclusions {
+    fn default() -> Self {
+        Self {
+            components: vec![TypeId::of::<Node>(), TypeId::of::<Window>()],
+            predicates: Vec::new(),
+        }
+    }
+}
+
+impl EntityExclusions {
+    /// Hides entities with a `C` component.
+    pub fn exclude_component<C: Component>(&mut self) -> &mut Self {
+        let type_id = TypeId::of::<C>();
+        if !self.components.contains(&type_id) {
+            self.components.push(type_id);
+        }
+        self
+    }
+
+    /// Shows entities with a `C` component again, e.g. to inspect UI nodes.
+    ///
+    /// Entities are still hidden if another marker component or a predicate excludes them.
+    pub fn include_component<C: Component>(&mut self) -> &mut Self {
+        let type_id = TypeId::of::<C>();
+        self.components.retain(|&id| id != type_id);
+        self
+    }
+
+    /// Hides entities for which `predicate` returns `true`.
+    pub fn exclude_if(
+        &mut self,
+        predicate: impl Fn(EntityRef) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Returns whether `entity` is hidden from the inspector.
+    pub fn is_excluded(&self, entity: EntityRef) -> bool {
+        self.is_excluded_except(entity, &[])
+    }
+
+    /// Returns whether `entity` is hidden from the inspector,
+    /// ignoring the marker components in `shown`, e.g. to list UI nodes when asked for them.
+    ///
+    /// The inspector's own entities are only listed if `shown` contains [`InspectorInternal`].
+    pub fn is_excluded_except(&self, entity: EntityRef, shown: &[TypeId]) -> bool {
+        (entity.contains::<InspectorInternal>()
+            && !shown.contains(&TypeId::of::<InspectorInternal>()))
+            || self
+                .components
+                .iter()
+                .filter(|type_id| !shown.contains(type_id))
+                .any(|&type_id| entity.contains_type_id(type_id))
+            || self.predicates.iter().any(|predicate| predicate(entity))
+    }
+}
+
+/// A kind of entity hidden from the entity list by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HiddenEntityKind {
+    /// UI nodes.
+    UiNodes,
+    /// Windows.
+    Windows,
+    /// The inspector's own entities, marked with [`InspectorInternal`].
+    Inspector,
+}
+
+impl HiddenEntityKind {
+    /// Every kind, in the order their checkboxes are shown.
+    pub const ALL: [HiddenEntityKind; 3] = [
+        HiddenEntityKind::UiNodes,
+        HiddenEntityKind::Windows,
+        HiddenEntityKind::Inspector,
+    ];
+
+    /// The text of the kind's checkbox.
+    pub fn label(self, strings: &InspectorStrings) -> &str {
+        match self {
+            HiddenEntityKind::UiNodes => &strings.ui_nodes,
+            HiddenEntityKind::Windows => &strings.windows,
+            HiddenEntityKind::Inspector => &strings.inspector_entities,
+        }
+    }
+
+    /// The marker component hiding entities of this kind.
+    pub fn component_type(self) -> TypeId {
+        match self {
+            HiddenEntityKind::UiNodes => TypeId::of::<Node>(),
+            HiddenEntityKind::Windows => TypeId::of::<Window>(),
+            HiddenEntityKind::Inspector => TypeId::of::<InspectorInternal>(),
+        }
+    }
+}
+
+/// Extension methods for hiding entities from the inspector.
+pub trait InspectorExclusionAppExt {
+    /// Hides entities with a `C` component from the inspector.
+    fn exclude_from_inspector<C: Component>(&mut self) -> &mut Self;
+    /// Hides entities for which `predicate` returns `true` from the inspector.
+    fn exclude_from_inspector_if(
+        &mut self,
+        predicate: impl Fn(EntityRef) -> bool + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl InspectorExclusionAppExt for App {
+    fn exclude_from_inspector<C: Component>(&mut self) -> &mut Self {
+        self.init_resource::<EntityExclusions>();
+        self.world_mut()
+            .resource_mut::<EntityExclusions>()
+            .exclude_component::<C>();
+        self
+    }
+
+    fn exclude_from_inspector_if(
+        &mut self,
+        predicate: impl Fn(EntityRef) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.init_resource::<EntityExclusions>();
+        self.world_mut()
+            .resource_mut::<EntityExclusions>()
+            .exclude_if(predicate);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Particle;
+
+    #[test]
+    fn markers_and_predicates_exclude_entities() {
+        let mut world = World::new();
+        let particle = world.spawn(Particle).id();
+        let pooled = world.spawn(Name::new("pool:bullet")).id();
+        let node = world.spawn(Node::default()).id();
+        let internal = world.spawn(InspectorInternal).id();
+        let visible = world.spawn(Name::new("Player")).id();
+
+        let mut exclusions = EntityExclusions::default();
+        exclusions
+            .exclude_component::<Particle>()
+            .include_component::<Node>()
+            .exclude_if(|entity| {
+                entity
+                    .get::<Name>()
+                    .is_some_and(|name| name.starts_with("pool:"))
+            });
+
+        let excluded = |entity| exclusions.is_excluded(world.entity(entity));
+        assert!(excluded(particle));
+        assert!(excluded(pooled));
+        assert!(!excluded(node));
+        assert!(excluded(internal));
+        assert!(!excluded(visible));
+
+        let shown = [HiddenEntityKind::Inspector.component_type()];
+        assert!(!exclusions.is_excluded_except(world.entity(internal), &shown));
+        assert!(exclusions.is_excluded_except(world.entity(particle), &shown));
+    }
+}
@@ -0,0 +1,179 @@
+//! Access control for fields that are dangerous to edit.
+//!
+//! Some values, such as window handles or asset ids, can corrupt the app when edited by accident.
+//! Components and fields marked as dangerous are shown read-only in the detail panel
+//! until they are unlocked with the lock toggle next to them.
+//! They are marked with the [`Dangerous`] custom attribute,
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use feathers_inspector::inspector::Dangerous;
+//! #[derive(Component, Reflect)]
+//! struct Surface {
+//!     #[reflect(@Dangerous)]
+//!     handle: u64,
+//!     tint: f32,
+//! }
+//! ```
+//!
+//! or, for types you don't own, in [`DangerousFields`].
+//! Edits made from code, e.g. with [`InspectorEdits`](crate::inspector::InspectorEdits),
+//! are not affected.
+
+use core::any::TypeId;
+
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy::reflect::TypeInfo;
+
+use crate::inspector::widgets::FieldPathSegment;
+use crate::inspector::widgets::drag_value::field_at_path;
+
+/// Custom attribute marking a field, or a whole component type, as dangerous to edit.
+#[derive(Reflect, Clone, Copy, Debug, Default)]
+pub struct Dangerous;
+
+/// Registry of components and fields that must be unlocked before they can be edited.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::DangerousFields;
+/// # let mut app = App::new();
+/// # app.init_resource::<DangerousFields>();
+/// app.world_mut()
+///     .resource_mut::<DangerousFields>()
+///     .register_component::<Window>()
+///     .register_field::<Sprite>("image");
+/// ```
+#[derive(Resource, Default)]
+pub struct DangerousFields {
+    components: HashSet<TypeId>,
+    fields: HashSet<(TypeId, String)>,
+}
+
+impl DangerousFields {
+    /// Marks every field of component `C` as dangerous.
+    pub fn register_component<C: Component>(&mut self) -> &mut Self {
+        self.components.insert(TypeId::of::<C>());
+        self
+    }
+
+    /// Marks `field` of type `T` as dangerous.
+    ///
+    /// Tuple struct fields are named by their index, e.g. `"0"`.
+    pub fn register_field<T: 'static>(&mut self, field: &str) -> &mut Self {
+        self.fields.insert((TypeId::of::<T>(), field.to_string()));
+        self
+    }
+
+    /// Returns whether the component `component` is dangerous as a whole.
+    pub fn is_component_dangerous(&self, component: &dyn PartialReflect) -> bool {
+        let Some(type_info) = component.get_represented_type_info() else {
+            return false;
+        };
+        let attributes = match type_info {
+            TypeInfo::Struct(info) => info.custom_attributes(),
+            TypeInfo::TupleStruct(info) => info.custom_attributes(),
+            TypeInfo::Enum(info) => info.custom_attributes(),
+            _ => return self.components.contains(&type_info.type_id()),
+        };
+        self.components.contains(&type_info.type_id()) || attributes.contains::<Dangerous>()
+    }
+
+    /// Returns whether the field at `segment` in `parent` is dangerous.
+    pub fn is_field_dangerous(
+        &self,
+        parent: &dyn PartialReflect,
+        segment: &FieldPathSegment,
+    ) -> bool {
+        let Some(type_info) = parent.get_represented_type_info() else {
+            return false;
+        };
+        let key = match segment {
+            FieldPathSegment::Named(name) => name.clone(),
+            FieldPathSegment::Index(index) => index.to_string(),
+            FieldPathSegment::ListIndex(_) => return false,
+        };
+        if self.fields.contains(&(type_info.type_id(), key)) {
+            return true;
+        }
+        match (type_info, segment) {
+            (TypeInfo::Struct(info), FieldPathSegment::Named(name)) => info
+                .field(name)
+                .is_some_and(|field| field.custom_attributes().contains::<Dangerous>()),
+            (TypeInfo::TupleStruct(info), FieldPathSegment::Index(index)) => info
+                .field_at(*index)
+                .is_some_and(|field| field.custom_attributes().contains::<Dangerous>()),
+            _ => false,
+        }
+    }
+
+    /// Returns the path of the outermost dangerous value containing the field at `path`
+    /// of `component`, if any: an empty path if the whole component is dangerous,
+    /// or `path` itself if the field is.
+    pub fn dangerous_root<'a>(
+        &self,
+        component: &dyn PartialReflect,
+        path: &'a [FieldPathSegment],
+    ) -> Option<&'a [FieldPathSegment]> {
+        if self.is_component_dangerous(component) {
+            return Some(&[]);
+        }
+        (1..=path.len()).find_map(|len| {
+            let (segment, parent_path) = path[..len].split_last()?;
+            let parent = field_at_path(component, parent_path)?;
+            self.is_field_dangerous(parent, segment)
+                .then_some(&path[..len])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect)]
+    struct Surface {
+        #[reflect(@Dangerous)]
+        handle: u64,
+        inner: Inner,
+    }
+
+    #[derive(Reflect)]
+    struct Inner {
+        id: u32,
+        tint: f32,
+    }
+
+    #[test]
+    fn dangerous_roots_come_from_attributes_and_registrations() {
+        let surface = Surface {
+            handle: 7,
+            inner: Inner { id: 1, tint: 0.5 },
+        };
+        let mut dangerous = DangerousFields::default();
+        let path = |names: &[&str]| -> Vec<FieldPathSegment> {
+            names
+                .iter()
+                .map(|name| FieldPathSegment::Named(name.to_string()))
+                .collect()
+        };
+
+        let handle = path(&["handle"]);
+        assert_eq!(
+            dangerous.dangerous_root(&surface, &handle),
+            Some(handle.as_slice())
+        );
+        assert_eq!(
+            dangerous.dangerous_root(&surface, &path(&["inner", "id"])),
+            None
+        );
+
+        dangerous.register_field::<Surface>("inner");
+        let id = path(&["inner", "id"]);
+        assert_eq!(dangerous.dangerous_root(&surface, &id), Some(&id[..1]));
+
+        dangerous.register_component::<Surface>();
+        assert_eq!(dangerous.dangerous_root(&surface, &handle), Some(&[][..]));
+    }
+}
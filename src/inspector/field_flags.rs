@@ -0,0 +1,116 @@
+//! Named bits of integer fields that store bitflags.
+//!
+//! Fields such as render layer or interaction masks are stored as plain integers.
+//! Once their bits are named in [`FieldFlags`], they are edited with one checkbox per bit
+//! instead of a drag value.
+
+use core::any::TypeId;
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::reflect::TypeInfo;
+
+use crate::inspector::widgets::FieldPathSegment;
+
+/// A named bit (or group of bits) of a bitflags field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlagBit {
+    /// The name shown next to the checkbox.
+    pub name: String,
+    /// The mask of the flag; usually a single bit.
+    pub mask: u64,
+}
+
+/// Registry of named bits for integer fields, keyed by the type containing the field.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::FieldFlags;
+/// #[derive(Component, Reflect)]
+/// struct Collider {
+///     layers: u32,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.init_resource::<FieldFlags>();
+/// app.world_mut().resource_mut::<FieldFlags>().register::<Collider>(
+///     "layers",
+///     [("Player", 1 << 0), ("Enemy", 1 << 1), ("Terrain", 1 << 2)],
+/// );
+/// ```
+#[derive(Resource, Default)]
+pub struct FieldFlags {
+    flags: HashMap<(TypeId, String), Vec<FlagBit>>,
+}
+
+impl FieldFlags {
+    /// Registers the named bits of `field` in type `T`.
+    ///
+    /// Tuple struct fields are named by their index, e.g. `"0"` for a newtype mask.
+    pub fn register<T: 'static>(
+        &mut self,
+        field: &str,
+        bits: impl IntoIterator<Item = (&'static str, u64)>,
+    ) -> &mut Self {
+        let bits = bits
+            .into_iter()
+            .map(|(name, mask)| FlagBit {
+                name: name.to_string(),
+                mask,
+            })
+            .collect();
+        self.flags
+            .insert((TypeId::of::<T>(), field.to_string()), bits);
+        self
+    }
+
+    /// Returns the named bits of the field at `segment` in `parent`, if registered.
+    pub fn get(
+        &self,
+        parent: &dyn PartialReflect,
+        segment: &FieldPathSegment,
+    ) -> Option<&[FlagBit]> {
+        let type_info = parent.get_represented_type_info()?;
+        let key = match (type_info, segment) {
+            (TypeInfo::Struct(_), FieldPathSegment::Named(name)) => name.clone(),
+            (TypeInfo::TupleStruct(_), FieldPathSegment::Index(index)) => index.to_string(),
+            _ => return None,
+        };
+        self.flags
+            .get(&(type_info.type_id(), key))
+            .map(Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect)]
+    struct Collider {
+        layers: u32,
+        group: u32,
+    }
+
+    #[derive(Reflect)]
+    struct Mask(u8);
+
+    #[test]
+    fn flags_are_looked_up_by_parent_type_and_field() {
+        let mut flags = FieldFlags::default();
+        flags
+            .register::<Collider>("layers", [("Player", 1), ("Enemy", 2)])
+            .register::<Mask>("0", [("Visible", 1)]);
+        let collider = Collider {
+            layers: 3,
+            group: 0,
+        };
+        let field = |name: &str| FieldPathSegment::Named(name.to_string());
+
+        let layers = flags.get(&collider, &field("layers")).unwrap();
+        assert_eq!(layers[1].name, "Enemy");
+        assert_eq!(layers[1].mask, 2);
+        assert!(flags.get(&collider, &field("group")).is_none());
+        assert!(flags.get(&Mask(1), &FieldPathSegment::Index(0)).is_some());
+    }
+}
@@ -0,0 +1,124 @@
+//! Known value ranges of numeric fields.
+//!
+//! Numeric fields with a known range are edited with a slider instead of an unbounded drag value.
+//! Ranges come from `RangeInclusive` custom attributes on the field,
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! #[derive(Component, Reflect)]
+//! struct Light {
+//!     #[reflect(@0.0..=1.0_f32)]
+//!     intensity: f32,
+//! }
+//! ```
+//!
+//! or, for types you don't own, from ranges registered in [`FieldRanges`].
+
+use core::any::TypeId;
+use core::ops::RangeInclusive;
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::reflect::TypeInfo;
+use bevy::reflect::attributes::CustomAttributes;
+
+use crate::inspector::widgets::FieldPathSegment;
+
+/// Registry of value ranges for numeric fields, keyed by the type containing the field.
+///
+/// Registered ranges take precedence over `RangeInclusive` custom attributes.
+#[derive(Resource, Default)]
+pub struct FieldRanges {
+    ranges: HashMap<(TypeId, String), RangeInclusive<f64>>,
+}
+
+impl FieldRanges {
+    /// Registers the range of `field` in type `T`.
+    ///
+    /// Tuple struct fields are named by their index, e.g. `"0"`.
+    pub fn register<T: 'static>(&mut self, field: &str, range: RangeInclusive<f64>) -> &mut Self {
+        self.ranges
+            .insert((TypeId::of::<T>(), field.to_string()), range);
+        self
+    }
+
+    /// Returns the range of the field at `segment` in `parent`, if known.
+    pub fn get(
+        &self,
+        parent: &dyn PartialReflect,
+        segment: &FieldPathSegment,
+    ) -> Option<RangeInclusive<f64>> {
+        let type_info = parent.get_represented_type_info()?;
+        let key = match segment {
+            FieldPathSegment::Named(name) => name.clone(),
+            FieldPathSegment::Index(index) => index.to_string(),
+            FieldPathSegment::ListIndex(_) => return None,
+        };
+        if let Some(range) = self.ranges.get(&(type_info.type_id(), key)) {
+            return Some(range.clone());
+        }
+
+        let attributes = match (type_info, segment) {
+            (TypeInfo::Struct(info), FieldPathSegment::Named(name)) => {
+                info.field(name)?.custom_attributes()
+            }
+            (TypeInfo::TupleStruct(info), FieldPathSegment::Index(index)) => {
+                info.field_at(*index)?.custom_attributes()
+            }
+            _ => return None,
+        };
+        range_attribute(attributes)
+    }
+}
+
+/// Reads a `RangeInclusive` custom attribute of any common numeric type.
+fn range_attribute(attributes: &CustomAttributes) -> Option<RangeInclusive<f64>> {
+    if let Some(range) = attributes.get::<RangeInclusive<f32>>() {
+        return Some(*range.start() as f64..=*range.end() as f64);
+    }
+    if let Some(range) = attributes.get::<RangeInclusive<f64>>() {
+        return Some(range.clone());
+    }
+    if let Some(range) = attributes.get::<RangeInclusive<i32>>() {
+        return Some(*range.start() as f64..=*range.end() as f64);
+    }
+    if let Some(range) = attributes.get::<RangeInclusive<u32>>() {
+        return Some(*range.start() as f64..=*range.end() as f64);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect)]
+    struct Light {
+        #[reflect(@0.0..=1.0_f32)]
+        intensity: f32,
+        #[reflect(@1..=8_i32)]
+        samples: i32,
+        radius: f32,
+    }
+
+    #[test]
+    fn ranges_come_from_attributes_and_registrations() {
+        let light = Light {
+            intensity: 0.5,
+            samples: 4,
+            radius: 2.0,
+        };
+        let mut ranges = FieldRanges::default();
+        let field = |name: &str| FieldPathSegment::Named(name.to_string());
+
+        assert_eq!(ranges.get(&light, &field("intensity")), Some(0.0..=1.0));
+        assert_eq!(ranges.get(&light, &field("samples")), Some(1.0..=8.0));
+        assert_eq!(ranges.get(&light, &field("radius")), None);
+
+        ranges
+            .register::<Light>("radius", 0.0..=10.0)
+            .register::<Light>("intensity", 0.0..=2.0);
+        assert_eq!(ranges.get(&light, &field("radius")), Some(0.0..=10.0));
+        assert_eq!(ranges.get(&light, &field("intensity")), Some(0.0..=2.0));
+    }
+}
@@ -0,0 +1,127 @@
+//! Quick filters for common kinds of entities.
+//!
+//! The chips above the entity list narrow it down to entities with the components of a category,
+//! such as cameras or lights, to get oriented quickly in an unfamiliar project.
+//! With several chips active, entities matching any of them are listed.
+
+use core::any::TypeId;
+
+use bevy::audio::{AudioSink, SpatialAudioSink};
+use bevy::prelude::*;
+
+/// A category of entities that can be listed with one click.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FilterChip {
+    /// Entities with a [`Camera`].
+    Cameras,
+    /// Point, spot and directional lights.
+    Lights,
+    /// UI nodes, which are otherwise hidden.
+    Ui,
+    /// Entities with a [`Sprite`].
+    Sprites,
+    /// 2D and 3D meshes.
+    Meshes,
+    /// Audio players, and the sinks of playing audio.
+    Audio,
+    /// Windows, which are otherwise hidden.
+    Windows,
+}
+
+impl FilterChip {
+    /// Every chip, in the order they are shown.
+    pub const ALL: [FilterChip; 7] = [
+        FilterChip::Cameras,
+        FilterChip::Lights,
+        FilterChip::Ui,
+        FilterChip::Sprites,
+        FilterChip::Meshes,
+        FilterChip::Audio,
+        FilterChip::Windows,
+    ];
+
+    /// The text of the chip.
+    pub fn label(self) -> &'static str {
+        match self {
+            FilterChip::Cameras => "Cameras",
+            FilterChip::Lights => "Lights",
+            FilterChip::Ui => "UI",
+            FilterChip::Sprites => "Sprites",
+            FilterChip::Meshes => "Meshes",
+            FilterChip::Audio => "Audio",
+            FilterChip::Windows => "Windows",
+        }
+    }
+
+    /// The glyph marking entities of the category in the entity list.
+    pub fn glyph(self) -> char {
+        match self {
+            FilterChip::Cameras => 'C',
+            FilterChip::Lights => 'L',
+            FilterChip::Ui => 'U',
+            FilterChip::Sprites => 'S',
+            FilterChip::Meshes => 'M',
+            FilterChip::Audio => 'A',
+            FilterChip::Windows => 'W',
+        }
+    }
+
+    /// The first category `entity` belongs to, in the order of [`FilterChip::ALL`].
+    pub fn of(entity: EntityRef) -> Option<FilterChip> {
+        FilterChip::ALL
+            .into_iter()
+            .find(|chip| chip.matches(entity))
+    }
+
+    /// The components of the category; entities with any of them match the chip.
+    pub fn component_types(self) -> Vec<TypeId> {
+        match self {
+            FilterChip::Cameras => vec![TypeId::of::<Camera>()],
+            FilterChip::Lights => vec![
+                TypeId::of::<PointLight>(),
+                TypeId::of::<SpotLight>(),
+                TypeId::of::<DirectionalLight>(),
+            ],
+            FilterChip::Ui => vec![TypeId::of::<Node>()],
+            FilterChip::Sprites => vec![TypeId::of::<Sprite>()],
+            FilterChip::Meshes => vec![TypeId::of::<Mesh2d>(), TypeId::of::<Mesh3d>()],
+            FilterChip::Audio => vec![
+                TypeId::of::<AudioPlayer>(),
+                TypeId::of::<AudioSink>(),
+                TypeId::of::<SpatialAudioSink>(),
+            ],
+            FilterChip::Windows => vec![TypeId::of::<Window>()],
+        }
+    }
+
+    /// Returns whether `entity` belongs to the category.
+    pub fn matches(self, entity: EntityRef) -> bool {
+        self.component_types()
+            .into_iter()
+            .any(|type_id| entity.contains_type_id(type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::exclusion::EntityExclusions;
+
+    #[test]
+    fn chips_reveal_the_entities_they_ask_for() {
+        let mut world = World::new();
+        let light = world.spawn(SpotLight::default()).id();
+        let node = world.spawn(Node::default()).id();
+
+        assert!(FilterChip::Lights.matches(world.entity(light)));
+        assert!(!FilterChip::Lights.matches(world.entity(node)));
+        assert_eq!(FilterChip::of(world.entity(node)), Some(FilterChip::Ui));
+
+        // UI nodes are hidden by default, unless the UI chip asks for them
+        let exclusions = EntityExclusions::default();
+        assert!(exclusions.is_excluded(world.entity(node)));
+        let shown = FilterChip::Ui.component_types();
+        assert!(!exclusions.is_excluded_except(world.entity(node), &shown));
+    }
+}
@@ -0,0 +1,76 @@
+//! Fonts of the inspector UI.
+//!
+//! Text in the inspector window uses [`InspectorConfig::font`], and text marked with
+//! [`ValueText`] (numeric values and other field values) uses [`InspectorConfig::value_font`],
+//! so a monospace font can keep columns of numbers aligned.
+
+use bevy::prelude::*;
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::plugin::InspectorUiRoot;
+
+/// Marker for text showing a field value, drawn with [`InspectorConfig::value_font`].
+#[derive(Component, Default)]
+pub struct ValueText;
+
+/// The font `config` assigns to inspector text, depending on whether it shows a value.
+fn configured_font(config: &InspectorConfig, is_value: bool) -> Handle<Font> {
+    let font = if is_value {
+        config.value_font.as_ref().or(config.font.as_ref())
+    } else {
+        config.font.as_ref()
+    };
+    font.cloned().unwrap_or_default()
+}
+
+/// Applies the configured fonts to text spawned in the inspector window,
+/// and to all of it when the configuration changes.
+pub(crate) fn apply_inspector_fonts(
+    config: Res<InspectorConfig>,
+    roots: Query<(), With<InspectorUiRoot>>,
+    parents: Query<&ChildOf>,
+    mut texts: Query<(Entity, &mut TextFont, Has<ValueText>)>,
+) {
+    // With the default fonts, freshly spawned text is already right
+    if !config.is_changed() && config.font.is_none() && config.value_font.is_none() {
+        return;
+    }
+
+    for (entity, mut text_font, is_value) in &mut texts {
+        if !config.is_changed() && !text_font.is_added() {
+            continue;
+        }
+        let font = configured_font(&config, is_value);
+        if text_font.font == font {
+            continue;
+        }
+        if parents
+            .iter_ancestors(entity)
+            .any(|ancestor| roots.contains(ancestor))
+        {
+            text_font.font = font;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::uuid_handle;
+
+    use super::*;
+
+    #[test]
+    fn values_fall_back_to_the_inspector_font() {
+        let mut config = InspectorConfig::default();
+        assert_eq!(configured_font(&config, true), Handle::default());
+
+        let font: Handle<Font> = uuid_handle!("6f1c1c5e-1d5a-4b53-9f3e-1a2b3c4d5e01");
+        config.font = Some(font.clone());
+        assert_eq!(configured_font(&config, true), font);
+
+        let monospace: Handle<Font> = uuid_handle!("6f1c1c5e-1d5a-4b53-9f3e-1a2b3c4d5e02");
+        config.value_font = Some(monospace.clone());
+        assert_eq!(configured_font(&config, true), monospace);
+        assert_eq!(configured_font(&config, false), font);
+    }
+}
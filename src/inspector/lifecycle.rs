@@ -0,0 +1,622 @@
+//! Component lifecycle log used by the timeline view.
+//!
+//! Records when components are added to and removed from entities
+//! (via per-component observers, spawned if enabled with
+//! [`InspectorWindowPlugin::with_component_lifecycles`]), and samples change ticks
+//! on the selected entity to record when its components were mutated.
+//!
+//! The same observers can also record the source location that inserted each component,
+//! see [`ComponentInsertionSources`], and the archetype moves of each entity,
+//! see [`ArchetypeTransitionLog`].
+//!
+//! [`InspectorWindowPlugin::with_component_lifecycles`]:
+//! crate::inspector::InspectorWindowPlugin::with_component_lifecycles
+
+use std::collections::{HashMap, VecDeque};
+use std::panic::Location;
+
+use bevy::ecs::archetype::{Archetype, ArchetypeId};
+use bevy::ecs::change_detection::MaybeLocation;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::entity::Entities;
+use bevy::ecs::lifecycle::{Add, Remove};
+use bevy::prelude::*;
+
+use crate::inspector::exclusion::EntityExclusions;
+use crate::inspector::state::{InspectorInternal, InspectorState};
+
+/// The kind of lifecycle event recorded for a component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleEventKind {
+    /// The component was added to the entity.
+    Added,
+    /// The component was mutated.
+    Changed,
+    /// The component was removed from the entity (or the entity was despawned).
+    Removed,
+}
+
+/// A single entry in the [`ComponentLifecycleLog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LifecycleEvent {
+    /// The component this event refers to.
+    pub component_id: ComponentId,
+    /// What happened to the component.
+    pub kind: LifecycleEventKind,
+    /// The log frame on which the event was recorded.
+    pub frame: u64,
+}
+
+/// Per-entity history of component lifecycle events over a rolling window of frames.
+///
+/// Additions and removals are recorded for every entity that would appear in the entity list.
+/// Changes are only sampled for the currently selected entity,
+/// since walking the change ticks of every component in the world each frame is too costly.
+#[derive(Resource)]
+pub struct ComponentLifecycleLog {
+    /// The current frame, advanced once per update.
+    frame: u64,
+    /// How many frames of history are kept.
+    pub history_frames: u64,
+    /// Maximum number of events kept per entity.
+    pub max_events_per_entity: usize,
+    events: HashMap<Entity, VecDeque<LifecycleEvent>>,
+}
+
+impl Default for ComponentLifecycleLog {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            history_frames: 600,
+            max_events_per_entity: 512,
+            events: HashMap::new(),
+        }
+    }
+}
+
+impl ComponentLifecycleLog {
+    /// Returns the current frame of the log.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns the first frame that is still inside the history window.
+    pub fn window_start(&self) -> u64 {
+        self.frame.saturating_sub(self.history_frames)
+    }
+
+    /// Records an event for `entity` on the current frame.
+    pub fn record(&mut self, entity: Entity, component_id: ComponentId, kind: LifecycleEventKind) {
+        let frame = self.frame;
+        let max_events = self.max_events_per_entity;
+        let events = self.events.entry(entity).or_default();
+        events.push_back(LifecycleEvent {
+            component_id,
+            kind,
+            frame,
+        });
+        while events.len() > max_events {
+            events.pop_front();
+        }
+    }
+
+    /// Returns the recorded events for `entity`, oldest first.
+    pub fn events_for(&self, entity: Entity) -> impl Iterator<Item = &LifecycleEvent> {
+        self.events.get(&entity).into_iter().flatten()
+    }
+
+    /// Advances the log by one frame, dropping events that fell out of the history window.
+    pub fn advance_frame(&mut self) {
+        self.frame += 1;
+        let window_start = self.window_start();
+        self.events.retain(|_, events| {
+            while events
+                .front()
+                .is_some_and(|event| event.frame < window_start)
+            {
+                events.pop_front();
+            }
+            !events.is_empty()
+        });
+    }
+}
+
+/// The source code location that inserted each component, for "who put this here?" debugging.
+///
+/// Recording is opt-in, since it stores an entry for every component of every inspected entity,
+/// and needs the inspector to observe component lifecycles:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::{ComponentInsertionSources, InspectorWindowPlugin};
+/// App::new()
+///     .add_plugins(InspectorWindowPlugin::default().with_component_lifecycles())
+///     .insert_resource(ComponentInsertionSources::recording());
+/// ```
+///
+/// Locations point at the `spawn`/`insert` call (or the command that performed it) and
+/// require Bevy's `track_location` feature. Components added before the inspector started
+/// observing them, or while recording was disabled, have no recorded source.
+#[derive(Resource, Default)]
+pub struct ComponentInsertionSources {
+    /// Whether insertion sources are recorded.
+    pub enabled: bool,
+    sources: HashMap<(Entity, ComponentId), &'static Location<'static>>,
+}
+
+impl ComponentInsertionSources {
+    /// Returns a resource with recording enabled.
+    pub fn recording() -> Self {
+        Self {
+            enabled: true,
+            ..default()
+        }
+    }
+
+    /// Returns the location that inserted the component on `entity`, if it was recorded.
+    pub fn get(
+        &self,
+        entity: Entity,
+        component_id: ComponentId,
+    ) -> Option<&'static Location<'static>> {
+        self.sources.get(&(entity, component_id)).copied()
+    }
+
+    fn record(&mut self, entity: Entity, component_id: ComponentId, caller: MaybeLocation) {
+        if self.enabled
+            && let Some(location) = caller.into_option()
+        {
+            self.sources.insert((entity, component_id), location);
+        }
+    }
+
+    fn forget(&mut self, entity: Entity, component_id: ComponentId) {
+        self.sources.remove(&(entity, component_id));
+    }
+}
+
+/// A move of an entity from one archetype to another, see [`ArchetypeTransitionLog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchetypeTransition {
+    /// The log frame on which the move happened.
+    pub frame: u64,
+    /// The components added by the move.
+    pub added: Vec<ComponentId>,
+    /// The components removed by the move (all of them if the entity was despawned).
+    pub removed: Vec<ComponentId>,
+    /// The components of the entity after the move, sorted.
+    pub components: Vec<ComponentId>,
+    /// The archetype the observers of the move ran in:
+    /// the new one for additions, the old one for removals.
+    observed_in: ArchetypeId,
+}
+
+/// Per-entity history of archetype moves, i.e. of the entity's set of components over time,
+/// to find component churn that fragments archetypes.
+///
+/// Components added or removed together, e.g. by inserting a bundle, count as a single move.
+/// History is kept for every entity that would appear in the entity list,
+/// and dropped once the entity is despawned.
+///
+/// Recording is opt-in, since it copies the component set of every entity on each move,
+/// and needs the inspector to observe component lifecycles:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::{ArchetypeTransitionLog, InspectorWindowPlugin};
+/// App::new()
+///     .add_plugins(InspectorWindowPlugin::default().with_component_lifecycles())
+///     .insert_resource(ArchetypeTransitionLog::recording());
+/// ```
+#[derive(Resource)]
+pub struct ArchetypeTransitionLog {
+    /// Whether archetype moves are recorded.
+    pub enabled: bool,
+    /// Maximum number of moves kept per entity.
+    pub max_transitions_per_entity: usize,
+    transitions: HashMap<Entity, VecDeque<ArchetypeTransition>>,
+}
+
+impl Default for ArchetypeTransitionLog {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_transitions_per_entity: 64,
+            transitions: HashMap::new(),
+        }
+    }
+}
+
+impl ArchetypeTransitionLog {
+    /// Returns a log with recording enabled.
+    pub fn recording() -> Self {
+        Self {
+            enabled: true,
+            ..default()
+        }
+    }
+
+    /// Returns the recorded moves of `entity`, oldest first.
+    pub fn transitions_for(&self, entity: Entity) -> impl Iterator<Item = &ArchetypeTransition> {
+        self.transitions.get(&entity).into_iter().flatten()
+    }
+
+    /// Records that `component_id` was added to `entity`, which is now in `archetype`.
+    fn record_added(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        archetype: &Archetype,
+        frame: u64,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let transitions = self.transitions.entry(entity).or_default();
+        if let Some(last) = transitions.back_mut()
+            && last.frame == frame
+            && last.observed_in == archetype.id()
+            && last.removed.is_empty()
+        {
+            last.added.push(component_id);
+            return;
+        }
+        let mut components = archetype.components().to_vec();
+        components.sort();
+        self.push(
+            entity,
+            ArchetypeTransition {
+                frame,
+                added: vec![component_id],
+                removed: Vec::new(),
+                components,
+                observed_in: archetype.id(),
+            },
+        );
+    }
+
+    /// Records that `component_id` is being removed from `entity`, which is still in `archetype`.
+    fn record_removed(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        archetype: &Archetype,
+        frame: u64,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let transitions = self.transitions.entry(entity).or_default();
+        if let Some(last) = transitions.back_mut()
+            && last.frame == frame
+            && last.observed_in == archetype.id()
+            && last.added.is_empty()
+        {
+            last.removed.push(component_id);
+            last.components.retain(|&id| id != component_id);
+            return;
+        }
+        let mut components = archetype.components().to_vec();
+        components.retain(|&id| id != component_id);
+        components.sort();
+        self.push(
+            entity,
+            ArchetypeTransition {
+                frame,
+                added: Vec::new(),
+                removed: vec![component_id],
+                components,
+                observed_in: archetype.id(),
+            },
+        );
+    }
+
+    fn push(&mut self, entity: Entity, transition: ArchetypeTransition) {
+        let max_transitions = self.max_transitions_per_entity;
+        let transitions = self.transitions.entry(entity).or_default();
+        transitions.push_back(transition);
+        while transitions.len() > max_transitions {
+            transitions.pop_front();
+        }
+    }
+}
+
+/// Tracks which components already have lifecycle observers attached.
+#[derive(Resource, Default)]
+pub(crate) struct LifecycleObservers {
+    /// Number of registered components that are being observed.
+    observed_components: usize,
+}
+
+/// Spawns `Add` and `Remove` observers for any components registered since the last run.
+///
+/// Observers are attached per component so that each one knows exactly which component triggered it.
+pub(crate) fn observe_new_components(world: &mut World) {
+    let component_count = world.components().len();
+    let observed = world.resource::<LifecycleObservers>().observed_components;
+    if component_count == observed {
+        return;
+    }
+
+    for index in observed..component_count {
+        let component_id = ComponentId::new(index);
+        world.spawn((
+            Observer::new(
+                move |add: On<Add>,
+                      entities: Query<EntityRef>,
+                      exclusions: Res<EntityExclusions>,
+                      mut log: ResMut<ComponentLifecycleLog>,
+                      mut sources: ResMut<ComponentInsertionSources>,
+                      mut transitions: ResMut<ArchetypeTransitionLog>| {
+                    // Mirrors the exclusions used by the entity list
+                    if let Ok(entity) = entities.get(add.entity)
+                        && !exclusions.is_excluded(entity)
+                    {
+                        log.record(add.entity, component_id, LifecycleEventKind::Added);
+                        sources.record(add.entity, component_id, add.caller());
+                        transitions.record_added(
+                            add.entity,
+                            component_id,
+                            entity.archetype(),
+                            log.frame(),
+                        );
+                    }
+                },
+            )
+            .with_component(component_id),
+            InspectorInternal,
+        ));
+        world.spawn((
+            Observer::new(
+                move |remove: On<Remove>,
+                      entities: Query<EntityRef>,
+                      exclusions: Res<EntityExclusions>,
+                      mut log: ResMut<ComponentLifecycleLog>,
+                      mut sources: ResMut<ComponentInsertionSources>,
+                      mut transitions: ResMut<ArchetypeTransitionLog>| {
+                    // Mirrors the exclusions used by the entity list
+                    if let Ok(entity) = entities.get(remove.entity)
+                        && !exclusions.is_excluded(entity)
+                    {
+                        log.record(remove.entity, component_id, LifecycleEventKind::Removed);
+                        sources.forget(remove.entity, component_id);
+                        transitions.record_removed(
+                            remove.entity,
+                            component_id,
+                            entity.archetype(),
+                            log.frame(),
+                        );
+                    }
+                },
+            )
+            .with_component(component_id),
+            InspectorInternal,
+        ));
+    }
+
+    // Components registered while spawning the observers are picked up on the next run
+    world
+        .resource_mut::<LifecycleObservers>()
+        .observed_components = component_count;
+}
+
+/// Exclusive system that records changes to the selected entity's components,
+/// then advances the log to the next frame.
+pub(crate) fn sample_component_changes(world: &mut World) {
+    let last_run = world.last_change_tick();
+    let this_run = world.change_tick();
+
+    let selected = world.resource::<InspectorState>().selected_entity;
+    let changed: Vec<ComponentId> = selected
+        .and_then(|entity| world.get_entity(entity).ok())
+        .map(|entity| {
+            entity
+                .archetype()
+                .components()
+                .iter()
+                .copied()
+                .filter(|&id| {
+                    entity.get_change_ticks_by_id(id).is_some_and(|ticks| {
+                        // Additions are already recorded by the observers
+                        ticks.is_changed(last_run, this_run) && !ticks.is_added(last_run, this_run)
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut log = world.resource_mut::<ComponentLifecycleLog>();
+    if let Some(entity) = selected {
+        for component_id in changed {
+            log.record(entity, component_id, LifecycleEventKind::Changed);
+        }
+    }
+    log.advance_frame();
+}
+
+/// System that drops the archetype moves of despawned entities.
+pub(crate) fn prune_archetype_transitions(
+    entities: &Entities,
+    mut transitions: ResMut<ArchetypeTransitionLog>,
+) {
+    transitions
+        .transitions
+        .retain(|&entity, _| entities.contains(entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_outside_history_window_are_dropped() {
+        let mut log = ComponentLifecycleLog {
+            history_frames: 2,
+            ..Default::default()
+        };
+        let entity = Entity::from_raw_u32(1).unwrap();
+        let component_id = ComponentId::new(0);
+
+        log.record(entity, component_id, LifecycleEventKind::Added);
+        log.advance_frame();
+        log.record(entity, component_id, LifecycleEventKind::Changed);
+        assert_eq!(log.events_for(entity).count(), 2);
+
+        log.advance_frame();
+        log.advance_frame();
+        let kinds: Vec<_> = log.events_for(entity).map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![LifecycleEventKind::Changed]);
+
+        log.advance_frame();
+        assert_eq!(log.events_for(entity).count(), 0);
+    }
+
+    #[test]
+    fn per_entity_event_cap_is_enforced() {
+        let mut log = ComponentLifecycleLog {
+            max_events_per_entity: 3,
+            ..Default::default()
+        };
+        let entity = Entity::from_raw_u32(1).unwrap();
+        for index in 0..5 {
+            log.record(entity, ComponentId::new(index), LifecycleEventKind::Changed);
+        }
+        let ids: Vec<_> = log
+            .events_for(entity)
+            .map(|e| e.component_id.index())
+            .collect();
+        assert_eq!(ids, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn additions_and_removals_are_observed() {
+        #[derive(Component)]
+        struct Marker;
+
+        let mut world = World::new();
+        world.init_resource::<ComponentLifecycleLog>();
+        world.init_resource::<LifecycleObservers>();
+        world.init_resource::<EntityExclusions>();
+        world.init_resource::<ComponentInsertionSources>();
+        world.init_resource::<ArchetypeTransitionLog>();
+        let marker_id = world.register_component::<Marker>();
+        observe_new_components(&mut world);
+
+        let entity = world.spawn(Marker).id();
+        world.entity_mut(entity).remove::<Marker>();
+
+        let log = world.resource::<ComponentLifecycleLog>();
+        let kinds: Vec<_> = log
+            .events_for(entity)
+            .filter(|e| e.component_id == marker_id)
+            .map(|e| e.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![LifecycleEventKind::Added, LifecycleEventKind::Removed]
+        );
+        // Archetype moves are only recorded on request
+        let transitions = world.resource::<ArchetypeTransitionLog>();
+        assert_eq!(transitions.transitions_for(entity).count(), 0);
+    }
+
+    #[test]
+    fn excluded_entities_are_not_logged() {
+        #[derive(Component)]
+        struct Particle;
+
+        let mut world = World::new();
+        world.init_resource::<ComponentLifecycleLog>();
+        world.init_resource::<LifecycleObservers>();
+        world.init_resource::<ComponentInsertionSources>();
+        world.init_resource::<ArchetypeTransitionLog>();
+        let mut exclusions = EntityExclusions::default();
+        exclusions.exclude_component::<Particle>();
+        world.insert_resource(exclusions);
+        world.register_component::<Transform>();
+        world.register_component::<Node>();
+        observe_new_components(&mut world);
+
+        let particle = world.spawn((Particle, Transform::default())).id();
+        let node = world.spawn(Node::default()).id();
+        let shown = world.spawn(Transform::default()).id();
+
+        let log = world.resource::<ComponentLifecycleLog>();
+        assert_eq!(log.events_for(particle).count(), 0);
+        assert_eq!(log.events_for(node).count(), 0);
+        assert!(log.events_for(shown).count() > 0);
+    }
+
+    #[test]
+    fn insertion_source_is_recorded_until_removal() {
+        #[derive(Component)]
+        struct Marker;
+
+        let mut world = World::new();
+        world.init_resource::<ComponentLifecycleLog>();
+        world.init_resource::<LifecycleObservers>();
+        world.init_resource::<EntityExclusions>();
+        world.insert_resource(ComponentInsertionSources::recording());
+        world.init_resource::<ArchetypeTransitionLog>();
+        let marker_id = world.register_component::<Marker>();
+        observe_new_components(&mut world);
+
+        let entity = world.spawn(Marker).id();
+        let source = world
+            .resource::<ComponentInsertionSources>()
+            .get(entity, marker_id)
+            .expect("insertion source should be recorded with `track_location`");
+        assert_eq!(source.file(), file!());
+
+        world.entity_mut(entity).remove::<Marker>();
+        let sources = world.resource::<ComponentInsertionSources>();
+        assert!(sources.get(entity, marker_id).is_none());
+    }
+
+    #[test]
+    fn bundle_changes_are_single_archetype_moves() {
+        #[derive(Component)]
+        struct A;
+        #[derive(Component)]
+        struct B;
+        #[derive(Component)]
+        struct C;
+
+        let mut world = World::new();
+        world.init_resource::<ComponentLifecycleLog>();
+        world.init_resource::<LifecycleObservers>();
+        world.init_resource::<EntityExclusions>();
+        world.init_resource::<ComponentInsertionSources>();
+        world.insert_resource(ArchetypeTransitionLog::recording());
+        let a = world.register_component::<A>();
+        let b = world.register_component::<B>();
+        let c = world.register_component::<C>();
+        observe_new_components(&mut world);
+
+        let entity = world.spawn((A, B)).id();
+        world.entity_mut(entity).insert(C);
+        world.entity_mut(entity).remove::<(A, B)>();
+        world.despawn(entity);
+
+        let transitions = world.resource::<ArchetypeTransitionLog>();
+        let moves: Vec<_> = transitions
+            .transitions_for(entity)
+            .map(|transition| {
+                let mut added = transition.added.clone();
+                let mut removed = transition.removed.clone();
+                added.sort();
+                removed.sort();
+                (added, removed, transition.components.clone())
+            })
+            .collect();
+        assert_eq!(
+            moves,
+            vec![
+                (vec![a, b], vec![], vec![a, b]),
+                (vec![c], vec![], vec![a, b, c]),
+                (vec![], vec![a, b], vec![c]),
+                (vec![], vec![c], vec![]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Memory trend of the selected entity.
+//!
+//! The total memory and component count of the selected entity are sampled at a fixed interval
+//! and shown as a small bar chart in the detail panel header,
+//! which makes entities that slowly accumulate components or grow heap containers stand out.
+
+use core::time::Duration;
+use std::collections::VecDeque;
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+
+use crate::entity_inspection::EntityInspectionSettings;
+use crate::extension_methods::WorldInspectionExtensionTrait;
+use crate::inspector::state::InspectorState;
+use crate::memory_size::MemorySize;
+
+/// One sample of an entity's size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemorySample {
+    /// Total memory of the entity's components, in bytes.
+    pub bytes: usize,
+    /// Number of components on the entity.
+    pub components: usize,
+}
+
+/// Recent [`MemorySample`]s of the selected entity, oldest first.
+#[derive(Resource)]
+pub struct MemoryTrend {
+    /// Time between samples.
+    pub interval: Duration,
+    /// Maximum number of samples kept.
+    pub capacity: usize,
+    entity: Option<Entity>,
+    samples: VecDeque<MemorySample>,
+    since_last_sample: Duration,
+}
+
+impl Default for MemoryTrend {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            capacity: 60,
+            entity: None,
+            samples: VecDeque::new(),
+            since_last_sample: Duration::ZERO,
+        }
+    }
+}
+
+impl MemoryTrend {
+    /// Records a sample of `entity`, starting a new trend if it is not the entity sampled so far.
+    pub fn record(&mut self, entity: Entity, sample: MemorySample) {
+        if self.entity != Some(entity) {
+            self.entity = Some(entity);
+            self.samples.clear();
+        }
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Forgets all samples.
+    pub fn clear(&mut self) {
+        self.entity = None;
+        self.samples.clear();
+    }
+
+    /// The entity the samples belong to.
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// The recorded samples, oldest first.
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = &MemorySample> {
+        self.samples.iter()
+    }
+
+    /// Change in bytes and component count between the oldest and newest sample.
+    pub fn growth(&self) -> Option<(isize, isize)> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+        Some((
+            last.bytes as isize - first.bytes as isize,
+            last.components as isize - first.components as isize,
+        ))
+    }
+
+    /// Time covered by the recorded samples.
+    pub fn span(&self) -> Duration {
+        self.interval * self.samples.len().saturating_sub(1) as u32
+    }
+}
+
+/// Container of the bars of the memory trend chart in the detail panel header.
+#[derive(Component)]
+pub struct MemoryTrendChart;
+
+/// Text describing the growth over the memory trend.
+#[derive(Component)]
+pub struct MemoryTrendLabel;
+
+/// Samples the selected entity's memory and component count every [`MemoryTrend::interval`].
+pub fn sample_memory_trend(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta();
+    let selected = world.resource::<InspectorState>().selected_entity;
+    let mut trend = world.resource_mut::<MemoryTrend>();
+    let Some(entity) = selected else {
+        if trend.entity.is_some() {
+            trend.clear();
+        }
+        return;
+    };
+
+    // Only recording a sample counts as a change, so the chart is not redrawn every frame
+    let trend = trend.bypass_change_detection();
+    // Sample a newly selected entity right away
+    trend.since_last_sample += delta;
+    if trend.entity == Some(entity) && trend.since_last_sample < trend.interval {
+        return;
+    }
+    trend.since_last_sample = Duration::ZERO;
+
+    let Ok(inspection) = world.inspect(entity, EntityInspectionSettings::default()) else {
+        return;
+    };
+    let sample = MemorySample {
+        bytes: inspection
+            .total_memory_size
+            .map_or(0, |size| size.as_bytes()),
+        components: inspection
+            .components
+            .map_or(0, |components| components.len()),
+    };
+    world.resource_mut::<MemoryTrend>().record(entity, sample);
+}
+
+/// Heights of the chart bars as fractions of the chart height.
+///
+/// Bars are scaled between the smallest and largest sample, so slow growth is visible;
+/// a flat trend is drawn at half height.
+fn bar_heights(samples: &[usize]) -> Vec<f32> {
+    let (Some(&min), Some(&max)) = (samples.iter().min(), samples.iter().max()) else {
+        return Vec::new();
+    };
+    samples
+        .iter()
+        .map(|&sample| {
+            if max == min {
+                0.5
+            } else {
+                0.15 + 0.85 * (sample - min) as f32 / (max - min) as f32
+            }
+        })
+        .collect()
+}
+
+/// Formats the growth of a trend, e.g. `+1.5 KB, +2 components over 30s`.
+fn growth_label(trend: &MemoryTrend) -> String {
+    let Some((bytes, components)) = trend.growth() else {
+        return String::new();
+    };
+    let sign = if bytes < 0 { "-" } else { "+" };
+    format!(
+        "{}{}, {:+} components over {}s",
+        sign,
+        MemorySize::new(bytes.unsigned_abs()),
+        components,
+        trend.span().as_secs()
+    )
+}
+
+/// Spawns the memory trend chart and its label, filled in by [`sync_memory_trend_chart`].
+pub fn spawn_memory_trend_chart(parent: &mut ChildSpawner, font_size: f32, color: Color) {
+    parent
+        .spawn(Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            column_gap: Px(6.0),
+            margin: UiRect::bottom(Px(4.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    height: Px(16.0),
+                    display: Display::Flex,
+                    align_items: AlignItems::FlexEnd,
+                    column_gap: Px(1.0),
+                    ..default()
+                },
+                MemoryTrendChart,
+            ));
+            row.spawn((
+                Text::default(),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(color),
+                MemoryTrendLabel,
+            ));
+        });
+}
+
+/// Redraws the memory trend chart whenever a sample is recorded or the chart is respawned.
+pub fn sync_memory_trend_chart(
+    mut commands: Commands,
+    trend: Res<MemoryTrend>,
+    charts: Query<Entity, With<MemoryTrendChart>>,
+    new_charts: Query<(), Added<MemoryTrendChart>>,
+    mut labels: Query<&mut Text, With<MemoryTrendLabel>>,
+) {
+    if !trend.is_changed() && new_charts.is_empty() {
+        return;
+    }
+
+    let bytes: Vec<usize> = trend.samples().map(|sample| sample.bytes).collect();
+    let heights = bar_heights(&bytes);
+    let growing = trend
+        .growth()
+        .is_some_and(|(bytes, components)| bytes > 0 || components > 0);
+    let bar_color = if growing {
+        Color::srgba(0.95, 0.65, 0.25, 1.0)
+    } else {
+        Color::srgba(0.5, 0.65, 0.95, 1.0)
+    };
+    for chart in &charts {
+        commands
+            .entity(chart)
+            .despawn_children()
+            .with_children(|bars| {
+                for height in &heights {
+                    bars.spawn((
+                        Node {
+                            width: Px(2.0),
+                            height: Percent(height * 100.0),
+                            ..default()
+                        },
+                        BackgroundColor(bar_color),
+                    ));
+                }
+            });
+    }
+
+    let label = growth_label(&trend);
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trend_tracks_growth_of_one_entity() {
+        let mut trend = MemoryTrend {
+            capacity: 3,
+            ..default()
+        };
+        let entity = Entity::from_raw_u32(1).unwrap();
+        for (bytes, components) in [(100, 2), (150, 2), (200, 3), (2148, 4)] {
+            trend.record(entity, MemorySample { bytes, components });
+        }
+        assert_eq!(trend.samples().len(), 3);
+        assert_eq!(trend.growth(), Some((1998, 2)));
+        assert_eq!(growth_label(&trend), "+1.95 KB, +2 components over 1s");
+
+        trend.record(
+            Entity::from_raw_u32(2).unwrap(),
+            MemorySample {
+                bytes: 1,
+                components: 1,
+            },
+        );
+        assert_eq!(trend.samples().len(), 1);
+        assert_eq!(bar_heights(&[10, 10]), [0.5, 0.5]);
+        assert_eq!(bar_heights(&[10, 20]), [0.15, 1.0]);
+    }
+}
@@ -3,15 +3,94 @@
 //! Provides a separate window for inspecting entities, components, and relationships
 //! in a Bevy application using bevy_ui and bevy_experimental_feathers.
 
+pub mod baseline;
+pub mod bulk_edit;
+pub mod clipboard;
+pub mod component_settings;
 pub mod config;
+pub mod despawn;
+pub mod edit_history;
+pub mod edits;
+pub mod exclusion;
+pub mod field_access;
+pub mod field_flags;
+pub mod field_ranges;
+pub mod filter_chips;
+pub mod fonts;
+pub mod lifecycle;
+pub mod memory_trend;
+pub mod offscreen_indicator;
+pub mod opaque_math;
 pub mod panels;
 pub mod plugin;
+pub mod profiles;
+pub mod run_conditions;
+pub mod scene_origin;
+pub mod scene_validation;
+pub mod search_index;
+pub mod selection;
 pub mod semantic_names;
 pub mod state;
+pub mod states;
+pub mod strings;
+pub mod structural_changes;
+pub mod widget_registry;
 pub mod widgets;
 
+pub use baseline::{BaselineDiff, EntityBaseline, EntityBaselines};
+pub use bulk_edit::{BulkComponentAction, BulkComponentEdits};
+pub use clipboard::{
+    CopyButton, CopyOnDoubleClick, InspectorClipboard, ValueCopied, copy_to_clipboard,
+    reflect_to_ron,
+};
+pub use component_settings::{
+    ComponentCardDisplay, ComponentDisplayOverrides, ComponentDisplaySettings,
+};
 pub use config::InspectorConfig;
+pub use despawn::{
+    BulkDespawnReport, DespawnError, DespawnProtection, DespawnProtectionMode, Graveyard,
+    GraveyardEntry, despawn_entities, despawn_entity, purge_entity, restore_entity,
+};
+pub use edit_history::{EditHistory, EditRecord, EditStep, redo_edit, undo_edit};
+pub use edits::InspectorEdits;
+pub use exclusion::{
+    EntityExclusions, ExclusionPredicate, HiddenEntityKind, InspectorExclusionAppExt,
+};
+pub use field_access::{Dangerous, DangerousFields};
+pub use field_flags::{FieldFlags, FlagBit};
+pub use field_ranges::FieldRanges;
+pub use filter_chips::FilterChip;
+pub use fonts::ValueText;
+pub use lifecycle::{
+    ArchetypeTransition, ArchetypeTransitionLog, ComponentInsertionSources, ComponentLifecycleLog,
+    LifecycleEvent, LifecycleEventKind,
+};
+pub use memory_trend::{MemorySample, MemoryTrend};
 pub use plugin::{InspectorSet, InspectorWindow, InspectorWindowPlugin};
+pub use profiles::{
+    InspectorProfile, InspectorProfiles, ProfileError, apply_profile, save_profile,
+};
+pub use run_conditions::RunConditions;
+pub use scene_origin::{SceneOrigin, SceneOrigins};
+pub use scene_validation::{
+    ValidationError, ValidationReport, ValidationReports, import_scene_ron, validate_component_ron,
+    validate_scene_ron,
+};
+pub use search_index::SearchIndex;
+pub use selection::{CommandsSelectionExt, InspectorSelection, SelectionChanged, SelectionRequest};
 pub use semantic_names::SemanticFieldNames;
-pub use state::{DetailTab, EntityListEntry, InspectorCache, InspectorState, InspectorWindowState};
-pub use widgets::{DragValue, DragValueChanged, DragValuePlugin, FieldPath, FieldPathSegment};
+pub use state::{
+    DetailTab, EntityListColumn, EntityListEntry, EntityListGroup, HierarchyRollup, InspectorCache,
+    InspectorState, InspectorWindowState, SearchMode,
+};
+pub use states::{InspectStatesAppExt, InspectedStates, StateKind};
+pub use strings::InspectorStrings;
+pub use structural_changes::{
+    PendingStructuralChanges, StructuralChange, StructuralChangeError, StructuralChangesApplied,
+    apply_pending_structural_changes,
+};
+pub use widget_registry::{InspectorWidgetFn, InspectorWidgetRegistry};
+pub use widgets::{
+    ColorPicker, ColorPickerPlugin, DragValue, DragValueChanged, DragValuePlugin, FieldOwner,
+    FieldPath, FieldPathSegment, InspectorFieldEdited,
+};
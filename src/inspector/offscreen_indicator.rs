@@ -0,0 +1,201 @@
+//! Offscreen indicator for the selected entity.
+//!
+//! When [`InspectorConfig::show_offscreen_indicator`] is enabled and the selected entity
+//! is outside the main camera's view, an arrow at the edge of the game viewport
+//! points toward it.
+
+use bevy::camera::RenderTarget;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::window::WindowRef;
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::state::{InspectorInternal, InspectorState};
+
+/// Size of the indicator node (arrow and label).
+const INDICATOR_SIZE: Vec2 = Vec2::new(120.0, 36.0);
+/// Distance between the indicator and the viewport edge.
+const EDGE_MARGIN: f32 = 8.0;
+const INDICATOR_COLOR: Color = Color::srgba(1.0, 0.8, 0.2, 0.9);
+
+/// The indicator shown in the game viewport while the selected entity is offscreen.
+#[derive(Component)]
+pub struct OffscreenIndicator;
+
+/// The arrow of the [`OffscreenIndicator`], rotated toward the selected entity.
+#[derive(Component)]
+pub struct OffscreenIndicatorArrow;
+
+/// The label of the [`OffscreenIndicator`], naming the selected entity.
+#[derive(Component)]
+pub struct OffscreenIndicatorLabel;
+
+/// Returns the point where the ray from the center of a viewport of `size` in `direction`
+/// leaves the viewport shrunk by `inset` on each side.
+///
+/// `direction` is in UI coordinates, with y pointing down.
+pub fn viewport_edge_point(size: Vec2, direction: Vec2, inset: Vec2) -> Vec2 {
+    let center = size / 2.0;
+    let half_extent = (center - inset).max(Vec2::ZERO);
+    let direction = direction.normalize_or(Vec2::X);
+    let scale_x = if direction.x == 0.0 {
+        f32::INFINITY
+    } else {
+        half_extent.x / direction.x.abs()
+    };
+    let scale_y = if direction.y == 0.0 {
+        f32::INFINITY
+    } else {
+        half_extent.y / direction.y.abs()
+    };
+    center + direction * scale_x.min(scale_y)
+}
+
+/// Returns the main camera: the active, non-inspector camera rendering to the primary window
+/// with the lowest order.
+fn main_camera<'a>(
+    cameras: &'a Query<(Entity, &Camera, &GlobalTransform), Without<InspectorInternal>>,
+) -> Option<(Entity, &'a Camera, &'a GlobalTransform)> {
+    cameras
+        .iter()
+        .filter(|(_, camera, _)| {
+            camera.is_active && matches!(camera.target, RenderTarget::Window(WindowRef::Primary))
+        })
+        .min_by_key(|(_, camera, _)| camera.order)
+}
+
+/// Shows, moves and hides the [`OffscreenIndicator`] for the selected entity.
+pub fn update_offscreen_indicator(
+    config: Res<InspectorConfig>,
+    state: Res<InspectorState>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform), Without<InspectorInternal>>,
+    targets: Query<(&GlobalTransform, Option<&Name>)>,
+    mut indicators: Query<(Entity, &mut Node, Option<&UiTargetCamera>), With<OffscreenIndicator>>,
+    mut arrows: Query<&mut UiTransform, With<OffscreenIndicatorArrow>>,
+    mut labels: Query<&mut Text, With<OffscreenIndicatorLabel>>,
+    mut commands: Commands,
+) {
+    let placement = config
+        .show_offscreen_indicator
+        .then_some(())
+        .and(state.selected_entity)
+        .and_then(|selected| {
+            let (target_transform, name) = targets.get(selected).ok()?;
+            let (camera_entity, camera, camera_transform) = main_camera(&cameras)?;
+            let position = target_transform.translation();
+            if let Some(ndc) = camera.world_to_ndc(camera_transform, position)
+                && ndc.x.abs() <= 1.0
+                && ndc.y.abs() <= 1.0
+                && (0.0..=1.0).contains(&ndc.z)
+            {
+                return None;
+            }
+
+            // Direction in camera space, flipped to the UI's y-down convention
+            let local = camera_transform
+                .affine()
+                .inverse()
+                .transform_point3(position);
+            let direction = Vec2::new(local.x, -local.y);
+            let viewport = camera.logical_viewport_rect()?;
+            let inset = INDICATOR_SIZE / 2.0 + EDGE_MARGIN;
+            let point = viewport.min + viewport_edge_point(viewport.size(), direction, inset);
+            let label = match name {
+                Some(name) => format!("{} ({})", name, selected),
+                None => selected.to_string(),
+            };
+            Some((camera_entity, point, direction.to_angle(), label))
+        });
+
+    let Some((camera_entity, point, angle, label)) = placement else {
+        for (_, mut node, _) in &mut indicators {
+            node.display = Display::None;
+        }
+        return;
+    };
+
+    let Ok((indicator, mut node, target_camera)) = indicators.single_mut() else {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Px(INDICATOR_SIZE.x),
+                height: Px(INDICATOR_SIZE.y),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Px(4.0),
+                ..default()
+            },
+            GlobalZIndex(i32::MAX),
+            Pickable::IGNORE,
+            UiTargetCamera(camera_entity),
+            OffscreenIndicator,
+            InspectorInternal,
+            children![
+                (
+                    Node {
+                        width: Px(24.0),
+                        height: Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(INDICATOR_COLOR),
+                    UiTransform::default(),
+                    OffscreenIndicatorArrow,
+                    InspectorInternal,
+                ),
+                (
+                    Text::new(label),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextColor(INDICATOR_COLOR),
+                    OffscreenIndicatorLabel,
+                    InspectorInternal,
+                ),
+            ],
+        ));
+        return;
+    };
+
+    if target_camera.map(|target| target.entity()) != Some(camera_entity) {
+        commands
+            .entity(indicator)
+            .insert(UiTargetCamera(camera_entity));
+    }
+    node.display = Display::Flex;
+    node.left = Px(point.x - INDICATOR_SIZE.x / 2.0);
+    node.top = Px(point.y - INDICATOR_SIZE.y / 2.0);
+    for mut transform in &mut arrows {
+        transform.rotation = Rot2::radians(angle);
+    }
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_point_lies_on_the_inset_viewport_edge() {
+        let size = Vec2::new(800.0, 600.0);
+        let inset = Vec2::splat(50.0);
+
+        assert_eq!(
+            viewport_edge_point(size, Vec2::X, inset),
+            Vec2::new(750.0, 300.0)
+        );
+        assert_eq!(
+            viewport_edge_point(size, -Vec2::Y, inset),
+            Vec2::new(400.0, 50.0)
+        );
+        // Diagonals hit the nearer edge first
+        let diagonal = viewport_edge_point(size, Vec2::ONE, inset);
+        assert!(diagonal.abs_diff_eq(Vec2::new(650.0, 550.0), 1e-3));
+    }
+}
@@ -0,0 +1,208 @@
+//! Field access for glam math types that are reflected as opaque values.
+//!
+//! Depending on feature flags, math types such as [`Vec3`] or [`Quat`] may be reflected
+//! as opaque values instead of structs, which hides their fields from reflection.
+//! These helpers expose the same named fields (`x`, `y`, `x_axis`, ...) that the struct
+//! reflection would, so such values display and edit consistently either way.
+
+use bevy::math::{
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2,
+    IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, UVec2, UVec3, UVec4, Vec3A,
+};
+use bevy::prelude::*;
+use bevy::reflect::ReflectRef;
+
+/// Expands to the `(field name, field value)` pairs of the first matching type.
+macro_rules! opaque_fields {
+    ($reflected:expr, $($ty:ty => [$($field:ident),+]),+ $(,)?) => {
+        $(
+            if let Some(value) = $reflected.try_downcast_ref::<$ty>() {
+                return Some(vec![$((stringify!($field), &value.$field as &dyn PartialReflect)),+]);
+            }
+        )+
+    };
+}
+
+/// Expands to a mutable reference to the named field of the first matching type.
+macro_rules! opaque_field_mut {
+    ($reflected:expr, $name:expr, $($ty:ty => [$($field:ident),+]),+ $(,)?) => {
+        $(
+            if $reflected.try_downcast_ref::<$ty>().is_some() {
+                let value = $reflected.try_downcast_mut::<$ty>()?;
+                return match $name {
+                    $(stringify!($field) => Some(&mut value.$field as &mut dyn PartialReflect),)+
+                    _ => None,
+                };
+            }
+        )+
+    };
+}
+
+/// Invokes `$macro` with the list of supported types and their fields.
+macro_rules! with_supported_types {
+    ($macro:ident!($($args:expr),+)) => {
+        $macro!(
+            $($args),+,
+            Vec2 => [x, y],
+            Vec3 => [x, y, z],
+            Vec3A => [x, y, z],
+            Vec4 => [x, y, z, w],
+            DVec2 => [x, y],
+            DVec3 => [x, y, z],
+            DVec4 => [x, y, z, w],
+            IVec2 => [x, y],
+            IVec3 => [x, y, z],
+            IVec4 => [x, y, z, w],
+            UVec2 => [x, y],
+            UVec3 => [x, y, z],
+            UVec4 => [x, y, z, w],
+            Quat => [x, y, z, w],
+            DQuat => [x, y, z, w],
+            Mat2 => [x_axis, y_axis],
+            Mat3 => [x_axis, y_axis, z_axis],
+            Mat3A => [x_axis, y_axis, z_axis],
+            Mat4 => [x_axis, y_axis, z_axis, w_axis],
+            DMat2 => [x_axis, y_axis],
+            DMat3 => [x_axis, y_axis, z_axis],
+            DMat4 => [x_axis, y_axis, z_axis, w_axis],
+            Affine2 => [matrix2, translation],
+            Affine3A => [matrix3, translation],
+            DAffine2 => [matrix2, translation],
+            DAffine3 => [matrix3, translation],
+        )
+    };
+}
+
+/// Returns the named fields of a math type that is reflected as an opaque value.
+///
+/// Returns `None` for values that are not opaque, or not a supported math type.
+pub fn opaque_math_fields(
+    reflected: &dyn PartialReflect,
+) -> Option<Vec<(&'static str, &dyn PartialReflect)>> {
+    if !matches!(reflected.reflect_ref(), ReflectRef::Opaque(_)) {
+        return None;
+    }
+    math_fields(reflected)
+}
+
+/// Returns the named fields of a supported math type, however it is reflected.
+fn math_fields(reflected: &dyn PartialReflect) -> Option<Vec<(&'static str, &dyn PartialReflect)>> {
+    with_supported_types!(opaque_fields!(reflected));
+    None
+}
+
+/// Returns the named field of a math type that is reflected as an opaque value.
+///
+/// Returns `None` for values that are not opaque, not a supported math type,
+/// or do not have a field with that name.
+pub fn opaque_math_field_mut<'a>(
+    reflected: &'a mut dyn PartialReflect,
+    name: &str,
+) -> Option<&'a mut dyn PartialReflect> {
+    if !matches!(reflected.reflect_ref(), ReflectRef::Opaque(_)) {
+        return None;
+    }
+    math_field_mut(reflected, name)
+}
+
+/// Returns the named field of a supported math type, however it is reflected.
+fn math_field_mut<'a>(
+    reflected: &'a mut dyn PartialReflect,
+    name: &str,
+) -> Option<&'a mut dyn PartialReflect> {
+    with_supported_types!(opaque_field_mut!(reflected, name));
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sets the field `name` of `value` to `field`, then reads all fields back.
+    fn round_trip<T: PartialReflect + Clone>(
+        value: &T,
+        name: &str,
+        field: &dyn PartialReflect,
+    ) -> (T, Vec<(&'static str, Box<dyn PartialReflect>)>) {
+        let mut edited = value.clone();
+        math_field_mut(&mut edited, name)
+            .unwrap()
+            .try_apply(field)
+            .unwrap();
+        let fields = math_fields(&edited)
+            .unwrap()
+            .into_iter()
+            .map(|(name, value)| (name, value.to_dynamic()))
+            .collect();
+        (edited, fields)
+    }
+
+    fn field<'a>(
+        fields: &'a [(&str, Box<dyn PartialReflect>)],
+        name: &str,
+    ) -> &'a dyn PartialReflect {
+        fields
+            .iter()
+            .find_map(|(field_name, value)| (*field_name == name).then_some(value.as_ref()))
+            .unwrap()
+    }
+
+    #[test]
+    fn quaternion_fields_round_trip() {
+        let rotation = Quat::from_rotation_y(1.0);
+        let names: Vec<_> = math_fields(&rotation)
+            .unwrap()
+            .into_iter()
+            .map(|f| f.0)
+            .collect();
+        assert_eq!(names, ["x", "y", "z", "w"]);
+
+        let (edited, fields) = round_trip(&rotation, "w", &0.5f32);
+        assert_eq!(
+            edited,
+            Quat::from_xyzw(rotation.x, rotation.y, rotation.z, 0.5)
+        );
+        assert_eq!(
+            field(&fields, "y").reflect_partial_eq(&rotation.y),
+            Some(true)
+        );
+        assert_eq!(field(&fields, "w").reflect_partial_eq(&0.5f32), Some(true));
+        assert!(math_field_mut(&mut rotation.clone(), "v").is_none());
+    }
+
+    #[test]
+    fn matrix_and_affine_fields_round_trip() {
+        let matrix = Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0));
+        let (edited, fields) = round_trip(&matrix, "y_axis", &Vec3::ONE);
+        assert_eq!(
+            edited,
+            Mat3::from_cols(matrix.x_axis, Vec3::ONE, matrix.z_axis)
+        );
+        assert_eq!(
+            field(&fields, "z_axis").reflect_partial_eq(&matrix.z_axis),
+            Some(true)
+        );
+
+        let affine = Affine3A::from_scale_rotation_translation(
+            Vec3::splat(2.0),
+            Quat::IDENTITY,
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let (edited, fields) = round_trip(&affine, "translation", &Vec3A::ZERO);
+        assert_eq!(edited.matrix3, affine.matrix3);
+        assert_eq!(edited.translation, Vec3A::ZERO);
+        assert_eq!(
+            field(&fields, "matrix3").reflect_partial_eq(&affine.matrix3),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn struct_reflected_math_types_are_not_opaque() {
+        // Only values reflected as opaque need these helpers
+        let mut rotation = Quat::IDENTITY;
+        assert!(matches!(rotation.reflect_ref(), ReflectRef::Struct(_)));
+        assert!(opaque_math_fields(&rotation).is_none());
+        assert!(opaque_math_field_mut(&mut rotation, "w").is_none());
+    }
+}
@@ -0,0 +1,497 @@
+//! "Components..." dialog for the entity list.
+//!
+//! Lists the registered component types matching a search of their short names,
+//! and lets each of them be required or excluded,
+//! see [`InspectorState::required_components`] and [`InspectorState::excluded_components`].
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::hierarchy::{ChildSpawner, ChildSpawnerCommands};
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{ButtonProps, button};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::search_index::SearchIndex;
+use crate::inspector::state::{InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::{TextInput, TextInputChanged, TextInputText, text_input};
+
+/// Maximum number of search matches offered at once.
+const MAX_FILTER_MATCHES: usize = 12;
+
+const REQUIRED_COLOR: Color = Color::srgba(0.4, 0.8, 0.4, 1.0);
+const EXCLUDED_COLOR: Color = Color::srgba(0.85, 0.35, 0.35, 1.0);
+const ACTION_COLOR: Color = Color::srgba(0.9, 0.9, 0.6, 1.0);
+
+/// The state of the "Components..." dialog, while it is open.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentFilterPicker {
+    /// Search text for component types, matched against their short names.
+    pub search: String,
+}
+
+/// What a [`ComponentFilterActionButton`] does with its component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComponentFilterAction {
+    /// Only list entities with the component.
+    Require,
+    /// Only list entities without the component.
+    Exclude,
+    /// Neither require nor exclude the component.
+    Clear,
+}
+
+/// Button opening the "Components..." dialog.
+#[derive(Component)]
+pub struct ComponentFilterButton;
+
+/// The text of the [`ComponentFilterButton`], showing how many filters are active.
+#[derive(Component)]
+pub struct ComponentFilterLabel;
+
+/// The dialog overlaying the entity list, hidden while no [`ComponentFilterPicker`] is open.
+#[derive(Component)]
+pub struct ComponentFilterDialog;
+
+/// The search field of the dialog. Click it to start typing.
+#[derive(Component)]
+pub struct ComponentFilterSearchField;
+
+/// The active filters and the matches, rebuilt whenever the picker changes.
+#[derive(Component)]
+pub struct ComponentFilterContent;
+
+/// Button applying a [`ComponentFilterAction`] to a component.
+#[derive(Component)]
+pub struct ComponentFilterActionButton {
+    /// The component.
+    pub component_id: ComponentId,
+    /// What the button does.
+    pub action: ComponentFilterAction,
+}
+
+/// Button clearing every component filter.
+#[derive(Component)]
+pub struct ComponentFilterClearButton;
+
+/// Button closing the dialog.
+#[derive(Component)]
+pub struct ComponentFilterCloseButton;
+
+/// Applies `action` for `component_id` to the `required` and `excluded` components,
+/// so a component is never both required and excluded.
+pub fn apply_component_filter_action(
+    required: &mut Vec<ComponentId>,
+    excluded: &mut Vec<ComponentId>,
+    component_id: ComponentId,
+    action: ComponentFilterAction,
+) {
+    required.retain(|&id| id != component_id);
+    excluded.retain(|&id| id != component_id);
+    match action {
+        ComponentFilterAction::Require => required.push(component_id),
+        ComponentFilterAction::Exclude => excluded.push(component_id),
+        ComponentFilterAction::Clear => {}
+    }
+}
+
+/// Returns the label of the [`ComponentFilterButton`].
+fn component_filter_label(state: &InspectorState, strings: &InspectorStrings) -> String {
+    match state.required_components.len() + state.excluded_components.len() {
+        0 => format!("{}...", strings.component_filter),
+        count => format!("{} ({})", strings.component_filter, count),
+    }
+}
+
+/// Spawns the button opening the "Components..." dialog.
+pub fn spawn_component_filter_button(
+    parent: &mut ChildSpawnerCommands<'_>,
+    font_size: f32,
+    strings: &InspectorStrings,
+) {
+    parent.spawn((
+        button(
+            ButtonProps::default(),
+            ComponentFilterButton,
+            bevy::prelude::Spawn((
+                Text::new(component_filter_label(&InspectorState::default(), strings)),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                ComponentFilterLabel,
+            )),
+        ),
+        observe(on_component_filter_click),
+    ));
+}
+
+/// Spawns the "Components..." dialog, hidden until [`ComponentFilterButton`] is clicked.
+pub fn spawn_component_filter_dialog(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Px(0.0),
+                left: Px(0.0),
+                width: Percent(100.0),
+                height: Percent(100.0),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                row_gap: config.item_gap,
+                padding: config.panel_padding,
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.98)),
+            ZIndex(10),
+            ComponentFilterDialog,
+        ))
+        .with_children(|dialog| {
+            dialog.spawn((
+                Text::new(strings.component_filter_title.clone()),
+                TextFont {
+                    font_size: config.title_font_size,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            dialog
+                .spawn((
+                    Node {
+                        width: Percent(100.0),
+                        padding: UiRect::axes(Px(6.0), Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    text_input(TextInput::new(strings.search_components.clone())),
+                    ComponentFilterSearchField,
+                    observe(on_component_filter_search_changed),
+                ))
+                .with_child((
+                    Text::new(strings.search_components.clone()),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextInputText,
+                ));
+
+            dialog.spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    ..default()
+                },
+                ComponentFilterContent,
+            ));
+
+            dialog
+                .spawn(Node {
+                    display: Display::Flex,
+                    column_gap: config.column_gap,
+                    ..default()
+                })
+                .with_children(|actions| {
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            ComponentFilterClearButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.clear_all.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_component_filter_clear_click),
+                    ));
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            ComponentFilterCloseButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.done.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_component_filter_close_click),
+                    ));
+                });
+        });
+}
+
+/// Observer for the "Components..." button. Opens the dialog with an empty search.
+fn on_component_filter_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.component_filter_picker = Some(ComponentFilterPicker::default());
+    commands.queue(rebuild_component_filter);
+}
+
+/// Observer: lists the components matching the edited search text.
+fn on_component_filter_search_changed(
+    changed: On<TextInputChanged>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    let Some(picker) = state.component_filter_picker.as_mut() else {
+        return;
+    };
+    picker.search.clone_from(&changed.text);
+    commands.queue(rebuild_component_filter);
+}
+
+/// Observer: requires, excludes or clears a component and refreshes the entity list.
+fn on_component_filter_action_click(
+    activate: On<Activate>,
+    buttons: Query<&ComponentFilterActionButton>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let state = &mut *state;
+    apply_component_filter_action(
+        &mut state.required_components,
+        &mut state.excluded_components,
+        button.component_id,
+        button.action,
+    );
+    cache.stale = true;
+    commands.queue(rebuild_component_filter);
+}
+
+/// Observer: clears every component filter.
+fn on_component_filter_clear_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut commands: Commands,
+) {
+    state.required_components.clear();
+    state.excluded_components.clear();
+    cache.stale = true;
+    commands.queue(rebuild_component_filter);
+}
+
+/// Observer: closes the dialog, keeping the filters.
+fn on_component_filter_close_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.component_filter_picker = None;
+    commands.queue(rebuild_component_filter);
+}
+
+/// Shows or hides the dialog, updates the filter button's label,
+/// and rebuilds the dialog's content from the [`ComponentFilterPicker`],
+/// without rebuilding the search field itself, so it keeps the input focus.
+pub(super) fn rebuild_component_filter(world: &mut World) {
+    let config = world.resource::<InspectorConfig>().clone();
+    let state = world.resource::<InspectorState>();
+    let picker = state.component_filter_picker.clone();
+    let required = state.required_components.clone();
+    let excluded = state.excluded_components.clone();
+    let label = component_filter_label(state, world.resource::<InspectorStrings>());
+
+    let mut labels = world.query_filtered::<&mut Text, With<ComponentFilterLabel>>();
+    for mut text in labels.iter_mut(world) {
+        text.0.clone_from(&label);
+    }
+
+    let mut dialogs = world.query_filtered::<&mut Node, With<ComponentFilterDialog>>();
+    for mut node in dialogs.iter_mut(world) {
+        node.display = if picker.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    // A reopened dialog starts with an empty search
+    let search = picker.as_ref().map_or("", |picker| picker.search.as_str());
+    let mut fields = world.query_filtered::<&mut TextInput, With<ComponentFilterSearchField>>();
+    for mut field in fields.iter_mut(world) {
+        if field.text != search {
+            field.text = search.to_string();
+        }
+    }
+
+    let mut contents = world.query_filtered::<Entity, With<ComponentFilterContent>>();
+    let Some(content) = contents.iter(world).next() else {
+        return;
+    };
+    world.entity_mut(content).despawn_children();
+    let Some(picker) = picker else {
+        return;
+    };
+
+    // Collect the names first, then spawn the rows
+    let name_of = |component_id: ComponentId| {
+        world
+            .components()
+            .get_name(component_id)
+            .map(|name| name.shortname().to_string())
+            .unwrap_or_else(|| format!("Component #{}", component_id.index()))
+    };
+    let active: Vec<(ComponentId, String, bool)> = required
+        .iter()
+        .map(|&id| (id, name_of(id), true))
+        .chain(excluded.iter().map(|&id| (id, name_of(id), false)))
+        .collect();
+    let mut matches: Vec<(String, ComponentId)> = world
+        .get_resource::<SearchIndex>()
+        .map(|index| index.components_matching(picker.search.trim()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|id| !required.contains(id) && !excluded.contains(id))
+        .map(|id| (name_of(id), id))
+        .collect();
+    matches.sort();
+    let more = matches.len().saturating_sub(MAX_FILTER_MATCHES);
+    matches.truncate(MAX_FILTER_MATCHES);
+
+    world.entity_mut(content).with_children(|p| {
+        let text_font = TextFont {
+            font_size: config.small_font_size,
+            ..default()
+        };
+        let spawn_action = |row: &mut ChildSpawner<'_>,
+                            label: &str,
+                            component_id: ComponentId,
+                            action: ComponentFilterAction| {
+            row.spawn((
+                Node {
+                    padding: UiRect::horizontal(Px(4.0)),
+                    ..default()
+                },
+                bevy::ui_widgets::Button,
+                ComponentFilterActionButton {
+                    component_id,
+                    action,
+                },
+                observe(on_component_filter_action_click),
+            ))
+            .with_child((
+                Text::new(label),
+                text_font.clone(),
+                TextColor(ACTION_COLOR),
+            ));
+        };
+
+        for (component_id, name, is_required) in active {
+            p.spawn(Node {
+                display: Display::Flex,
+                column_gap: config.column_gap,
+                ..default()
+            })
+            .with_children(|row| {
+                let (prefix, color) = if is_required {
+                    ("with", REQUIRED_COLOR)
+                } else {
+                    ("without", EXCLUDED_COLOR)
+                };
+                row.spawn((
+                    Text::new(format!("{} {}", prefix, name)),
+                    text_font.clone(),
+                    TextColor(color),
+                ));
+                spawn_action(row, "x", component_id, ComponentFilterAction::Clear);
+            });
+        }
+
+        p.spawn((
+            Text::new(if matches.is_empty() {
+                "No matching components".to_string()
+            } else {
+                format!("Matches ({})", matches.len() + more)
+            }),
+            text_font.clone(),
+            TextColor(config.muted_text_color),
+            Node {
+                margin: UiRect::top(Px(8.0)),
+                ..default()
+            },
+        ));
+        for (name, component_id) in matches {
+            p.spawn(Node {
+                display: Display::Flex,
+                column_gap: config.column_gap,
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(name),
+                    text_font.clone(),
+                    TextColor(Color::WHITE),
+                    Node {
+                        flex_grow: 1.0,
+                        ..default()
+                    },
+                ));
+                spawn_action(row, "require", component_id, ComponentFilterAction::Require);
+                spawn_action(row, "exclude", component_id, ComponentFilterAction::Exclude);
+            });
+        }
+        if more > 0 {
+            p.spawn((
+                Text::new(format!("... and {} more; refine the search", more)),
+                text_font.clone(),
+                TextColor(config.muted_text_color),
+            ));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_are_never_both_required_and_excluded() {
+        use ComponentFilterAction::*;
+
+        let (a, b) = (ComponentId::new(1), ComponentId::new(2));
+        let mut required = Vec::new();
+        let mut excluded = Vec::new();
+
+        apply_component_filter_action(&mut required, &mut excluded, a, Require);
+        apply_component_filter_action(&mut required, &mut excluded, b, Exclude);
+        assert_eq!(
+            (required.as_slice(), excluded.as_slice()),
+            (&[a][..], &[b][..])
+        );
+
+        apply_component_filter_action(&mut required, &mut excluded, a, Exclude);
+        assert_eq!(
+            (required.as_slice(), excluded.as_slice()),
+            (&[][..], &[b, a][..])
+        );
+
+        apply_component_filter_action(&mut required, &mut excluded, b, Clear);
+        assert_eq!(
+            (required.as_slice(), excluded.as_slice()),
+            (&[][..], &[a][..])
+        );
+    }
+}
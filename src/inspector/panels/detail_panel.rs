@@ -1,28 +1,83 @@
 //! Detail panel for the right side of the inspector.
 //! Contains tabs for Components and Relationships.
 
-use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::asset::{LoadState, ReflectHandle, UntypedAssetId};
+use bevy::ecs::component::Tick;
+use bevy::ecs::entity::Entities;
+use bevy::ecs::hierarchy::{ChildSpawner, ChildSpawnerCommands};
 use bevy::ecs::observer::On;
 use bevy::ecs::relationship::Relationship;
-use bevy::feathers::controls::{button, ButtonProps};
+use bevy::feathers::controls::{ButtonProps, button};
 use bevy::feathers::theme::ThemeBackgroundColor;
 use bevy::feathers::tokens;
 use bevy::prelude::*;
 use bevy::reflect::{ReflectRef, VariantType};
 use bevy::ui::Val::*;
-use bevy::ui_widgets::{observe, Activate, ControlOrientation, CoreScrollbarThumb, Scrollbar};
+use bevy::ui_widgets::{Activate, ControlOrientation, CoreScrollbarThumb, Scrollbar, observe};
 
 use core::any::TypeId;
+use core::ops::RangeInclusive;
+use std::collections::{HashMap, HashSet};
+use std::panic::Location;
 
 use crate::component_inspection::{
     ComponentDetailLevel, ComponentInspectionSettings, ComponentMetadataMap,
 };
-use crate::entity_inspection::{EntityInspectExtensionTrait, EntityInspectionSettings};
+use crate::entity_inspection::EntityInspectionSettings;
+use crate::extension_methods::{EntityCommandsInspectionTrait, WorldInspectionExtensionTrait};
+use crate::inspector::baseline::EntityBaselines;
+use crate::inspector::clipboard::{CopyButton, CopyOnDoubleClick, spawn_copy_button};
+use crate::inspector::component_settings::{ComponentCardDisplay, ComponentDisplayOverrides};
 use crate::inspector::config::InspectorConfig;
+use crate::inspector::despawn::DespawnError;
+use crate::inspector::field_access::DangerousFields;
+use crate::inspector::field_flags::{FieldFlags, FlagBit};
+use crate::inspector::field_ranges::FieldRanges;
+use crate::inspector::fonts::ValueText;
+use crate::inspector::lifecycle::ComponentInsertionSources;
+use crate::inspector::memory_trend::spawn_memory_trend_chart;
+use crate::inspector::opaque_math::opaque_math_fields;
+use crate::inspector::panels::notes::spawn_notes_tab_exclusive;
+use crate::inspector::panels::resources::spawn_resources_tab_exclusive;
+use crate::inspector::panels::states::spawn_states_tab_exclusive;
+use crate::inspector::panels::systems::spawn_systems_tab_exclusive;
+use crate::inspector::panels::time::spawn_time_tab_exclusive;
+use crate::inspector::panels::timeline::spawn_timeline_tab_exclusive;
+use crate::inspector::panels::validation::spawn_validation_tab_exclusive;
+use crate::inspector::scene_origin::SceneOrigins;
 use crate::inspector::semantic_names::SemanticFieldNames;
 use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
-use crate::inspector::widgets::{DragValue, DragValueDragState, FieldPath, FieldPathSegment};
-use crate::reflection_tools::get_reflected_component_ref;
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::structural_changes::{
+    PendingStructuralChanges, StructuralChange, StructuralChangeError, StructuralChangesApplied,
+};
+use crate::inspector::widget_registry::InspectorWidgetRegistry;
+use crate::inspector::widgets::color_picker::spawn_color_picker;
+use crate::inspector::widgets::drag_value::{
+    DragValueFormat, clone_reflected, field_at_path, numeric_format, supports_hex_display,
+    try_extract_bits, try_extract_exact_integer, try_extract_numeric,
+};
+use crate::inspector::widgets::field_slider::spawn_field_slider;
+use crate::inspector::widgets::flag_checkboxes::spawn_flag_checkboxes;
+use crate::inspector::widgets::list_editor::{spawn_list_header, spawn_list_item_buttons};
+use crate::inspector::widgets::matrix_grid::{MatrixGrid, matrix_grid, spawn_matrix_grid};
+use crate::inspector::widgets::option_toggle::{option_is_some, spawn_option_toggle};
+use crate::inspector::widgets::selectable_text::selectable_text;
+use crate::inspector::widgets::set_editor::{
+    set_has_primitive_members, spawn_set_header, spawn_set_member_remove_button,
+};
+use crate::inspector::widgets::timer_controls::{spawn_timer_controls, timer_summary};
+use crate::inspector::widgets::tween::{FieldTweens, spawn_tween_controls};
+use crate::inspector::widgets::vector_drag::{
+    VectorAxis, quat_euler_degrees, spawn_euler_angle_drag, spawn_vector_drag, vector_axes,
+};
+use crate::inspector::widgets::{
+    ColorFieldType, DragValue, DragValueDragState, FieldOwner, FieldPath, FieldPathSegment,
+    FieldValue, InspectorFieldEdited, PendingValueChange, PendingValueChanges, Tooltip,
+    apply_pending_value_changes, color_from_reflect, truncate_middle,
+};
+use crate::memory_size::{MemorySize, serialized_ron_size};
+use crate::reflection_tools::{get_reflected_component_ref, get_reflected_resource_ref};
 
 /// Marker component for the detail panel container.
 #[derive(Component)]
@@ -40,6 +95,73 @@ pub struct DetailContent;
 #[derive(Component)]
 pub struct ComponentCard;
 
+/// Marker for the header (name + size) of a component card.
+/// Pinned to the top of the scroll viewport while its card is scrolled past.
+#[derive(Component)]
+pub struct ComponentCardHeader;
+
+/// Makes a component card header toggle whether the card of this component type is collapsed.
+#[derive(Component)]
+pub struct ComponentCardToggle(pub TypeId);
+
+/// Button pinning or unpinning the card of this component type,
+/// see [`InspectorState::pinned_component_cards`].
+#[derive(Component)]
+pub struct ComponentCardPinButton(pub TypeId);
+
+/// Maximum number of characters shown for a component in [`ComponentCardDisplay::Summary`] mode.
+const SUMMARY_MAX_CHARS: usize = 80;
+
+/// Button that despawns the given entity, see [`despawn_entity`](crate::inspector::despawn_entity).
+#[derive(Component)]
+pub struct DespawnButton(pub Entity);
+
+/// Button logging the inspection of the given entity with [`info!`],
+/// see [`EntityCommandsInspectionTrait::inspect`].
+#[derive(Component)]
+pub struct DumpToLogButton(pub Entity);
+
+/// A link to the entity referenced by a component field. Clicking it selects that entity.
+#[derive(Component)]
+pub struct EntityLink(pub Entity);
+
+/// Button that selects the asset referenced by a [`Handle`] field,
+/// see [`InspectorState::selected_asset`].
+#[derive(Component)]
+pub struct AssetSelectButton(pub UntypedAssetId);
+
+/// Button switching an unsigned integer field between decimal and hexadecimal display,
+/// see [`InspectorState::hex_fields`].
+#[derive(Component)]
+pub struct HexDisplayToggle(pub FieldPath);
+
+/// Button unlocking or locking a dangerous component or field for editing,
+/// see [`InspectorState::unlocked_fields`].
+#[derive(Component)]
+pub struct FieldLockToggle(pub FieldPath);
+
+/// Button expanding or collapsing a nested struct or enum field,
+/// see [`InspectorState::expanded_structs`].
+#[derive(Component)]
+pub struct NestedFieldToggle(pub FieldPath);
+
+/// Button setting the NaN or infinite numbers of a field to zero.
+#[derive(Component)]
+pub struct RepairNonFiniteButton(pub Vec<FieldPath>);
+
+/// Button capturing or comparing against a baseline of the given entity,
+/// see [`EntityBaselines`].
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BaselineButton {
+    /// Captures a baseline of the selected entity.
+    Capture,
+    /// Starts or stops highlighting changes since the baseline.
+    ToggleCompare,
+}
+
+/// Type path prefix shared by all asset [`Handle`] types.
+const HANDLE_TYPE_PATH_PREFIX: &str = "bevy_asset::handle::Handle<";
+
 /// Marker for hierarchy nodes (parent/child entities).
 #[derive(Component)]
 pub struct HierarchyNode(pub Entity);
@@ -55,6 +177,420 @@ fn on_tab_button_click(
     }
 }
 
+/// Observer for component card header clicks. Toggles whether the card is collapsed.
+fn on_component_card_toggle_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    toggles: Query<&ComponentCardToggle>,
+) {
+    if let Ok(toggle) = toggles.get(activate.entity) {
+        if !state.toggled_component_cards.remove(&toggle.0) {
+            state.toggled_component_cards.insert(toggle.0);
+        }
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for component card pin button clicks. Pins or unpins the component type.
+fn on_component_card_pin_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    pins: Query<&ComponentCardPinButton>,
+) {
+    if let Ok(pin) = pins.get(activate.entity) {
+        let pinned = &mut state.pinned_component_cards;
+        match pinned.iter().position(|type_id| *type_id == pin.0) {
+            Some(index) => {
+                pinned.remove(index);
+            }
+            None => pinned.push(pin.0),
+        }
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for despawn button clicks. Queues the despawn as a [`StructuralChange`].
+///
+/// Protected entities need a second click, which confirms the despawn.
+/// With several entities selected, all of them are despawned,
+/// though only the shown entity's protection can be confirmed.
+fn on_despawn_click(
+    activate: On<Activate>,
+    buttons: Query<&DespawnButton>,
+    mut state: ResMut<InspectorState>,
+    mut pending: ResMut<PendingStructuralChanges>,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let entity = button.0;
+    let confirmed = state.despawn_confirmation == Some(entity);
+    state.despawn_confirmation = None;
+    state.despawn_notice = None;
+    for &target in state.batch_edit_targets(entity) {
+        if target != entity {
+            pending.push(StructuralChange::Despawn {
+                entity: target,
+                confirmed: false,
+            });
+        }
+    }
+    pending.push(StructuralChange::Despawn { entity, confirmed });
+}
+
+/// Observer: deselects despawned entities, and explains why the selected entity was not
+/// despawned, asking for confirmation if it is protected.
+pub(crate) fn report_despawn_results(
+    applied: On<StructuralChangesApplied>,
+    mut state: ResMut<InspectorState>,
+) {
+    for (change, result) in &applied.results {
+        let StructuralChange::Despawn { entity, .. } = *change else {
+            continue;
+        };
+        match result {
+            Ok(()) => {
+                if state.selected_entity == Some(entity) {
+                    state.selected_entity = None;
+                }
+                state
+                    .selected_entities
+                    .retain(|&selected| selected != entity);
+            }
+            Err(error) if state.selected_entity == Some(entity) => {
+                if matches!(
+                    error,
+                    StructuralChangeError::Despawn(DespawnError::ConfirmationRequired { .. })
+                ) {
+                    state.despawn_confirmation = Some(entity);
+                }
+                state.despawn_notice = Some((entity, error.to_string()));
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// Observer for the dump button. Logs the same components and values as the cards.
+fn on_dump_to_log_click(
+    activate: On<Activate>,
+    buttons: Query<&DumpToLogButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    commands
+        .entity(button.0)
+        .inspect(EntityInspectionSettings::default());
+}
+
+/// Observer for the baseline buttons of the selected entity.
+fn on_baseline_button_click(
+    activate: On<Activate>,
+    buttons: Query<&BaselineButton>,
+    mut commands: Commands,
+) {
+    let Ok(&button) = buttons.get(activate.entity) else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        let Some(entity) = world.resource::<InspectorState>().selected_entity else {
+            return;
+        };
+        match button {
+            BaselineButton::Capture => {
+                world.resource_scope(|world, mut baselines: Mut<EntityBaselines>| {
+                    baselines.capture(world, entity);
+                });
+            }
+            BaselineButton::ToggleCompare => {
+                let mut state = world.resource_mut::<InspectorState>();
+                state.compare_to_baseline = !state.compare_to_baseline;
+            }
+        }
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+/// Observer for entity link clicks (navigate to the referenced entity).
+fn on_entity_link_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    links: Query<&EntityLink>,
+) {
+    if let Ok(link) = links.get(activate.entity) {
+        state.selected_entity = Some(link.0);
+        state.selected_entities.clear();
+    }
+}
+
+/// Observer for asset select button clicks.
+fn on_asset_select_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    buttons: Query<&AssetSelectButton>,
+) {
+    if let Ok(button) = buttons.get(activate.entity) {
+        state.selected_asset = Some(button.0);
+    }
+}
+
+/// Observer for hex display toggle clicks.
+fn on_hex_display_toggle_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    toggles: Query<&HexDisplayToggle>,
+) {
+    if let Ok(toggle) = toggles.get(activate.entity) {
+        if !state.hex_fields.remove(&toggle.0) {
+            state.hex_fields.insert(toggle.0.clone());
+        }
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for lock toggle clicks. Unlocks or locks a dangerous value for editing.
+fn on_field_lock_toggle_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    toggles: Query<&FieldLockToggle>,
+) {
+    if let Ok(toggle) = toggles.get(activate.entity) {
+        if !state.unlocked_fields.remove(&toggle.0) {
+            state.unlocked_fields.insert(toggle.0.clone());
+        }
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for nested field toggle clicks. Shows or hides the fields of the nested value.
+fn on_nested_field_toggle_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    toggles: Query<&NestedFieldToggle>,
+) {
+    if let Ok(toggle) = toggles.get(activate.entity) {
+        if !state.expanded_structs.remove(&toggle.0) {
+            state.expanded_structs.insert(toggle.0.clone());
+        }
+        cache.detail_stale = true;
+    }
+}
+
+/// System that forgets the expanded and toggled fields of despawned entities,
+/// so [`InspectorState`] doesn't grow with every entity that was ever inspected.
+pub(crate) fn prune_field_display_state(entities: &Entities, mut state: ResMut<InspectorState>) {
+    let exists = |path: &FieldPath| path.owner.entity().is_none_or(|e| entities.contains(e));
+    let stale = [
+        &state.expanded_lists,
+        &state.expanded_structs,
+        &state.decomposed_matrices,
+        &state.hex_fields,
+        &state.unlocked_fields,
+        &state.linked_vectors,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(&state.tween_field)
+    .any(|path| !exists(path));
+    // Only touch the state when something is stale, so it isn't marked as changed every frame
+    if stale {
+        state.retain_field_paths(exists);
+    }
+}
+
+/// The serialized RON sizes of the inspected entity's components,
+/// so rebuilding the detail panel only serializes the components that changed since.
+#[derive(Resource, Default)]
+pub struct SerializedSizeCache {
+    /// The entity whose components the sizes are of.
+    entity: Option<Entity>,
+    /// The size of each component, with the change tick of the component it was computed at.
+    sizes: HashMap<TypeId, (Tick, Option<MemorySize>)>,
+}
+
+impl SerializedSizeCache {
+    /// Returns the serialized size of the component of `entity` with type `type_id`,
+    /// serializing it again only if it changed since its size was last computed.
+    fn get_or_compute(
+        &mut self,
+        world: &World,
+        entity: Entity,
+        type_id: TypeId,
+    ) -> Option<MemorySize> {
+        if self.entity != Some(entity) {
+            self.entity = Some(entity);
+            self.sizes.clear();
+        }
+        let entity_ref = world.get_entity(entity).ok()?;
+        let component_id = world.components().get_id(type_id)?;
+        let changed = entity_ref.get_change_ticks_by_id(component_id)?.changed;
+        if let Some(&(tick, size)) = self.sizes.get(&type_id)
+            && tick == changed
+        {
+            return size;
+        }
+        let reflected = get_reflected_component_ref(world, entity, type_id).ok()?;
+        let size = serialized_ron_size(reflected, &world.resource::<AppTypeRegistry>().read());
+        self.sizes.insert(type_id, (changed, size));
+        size
+    }
+}
+
+/// Observer: forgets the serialized size of components edited in the inspector,
+/// as edits are written without marking the component as changed.
+pub(crate) fn forget_edited_serialized_sizes(
+    edit: On<InspectorFieldEdited>,
+    mut cache: ResMut<SerializedSizeCache>,
+) {
+    if edit.owner.entity() == cache.entity {
+        cache.sizes.remove(&edit.component_type_id);
+    }
+}
+
+/// Observer for repair button clicks. Writes zero to each NaN or infinite number of the field.
+fn on_repair_non_finite_click(
+    activate: On<Activate>,
+    buttons: Query<&RepairNonFiniteButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let field_paths = button.0.clone();
+    commands.queue(move |world: &mut World| {
+        let mut pending = world.resource_mut::<PendingValueChanges>();
+        for field_path in field_paths {
+            pending.push(PendingValueChange {
+                field_path,
+                value: FieldValue::Numeric(0.0),
+            });
+        }
+        apply_pending_value_changes(world);
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+/// Spawns the button expanding the nested value at `field_path`, labeled with its type name.
+fn spawn_nested_field_toggle(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    type_label: &str,
+    expanded: bool,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                ..default()
+            },
+            bevy::ui_widgets::Button,
+            Tooltip(if expanded { "Collapse" } else { "Expand" }.to_string()),
+            NestedFieldToggle(field_path),
+            observe(on_nested_field_toggle_click),
+        ))
+        .with_child((
+            Text::new(format!(
+                "{} {}",
+                if expanded { "v" } else { ">" },
+                type_label
+            )),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(Color::srgba(0.7, 0.7, 0.7, 1.0)),
+        ));
+}
+
+/// Spawns the button switching the integer field at `field_path` between decimal and hex.
+fn spawn_hex_display_toggle(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    hex: bool,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                margin: UiRect::left(Px(4.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            bevy::ui_widgets::Button,
+            Tooltip(
+                if hex {
+                    "Show as decimal"
+                } else {
+                    "Show as hexadecimal"
+                }
+                .to_string(),
+            ),
+            HexDisplayToggle(field_path),
+            observe(on_hex_display_toggle_click),
+        ))
+        .with_child((
+            Text::new(if hex { "dec" } else { "hex" }),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+        ));
+}
+
+/// Spawns the button unlocking or locking the dangerous value at `field_path`.
+fn spawn_field_lock_toggle(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    locked: bool,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            bevy::ui_widgets::Button,
+            Tooltip(
+                if locked {
+                    "Dangerous to edit: unlock to edit anyway"
+                } else {
+                    "Lock to prevent accidental edits"
+                }
+                .to_string(),
+            ),
+            FieldLockToggle(field_path),
+            observe(on_field_lock_toggle_click),
+        ))
+        .with_child((
+            Text::new(if locked { "locked" } else { "unlocked" }),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(if locked {
+                Color::srgba(0.5, 0.5, 0.5, 1.0)
+            } else {
+                Color::srgba(1.0, 0.6, 0.3, 1.0)
+            }),
+        ));
+}
+
 /// Observer for hierarchy node clicks (navigate to parent/child).
 fn on_hierarchy_node_click(
     activate: On<Activate>,
@@ -63,6 +599,7 @@ fn on_hierarchy_node_click(
 ) {
     if let Ok(node) = nodes.get(activate.entity) {
         state.selected_entity = Some(node.0);
+        state.selected_entities.clear();
     }
 }
 
@@ -76,11 +613,12 @@ pub fn sync_detail_panel(world: &mut World) {
     let active_tab = state.active_tab;
     let previous_selection = state.previous_selection;
     let previous_tab = state.previous_tab;
+    let detail_stale = world.resource::<InspectorCache>().detail_stale;
 
     // Skip if nothing has changed
     let selection_changed = selected_entity != previous_selection;
     let tab_changed = active_tab != previous_tab;
-    if !selection_changed && !tab_changed {
+    if !selection_changed && !tab_changed && !detail_stale {
         return;
     }
 
@@ -90,6 +628,7 @@ pub fn sync_detail_panel(world: &mut World) {
         state.previous_selection = selected_entity;
         state.previous_tab = active_tab;
     }
+    world.resource_mut::<InspectorCache>().detail_stale = false;
 
     // Find the detail content entity
     let mut query = world.query_filtered::<Entity, With<DetailContent>>();
@@ -114,16 +653,62 @@ pub fn sync_detail_panel(world: &mut World) {
 
     // Get config (clone values we need)
     let config = world.resource::<InspectorConfig>().clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+
+    // Why the selection just moved, until the notice expires
+    if let Some(notice) = world.resource::<InspectorState>().selection_notice.clone() {
+        world.entity_mut(content_entity).with_child((
+            Text::new(notice),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+            Node {
+                margin: UiRect::bottom(Px(8.0)),
+                ..default()
+            },
+        ));
+    }
+
+    // World-level tabs don't depend on the selection
+    match active_tab {
+        DetailTab::States => {
+            spawn_states_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        DetailTab::Systems => {
+            spawn_systems_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        DetailTab::Time => {
+            spawn_time_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        DetailTab::Validation => {
+            spawn_validation_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        DetailTab::Resources => {
+            spawn_resources_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        DetailTab::Notes => {
+            spawn_notes_tab_exclusive(world, content_entity, &config);
+            return;
+        }
+        _ => {}
+    }
 
     // Show empty state if no entity selected
     let Some(entity) = selected_entity else {
-        spawn_empty_state_exclusive(world, content_entity, &config, "Select an entity to view details");
+        spawn_empty_state_exclusive(world, content_entity, &config, &strings.no_selection);
         return;
     };
 
     // Check if entity still exists
     if !world.entities().contains(entity) {
-        spawn_error_state_exclusive(world, content_entity, &config, "Selected entity no longer exists");
+        spawn_error_state_exclusive(world, content_entity, &config, &strings.selection_despawned);
         return;
     }
 
@@ -151,6 +736,18 @@ pub fn sync_detail_panel(world: &mut World) {
                 spawn_relationships_tab_exclusive(world, content_entity, entity, mm, &config);
             }
         }
+        DetailTab::Timeline => {
+            if let Some(ref mm) = metadata_map {
+                spawn_timeline_tab_exclusive(world, content_entity, entity, mm, &config);
+            }
+        }
+        // Handled above, before the selection is checked
+        DetailTab::States
+        | DetailTab::Systems
+        | DetailTab::Time
+        | DetailTab::Validation
+        | DetailTab::Resources
+        | DetailTab::Notes => {}
     }
 
     // Put metadata_map back
@@ -214,30 +811,135 @@ fn spawn_error_state_exclusive(
 }
 
 /// Represents a field extracted from a reflected component
+#[derive(Default)]
 struct ReflectedField {
     name: String,
     value: String,
     indent: u8,
-    /// If this is an editable numeric field, contains the numeric value and path segments
-    editable: Option<EditableFieldInfo>,
+    /// Path segments to reach this field's value from the component root, if addressable
+    path: Option<Vec<FieldPathSegment>>,
+    /// How the value is edited or displayed, beyond its text
+    role: FieldRole,
+    /// If this is a list element, its position in the list, for its reorder and remove buttons.
+    /// Elements can have a role of their own, e.g. lists of lists, so this is kept apart from it
+    list_element: Option<ListElement>,
+    /// Whether the value differs from the component's `ReflectDefault` value
+    differs_from_default: bool,
+    /// Whether the value changed since the entity's baseline was captured, while comparing
+    changed_since_baseline: bool,
+    /// Paths of the NaN or infinite numbers in this field, which can be reset to zero
+    non_finite: Vec<Vec<FieldPathSegment>>,
+}
+
+/// How the value of a [`ReflectedField`] is edited or displayed, beyond its text.
+#[derive(Default)]
+enum FieldRole {
+    /// Read-only text.
+    #[default]
+    Text,
+    /// An editable number, edited with a slider if its range is known.
+    Numeric(EditableFieldInfo),
+    /// An editable color, with the field's type.
+    Color(Color, ColorFieldType),
+    /// A math vector, whose axes are edited in a single row.
+    Vector(Vec<VectorAxis>),
+    /// A quaternion, whose components are edited like a vector's but never snap;
+    /// its Euler angles follow as a nested field.
+    Quaternion(Vec<VectorAxis>),
+    /// The Euler angles of the quaternion at the field's path, in degrees.
+    EulerAngles(Vec3),
+    /// A matrix or affine transform, with its values laid out as a grid.
+    Matrix(MatrixGrid),
+    /// An [`Entity`], shown as a link that selects it.
+    EntityLink(Entity),
+    /// An asset [`Handle`], with the id of the referenced asset.
+    Asset(UntypedAssetId),
+    /// An [`Option`], with whether it is `Some`; the inner value follows as a nested field.
+    Option(bool),
+    /// A list, with its length and whether its elements are shown.
+    List { len: usize, expanded: bool },
+    /// A set, with its size, whether its members are shown,
+    /// and whether members can be inserted and removed.
+    Set {
+        len: usize,
+        expanded: bool,
+        editable: bool,
+    },
+    /// A member of the set at `set_path`, with a copy of it if it can be removed.
+    SetMember {
+        set_path: Vec<FieldPathSegment>,
+        removable: Option<Box<dyn PartialReflect>>,
+    },
+    /// An integer with named bits, edited as checkboxes.
+    Flags(u64, Vec<FlagBit>),
+    /// A [`Timer`] or [`Stopwatch`](bevy::time::Stopwatch), with whether it is paused.
+    Timer(bool),
+    /// A value with an editor registered in [`InspectorWidgetRegistry`],
+    /// with a copy of the value to pass to it.
+    Widget(Box<dyn PartialReflect>),
+    /// A nested struct or enum, with whether its fields are shown below it.
+    Nested(bool),
+}
+
+impl FieldRole {
+    /// Returns whether this role lets the value be edited,
+    /// rather than only displayed or navigated.
+    fn edits_value(&self) -> bool {
+        !matches!(
+            self,
+            FieldRole::Text | FieldRole::EntityLink(_) | FieldRole::Asset(_) | FieldRole::Nested(_)
+        )
+    }
+}
+
+/// The element at `index` of a list of length `len`.
+#[derive(Clone, Copy)]
+struct ListElement {
+    index: usize,
+    len: usize,
 }
 
 /// Information needed to make a field editable
 struct EditableFieldInfo {
     /// The numeric value (as f64 for generality)
     numeric_value: f64,
+    /// The exact value of an integer field, which `numeric_value` rounds beyond 2^53
+    integer_value: Option<i128>,
     /// Path segments to reach this field from the component root
     path: Vec<FieldPathSegment>,
+    /// The field's value range, if known; such fields are edited with a slider
+    range: Option<RangeInclusive<f64>>,
+    /// How the value is displayed, e.g. without decimals for integers
+    format: DragValueFormat,
+    /// Whether the value can be toggled to hexadecimal display
+    hex_capable: bool,
+}
+
+/// Returns the `(indent, name, value)` rows the components tab shows for `value`,
+/// e.g. to preview a component before it is inserted.
+pub(crate) fn preview_field_rows(
+    value: &dyn PartialReflect,
+    semantic_names: &SemanticFieldNames,
+) -> Vec<(u8, String, String)> {
+    let mut fields = Vec::new();
+    extract_fields_from_reflect(value, &mut fields, 0, semantic_names, &HashSet::new(), &[]);
+    fields
+        .into_iter()
+        .map(|field| (field.indent, field.name, field.value))
+        .collect()
 }
 
 /// Extracts fields from a reflected value into a flat list of label/value pairs.
 /// Uses `SemanticFieldNames` to provide better field names for tuple structs (e.g., x/y/z instead of .0/.1/.2).
 /// Tracks the path to each field for write-back support.
+///
+/// Lists and nested structs are only recursed into if their paths are in `expanded_fields`.
 fn extract_fields_from_reflect(
     reflected: &dyn PartialReflect,
     fields: &mut Vec<ReflectedField>,
     indent: u8,
     semantic_names: &SemanticFieldNames,
+    expanded_fields: &HashSet<Vec<FieldPathSegment>>,
     current_path: &[FieldPathSegment],
 ) {
     // Get the TypeId of this reflected value for semantic name lookup
@@ -245,6 +947,24 @@ fn extract_fields_from_reflect(
         .get_represented_type_info()
         .map(|info| info.type_id());
 
+    // Math types reflected as opaque values expose the same fields as their struct reflection
+    if let Some(math_fields) = opaque_math_fields(reflected) {
+        for (field_name, field_value) in math_fields {
+            let mut field_path = current_path.to_vec();
+            field_path.push(FieldPathSegment::Named(field_name.to_string()));
+            push_field(
+                field_name.to_string(),
+                field_value,
+                field_path,
+                fields,
+                indent,
+                semantic_names,
+                expanded_fields,
+            );
+        }
+        return;
+    }
+
     match reflected.reflect_ref() {
         ReflectRef::Struct(s) => {
             for i in 0..s.field_len() {
@@ -255,40 +975,15 @@ fn extract_fields_from_reflect(
                 let mut field_path = current_path.to_vec();
                 field_path.push(FieldPathSegment::Named(field_name.to_string()));
 
-                // Check if this is an editable numeric field
-                let editable = try_extract_numeric(field_value).map(|num| EditableFieldInfo {
-                    numeric_value: num,
-                    path: field_path.clone(),
-                });
-
-                let value_str = format_simple_value(field_value);
-                if let Some(val) = value_str {
-                    fields.push(ReflectedField {
-                        name: field_name.to_string(),
-                        value: val,
-                        indent,
-                        editable,
-                    });
-                } else {
-                    // Complex nested type - add header and recurse
-                    let type_name = field_value
-                        .get_represented_type_info()
-                        .map(|t| ShortName::from(t.type_path()).to_string())
-                        .unwrap_or_else(|| "?".to_string());
-                    fields.push(ReflectedField {
-                        name: field_name.to_string(),
-                        value: format!("[{}]", type_name),
-                        indent,
-                        editable: None,
-                    });
-                    extract_fields_from_reflect(
-                        field_value,
-                        fields,
-                        indent + 1,
-                        semantic_names,
-                        &field_path,
-                    );
-                }
+                push_field(
+                    field_name.to_string(),
+                    field_value,
+                    field_path,
+                    fields,
+                    indent,
+                    semantic_names,
+                    expanded_fields,
+                );
             }
         }
         ReflectRef::TupleStruct(ts) => {
@@ -299,45 +994,21 @@ fn extract_fields_from_reflect(
                 let mut field_path = current_path.to_vec();
                 field_path.push(FieldPathSegment::Index(i));
 
-                // Check if this is an editable numeric field
-                let editable = try_extract_numeric(field_value).map(|num| EditableFieldInfo {
-                    numeric_value: num,
-                    path: field_path.clone(),
-                });
-
                 // Try to get semantic name (e.g., "x", "y", "z") for this field index
                 let field_name = type_id
                     .and_then(|tid| semantic_names.get_field_name(tid, i))
                     .map(|s| s.to_string())
                     .unwrap_or_else(|| format!(".{}", i));
 
-                let value_str = format_simple_value(field_value);
-                if let Some(val) = value_str {
-                    fields.push(ReflectedField {
-                        name: field_name,
-                        value: val,
-                        indent,
-                        editable,
-                    });
-                } else {
-                    let type_name = field_value
-                        .get_represented_type_info()
-                        .map(|t| ShortName::from(t.type_path()).to_string())
-                        .unwrap_or_else(|| "?".to_string());
-                    fields.push(ReflectedField {
-                        name: field_name,
-                        value: format!("[{}]", type_name),
-                        indent,
-                        editable: None,
-                    });
-                    extract_fields_from_reflect(
-                        field_value,
-                        fields,
-                        indent + 1,
-                        semantic_names,
-                        &field_path,
-                    );
-                }
+                push_field(
+                    field_name,
+                    field_value,
+                    field_path,
+                    fields,
+                    indent,
+                    semantic_names,
+                    expanded_fields,
+                );
             }
         }
         ReflectRef::Enum(e) => {
@@ -348,7 +1019,8 @@ fn extract_fields_from_reflect(
                         name: "variant".to_string(),
                         value: variant_name.to_string(),
                         indent,
-                        editable: None,
+                        path: Some(current_path.to_vec()),
+                        ..Default::default()
                     });
                 }
                 VariantType::Tuple => {
@@ -356,7 +1028,8 @@ fn extract_fields_from_reflect(
                         name: "variant".to_string(),
                         value: variant_name.to_string(),
                         indent,
-                        editable: None,
+                        path: Some(current_path.to_vec()),
+                        ..Default::default()
                     });
                     for i in 0..e.field_len() {
                         let field_value = e.field_at(i).unwrap();
@@ -366,7 +1039,9 @@ fn extract_fields_from_reflect(
                                 name: format!(".{}", i),
                                 value: val,
                                 indent: indent + 1,
-                                editable: None, // TODO: enum field editing
+                                // TODO: enum field editing
+                                role: plain_value_role(field_value),
+                                ..Default::default()
                             });
                         }
                     }
@@ -376,7 +1051,8 @@ fn extract_fields_from_reflect(
                         name: "variant".to_string(),
                         value: variant_name.to_string(),
                         indent,
-                        editable: None,
+                        path: Some(current_path.to_vec()),
+                        ..Default::default()
                     });
                     for i in 0..e.field_len() {
                         let field_name = e.name_at(i).unwrap_or("?");
@@ -387,7 +1063,9 @@ fn extract_fields_from_reflect(
                                 name: field_name.to_string(),
                                 value: val,
                                 indent: indent + 1,
-                                editable: None, // TODO: enum field editing
+                                // TODO: enum field editing
+                                role: plain_value_role(field_value),
+                                ..Default::default()
                             });
                         }
                     }
@@ -401,41 +1079,583 @@ fn extract_fields_from_reflect(
                     name: "value".to_string(),
                     value: val,
                     indent,
-                    editable: None,
+                    path: Some(current_path.to_vec()),
+                    ..Default::default()
                 });
             }
         }
     }
 }
 
-/// Tries to extract a numeric value from a reflected type.
-/// Returns the value as f64 if it's a supported numeric type.
-fn try_extract_numeric(reflected: &dyn PartialReflect) -> Option<f64> {
-    // Try f32
-    if let Some(val) = reflected.try_downcast_ref::<f32>() {
-        return Some(*val as f64);
-    }
-    // Try f64
-    if let Some(val) = reflected.try_downcast_ref::<f64>() {
-        return Some(*val);
-    }
-    // Try i32
-    if let Some(val) = reflected.try_downcast_ref::<i32>() {
-        return Some(*val as f64);
-    }
-    // Try i64
-    if let Some(val) = reflected.try_downcast_ref::<i64>() {
-        return Some(*val as f64);
+/// Adds a single named field, recursing into complex values under a type-name header.
+fn push_field(
+    field_name: String,
+    field_value: &dyn PartialReflect,
+    field_path: Vec<FieldPathSegment>,
+    fields: &mut Vec<ReflectedField>,
+    indent: u8,
+    semantic_names: &SemanticFieldNames,
+    expanded_fields: &HashSet<Vec<FieldPathSegment>>,
+) {
+    // Colors get a color picker instead of a nested enum or struct
+    if let Some((color, field_type)) = color_from_reflect(field_value) {
+        fields.push(ReflectedField {
+            name: field_name,
+            value: color.to_srgba().to_hex(),
+            indent,
+            path: Some(field_path),
+            role: FieldRole::Color(color, field_type),
+            ..Default::default()
+        });
+        return;
     }
-    // Try u32
-    if let Some(val) = reflected.try_downcast_ref::<u32>() {
-        return Some(*val as f64);
+
+    // Asset handles are labeled with the asset's path and load state, see `label_asset_handles`
+    if let Some(type_info) = field_value.get_represented_type_info()
+        && type_info.type_path().starts_with(HANDLE_TYPE_PATH_PREFIX)
+    {
+        fields.push(ReflectedField {
+            name: field_name,
+            value: ShortName::from(type_info.type_path()).to_string(),
+            indent,
+            path: Some(field_path),
+            ..Default::default()
+        });
+        return;
+    }
+
+    // Options get a Some/None toggle, with the inner value nested below while it is `Some`
+    if let Some(is_some) = option_is_some(field_value) {
+        fields.push(ReflectedField {
+            name: field_name,
+            value: if is_some { "Some" } else { "None" }.to_string(),
+            indent,
+            path: Some(field_path.clone()),
+            role: FieldRole::Option(is_some),
+            ..Default::default()
+        });
+        if let ReflectRef::Enum(e) = field_value.reflect_ref()
+            && let Some(inner) = e.field_at(0)
+        {
+            let mut inner_path = field_path;
+            inner_path.push(FieldPathSegment::Index(0));
+            push_field(
+                "value".to_string(),
+                inner,
+                inner_path,
+                fields,
+                indent + 1,
+                semantic_names,
+                expanded_fields,
+            );
+        }
+        return;
+    }
+
+    // Timers show their elapsed and remaining time, with pause and reset buttons
+    if let Some((summary, paused)) = timer_summary(field_value) {
+        fields.push(ReflectedField {
+            name: field_name,
+            value: summary,
+            indent,
+            path: Some(field_path.clone()),
+            role: FieldRole::Timer(paused),
+            ..Default::default()
+        });
+        // The duration and mode of a timer stay editable; its stopwatch is controlled above
+        if let ReflectRef::Struct(timer) = field_value.reflect_ref() {
+            for name in ["duration", "mode"] {
+                let Some(value) = timer.field(name) else {
+                    continue;
+                };
+                let mut child_path = field_path.clone();
+                child_path.push(FieldPathSegment::Named(name.to_string()));
+                push_field(
+                    name.to_string(),
+                    value,
+                    child_path,
+                    fields,
+                    indent + 1,
+                    semantic_names,
+                    expanded_fields,
+                );
+            }
+        }
+        return;
+    }
+
+    // Matrices are edited as a grid instead of one nested row per axis
+    if let Some(grid) = matrix_grid(field_value) {
+        let non_finite = grid
+            .rows
+            .iter()
+            .flatten()
+            .filter(|cell| !cell.value.is_finite())
+            .map(|cell| [field_path.as_slice(), &cell.path].concat())
+            .collect();
+        fields.push(ReflectedField {
+            name: field_name,
+            value: format!("[{}]", grid.column_labels.join(", ")),
+            indent,
+            path: Some(field_path),
+            role: FieldRole::Matrix(grid),
+            non_finite,
+            ..Default::default()
+        });
+        return;
+    }
+
+    if let Some(axes) = vector_axes(field_value, semantic_names) {
+        let values: Vec<String> = axes
+            .iter()
+            .map(|axis| format!("{:.2}", axis.value))
+            .collect();
+        let non_finite = axes
+            .iter()
+            .filter(|axis| !axis.value.is_finite())
+            .map(|axis| [field_path.as_slice(), std::slice::from_ref(&axis.segment)].concat())
+            .collect();
+        let euler_degrees = quat_euler_degrees(field_value);
+        fields.push(ReflectedField {
+            name: field_name,
+            value: format!("({})", values.join(", ")),
+            indent,
+            path: Some(field_path.clone()),
+            role: if euler_degrees.is_some() {
+                FieldRole::Quaternion(axes)
+            } else {
+                FieldRole::Vector(axes)
+            },
+            non_finite,
+            ..Default::default()
+        });
+        if let Some(degrees) = euler_degrees {
+            fields.push(ReflectedField {
+                name: "euler (deg)".to_string(),
+                value: format!("({:.1}, {:.1}, {:.1})", degrees.x, degrees.y, degrees.z),
+                indent: indent + 1,
+                path: Some(field_path),
+                role: FieldRole::EulerAngles(degrees),
+                ..Default::default()
+            });
+        }
+        return;
+    }
+
+    // Lists can be expanded into one row per element, with buttons for structural edits
+    if let ReflectRef::List(list) = field_value.reflect_ref() {
+        let len = list.len();
+        let expanded = expanded_fields.contains(&field_path);
+        // The NaN and infinities of hidden elements are flagged on the list's row
+        let mut non_finite = Vec::new();
+        if !expanded {
+            collect_non_finite_paths(field_value, &field_path, &mut non_finite);
+        }
+        fields.push(ReflectedField {
+            name: field_name,
+            value: format!("[{} items]", len),
+            indent,
+            path: Some(field_path.clone()),
+            role: FieldRole::List { len, expanded },
+            non_finite,
+            ..Default::default()
+        });
+        if expanded {
+            for (index, element) in list.iter().enumerate() {
+                let mut element_path = field_path.clone();
+                element_path.push(FieldPathSegment::ListIndex(index));
+                let element_row = fields.len();
+                push_field(
+                    format!("[{}]", index),
+                    element,
+                    element_path,
+                    fields,
+                    indent + 1,
+                    semantic_names,
+                    expanded_fields,
+                );
+                fields[element_row].list_element = Some(ListElement { index, len });
+            }
+        }
+        return;
+    }
+
+    // Sets can be expanded into one read-only row per member, sorted for a stable order
+    if let ReflectRef::Set(set) = field_value.reflect_ref() {
+        let len = set.len();
+        let expanded = expanded_fields.contains(&field_path);
+        let editable = set_has_primitive_members(field_value);
+        fields.push(ReflectedField {
+            name: field_name,
+            value: format!("{{{} items}}", len),
+            indent,
+            path: Some(field_path.clone()),
+            role: FieldRole::Set {
+                len,
+                expanded,
+                editable,
+            },
+            ..Default::default()
+        });
+        if expanded {
+            let mut members: Vec<_> = set
+                .iter()
+                .map(|member| {
+                    let value = format_simple_value(member).unwrap_or_else(|| {
+                        truncate_middle(&format!("{:?}", member), SUMMARY_MAX_CHARS).into_owned()
+                    });
+                    (value, member)
+                })
+                .collect();
+            members.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (value, member) in members {
+                fields.push(ReflectedField {
+                    name: "-".to_string(),
+                    value,
+                    indent: indent + 1,
+                    role: FieldRole::SetMember {
+                        set_path: field_path.clone(),
+                        removable: editable.then(|| clone_reflected(member)),
+                    },
+                    ..Default::default()
+                });
+            }
+        }
+        return;
     }
-    // Try u64
-    if let Some(val) = reflected.try_downcast_ref::<u64>() {
-        return Some(*val as f64);
+
+    if let Some(val) = format_simple_value(field_value) {
+        // Check if this is an editable numeric field
+        let editable = try_extract_numeric(field_value).map(|num| EditableFieldInfo {
+            numeric_value: num,
+            integer_value: try_extract_exact_integer(field_value),
+            path: field_path.clone(),
+            range: None,
+            format: numeric_format(field_value),
+            hex_capable: supports_hex_display(field_value),
+        });
+        // NaN and infinities, e.g. of exploded physics, are flagged for repair
+        let non_finite = editable
+            .as_ref()
+            .filter(|editable| !editable.numeric_value.is_finite())
+            .map(|editable| vec![editable.path.clone()])
+            .unwrap_or_default();
+        fields.push(ReflectedField {
+            name: field_name,
+            value: val,
+            indent,
+            path: Some(field_path),
+            role: editable.map_or_else(|| plain_value_role(field_value), FieldRole::Numeric),
+            non_finite,
+            ..Default::default()
+        });
+    } else {
+        // Complex nested type - add a collapsible header, recursing only once it is expanded
+        let expanded = expanded_fields.contains(&field_path);
+        let type_name = field_value
+            .get_represented_type_info()
+            .map(|t| ShortName::from(t.type_path()).to_string())
+            .unwrap_or_else(|| "?".to_string());
+        // The NaN and infinities of hidden fields are flagged on the collapsed header
+        let mut non_finite = Vec::new();
+        if !expanded {
+            collect_non_finite_paths(field_value, &field_path, &mut non_finite);
+        }
+        fields.push(ReflectedField {
+            name: field_name,
+            value: format!("[{}]", type_name),
+            indent,
+            path: Some(field_path.clone()),
+            role: FieldRole::Nested(expanded),
+            non_finite,
+            ..Default::default()
+        });
+        if !expanded {
+            return;
+        }
+        extract_fields_from_reflect(
+            field_value,
+            fields,
+            indent + 1,
+            semantic_names,
+            expanded_fields,
+            &field_path,
+        );
+    }
+}
+
+/// Appends the paths of the NaN or infinite numbers anywhere within `value` to `paths`.
+fn collect_non_finite_paths(
+    value: &dyn PartialReflect,
+    path: &[FieldPathSegment],
+    paths: &mut Vec<Vec<FieldPathSegment>>,
+) {
+    if let Some(number) = try_extract_numeric(value) {
+        if !number.is_finite() {
+            paths.push(path.to_vec());
+        }
+        return;
+    }
+    let mut visit = |segment: FieldPathSegment, field: &dyn PartialReflect| {
+        collect_non_finite_paths(
+            field,
+            &[path, std::slice::from_ref(&segment)].concat(),
+            paths,
+        );
+    };
+    if let Some(math_fields) = opaque_math_fields(value) {
+        for (name, field) in math_fields {
+            visit(FieldPathSegment::Named(name.to_string()), field);
+        }
+        return;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            for (i, field) in s.iter_fields().enumerate() {
+                let name = s.name_at(i).unwrap_or_default();
+                visit(FieldPathSegment::Named(name.to_string()), field);
+            }
+        }
+        ReflectRef::TupleStruct(ts) => {
+            for (i, field) in ts.iter_fields().enumerate() {
+                visit(FieldPathSegment::Index(i), field);
+            }
+        }
+        ReflectRef::Tuple(t) => {
+            for (i, field) in t.iter_fields().enumerate() {
+                visit(FieldPathSegment::Index(i), field);
+            }
+        }
+        ReflectRef::List(list) => {
+            for (i, element) in list.iter().enumerate() {
+                visit(FieldPathSegment::ListIndex(i), element);
+            }
+        }
+        ReflectRef::Array(array) => {
+            for (i, element) in array.iter().enumerate() {
+                visit(FieldPathSegment::ListIndex(i), element);
+            }
+        }
+        ReflectRef::Enum(e) => {
+            for (i, field) in e.iter_fields().enumerate() {
+                visit(FieldPathSegment::Index(i), field.value());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the role of a value without an editor: a link if it is an [`Entity`], or else text.
+fn plain_value_role(value: &dyn PartialReflect) -> FieldRole {
+    value
+        .try_downcast_ref::<Entity>()
+        .map_or(FieldRole::Text, |&entity| FieldRole::EntityLink(entity))
+}
+
+/// Creates the default value of a type, if it registers [`ReflectDefault`].
+fn reflect_default_value(world: &World, type_id: TypeId) -> Option<Box<dyn Reflect>> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_default = registry.get_type_data::<ReflectDefault>(type_id)?;
+    Some(reflect_default.default())
+}
+
+/// Returns whether the field at `path` differs between `value` and `other`.
+/// Fields that cannot be located or compared don't differ.
+fn field_differs(
+    value: &dyn PartialReflect,
+    other: &dyn PartialReflect,
+    path: &[FieldPathSegment],
+) -> bool {
+    let (Some(current), Some(other)) = (field_at_path(value, path), field_at_path(other, path))
+    else {
+        return false;
+    };
+    current.reflect_partial_eq(other) == Some(false)
+}
+
+/// Marks the fields whose value differs from the same field of `default_value`.
+fn mark_fields_differing_from_default(
+    fields: &mut [ReflectedField],
+    value: &dyn PartialReflect,
+    default_value: &dyn PartialReflect,
+) {
+    for field in fields {
+        if let Some(path) = &field.path {
+            field.differs_from_default = field_differs(value, default_value, path);
+        }
+    }
+}
+
+/// Marks the fields whose value differs from the same field of the component's baseline value.
+fn mark_fields_changed_since_baseline(
+    fields: &mut [ReflectedField],
+    value: &dyn PartialReflect,
+    baseline_value: &dyn PartialReflect,
+) {
+    for field in fields {
+        if let Some(path) = &field.path {
+            field.changed_since_baseline = field_differs(value, baseline_value, path);
+        }
+    }
+}
+
+/// Labels entity links with the [`Name`] of the referenced entity,
+/// and turns links to entities that no longer exist back into plain values.
+fn label_entity_links(fields: &mut [ReflectedField], world: &World) {
+    for field in fields {
+        let FieldRole::EntityLink(target) = field.role else {
+            continue;
+        };
+        if !world.entities().contains(target) {
+            field.role = FieldRole::Text;
+            field.value = format!("{} (despawned)", target);
+        } else if let Some(name) = world.get::<Name>(target) {
+            field.value = format!("{} ({})", name, target);
+        } else {
+            field.value = target.to_string();
+        }
+    }
+}
+
+/// Labels asset [`Handle`] fields with the path and [`LoadState`] of the referenced asset.
+///
+/// Requires the handle type to be registered with [`ReflectHandle`],
+/// which `App::register_asset_reflect` does; other handles keep their type name.
+fn label_asset_handles(fields: &mut [ReflectedField], value: &dyn PartialReflect, world: &World) {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let asset_server = world.get_resource::<AssetServer>();
+    for field in fields {
+        let Some(path) = &field.path else {
+            continue;
+        };
+        let Some(handle) = field_at_path(value, path)
+            .and_then(|field_value| field_value.try_as_reflect())
+            .filter(|field_value| {
+                field_value
+                    .reflect_type_path()
+                    .starts_with(HANDLE_TYPE_PATH_PREFIX)
+            })
+            .and_then(|field_value| {
+                let type_id = field_value.get_represented_type_info()?.type_id();
+                registry
+                    .get_type_data::<ReflectHandle>(type_id)?
+                    .downcast_handle_untyped(field_value.as_any())
+            })
+        else {
+            continue;
+        };
+
+        let asset_path = handle
+            .path()
+            .map_or_else(|| "<no path>".to_string(), ToString::to_string);
+        let load_state = asset_server.and_then(|server| server.get_load_state(handle.id()));
+        field.value = match load_state {
+            Some(LoadState::NotLoaded) => format!("{} (not loaded)", asset_path),
+            Some(LoadState::Loading) => format!("{} (loading)", asset_path),
+            Some(LoadState::Loaded) => format!("{} (loaded)", asset_path),
+            Some(LoadState::Failed(_)) => format!("{} (failed)", asset_path),
+            None => asset_path,
+        };
+        field.role = FieldRole::Asset(handle.id());
+    }
+}
+
+/// Looks up the value ranges of editable fields in [`FieldRanges`].
+fn apply_field_ranges(
+    fields: &mut [ReflectedField],
+    value: &dyn PartialReflect,
+    field_ranges: &FieldRanges,
+) {
+    for field in fields {
+        let FieldRole::Numeric(editable) = &mut field.role else {
+            continue;
+        };
+        if let Some((segment, parent_path)) = editable.path.split_last()
+            && let Some(parent) = field_at_path(value, parent_path)
+        {
+            editable.range = field_ranges.get(parent, segment);
+        }
+    }
+}
+
+/// Passes values with an editor registered in [`InspectorWidgetRegistry`] to that editor,
+/// leaving out the rows of their nested fields.
+fn apply_custom_widgets(
+    fields: &mut Vec<ReflectedField>,
+    value: &dyn PartialReflect,
+    widgets: &InspectorWidgetRegistry,
+) {
+    let mut widget_indent = None;
+    fields.retain_mut(|field| {
+        if let Some(indent) = widget_indent {
+            if field.indent > indent {
+                return false;
+            }
+            widget_indent = None;
+        }
+        if let Some(path) = &field.path
+            && let Some(field_value) = field_at_path(value, path)
+            && widgets.get_for(field_value).is_some()
+        {
+            field.role = FieldRole::Widget(clone_reflected(field_value));
+            widget_indent = Some(field.indent);
+        }
+        true
+    });
+}
+
+/// Makes the fields inside dangerous values that are still locked read-only,
+/// see [`DangerousFields`], and returns the paths of the dangerous values,
+/// each with whether it is locked.
+fn apply_field_access(
+    fields: &mut [ReflectedField],
+    value: &dyn PartialReflect,
+    dangerous_fields: &DangerousFields,
+    unlocked: &HashSet<Vec<FieldPathSegment>>,
+) -> Vec<(Vec<FieldPathSegment>, bool)> {
+    let mut roots = Vec::new();
+    if dangerous_fields.is_component_dangerous(value) {
+        roots.push((Vec::new(), !unlocked.contains(&Vec::new())));
+    }
+    for field in fields {
+        let Some(root) = field
+            .path
+            .as_deref()
+            .and_then(|path| dangerous_fields.dangerous_root(value, path))
+        else {
+            continue;
+        };
+        let locked = !unlocked.contains(root);
+        if !roots.iter().any(|(known, _)| known == root) {
+            roots.push((root.to_vec(), locked));
+        }
+        if locked {
+            if field.role.edits_value() {
+                field.role = FieldRole::Text;
+            }
+            field.list_element = None;
+            field.non_finite.clear();
+        }
+    }
+    roots
+}
+
+/// Looks up the named bits of integer fields in [`FieldFlags`].
+fn apply_field_flags(
+    fields: &mut [ReflectedField],
+    value: &dyn PartialReflect,
+    field_flags: &FieldFlags,
+) {
+    for field in fields {
+        if let Some(path) = &field.path
+            && let Some((segment, parent_path)) = path.split_last()
+            && let Some(parent) = field_at_path(value, parent_path)
+            && let Some(flags) = field_flags.get(parent, segment)
+            && let Some(bits) = field_at_path(value, path).and_then(try_extract_bits)
+        {
+            field.role = FieldRole::Flags(bits, flags.to_vec());
+        }
     }
-    None
 }
 
 /// Tries to format a value as a simple string, returns None if it's a complex type
@@ -458,6 +1678,8 @@ fn format_simple_value(reflected: &dyn PartialReflect) -> Option<String> {
         ReflectRef::Array(a) => Some(format!("[{} items]", a.len())),
         ReflectRef::Map(m) => Some(format!("{{{} entries}}", m.len())),
         ReflectRef::Set(s) => Some(format!("{{{} items}}", s.len())),
+        // Opaque math types are expanded into their fields instead
+        ReflectRef::Opaque(_) if opaque_math_fields(reflected).is_some() => None,
         ReflectRef::Opaque(o) => Some(format!("{:?}", o)),
     }
 }
@@ -466,11 +1688,543 @@ fn format_simple_value(reflected: &dyn PartialReflect) -> Option<String> {
 struct ComponentCardData {
     name: String,
     size: String,
+    /// Estimated size when serialized as RON, if the component is serializable
+    serialized_size: Option<MemorySize>,
     fields: Vec<ReflectedField>,
     /// The entity this component belongs to (for write-back)
     entity: Entity,
     /// The TypeId of this component (for write-back)
     component_type_id: Option<TypeId>,
+    /// Whether only the header is shown
+    collapsed: bool,
+    /// Position among the pinned components, if pinned
+    pin_order: Option<usize>,
+    /// Single-line value, shown instead of the fields in summary mode
+    summary: Option<String>,
+    /// Whether reading the value was skipped because of the component's detail level
+    values_hidden: bool,
+    /// Source location that inserted the component, if recorded
+    inserted_at: Option<&'static Location<'static>>,
+    /// Copy of the component, shown by its registered editor instead of the fields,
+    /// see [`InspectorWidgetRegistry`]
+    widget: Option<Box<dyn PartialReflect>>,
+    /// How the component differs from the entity's baseline, while comparing
+    baseline_status: Option<&'static str>,
+    /// Paths of the dangerous values of the component, each with whether it is locked,
+    /// see [`DangerousFields`]; an empty path is the whole component
+    lock_toggles: Vec<(Vec<FieldPathSegment>, bool)>,
+}
+
+/// Styling and display state shared by the field rows of component cards and resources.
+struct FieldRowsContext {
+    small_font_size: f32,
+    row_min_height: f32,
+    drag_value_min_height: f32,
+    drag_snap_increment: Option<f64>,
+    drag_angle_snap_increment: Option<f64>,
+    muted_text_color: Color,
+    error_text_color: Color,
+    modified_marker_color: Color,
+    baseline_changed_color: Color,
+    config: InspectorConfig,
+    strings: InspectorStrings,
+    widget_registry: InspectorWidgetRegistry,
+    decomposed_matrices: HashSet<FieldPath>,
+    hex_fields: HashSet<FieldPath>,
+    linked_vectors: HashSet<FieldPath>,
+    tween_field: Option<FieldPath>,
+    tweening_fields: HashSet<FieldPath>,
+}
+
+impl FieldRowsContext {
+    fn new(world: &World, config: &InspectorConfig) -> Self {
+        let state = world.resource::<InspectorState>();
+        Self {
+            small_font_size: config.small_font_size,
+            row_min_height: config.row_min_height,
+            drag_value_min_height: config.drag_value_min_height,
+            drag_snap_increment: config.drag_snap_increment,
+            drag_angle_snap_increment: config.drag_angle_snap_increment,
+            muted_text_color: config.muted_text_color,
+            error_text_color: config.error_text_color,
+            modified_marker_color: config.modified_marker_color,
+            baseline_changed_color: config.baseline_changed_color,
+            config: config.clone(),
+            strings: world.resource::<InspectorStrings>().clone(),
+            widget_registry: world.resource::<InspectorWidgetRegistry>().clone(),
+            decomposed_matrices: state.decomposed_matrices.clone(),
+            hex_fields: state.hex_fields.clone(),
+            linked_vectors: state.linked_vectors.clone(),
+            tween_field: state.tween_field.clone(),
+            tweening_fields: world
+                .get_resource::<FieldTweens>()
+                .map(|tweens| {
+                    tweens
+                        .tweens
+                        .iter()
+                        .map(|tween| tween.field_path.clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Spawns a row per field of `fields`, each with the editor matching the field's value.
+///
+/// `base` is the path of the component or resource the fields belong to, without segments,
+/// and `lock_toggles` the paths of its dangerous values, each with whether it is locked.
+fn spawn_field_rows(
+    card: &mut ChildSpawner,
+    fields: &[ReflectedField],
+    base: &FieldPath,
+    lock_toggles: &[(Vec<FieldPathSegment>, bool)],
+    context: &FieldRowsContext,
+) {
+    let small_font_size = context.small_font_size;
+    let row_min_height = context.row_min_height;
+    let drag_value_min_height = context.drag_value_min_height;
+    let drag_snap_increment = context.drag_snap_increment;
+    let drag_angle_snap_increment = context.drag_angle_snap_increment;
+    let muted_text_color = context.muted_text_color;
+    let error_text_color = context.error_text_color;
+    let modified_marker_color = context.modified_marker_color;
+    let baseline_changed_color = context.baseline_changed_color;
+    let field_name_color = Color::srgba(0.6, 0.8, 1.0, 1.0); // Light blue for field names
+    let entity_link_color = Color::srgba(0.45, 0.7, 1.0, 1.0);
+    let FieldRowsContext {
+        config,
+        strings,
+        widget_registry,
+        decomposed_matrices,
+        hex_fields,
+        linked_vectors,
+        tween_field,
+        tweening_fields,
+        ..
+    } = context;
+
+    for field in fields {
+        let indent_px = field.indent as f32 * 12.0;
+
+        // Row container for label: value,
+        // highlighted if the value changed since the baseline
+        card.spawn((
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                column_gap: Px(8.0),
+                min_height: Px(row_min_height),
+                margin: UiRect::left(Px(indent_px)),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(if field.changed_since_baseline {
+                baseline_changed_color
+            } else {
+                Color::NONE
+            }),
+        ))
+        .with_children(|row| {
+            // Marker for values that differ from the type's default
+            let mut marker = row.spawn((
+                Node {
+                    width: Px(6.0),
+                    height: Px(6.0),
+                    ..default()
+                },
+                BorderRadius::MAX,
+                BackgroundColor(if field.differs_from_default {
+                    modified_marker_color
+                } else {
+                    Color::NONE
+                }),
+            ));
+            if field.differs_from_default {
+                marker.insert(Tooltip(strings.differs_from_default.clone()));
+            }
+
+            // Field name (light blue), or in the error color if not finite
+            let non_finite = !field.non_finite.is_empty();
+            row.spawn((
+                Text::new(format!("{}:", field.name)),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(if non_finite {
+                    error_text_color
+                } else {
+                    field_name_color
+                }),
+            ));
+            if let Some(path) = &field.path
+                && let Some((_, locked)) = lock_toggles.iter().find(|(root, _)| root == path)
+            {
+                spawn_field_lock_toggle(
+                    row,
+                    base.with_path(path.clone()),
+                    *locked,
+                    small_font_size,
+                );
+            }
+
+            // The editor matching the field's role, or else its value as text
+            let field_path = field.path.as_ref().map(|path| base.with_path(path.clone()));
+            match (&field.role, field_path) {
+                (FieldRole::Widget(value), Some(field_path)) => {
+                    if let Some(widget) = widget_registry.get_for(value.as_ref()) {
+                        widget(row, &field_path, value.as_ref());
+                    }
+                }
+                (FieldRole::Flags(bits, flags), Some(field_path)) => {
+                    spawn_flag_checkboxes(row, field_path, *bits, flags, small_font_size);
+                }
+                (&FieldRole::List { len, expanded }, Some(field_path)) => {
+                    spawn_list_header(row, field_path, len, expanded, small_font_size);
+                }
+                (
+                    &FieldRole::Set {
+                        len,
+                        expanded,
+                        editable,
+                    },
+                    Some(field_path),
+                ) => {
+                    spawn_set_header(row, field_path, len, expanded, editable, small_font_size);
+                }
+                (&FieldRole::Nested(expanded), Some(field_path)) => {
+                    spawn_nested_field_toggle(
+                        row,
+                        field_path,
+                        &field.value,
+                        expanded,
+                        small_font_size,
+                    );
+                }
+                (&FieldRole::Option(is_some), Some(field_path)) => {
+                    spawn_option_toggle(row, field_path, is_some, small_font_size);
+                }
+                (&FieldRole::Color(color, field_type), Some(field_path)) => {
+                    spawn_color_picker(row, field_path, color, field_type, small_font_size);
+                }
+                (FieldRole::Matrix(grid), Some(field_path)) => {
+                    let decomposed = decomposed_matrices.contains(&field_path);
+                    spawn_matrix_grid(
+                        row,
+                        field_path,
+                        grid,
+                        decomposed,
+                        drag_value_min_height,
+                        small_font_size,
+                    );
+                }
+                (FieldRole::Vector(axes) | FieldRole::Quaternion(axes), Some(field_path)) => {
+                    let linked = linked_vectors.contains(&field_path);
+                    // Snapping quaternion components one by one gives arbitrary rotations;
+                    // they snap through their Euler angles instead
+                    let snap = match field.role {
+                        FieldRole::Quaternion(_) => None,
+                        _ => drag_snap_increment,
+                    };
+                    spawn_vector_drag(
+                        row,
+                        field_path,
+                        axes,
+                        linked,
+                        snap,
+                        drag_value_min_height,
+                        small_font_size,
+                    );
+                }
+                (&FieldRole::EulerAngles(degrees), Some(field_path)) => {
+                    spawn_euler_angle_drag(
+                        row,
+                        field_path,
+                        degrees,
+                        drag_angle_snap_increment,
+                        drag_value_min_height,
+                        small_font_size,
+                    );
+                }
+                (
+                    FieldRole::Numeric(EditableFieldInfo {
+                        numeric_value,
+                        path,
+                        range: Some(range),
+                        ..
+                    }),
+                    _,
+                ) => {
+                    let field_path = base.with_path(path.clone());
+                    spawn_field_slider(row, field_path, *numeric_value, range.clone());
+                }
+                (FieldRole::Numeric(editable), _) => {
+                    // Spawn DragValue widget for editable numeric fields
+                    let field_path = base.with_path(editable.path.clone());
+                    let hex = editable.hex_capable && hex_fields.contains(&field_path);
+                    let drag_value = DragValue {
+                        field_path: field_path.clone(),
+                        drag_speed: 0.1,
+                        precision: 2,
+                        min: None,
+                        max: None,
+                        snap: drag_snap_increment,
+                        format: if hex {
+                            DragValueFormat::Hex
+                        } else {
+                            editable.format
+                        },
+                    };
+                    let value_text = match editable.integer_value {
+                        Some(integer) => drag_value.format_integer(integer),
+                        None => drag_value.format_value(editable.numeric_value),
+                    };
+
+                    row.spawn((
+                        Node {
+                            min_width: Px(60.0),
+                            min_height: Px(drag_value_min_height),
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Px(4.0)),
+                            border: UiRect::all(Px(1.0)),
+                            ..default()
+                        },
+                        BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                        BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                        drag_value,
+                        DragValueDragState::default(),
+                        Interaction::default(),
+                    ))
+                    .with_child((
+                        Text::new(value_text),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        // Yellow for editable
+                        TextColor(if non_finite {
+                            error_text_color
+                        } else {
+                            Color::srgba(0.9, 0.9, 0.6, 1.0)
+                        }),
+                        ValueText,
+                    ));
+                    if editable.hex_capable {
+                        spawn_hex_display_toggle(row, field_path.clone(), hex, small_font_size);
+                    }
+                    spawn_tween_controls(
+                        row,
+                        field_path.clone(),
+                        tweening_fields.contains(&field_path),
+                        tween_field.as_ref() == Some(&field_path),
+                        config,
+                        strings,
+                    );
+                }
+                (&FieldRole::EntityLink(target), _) => {
+                    // Entity reference - link that selects the entity
+                    row.spawn((
+                        bevy::ui_widgets::Button,
+                        EntityLink(target),
+                        observe(on_entity_link_click),
+                        Tooltip(format!("Select {}", target)),
+                    ))
+                    .with_child((
+                        Text::new(field.value.clone()),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(entity_link_color),
+                    ));
+                }
+                (&FieldRole::Asset(asset_id), _) => {
+                    // Asset handle - path and load state, plus a select button
+                    row.spawn((
+                        Text::new(field.value.clone()),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                        CopyOnDoubleClick::new(field.value.clone()),
+                        Tooltip("Double-click to copy".to_string()),
+                    ));
+                    row.spawn((
+                        Node {
+                            margin: UiRect::left(Px(6.0)),
+                            ..default()
+                        },
+                        bevy::ui_widgets::Button,
+                        AssetSelectButton(asset_id),
+                        observe(on_asset_select_click),
+                        Tooltip(format!("Select asset {}", asset_id)),
+                    ))
+                    .with_child((
+                        Text::new(strings.select.clone()),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(entity_link_color),
+                    ));
+                }
+                _ => {
+                    // Field value (muted) - non-editable
+                    row.spawn((
+                        selectable_text(field.value.clone()),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                        ValueText,
+                        CopyOnDoubleClick::new(field.value.clone()),
+                        Tooltip(
+                            "Drag to select, Ctrl+C to copy the selection, \
+                                 double-click to copy all"
+                                .to_string(),
+                        ),
+                    ));
+                }
+            }
+
+            if !field.value.is_empty() {
+                spawn_copy_button(
+                    row,
+                    CopyButton::Text(field.value.clone()),
+                    &context.strings.copy_value,
+                    &context.strings,
+                    small_font_size,
+                );
+            }
+
+            if let (&FieldRole::Timer(paused), Some(path)) = (&field.role, &field.path) {
+                let field_path = base.with_path(path.clone());
+                spawn_timer_controls(row, field_path, paused, small_font_size, strings);
+            }
+
+            if non_finite {
+                let field_paths = field
+                    .non_finite
+                    .iter()
+                    .map(|path| base.with_path(path.clone()))
+                    .collect();
+                row.spawn((
+                    Node {
+                        padding: UiRect::horizontal(Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(error_text_color),
+                    bevy::ui_widgets::Button,
+                    Tooltip("NaN or infinite value".to_string()),
+                    RepairNonFiniteButton(field_paths),
+                    observe(on_repair_non_finite_click),
+                ))
+                .with_child((
+                    Text::new(strings.set_to_zero.clone()),
+                    TextFont {
+                        font_size: small_font_size,
+                        ..default()
+                    },
+                    TextColor(error_text_color),
+                ));
+            }
+
+            if let Some(ListElement { index, len }) = field.list_element
+                && let Some(path) = &field.path
+                && let Some((_, list_path)) = path.split_last()
+            {
+                let field_path = base.with_path(list_path.to_vec());
+                spawn_list_item_buttons(row, field_path, index, len, small_font_size);
+            }
+
+            if let FieldRole::SetMember {
+                set_path,
+                removable: Some(member),
+            } = &field.role
+            {
+                let field_path = base.with_path(set_path.clone());
+                spawn_set_member_remove_button(
+                    row,
+                    field_path,
+                    clone_reflected(member.as_ref()),
+                    small_font_size,
+                );
+            }
+        });
+    }
+}
+
+/// Spawns the fields of the resource with type `type_id` below `parent`,
+/// edited with the same widgets as the fields of components.
+pub(crate) fn spawn_resource_fields(
+    world: &mut World,
+    parent: Entity,
+    type_id: TypeId,
+    config: &InspectorConfig,
+) {
+    let base = FieldPath::resource(type_id, []);
+    let state = world.resource::<InspectorState>();
+    let resource_fields = |fields: &HashSet<FieldPath>| -> HashSet<Vec<FieldPathSegment>> {
+        fields
+            .iter()
+            .filter(|field| field.is_resource() && field.component_type_id == type_id)
+            .map(|field| field.path.clone())
+            .collect()
+    };
+    let mut expanded_fields = resource_fields(&state.expanded_lists);
+    expanded_fields.extend(resource_fields(&state.expanded_structs));
+    let unlocked = resource_fields(&state.unlocked_fields);
+
+    let mut fields = Vec::new();
+    let mut lock_toggles = Vec::new();
+    if let Ok(reflected) = get_reflected_resource_ref(world, type_id) {
+        let widget_registry = world.resource::<InspectorWidgetRegistry>();
+        extract_fields_from_reflect(
+            reflected,
+            &mut fields,
+            0,
+            world.resource::<SemanticFieldNames>(),
+            &expanded_fields,
+            &[],
+        );
+        apply_field_ranges(&mut fields, reflected, world.resource::<FieldRanges>());
+        apply_field_flags(&mut fields, reflected, world.resource::<FieldFlags>());
+        apply_custom_widgets(&mut fields, reflected, widget_registry);
+        lock_toggles = apply_field_access(
+            &mut fields,
+            reflected,
+            world.resource::<DangerousFields>(),
+            &unlocked,
+        );
+        label_entity_links(&mut fields, world);
+        label_asset_handles(&mut fields, reflected, world);
+        if let Some(default_value) = reflect_default_value(world, type_id) {
+            mark_fields_differing_from_default(
+                &mut fields,
+                reflected,
+                default_value.as_partial_reflect(),
+            );
+        }
+    }
+
+    let field_rows = FieldRowsContext::new(world, config);
+    world.entity_mut(parent).with_children(|card| {
+        spawn_field_rows(card, &fields, &base, &lock_toggles, &field_rows);
+        if fields.is_empty() {
+            card.spawn((
+                Text::new(field_rows.strings.no_reflected_data.clone()),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            ));
+        }
+    });
 }
 
 fn spawn_components_tab_exclusive(
@@ -489,15 +2243,35 @@ fn spawn_components_tab_exclusive(
     };
 
     let inspection_result = world.inspect_cached(entity, &settings, metadata_map);
+    let mut serialized_sizes = world
+        .remove_resource::<SerializedSizeCache>()
+        .unwrap_or_default();
 
     // Get semantic names resource for better tuple struct field names
     let semantic_names = world.resource::<SemanticFieldNames>();
+    let display_overrides = world.resource::<ComponentDisplayOverrides>();
+    let field_ranges = world.resource::<FieldRanges>();
+    let field_flags = world.resource::<FieldFlags>();
+    let toggled_cards = &world.resource::<InspectorState>().toggled_component_cards;
+    let pinned_cards = &world.resource::<InspectorState>().pinned_component_cards;
+    let expanded_lists = &world.resource::<InspectorState>().expanded_lists;
+    let expanded_structs = &world.resource::<InspectorState>().expanded_structs;
+    let insertion_sources = world.get_resource::<ComponentInsertionSources>();
+    let widget_registry = world.resource::<InspectorWidgetRegistry>().clone();
+    let dangerous_fields = world.resource::<DangerousFields>();
+    let unlocked_fields = &world.resource::<InspectorState>().unlocked_fields;
+    let baseline = world
+        .resource::<InspectorState>()
+        .compare_to_baseline
+        .then(|| world.get_resource::<EntityBaselines>()?.get(entity))
+        .flatten();
 
     match inspection_result {
         Ok(inspection) => {
-            // Resolve name using metadata map
             let resolved_name = inspection
-                .resolve_name(&metadata_map.map)
+                .name
+                .as_ref()
+                .map(|name| name.to_string())
                 .unwrap_or_else(|| format!("Entity {:?}", entity));
 
             let component_count = inspection.components.as_ref().map(|c| c.len()).unwrap_or(0);
@@ -506,12 +2280,27 @@ fn spawn_components_tab_exclusive(
                 .map(|m| m.to_string())
                 .unwrap_or_else(|| "?".to_string());
 
-            // Collect component IDs for reflection access
+            // Collect component IDs for reflection access,
+            // keeping only the components shared by every selected entity
+            let batch_targets = world
+                .resource::<InspectorState>()
+                .batch_edit_targets(entity)
+                .to_vec();
             let component_ids: Vec<_> = inspection
                 .components
                 .as_ref()
-                .map(|c| c.iter().map(|comp| comp.component_id).collect())
-                .unwrap_or_default();
+                .map(|c| c.iter().map(|comp| comp.component_id).collect::<Vec<_>>())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|&id| {
+                    batch_targets.iter().all(|&target| {
+                        world
+                            .get_entity(target)
+                            .ok()
+                            .is_none_or(|target| target.contains_id(id))
+                    })
+                })
+                .collect();
 
             // Clone config values needed in closure
             let title_font_size = config.title_font_size;
@@ -521,7 +2310,6 @@ fn spawn_components_tab_exclusive(
             let item_gap = config.item_gap;
             let border_color = config.border_color;
             let muted_text_color = config.muted_text_color;
-            let field_name_color = Color::srgba(0.6, 0.8, 1.0, 1.0); // Light blue for field names
 
             // Extract fields for each component using reflection
             let mut component_cards: Vec<ComponentCardData> = Vec::new();
@@ -536,41 +2324,345 @@ fn spawn_components_tab_exclusive(
                     .map(|m| m.memory_size.to_string())
                     .unwrap_or_else(|| "?".to_string());
                 let component_type_id = meta.and_then(|m| m.type_id);
+                let serialized_size = component_type_id
+                    .and_then(|type_id| serialized_sizes.get_or_compute(world, entity, type_id));
+
+                // Apply per-component display overrides; clicking the header inverts collapsing
+                let display_settings = component_type_id
+                    .map(|type_id| display_overrides.get(type_id))
+                    .unwrap_or_default();
+                let values_hidden = display_settings.detail_level == ComponentDetailLevel::Names;
+                let pin_order = component_type_id
+                    .and_then(|type_id| pinned_cards.iter().position(|pinned| *pinned == type_id));
+                // Pinned cards stay expanded whatever was toggled on other entities
+                let collapsed = pin_order.is_none()
+                    && component_type_id.is_some_and(|type_id| {
+                        (display_settings.display == ComponentCardDisplay::Collapsed)
+                            != toggled_cards.contains(&type_id)
+                    });
+
+                // Compare the whole component, even while its fields are hidden
+                let baseline_status = match (baseline, component_type_id) {
+                    (Some(baseline), Some(type_id)) => match baseline.component(type_id) {
+                        None => Some("new since baseline"),
+                        Some(baseline_value) => get_reflected_component_ref(world, entity, type_id)
+                            .ok()
+                            .filter(|value| value.reflect_partial_eq(baseline_value) != Some(true))
+                            .map(|_| "changed since baseline"),
+                    },
+                    _ => None,
+                };
 
                 // Try to get reflected component data
                 let mut fields = Vec::new();
-                if let Some(type_id) = component_type_id {
-                    if let Ok(reflected) = get_reflected_component_ref(world, entity, type_id) {
-                        extract_fields_from_reflect(reflected, &mut fields, 0, semantic_names, &[]);
+                let mut summary = None;
+                let mut widget = None;
+                let mut lock_toggles = Vec::new();
+                if !values_hidden
+                    && !collapsed
+                    && let Some(type_id) = component_type_id
+                    && let Ok(reflected) = get_reflected_component_ref(world, entity, type_id)
+                {
+                    if display_settings.display == ComponentCardDisplay::Summary {
+                        let value = format!("{:?}", reflected.as_partial_reflect());
+                        summary = Some(truncate_middle(&value, SUMMARY_MAX_CHARS).into_owned());
+                    } else if widget_registry.get(type_id).is_some()
+                        && (!dangerous_fields.is_component_dangerous(reflected)
+                            || unlocked_fields.contains(&FieldPath {
+                                owner: FieldOwner::Entity(entity),
+                                component_type_id: type_id,
+                                path: Vec::new(),
+                            }))
+                    {
+                        widget = Some(clone_reflected(reflected));
+                    } else {
+                        let owner = FieldOwner::Entity(entity);
+                        let expanded_fields: HashSet<Vec<FieldPathSegment>> = expanded_lists
+                            .iter()
+                            .chain(expanded_structs)
+                            .filter(|field| {
+                                field.owner == owner && field.component_type_id == type_id
+                            })
+                            .map(|field| field.path.clone())
+                            .collect();
+                        extract_fields_from_reflect(
+                            reflected,
+                            &mut fields,
+                            0,
+                            semantic_names,
+                            &expanded_fields,
+                            &[],
+                        );
+                        apply_field_ranges(&mut fields, reflected, field_ranges);
+                        apply_field_flags(&mut fields, reflected, field_flags);
+                        apply_custom_widgets(&mut fields, reflected, &widget_registry);
+                        let unlocked: HashSet<Vec<FieldPathSegment>> = unlocked_fields
+                            .iter()
+                            .filter(|field| {
+                                field.owner == owner && field.component_type_id == type_id
+                            })
+                            .map(|field| field.path.clone())
+                            .collect();
+                        lock_toggles =
+                            apply_field_access(&mut fields, reflected, dangerous_fields, &unlocked);
+                        if let Some(baseline_value) =
+                            baseline.and_then(|baseline| baseline.component(type_id))
+                        {
+                            mark_fields_changed_since_baseline(
+                                &mut fields,
+                                reflected,
+                                baseline_value,
+                            );
+                        }
+                        label_entity_links(&mut fields, world);
+                        label_asset_handles(&mut fields, reflected, world);
+                        if let Some(default_value) = reflect_default_value(world, type_id) {
+                            mark_fields_differing_from_default(
+                                &mut fields,
+                                reflected,
+                                default_value.as_partial_reflect(),
+                            );
+                        }
+                    }
+                }
+
+                component_cards.push(ComponentCardData {
+                    name,
+                    size,
+                    serialized_size,
+                    fields,
+                    entity,
+                    component_type_id,
+                    collapsed,
+                    pin_order,
+                    summary,
+                    values_hidden,
+                    inserted_at: insertion_sources
+                        .and_then(|sources| sources.get(entity, *comp_id)),
+                    widget,
+                    baseline_status,
+                    lock_toggles,
+                });
+            }
+            // Pinned cards come first, the others keep their order
+            component_cards.sort_by_key(|card| card.pin_order.unwrap_or(usize::MAX));
+
+            // Components that can't be serialized are left out of the entity's estimate
+            let serialized_display = component_cards
+                .iter()
+                .filter_map(|card| card.serialized_size)
+                .map(|size| size.as_bytes())
+                .reduce(|total, size| total + size)
+                .map(|total| format!(" | {} RON", MemorySize::new(total)))
+                .unwrap_or_default();
+
+            let scene_origin = world
+                .get_resource::<SceneOrigins>()
+                .and_then(|origins| origins.get(entity))
+                .map(|origin| origin.scene.clone());
+            let strings = world.resource::<InspectorStrings>().clone();
+            let state = world.resource::<InspectorState>();
+            let despawn_label = if state.despawn_confirmation == Some(entity) {
+                strings.confirm_despawn.clone()
+            } else if batch_targets.len() > 1 {
+                format!("{} {}", strings.despawn, batch_targets.len())
+            } else {
+                strings.despawn.clone()
+            };
+            let despawn_notice = state
+                .despawn_notice
+                .as_ref()
+                .filter(|(notice_entity, _)| *notice_entity == entity)
+                .map(|(_, notice)| notice.clone());
+            let error_text_color = config.error_text_color;
+            let baseline_changed_color = config.baseline_changed_color;
+            let has_baseline = world
+                .get_resource::<EntityBaselines>()
+                .is_some_and(|baselines| baselines.get(entity).is_some());
+            let compare_label = if baseline.is_some() {
+                strings.stop_comparing.clone()
+            } else {
+                strings.compare_to_baseline.clone()
+            };
+            let capture_label = strings.capture_baseline.clone();
+            let dump_label = strings.dump_to_log.clone();
+            let removed_since_baseline = baseline
+                .map(|baseline| {
+                    let registry = world.resource::<AppTypeRegistry>().read();
+                    baseline
+                        .diff(world, entity)
+                        .removed
+                        .into_iter()
+                        .map(|type_id| {
+                            registry
+                                .get(type_id)
+                                .map_or("?".to_string(), |registration| {
+                                    ShortName(registration.type_info().type_path()).to_string()
+                                })
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let field_rows = FieldRowsContext::new(world, config);
+            let batch_edit_count = state.batch_edit_targets(entity).len();
+            world.insert_resource(serialized_sizes);
+
+            world.entity_mut(parent).with_children(|p| {
+                // Header with entity name and memory
+                p.spawn((
+                    Text::new(format!(
+                        "{} | {} {} | {}{}",
+                        resolved_name,
+                        component_count,
+                        strings.components,
+                        memory_display,
+                        serialized_display
+                    )),
+                    TextFont {
+                        font_size: title_font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::bottom(Px(4.0)),
+                        ..default()
+                    },
+                ));
+
+                // Memory and component count over time
+                spawn_memory_trend_chart(p, small_font_size, muted_text_color);
+
+                // Scene the entity was spawned from
+                if let Some(scene) = scene_origin {
+                    p.spawn((
+                        Text::new(format!("{} {}", strings.scene_origin, scene)),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                        Node {
+                            margin: UiRect::bottom(Px(4.0)),
+                            ..default()
+                        },
+                    ));
+                }
+
+                // Entity actions
+                p.spawn(Node {
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    column_gap: Px(8.0),
+                    margin: UiRect::bottom(Px(12.0)),
+                    ..default()
+                })
+                .with_children(|actions| {
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            DespawnButton(entity),
+                            bevy::prelude::Spawn((
+                                Text::new(despawn_label),
+                                TextFont {
+                                    font_size: small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_despawn_click),
+                    ));
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            BaselineButton::Capture,
+                            bevy::prelude::Spawn((
+                                Text::new(capture_label),
+                                TextFont {
+                                    font_size: small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_baseline_button_click),
+                    ));
+                    if has_baseline {
+                        actions.spawn((
+                            button(
+                                ButtonProps::default(),
+                                BaselineButton::ToggleCompare,
+                                bevy::prelude::Spawn((
+                                    Text::new(compare_label),
+                                    TextFont {
+                                        font_size: small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_baseline_button_click),
+                        ));
+                    }
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            DumpToLogButton(entity),
+                            bevy::prelude::Spawn((
+                                Text::new(dump_label),
+                                TextFont {
+                                    font_size: small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_dump_to_log_click),
+                    ));
+                    if let Some(notice) = despawn_notice {
+                        actions.spawn((
+                            Text::new(notice),
+                            TextFont {
+                                font_size: small_font_size,
+                                ..default()
+                            },
+                            TextColor(error_text_color),
+                        ));
                     }
-                }
-
-                component_cards.push(ComponentCardData {
-                    name,
-                    size,
-                    fields,
-                    entity,
-                    component_type_id,
                 });
-            }
 
-            world.entity_mut(parent).with_children(|p| {
-                // Header with entity name and memory
-                p.spawn((
-                    Text::new(format!(
-                        "{} | {} components | {}",
-                        resolved_name, component_count, memory_display
-                    )),
-                    TextFont {
-                        font_size: title_font_size,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                    Node {
-                        margin: UiRect::bottom(Px(12.0)),
-                        ..default()
-                    },
-                ));
+                if !removed_since_baseline.is_empty() {
+                    p.spawn((
+                        Text::new(format!(
+                            "Removed since baseline: {}",
+                            removed_since_baseline.join(", ")
+                        )),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(baseline_changed_color),
+                        Node {
+                            margin: UiRect::bottom(Px(8.0)),
+                            ..default()
+                        },
+                    ));
+                }
+
+                if batch_edit_count > 1 {
+                    p.spawn((
+                        Text::new(format!(
+                            "Showing the {} components shared by all {} selected entities, \
+                             edits apply to each",
+                            component_ids.len(),
+                            batch_edit_count
+                        )),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        Node {
+                            margin: UiRect::bottom(Px(8.0)),
+                            ..default()
+                        },
+                    ));
+                }
 
                 // Component cards
                 for card_data in component_cards {
@@ -590,99 +2682,183 @@ fn spawn_components_tab_exclusive(
                     ))
                     .with_children(|card| {
                         // Component name and size header
-                        card.spawn((
-                            Text::new(format!("{} | {}", card_data.name, card_data.size)),
-                            TextFont {
-                                font_size: body_font_size,
-                                ..default()
-                            },
-                            TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                        // Only the file name of the insertion source fits in the header
+                        let inserted_at = card_data
+                            .inserted_at
+                            .map(|location| {
+                                let file = location
+                                    .file()
+                                    .rsplit(['/', '\\'])
+                                    .next()
+                                    .unwrap_or(location.file());
+                                format!(" | from {}:{}", file, location.line())
+                            })
+                            .unwrap_or_default();
+                        let serialized_size = card_data
+                            .serialized_size
+                            .map(|size| format!(" | {} RON", size))
+                            .unwrap_or_default();
+                        let baseline_status = card_data
+                            .baseline_status
+                            .map(|status| format!(" | {}", status))
+                            .unwrap_or_default();
+                        let mut header = card.spawn((
                             Node {
+                                display: Display::Flex,
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::SpaceBetween,
+                                column_gap: Px(8.0),
                                 margin: UiRect::bottom(Px(4.0)),
                                 ..default()
                             },
+                            // Opaque and raised so fields scroll underneath while pinned
+                            ThemeBackgroundColor(tokens::WINDOW_BG),
+                            ZIndex(1),
+                            ComponentCardHeader,
                         ));
-
-                        // Field rows (dear imgui style)
-                        for field in &card_data.fields {
-                            let indent_px = field.indent as f32 * 12.0;
-
-                            // Row container for label: value
-                            card.spawn(Node {
-                                display: Display::Flex,
-                                flex_direction: FlexDirection::Row,
-                                column_gap: Px(8.0),
-                                margin: UiRect::left(Px(indent_px)),
-                                align_items: AlignItems::Center,
-                                ..default()
-                            })
-                            .with_children(|row| {
-                                // Field name (light blue)
-                                row.spawn((
-                                    Text::new(format!("{}:", field.name)),
-                                    TextFont {
-                                        font_size: small_font_size,
-                                        ..default()
-                                    },
-                                    TextColor(field_name_color),
-                                ));
-
-                                // Check if this field is editable
-                                if let (Some(editable), Some(component_type_id)) =
-                                    (&field.editable, card_data.component_type_id)
-                                {
-                                    // Spawn DragValue widget for editable numeric fields
-                                    let field_path = FieldPath {
-                                        entity: card_data.entity,
-                                        component_type_id,
-                                        path: editable.path.clone(),
-                                    };
-
-                                    row.spawn((
+                        header.with_children(|header| {
+                            header.spawn((
+                                Text::new(format!(
+                                    "{} {} | {}{}{}{}",
+                                    if card_data.collapsed { "+" } else { "-" },
+                                    card_data.name,
+                                    card_data.size,
+                                    serialized_size,
+                                    inserted_at,
+                                    baseline_status
+                                )),
+                                TextFont {
+                                    font_size: body_font_size,
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                            ));
+                            if let Some(component_type_id) = card_data.component_type_id {
+                                header
+                                    .spawn((
                                         Node {
-                                            min_width: Px(60.0),
-                                            padding: UiRect::horizontal(Px(4.0)),
-                                            border: UiRect::all(Px(1.0)),
+                                            padding: UiRect::horizontal(Px(3.0)),
+                                            margin: UiRect::left(Auto),
                                             ..default()
                                         },
-                                        BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
-                                        BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
-                                        DragValue {
-                                            field_path,
-                                            drag_speed: 0.1,
-                                            precision: 2,
-                                            min: None,
-                                            max: None,
-                                        },
-                                        DragValueDragState::default(),
-                                        Interaction::default(),
+                                        bevy::ui_widgets::Button,
+                                        Tooltip(
+                                            "Show this component first and expanded on every entity"
+                                                .to_string(),
+                                        ),
+                                        ComponentCardPinButton(component_type_id),
+                                        observe(on_component_card_pin_click),
                                     ))
                                     .with_child((
-                                        Text::new(format!("{:.2}", editable.numeric_value)),
+                                        Text::new(if card_data.pin_order.is_some() {
+                                            "unpin"
+                                        } else {
+                                            "pin"
+                                        }),
                                         TextFont {
                                             font_size: small_font_size,
                                             ..default()
                                         },
-                                        TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)), // Yellow for editable
+                                        TextColor(Color::srgba(0.5, 0.5, 0.5, 1.0)),
                                     ));
-                                } else {
-                                    // Field value (muted) - non-editable
-                                    row.spawn((
-                                        Text::new(field.value.clone()),
-                                        TextFont {
-                                            font_size: small_font_size,
-                                            ..default()
-                                        },
-                                        TextColor(muted_text_color),
-                                    ));
-                                }
-                            });
+                            }
+                            if let Some(component_type_id) = card_data.component_type_id
+                                && let Some((_, locked)) = card_data
+                                    .lock_toggles
+                                    .iter()
+                                    .find(|(root, _)| root.is_empty())
+                            {
+                                spawn_field_lock_toggle(
+                                    header,
+                                    FieldPath {
+                                        owner: FieldOwner::Entity(card_data.entity),
+                                        component_type_id,
+                                        path: Vec::new(),
+                                    },
+                                    *locked,
+                                    small_font_size,
+                                );
+                            }
+                            // Only components that serialize can be copied as RON
+                            if let Some(component_type_id) = card_data.component_type_id
+                                && card_data.serialized_size.is_some()
+                            {
+                                spawn_copy_button(
+                                    header,
+                                    CopyButton::ComponentRon {
+                                        entity: card_data.entity,
+                                        component_type_id,
+                                    },
+                                    &strings.copy_component_ron,
+                                    &strings,
+                                    small_font_size,
+                                );
+                            }
+                        });
+                        if let Some(type_id) = card_data.component_type_id {
+                            header.insert((
+                                bevy::ui_widgets::Button,
+                                ComponentCardToggle(type_id),
+                                observe(on_component_card_toggle_click),
+                            ));
+                        }
+                        if let Some(location) = card_data.inserted_at {
+                            header.insert(Tooltip(format!("Inserted at {}", location)));
+                        }
+                        if card_data.collapsed {
+                            return;
+                        }
+
+                        if let Some(summary) = &card_data.summary {
+                            card.spawn((
+                                selectable_text(summary.clone()),
+                                TextFont {
+                                    font_size: small_font_size,
+                                    ..default()
+                                },
+                                TextColor(muted_text_color),
+                                CopyOnDoubleClick::new(summary.clone()),
+                            ));
+                            return;
+                        }
+
+                        if let (Some(value), Some(component_type_id)) =
+                            (&card_data.widget, card_data.component_type_id)
+                            && let Some(widget) = widget_registry.get(component_type_id)
+                        {
+                            let field_path = FieldPath {
+                                owner: FieldOwner::Entity(card_data.entity),
+                                component_type_id,
+                                path: Vec::new(),
+                            };
+                            widget(card, &field_path, value.as_ref());
+                            return;
+                        }
+
+                        // Field rows (dear imgui style)
+                        if let Some(component_type_id) = card_data.component_type_id {
+                            let base = FieldPath {
+                                owner: FieldOwner::Entity(card_data.entity),
+                                component_type_id,
+                                path: Vec::new(),
+                            };
+                            spawn_field_rows(
+                                card,
+                                &card_data.fields,
+                                &base,
+                                &card_data.lock_toggles,
+                                &field_rows,
+                            );
                         }
 
                         // Show placeholder if no fields extracted
                         if card_data.fields.is_empty() {
                             card.spawn((
-                                Text::new("<no reflected data>"),
+                                Text::new(if card_data.values_hidden {
+                                    &strings.values_hidden
+                                } else {
+                                    &strings.no_reflected_data
+                                }),
                                 TextFont {
                                     font_size: small_font_size,
                                     ..default()
@@ -695,6 +2871,7 @@ fn spawn_components_tab_exclusive(
             });
         }
         Err(e) => {
+            world.insert_resource(serialized_sizes);
             spawn_error_state_exclusive(world, parent, config, &format!("Error: {:?}", e));
         }
     }
@@ -752,11 +2929,12 @@ fn spawn_relationships_tab_exclusive(
     let muted_text_color = config.muted_text_color;
     let item_gap = config.item_gap;
     let children_len = children.len();
+    let strings = world.resource::<InspectorStrings>().clone();
 
     world.entity_mut(parent).with_children(|p| {
         // Parent section
         p.spawn((
-            Text::new("Parent"),
+            Text::new(strings.parent),
             TextFont {
                 font_size: title_font_size,
                 ..default()
@@ -794,7 +2972,7 @@ fn spawn_relationships_tab_exclusive(
             });
         } else {
             p.spawn((
-                Text::new("No parent (root entity)"),
+                Text::new(strings.no_parent),
                 TextFont {
                     font_size: body_font_size,
                     ..default()
@@ -809,7 +2987,7 @@ fn spawn_relationships_tab_exclusive(
 
         // Children section
         p.spawn((
-            Text::new(format!("Children ({})", children_len)),
+            Text::new(format!("{} ({})", strings.children, children_len)),
             TextFont {
                 font_size: title_font_size,
                 ..default()
@@ -823,7 +3001,7 @@ fn spawn_relationships_tab_exclusive(
 
         if children_node_data.is_empty() {
             p.spawn((
-                Text::new("No children"),
+                Text::new(strings.no_children),
                 TextFont {
                     font_size: body_font_size,
                     ..default()
@@ -860,8 +3038,56 @@ fn spawn_relationships_tab_exclusive(
     });
 }
 
+/// System that keeps component card headers pinned to the top of the detail viewport
+/// while the rest of their card is still visible, like CSS `position: sticky`.
+///
+/// Works from the previous frame's layout, so pinned headers may lag one frame behind scrolling.
+pub fn pin_component_card_headers(
+    content: Query<(&ComputedNode, &UiGlobalTransform), With<DetailContent>>,
+    cards: Query<(&ComputedNode, &UiGlobalTransform), With<ComponentCard>>,
+    mut headers: Query<
+        (&ChildOf, &ComputedNode, &UiGlobalTransform, &mut Node),
+        With<ComponentCardHeader>,
+    >,
+) {
+    let Ok((content_node, content_transform)) = content.single() else {
+        return;
+    };
+    let viewport_top = content_transform.translation.y - content_node.size().y / 2.0;
+    let inverse_scale = content_node.inverse_scale_factor();
+
+    for (child_of, header_node, header_transform, mut node) in &mut headers {
+        let Ok((card_node, card_transform)) = cards.get(child_of.parent()) else {
+            continue;
+        };
+        let current_offset = match node.top {
+            Px(offset) => offset,
+            _ => 0.0,
+        };
+
+        // Physical positions of the header as laid out without any offset, and of the card bottom
+        let header_top = header_transform.translation.y
+            - header_node.size().y / 2.0
+            - current_offset / inverse_scale;
+        let card_bottom = card_transform.translation.y + card_node.size().y / 2.0;
+
+        let max_offset =
+            ((card_bottom - header_top - header_node.size().y) * inverse_scale).max(0.0);
+        let offset = ((viewport_top - header_top) * inverse_scale).clamp(0.0, max_offset);
+
+        let top = if offset > 0.0 { Px(offset) } else { Auto };
+        if node.top != top {
+            node.top = top;
+        }
+    }
+}
+
 /// Spawns the detail panel structure.
-pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConfig) {
+pub fn spawn_detail_panel(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
     parent
         .spawn((
             Node {
@@ -898,7 +3124,7 @@ pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &Inspec
                             ButtonProps::default(),
                             TabButton(DetailTab::Components),
                             bevy::prelude::Spawn((
-                                Text::new("Components"),
+                                Text::new(strings.components_tab.clone()),
                                 TextFont {
                                     font_size: config.body_font_size,
                                     ..default()
@@ -914,7 +3140,119 @@ pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &Inspec
                             ButtonProps::default(),
                             TabButton(DetailTab::Relationships),
                             bevy::prelude::Spawn((
-                                Text::new("Relationships"),
+                                Text::new(strings.relationships_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Timeline tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Timeline),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.timeline_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // States tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::States),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.states_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Systems tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Systems),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.systems_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Time tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Time),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.time_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Validation tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Validation),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.validation_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Resources tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Resources),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.resources_tab.clone()),
+                                TextFont {
+                                    font_size: config.body_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
+
+                    // Notes tab
+                    tabs.spawn((
+                        button(
+                            ButtonProps::default(),
+                            TabButton(DetailTab::Notes),
+                            bevy::prelude::Spawn((
+                                Text::new(strings.notes_tab.clone()),
                                 TextFont {
                                     font_size: config.body_font_size,
                                     ..default()
@@ -926,7 +3264,7 @@ pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &Inspec
                 });
 
             // Scrollable area with scrollbar - use Grid layout
-            let scrollbar_width = 8.0;
+            let scrollbar_width = config.scrollbar_width;
             panel
                 .spawn(Node {
                     width: Percent(100.0),
@@ -980,3 +3318,269 @@ pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &Inspec
                 });
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[derive(Reflect)]
+    struct Grid {
+        cells: Vec<Vec<u32>>,
+    }
+
+    #[derive(Reflect)]
+    struct Body {
+        motion: Motion,
+    }
+
+    #[derive(Reflect)]
+    struct Motion {
+        speed: f32,
+        heading: Heading,
+    }
+
+    #[derive(Reflect)]
+    struct Heading {
+        degrees: f32,
+    }
+
+    fn named(name: &str) -> FieldPathSegment {
+        FieldPathSegment::Named(name.to_string())
+    }
+
+    fn extract(
+        value: &dyn PartialReflect,
+        expanded: &[Vec<FieldPathSegment>],
+    ) -> Vec<ReflectedField> {
+        let mut fields = Vec::new();
+        let expanded = expanded.iter().cloned().collect();
+        let semantic_names = SemanticFieldNames::default();
+        extract_fields_from_reflect(value, &mut fields, 0, &semantic_names, &expanded, &[]);
+        fields
+    }
+
+    #[test]
+    fn nested_structs_are_extracted_once_expanded() {
+        let body = Body {
+            motion: Motion {
+                speed: 2.0,
+                heading: Heading { degrees: 90.0 },
+            },
+        };
+        let fields = extract(&body, &[]);
+        assert_eq!(fields.len(), 1);
+        assert!(matches!(fields[0].role, FieldRole::Nested(false)));
+        assert_eq!(fields[0].value, "[Motion]");
+
+        let motion = vec![named("motion")];
+        let fields = extract(&body, std::slice::from_ref(&motion));
+        let rows: Vec<_> = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.indent))
+            .collect();
+        assert_eq!(rows, [("motion", 0), ("speed", 1), ("heading", 1)]);
+        assert!(matches!(fields[0].role, FieldRole::Nested(true)));
+        assert!(matches!(fields[2].role, FieldRole::Nested(false)));
+        assert_eq!(fields[1].path, Some(vec![named("motion"), named("speed")]));
+
+        let heading = [motion.clone(), vec![named("heading")]].concat();
+        let fields = extract(&body, &[motion, heading.clone()]);
+        let degrees = fields.last().unwrap();
+        assert_eq!((degrees.name.as_str(), degrees.indent), ("degrees", 2));
+        assert_eq!(
+            degrees.path,
+            Some([heading, vec![named("degrees")]].concat())
+        );
+        assert!(matches!(degrees.role, FieldRole::Numeric(_)));
+    }
+
+    #[test]
+    fn collapsed_structs_flag_their_non_finite_fields() {
+        let body = Body {
+            motion: Motion {
+                speed: f32::NAN,
+                heading: Heading {
+                    degrees: f32::INFINITY,
+                },
+            },
+        };
+        let motion = vec![named("motion")];
+        let heading = [motion.clone(), vec![named("heading")]].concat();
+        let fields = extract(&body, &[]);
+        assert_eq!(
+            fields[0].non_finite,
+            [
+                [motion.clone(), vec![named("speed")]].concat(),
+                [heading.clone(), vec![named("degrees")]].concat(),
+            ]
+        );
+
+        // Once expanded, each value is flagged on its own row instead
+        let fields = extract(&body, std::slice::from_ref(&motion));
+        assert!(fields[0].non_finite.is_empty());
+        assert_eq!(fields[1].non_finite.len(), 1);
+        assert_eq!(
+            fields[2].non_finite,
+            [[heading, vec![named("degrees")]].concat()]
+        );
+    }
+
+    #[test]
+    fn collapsed_lists_hide_their_elements() {
+        let grid = Grid {
+            cells: vec![vec![1, 2], vec![3]],
+        };
+        let fields = extract(&grid, &[]);
+        assert_eq!(fields.len(), 1);
+        assert!(matches!(
+            fields[0].role,
+            FieldRole::List {
+                len: 2,
+                expanded: false
+            }
+        ));
+
+        let fields = extract(&grid, &[vec![named("cells")]]);
+        assert_eq!(fields.len(), 3);
+        for (index, row) in fields[1..].iter().enumerate() {
+            assert!(matches!(
+                row.role,
+                FieldRole::List {
+                    expanded: false,
+                    ..
+                }
+            ));
+            assert!(matches!(row.list_element, Some(ListElement { len: 2, .. })));
+            assert_eq!(
+                row.path,
+                Some(vec![named("cells"), FieldPathSegment::ListIndex(index)])
+            );
+        }
+    }
+
+    #[test]
+    fn nested_lists_are_both_lists_and_elements() {
+        let grid = Grid {
+            cells: vec![vec![1, 2], vec![3]],
+        };
+        let cells = vec![named("cells")];
+        let first_row = [cells.clone(), vec![FieldPathSegment::ListIndex(0)]].concat();
+        let fields = extract(&grid, &[cells, first_row]);
+
+        let row = fields.iter().find(|field| field.name == "[0]").unwrap();
+        assert!(matches!(
+            row.role,
+            FieldRole::List {
+                len: 2,
+                expanded: true
+            }
+        ));
+        assert!(matches!(
+            row.list_element,
+            Some(ListElement { index: 0, len: 2 })
+        ));
+        let cells_of_first_row = fields.iter().filter(|field| field.indent == 2).count();
+        assert_eq!(cells_of_first_row, 2);
+    }
+
+    #[test]
+    fn quaternions_are_followed_by_their_euler_angles() {
+        let transform = Transform::from_rotation(Quat::from_rotation_y(90f32.to_radians()));
+        let fields = extract(&transform, &[]);
+
+        let rotation = fields
+            .iter()
+            .position(|field| field.name == "rotation")
+            .unwrap();
+        assert!(matches!(fields[rotation].role, FieldRole::Quaternion(_)));
+        assert!(matches!(fields[0].role, FieldRole::Vector(_)));
+        let FieldRole::EulerAngles(degrees) = fields[rotation + 1].role else {
+            panic!("no Euler angles after the quaternion");
+        };
+        assert!((degrees.y - 90.0).abs() < 1e-3);
+        assert_eq!(fields[rotation + 1].path, fields[rotation].path);
+    }
+
+    #[test]
+    fn locked_fields_are_read_only() {
+        let grid = Grid {
+            cells: vec![vec![1]],
+        };
+        let cells = vec![named("cells")];
+        let first_row = [cells.clone(), vec![FieldPathSegment::ListIndex(0)]].concat();
+        let mut fields = extract(&grid, &[cells.clone(), first_row]);
+        let mut dangerous_fields = DangerousFields::default();
+        dangerous_fields.register_field::<Grid>("cells");
+
+        let roots = apply_field_access(&mut fields, &grid, &dangerous_fields, &HashSet::new());
+        assert_eq!(roots, vec![(cells, true)]);
+        for field in &fields {
+            assert!(!field.role.edits_value(), "{} is editable", field.name);
+            assert!(field.list_element.is_none());
+        }
+    }
+
+    #[test]
+    fn serialized_sizes_are_recomputed_once_changed() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Health(f32);
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn(Health(1.0)).id();
+        let type_id = TypeId::of::<Health>();
+        let mut cache = SerializedSizeCache::default();
+        let size = cache.get_or_compute(&world, entity, type_id).unwrap();
+
+        // Unchanged components are not serialized again
+        world
+            .get_mut::<Health>(entity)
+            .unwrap()
+            .bypass_change_detection()
+            .0 = 100.25;
+        assert_eq!(cache.get_or_compute(&world, entity, type_id), Some(size));
+
+        world.increment_change_tick();
+        world.get_mut::<Health>(entity).unwrap().0 = 100.5;
+        let changed = cache.get_or_compute(&world, entity, type_id).unwrap();
+        assert_eq!(
+            changed.as_bytes(),
+            size.as_bytes() + "100.5".len() - "1.0".len()
+        );
+    }
+
+    #[test]
+    fn field_state_of_despawned_entities_is_pruned() {
+        let mut world = World::new();
+        let kept = world.spawn_empty().id();
+        let despawned = world.spawn_empty().id();
+        let resource = FieldPath::resource(TypeId::of::<Time>(), [named("delta")]);
+        let mut state = InspectorState::default();
+        for entity in [kept, despawned] {
+            state
+                .expanded_lists
+                .insert(FieldPath::new::<Transform>(entity, [named("translation")]));
+        }
+        state.expanded_structs.insert(resource.clone());
+        world.insert_resource(state);
+        world.despawn(despawned);
+
+        world.run_system_once(prune_field_display_state).unwrap();
+        let state = world.resource::<InspectorState>();
+        assert_eq!(state.expanded_lists.len(), 1);
+        assert!(
+            state
+                .expanded_lists
+                .iter()
+                .all(|path| path.owner == FieldOwner::Entity(kept))
+        );
+        assert!(state.expanded_structs.contains(&resource));
+    }
+}
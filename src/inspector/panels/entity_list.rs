@@ -1,17 +1,60 @@
 //! Entity list panel for the left side of the inspector.
 
+use core::any::TypeId;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
+use bevy::ecs::component::Tick;
+use bevy::ecs::entity::{Entities, EntityHashSet};
+use bevy::ecs::entity_disabling::Disabled;
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::ecs::observer::On;
 use bevy::ecs::relationship::Relationship;
-use bevy::feathers::controls::{button, ButtonProps};
+use bevy::feathers::controls::{ButtonProps, ButtonVariant, button, checkbox};
+use bevy::feathers::theme::ThemedText;
+use bevy::input_focus::InputFocus;
+use bevy::picking::hover::Hovered;
 use bevy::prelude::*;
-use bevy::ui::Val::*;
-use bevy::ui_widgets::{observe, Activate, ControlOrientation, CoreScrollbarThumb, Scrollbar};
+use bevy::ui::{Checked, Val::*};
+use bevy::ui_widgets::{
+    Activate, ControlOrientation, CoreScrollbarThumb, Scrollbar, ValueChange, observe,
+};
+use regex::Regex;
 
 use crate::component_inspection::ComponentMetadataMap;
-use crate::entity_inspection::{EntityInspectExtensionTrait, MultipleEntityInspectionSettings};
+use crate::entity_grouping::{EntityGrouping, GroupingStrategy};
+use crate::entity_inspection::{EntityQuery, MultipleEntityInspectionSettings};
+use crate::extension_methods::WorldInspectionExtensionTrait;
+use crate::fuzzy_name_mapping::fuzzy_match_score;
+use crate::inspector::bulk_edit::{BulkComponentAction, BulkComponentEdits, best_component_match};
 use crate::inspector::config::InspectorConfig;
-use crate::inspector::state::{EntityListEntry, InspectorCache, InspectorInternal, InspectorState};
+use crate::inspector::despawn::{BulkDespawnReport, Graveyard, purge_entity, restore_entity};
+use crate::inspector::exclusion::{EntityExclusions, HiddenEntityKind};
+use crate::inspector::filter_chips::FilterChip;
+use crate::inspector::panels::component_filter::{
+    spawn_component_filter_button, spawn_component_filter_dialog,
+};
+use crate::inspector::panels::entity_wizard::{
+    NewEntityButton, on_new_entity_click, spawn_entity_wizard_dialog,
+};
+use crate::inspector::panels::notes::parse_entity_id;
+use crate::inspector::plugin::{InspectorUiRoot, InspectorWindow};
+use crate::inspector::scene_origin::SceneOrigins;
+use crate::inspector::search_index::SearchIndex;
+use crate::inspector::selection::SelectionRequest;
+use crate::inspector::state::{
+    EntityListColumn, EntityListEntry, EntityListGroup, HierarchyRollup, InspectorCache,
+    InspectorState, SearchMode,
+};
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::structural_changes::{
+    PendingStructuralChanges, StructuralChange, StructuralChangeError, StructuralChangesApplied,
+};
+use crate::inspector::widgets::{
+    FieldPath, FieldValue, PendingValueChange, PendingValueChanges, TextInput, TextInputChanged,
+    TextInputSubmitted, TextInputText, Tooltip, text_input, truncate_middle,
+};
 use crate::memory_size::MemorySize;
 
 /// Marker component for the entity list panel container.
@@ -26,20 +69,240 @@ pub struct EntityListContent;
 #[derive(Component)]
 pub struct EntityRow(pub Entity);
 
-/// Marker for the search input.
+/// Position of an entity row in the list, used for zebra striping.
+#[derive(Component)]
+pub struct EntityRowIndex(pub usize);
+
+/// Briefly highlights an entity row after the selection jumped to it,
+/// fading from [`InspectorConfig::row_flash_color`] to the row's usual background.
+#[derive(Component)]
+pub struct EntityRowFlash(pub Timer);
+
+/// How long an entity row flashes after the selection jumped to it.
+const ROW_FLASH_SECONDS: f32 = 0.6;
+
+/// How many frames to wait for the selected entity's row to be laid out before giving up,
+/// e.g. because the entity isn't listed.
+const SCROLL_TO_SELECTION_FRAMES: u32 = 30;
+
+/// Header row of an entity group. Stores the index of the group in [`InspectorCache::groups`].
+#[derive(Component)]
+pub struct EntityGroupHeader(pub usize);
+
+/// What an entity row shows, besides its selection and striping.
+/// Rows are only respawned when this changes.
+#[derive(Component, PartialEq)]
+pub struct EntityRowContent {
+    /// The texts of the row's cells.
+    pub cells: Vec<String>,
+    /// Whether the row is indented below a group header.
+    pub indented: bool,
+    /// Whether the entity is pinned, see [`EntityPinToggle`].
+    pub pinned: bool,
+    /// Whether the row has a [`VisibilityToggle`], because the entity has a [`Visibility`].
+    pub visibility_toggle: bool,
+}
+
+/// Button on an entity row that pins the entity to the top of the list, or unpins it,
+/// see [`InspectorState::pinned_entities`]. Stores the entity of the row.
+#[derive(Component)]
+pub struct EntityPinToggle(pub Entity);
+
+/// Button on an entity row that shows or hides the entity by toggling its [`Visibility`].
+/// Stores the entity of the row.
+#[derive(Component)]
+pub struct VisibilityToggle(pub Entity);
+
+/// Text of a [`VisibilityToggle`].
+#[derive(Component)]
+pub struct VisibilityToggleText;
+
+/// The text of a group header. Headers are only respawned when this changes.
+#[derive(Component)]
+pub struct EntityGroupHeaderText(pub String);
+
+/// Marker for the button that cycles the grouping strategy.
+#[derive(Component)]
+pub struct GroupingStrategyButton;
+
+/// Marker for the text showing the active grouping strategy.
+#[derive(Component)]
+pub struct GroupingStrategyLabel;
+
+/// Marker for the button that cycles the search mode.
+#[derive(Component)]
+pub struct SearchModeButton;
+
+/// Marker for the text showing the active search mode.
+#[derive(Component)]
+pub struct SearchModeLabel;
+
+/// Marker for the button that cycles the change filter,
+/// see [`InspectorState::changed_within_frames`].
+#[derive(Component)]
+pub struct ChangedFilterButton;
+
+/// Marker for the text showing the active change filter.
+#[derive(Component)]
+pub struct ChangedFilterLabel;
+
+/// The change filters the change filter button cycles through, in frames.
+const CHANGED_FILTER_FRAMES: [Option<u32>; 4] = [None, Some(1), Some(10), Some(60)];
+
+/// Button refreshing the entity list right away,
+/// besides the refreshes every [`InspectorConfig::entity_list_refresh_interval`].
+#[derive(Component)]
+pub struct RefreshEntityListButton;
+
+/// Marker for the button that cycles the scene filter.
+#[derive(Component)]
+pub struct SceneFilterButton;
+
+/// Marker for the text showing the active scene filter.
+#[derive(Component)]
+pub struct SceneFilterLabel;
+
+/// Maximum number of characters of a scene path shown on the scene filter button.
+const MAX_SCENE_FILTER_CHARS: usize = 24;
+
+/// Button toggling a [`FilterChip`].
+#[derive(Component)]
+pub struct FilterChipButton(pub FilterChip);
+
+/// Checkbox listing entities of a [`HiddenEntityKind`] that are hidden by default.
+#[derive(Component)]
+pub struct ShowHiddenCheckbox(pub HiddenEntityKind);
+
+/// Marker for the text showing how many entities match the current filter.
+#[derive(Component)]
+pub struct SearchMatchCount;
+
+/// Button that moves the selection through the filtered entities.
+/// `true` steps forward, `false` steps backward.
+#[derive(Component)]
+pub struct SearchStepButton(pub bool);
+
+/// Row of actions applied to every entity in the filtered list, shown only while filtering.
+#[derive(Component)]
+pub struct BulkActionsRow;
+
+/// Button that despawns every filtered entity, after a confirmation showing how many there are.
+#[derive(Component)]
+pub struct BulkDespawnButton;
+
+/// Marker for the text of the [`BulkDespawnButton`].
+#[derive(Component)]
+pub struct BulkDespawnLabel;
+
+/// Button that cancels a pending "Despawn all" confirmation.
+#[derive(Component)]
+pub struct BulkDespawnCancelButton;
+
+/// Field choosing the component of the [`BulkComponentButton`]s. Click it to start typing.
+#[derive(Component)]
+pub struct BulkComponentSearchField;
+
+/// The text inside the [`BulkComponentSearchField`], showing the best matching component.
+#[derive(Component)]
+pub struct BulkComponentSearchText;
+
+/// Field selecting the entity whose id is typed into it. Click it to start typing.
+#[derive(Component)]
+pub struct GoToEntityField;
+
+/// The text inside the [`GoToEntityField`].
+#[derive(Component)]
+pub struct GoToEntityText;
+
+/// Button that adds the chosen component to (or removes it from) every filtered entity.
+#[derive(Component)]
+pub struct BulkComponentButton(pub BulkComponentAction);
+
+/// Marker for the text showing the progress of bulk component edits.
+#[derive(Component)]
+pub struct BulkComponentStatus;
+
+/// Row listing the entities soft-deleted into the [`Graveyard`], shown only while it isn't empty.
+#[derive(Component)]
+pub struct GraveyardRow;
+
+/// Button restoring a soft-deleted entity, see [`restore_entity`].
+#[derive(Component)]
+pub struct GraveyardRestoreButton(pub Entity);
+
+/// Button despawning a soft-deleted entity right away, see [`purge_entity`].
+#[derive(Component)]
+pub struct GraveyardPurgeButton(pub Entity);
+
+/// Maximum number of characters of an entity name shown in its row.
+const MAX_ROW_NAME_CHARS: usize = 20;
+
+/// Maximum number of characters of a group label shown in its header.
+const MAX_GROUP_LABEL_CHARS: usize = 28;
+
+/// Maximum number of shared component names shown in an archetype group label.
+const MAX_GROUP_LABEL_COMPONENTS: usize = 3;
+
+/// Marker for the search field, a [`TextInput`] editing [`InspectorState::filter_text`].
 #[derive(Component)]
 pub struct SearchInput;
 
+/// System that marks the entity cache stale every
+/// [`InspectorConfig::entity_list_refresh_interval`] of real time,
+/// so the entity list picks up entities and names the world changed.
+pub fn auto_refresh_entity_cache(
+    config: Res<InspectorConfig>,
+    time: Res<Time<Real>>,
+    mut since_refresh: Local<Duration>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Some(interval) = config.entity_list_refresh_interval else {
+        return;
+    };
+    *since_refresh += time.delta();
+    if *since_refresh >= interval {
+        *since_refresh = Duration::ZERO;
+        cache.stale = true;
+    }
+}
+
 /// Exclusive system that refreshes the entity cache when state changes.
 /// Uses exclusive world access to avoid resource conflicts.
-pub fn refresh_entity_cache(world: &mut World) {
+///
+/// `frame_ticks` holds the world's change tick at each of the last frames,
+/// telling which changes the change filter lists.
+pub fn refresh_entity_cache(world: &mut World, mut frame_ticks: Local<VecDeque<Tick>>) {
+    let this_run = world.read_change_tick();
+    frame_ticks.push_back(this_run);
+    let max_frames = CHANGED_FILTER_FRAMES
+        .iter()
+        .flatten()
+        .max()
+        .copied()
+        .unwrap_or(0);
+    while frame_ticks.len() > max_frames as usize + 1 {
+        frame_ticks.pop_front();
+    }
+
     // Check if we need to refresh - extract state info first
     let state = world.resource::<InspectorState>();
     let cache = world.resource::<InspectorCache>();
 
-    let needs_refresh = cache.stale;
+    // Changes are only listed while they are recent, so the change filter refreshes every frame
+    let needs_refresh = cache.stale || state.changed_within_frames.is_some();
+    let changed_since = state.changed_within_frames.map(|frames| {
+        let oldest = frame_ticks.len().saturating_sub(frames as usize + 1);
+        frame_ticks[oldest]
+    });
     let filter_text = state.filter_text.clone();
     let required_components = state.required_components.clone();
+    let excluded_components = state.excluded_components.clone();
+    let grouping_strategy = state.grouping_strategy;
+    let scene_filter = state.scene_filter.clone();
+    let filter_chips = state.filter_chips.clone();
+    let shown_hidden_kinds = state.shown_hidden_kinds.clone();
+    let search_mode = state.search_mode;
+    let pinned_entities = state.pinned_entities.clone();
 
     if !needs_refresh {
         return;
@@ -58,26 +321,92 @@ pub fn refresh_entity_cache(world: &mut World) {
         mm.update(world);
     }
 
-    // Query all entities (excluding UI nodes, windows, and inspector-internal entities)
-    let mut query = world.query::<EntityRef>();
-    let entities: Vec<Entity> = query
-        .iter(world)
-        .filter(|e| {
-            !e.contains::<Node>()
-                && !e.contains::<Window>()
-                && !e.contains::<InspectorInternal>()
-        })
-        .map(|e| e.id())
+    // Query all entities that aren't excluded (by default UI nodes, windows,
+    // and inspector-internal entities), unless a filter chip or checkbox asks for them
+    let shown: Vec<TypeId> = filter_chips
+        .iter()
+        .flat_map(|chip| chip.component_types())
+        .chain(shown_hidden_kinds.iter().map(|kind| kind.component_type()))
         .collect();
+    let shows_inspector = shown_hidden_kinds.contains(&HiddenEntityKind::Inspector);
+    let inspector_ui = if shown.is_empty() || shows_inspector {
+        EntityHashSet::default()
+    } else {
+        inspector_ui_entities(world)
+    };
+    // The search text may be a query such as `has:Transform !has:Sprite name:enemy`,
+    // whose name part is searched for within entity names
+    let mut settings = MultipleEntityInspectionSettings {
+        with_component_filter: required_components,
+        without_component_filter: excluded_components,
+        ..default()
+    };
+    // Regexes are kept verbatim besides the component terms, as they may contain spaces and colons
+    let query = match search_mode {
+        SearchMode::Regex => EntityQuery::parse_pattern(&filter_text),
+        _ => EntityQuery::parse(&filter_text),
+    };
+    let query_result = query.and_then(|query| {
+        settings.apply_query(world, &query)?;
+        Ok(query)
+    });
+    let (name_text, mut query_error) = match query_result {
+        Ok(query) => (query.name.unwrap_or_default(), None),
+        Err(error) => (String::new(), Some(error.to_string())),
+    };
+    let name_matcher = match NameMatcher::new(search_mode, &name_text) {
+        Ok(matcher) => Some(matcher),
+        Err(error) => {
+            // Syntax errors span several lines, pointing at the pattern; keep the cause
+            let message = error.to_string();
+            let cause = message
+                .lines()
+                .last()
+                .unwrap_or_default()
+                .trim_start_matches("error: ");
+            query_error.get_or_insert_with(|| {
+                let strings = world.resource::<InspectorStrings>();
+                format!("{} {}", strings.invalid_regex, cause)
+            });
+            None
+        }
+    };
+    // Names are matched below, as the name filter only finds substrings
+    settings.name_filter = None;
 
-    // Build inspection settings with filter
-    let mut settings = MultipleEntityInspectionSettings::default();
-    if !filter_text.is_empty() {
-        settings.name_filter = Some(filter_text.clone());
-    }
-    if !required_components.is_empty() {
-        settings.with_component_filter = required_components;
-    }
+    // While searching, only the entities with a matching name need to be inspected;
+    // the index only finds substrings, so fuzzy searches go through every entity
+    let name_matches = (!name_text.is_empty() && search_mode == SearchMode::Substring)
+        .then(|| entities_labelled_with(world, &name_text, metadata_map.as_ref()?))
+        .flatten();
+    let mut query = world.query::<EntityRef>();
+    let default_exclusions = EntityExclusions::default();
+    let exclusions = world
+        .get_resource::<EntityExclusions>()
+        .unwrap_or(&default_exclusions);
+    // Matches from the search index also include disabled (e.g. soft-deleted) entities,
+    // which the query skips
+    let is_listed = |e: &EntityRef| {
+        !e.contains::<Disabled>()
+            && !exclusions.is_excluded_except(*e, &shown)
+            && !inspector_ui.contains(&e.id())
+            && (filter_chips.is_empty() || filter_chips.iter().any(|chip| chip.matches(*e)))
+            && changed_since.is_none_or(|since| changed_after(*e, since, this_run))
+    };
+    let entities: Vec<Entity> = match name_matches {
+        _ if query_error.is_some() => Vec::new(),
+        Some(matches) => matches
+            .into_iter()
+            .filter_map(|entity| world.get_entity(entity).ok())
+            .filter(is_listed)
+            .map(|e| e.id())
+            .collect(),
+        None => query
+            .iter(world)
+            .filter(is_listed)
+            .map(|e| e.id())
+            .collect(),
+    };
 
     // Inspect entities
     let inspections = if let Some(ref mut mm) = metadata_map {
@@ -87,21 +416,21 @@ pub fn refresh_entity_cache(world: &mut World) {
     };
 
     // Build filtered list - use entity from each inspection since inspect_multiple reorders
-    let filtered_entities: Vec<EntityListEntry> = inspections
+    let mut scores: HashMap<Entity, usize> = HashMap::new();
+    let mut filtered_entities: Vec<EntityListEntry> = inspections
         .into_iter()
         .filter_map(|result| {
             let inspection = result.ok()?;
             let entity = inspection.entity;
-            let name = metadata_map
+            let name = inspection
+                .name
                 .as_ref()
-                .and_then(|mm| inspection.resolve_name(&mm.map))
-                .unwrap_or_else(|| format!("Entity {:?}", entity));
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| entity_label(entity));
 
             // Apply text filter
-            if !filter_text.is_empty()
-                && !name.to_lowercase().contains(&filter_text.to_lowercase())
-            {
-                return None;
+            if !name_text.is_empty() {
+                scores.insert(entity, name_matcher.as_ref()?.score(&name)?);
             }
 
             Some(EntityListEntry {
@@ -109,97 +438,1510 @@ pub fn refresh_entity_cache(world: &mut World) {
                 display_name: name,
                 component_count: inspection.components.as_ref().map(|c| c.len()).unwrap_or(0),
                 memory_size: inspection.total_memory_size.unwrap_or(MemorySize::new(0)),
+                kind: world.get_entity(entity).ok().and_then(FilterChip::of),
+                hidden: world
+                    .get::<Visibility>(entity)
+                    .map(|visibility| *visibility == Visibility::Hidden),
+                archetype: world
+                    .get_entity(entity)
+                    .map_or(0, |entity| entity.archetype().id().index()),
+                children_count: world
+                    .get::<Children>(entity)
+                    .map_or(0, |children| children.len()),
+                rollup: None,
             })
         })
         .collect();
 
+    if let Some(scene) = &scene_filter {
+        let origins = world.get_resource::<SceneOrigins>();
+        filtered_entities.retain(|entry| {
+            origins
+                .and_then(|origins| origins.get(entry.entity))
+                .is_some_and(|origin| origin.scene == *scene)
+        });
+    }
+
+    // Sort by entity for consistent display, then group the entities that aren't pinned
+    filtered_entities.sort_by_key(|e| e.entity.index());
+    let pinned_group = pinned_group(&filtered_entities, &pinned_entities);
+    let pinned = pinned_group
+        .as_ref()
+        .map_or(&[][..], |group| &group.entities[..]);
+    let grouping = EntityGrouping::generate(
+        world,
+        filtered_entities
+            .iter()
+            .map(|e| e.entity)
+            .filter(|entity| !pinned.contains(entity)),
+        grouping_strategy,
+    );
+    let mut groups =
+        build_entity_list_groups(world, &grouping, &filtered_entities, grouping_strategy);
+    if grouping_strategy == GroupingStrategy::Hierarchy {
+        apply_hierarchy_rollups(&grouping, &mut filtered_entities);
+    }
+    // Groups with the closest matches come first; entities keep their order within groups
+    if search_mode == SearchMode::Fuzzy && !scores.is_empty() {
+        groups.sort_by_key(|group| {
+            group
+                .entities
+                .iter()
+                .filter_map(|entity| scores.get(entity))
+                .min()
+                .copied()
+        });
+    }
+    // Pinned entities come first, whatever the search scores
+    groups.splice(0..0, pinned_group);
+
+    // Order entries so that each group's entities are contiguous
+    let order: HashMap<Entity, usize> = groups
+        .iter()
+        .flat_map(|group| &group.entities)
+        .enumerate()
+        .map(|(position, &entity)| (entity, position))
+        .collect();
+    filtered_entities.sort_by_key(|e| order.get(&e.entity).copied().unwrap_or(usize::MAX));
+
     // Put metadata_map back and update cache
     let mut cache = world.resource_mut::<InspectorCache>();
     cache.metadata_map = metadata_map;
     cache.filtered_entities = filtered_entities;
-
-    // Sort by entity for consistent display
-    cache.filtered_entities.sort_by_key(|e| e.entity.index());
+    cache.groups = groups;
+    cache.query_error = query_error;
     cache.stale = false;
 }
 
-/// System that syncs the entity list display with the cache.
-pub fn sync_entity_list(
-    mut commands: Commands,
-    cache: ResMut<InspectorCache>,
-    state: Res<InspectorState>,
-    config: Res<InspectorConfig>,
-    list_content: Query<Entity, With<EntityListContent>>,
-    existing_rows: Query<Entity, With<EntityRow>>,
+/// The label of an entity without a [`Name`] in the entity list.
+fn entity_label(entity: Entity) -> String {
+    format!("Entity {:?}", entity)
+}
+
+/// The entities whose label in the entity list contains `search`, ignoring case:
+/// the named entities found by the [`SearchIndex`], and the unnamed entities labelled
+/// by their name-defining components, as [`resolve_name`] does, or by their id.
+///
+/// [`resolve_name`]: crate::entity_name_resolution::resolve_name
+///
+/// Returns `None` without a [`SearchIndex`].
+fn entities_labelled_with(
+    world: &mut World,
+    search: &str,
+    metadata_map: &ComponentMetadataMap,
+) -> Option<Vec<Entity>> {
+    let search = search.to_lowercase();
+    world.try_resource_scope(|world, mut index: Mut<SearchIndex>| {
+        index.index_archetypes(world.archetypes(), world.components(), metadata_map);
+        let mut matches: Vec<Entity> = index.entities_matching(&search).into_iter().collect();
+        matches.extend(index.unnamed_entities_matching(&search, world.archetypes()));
+        // Searches with other characters can't be part of an id label
+        if search.chars().all(|c| "entity v0123456789".contains(c)) {
+            matches.extend(
+                index
+                    .id_labelled_entities(world.archetypes())
+                    .filter(|&entity| entity_label(entity).to_lowercase().contains(&search)),
+            );
+        }
+        matches
+    })
+}
+
+/// The search text of the entity list, prepared for matching entity names in a [`SearchMode`].
+enum NameMatcher {
+    /// The lowercase search text.
+    Substring(String),
+    Fuzzy(String),
+    Regex(Regex),
+}
+
+impl NameMatcher {
+    /// Prepares `search` for matching in `mode`, failing if it is an invalid regular expression.
+    fn new(mode: SearchMode, search: &str) -> Result<Self, regex::Error> {
+        Ok(match mode {
+            SearchMode::Substring => Self::Substring(search.to_lowercase()),
+            SearchMode::Fuzzy => Self::Fuzzy(search.to_string()),
+            SearchMode::Regex => Self::Regex(Regex::new(search)?),
+        })
+    }
+
+    /// Scores how well the entity name `name` matches, where lower scores are better,
+    /// or returns `None` if it doesn't match.
+    fn score(&self, name: &str) -> Option<usize> {
+        match self {
+            Self::Substring(search) => name.to_lowercase().contains(search).then_some(0),
+            Self::Fuzzy(search) => fuzzy_match_score(search, name),
+            Self::Regex(regex) => regex.is_match(name).then_some(0),
+        }
+    }
+}
+
+/// Returns whether a component of `entity` was added or changed after the tick `since`.
+fn changed_after(entity: EntityRef, since: Tick, this_run: Tick) -> bool {
+    entity.archetype().components().iter().any(|&id| {
+        entity
+            .get_change_ticks_by_id(id)
+            .is_some_and(|ticks| ticks.is_changed(since, this_run))
+    })
+}
+
+/// The inspector's window and UI nodes, which aren't listed even when a filter chip asks for
+/// windows or UI nodes.
+fn inspector_ui_entities(world: &mut World) -> EntityHashSet {
+    let mut entities = EntityHashSet::default();
+    let mut roots =
+        world.query_filtered::<Entity, Or<(With<InspectorUiRoot>, With<InspectorWindow>)>>();
+    let mut pending: Vec<Entity> = roots.iter(world).collect();
+    while let Some(entity) = pending.pop() {
+        if entities.insert(entity)
+            && let Some(children) = world.get::<Children>(entity)
+        {
+            pending.extend(children.iter());
+        }
+    }
+    entities
+}
+
+/// Turns the top level of an [`EntityGrouping`] into labeled groups for the entity list.
+///
+/// Each top-level sub-group (with all of its nested sub-groups) becomes one list group.
+/// Entities directly in the root of the grouping form a group of their own.
+fn build_entity_list_groups(
+    world: &World,
+    grouping: &EntityGrouping,
+    entries: &[EntityListEntry],
+    strategy: GroupingStrategy,
+) -> Vec<EntityListGroup> {
+    let entries: HashMap<Entity, &EntityListEntry> =
+        entries.iter().map(|entry| (entry.entity, entry)).collect();
+
+    let root_group = (!grouping.entities.is_empty()).then(|| grouping.entities.clone());
+    root_group
+        .into_iter()
+        .chain(grouping.sub_groups.iter().map(EntityGrouping::flatten))
+        .enumerate()
+        .map(|(index, entities)| {
+            let label = match strategy {
+                GroupingStrategy::Hierarchy => entities
+                    .first()
+                    .and_then(|root| entries.get(root))
+                    .map(|entry| entry.display_name.clone()),
+                GroupingStrategy::ArchetypeSimilarity => shared_components_label(world, &entities),
+            }
+            .unwrap_or_else(|| format!("Group {}", index + 1));
+            let (component_count, memory_size) = entities
+                .iter()
+                .filter_map(|entity| entries.get(entity))
+                .fold((0, 0), |(components, bytes), entry| {
+                    (
+                        components + entry.component_count,
+                        bytes + entry.memory_size.as_bytes(),
+                    )
+                });
+
+            EntityListGroup {
+                label,
+                entities,
+                component_count,
+                memory_size: MemorySize::new(memory_size),
+            }
+        })
+        .collect()
+}
+
+/// Returns the group of the entities of `entries` that are pinned, in the order pinned,
+/// or `None` if none of them are pinned.
+fn pinned_group(entries: &[EntityListEntry], pinned: &[Entity]) -> Option<EntityListGroup> {
+    let entries: Vec<&EntityListEntry> = pinned
+        .iter()
+        .filter_map(|&entity| entries.iter().find(|entry| entry.entity == entity))
+        .collect();
+    (!entries.is_empty()).then(|| EntityListGroup {
+        label: "Pinned".to_string(),
+        entities: entries.iter().map(|entry| entry.entity).collect(),
+        component_count: entries.iter().map(|entry| entry.component_count).sum(),
+        memory_size: MemorySize::new(
+            entries
+                .iter()
+                .map(|entry| entry.memory_size.as_bytes())
+                .sum(),
+        ),
+    })
+}
+
+/// Sets the [`HierarchyRollup`] of each entry with descendants in the hierarchy `grouping`.
+///
+/// Only descendants that are in the list are counted.
+fn apply_hierarchy_rollups(grouping: &EntityGrouping, entries: &mut [EntityListEntry]) {
+    /// Returns the number of entities, components and bytes in `group` and its sub-groups,
+    /// recording the totals of each single-entity group (an entity and its descendants).
+    fn visit(
+        group: &EntityGrouping,
+        own: &HashMap<Entity, (usize, usize)>,
+        rollups: &mut HashMap<Entity, HierarchyRollup>,
+    ) -> (usize, usize, usize) {
+        let mut totals = (0, 0, 0);
+        for entity in &group.entities {
+            let (components, bytes) = own.get(entity).copied().unwrap_or_default();
+            totals = (totals.0 + 1, totals.1 + components, totals.2 + bytes);
+        }
+        for sub_group in &group.sub_groups {
+            let (entities, components, bytes) = visit(sub_group, own, rollups);
+            totals = (totals.0 + entities, totals.1 + components, totals.2 + bytes);
+        }
+        if let [entity] = group.entities[..]
+            && totals.0 > 1
+        {
+            rollups.insert(
+                entity,
+                HierarchyRollup {
+                    descendants: totals.0 - 1,
+                    component_count: totals.1,
+                    memory_size: MemorySize::new(totals.2),
+                },
+            );
+        }
+        totals
+    }
+
+    let own = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.entity,
+                (entry.component_count, entry.memory_size.as_bytes()),
+            )
+        })
+        .collect();
+    let mut rollups = HashMap::new();
+    visit(grouping, &own, &mut rollups);
+    for entry in entries {
+        entry.rollup = rollups.get(&entry.entity).copied();
+    }
+}
+
+/// Builds a label from the names of the components shared by all `entities`.
+///
+/// Returns `None` if the entities share no components.
+fn shared_components_label(world: &World, entities: &[Entity]) -> Option<String> {
+    let mut component_sets = entities
+        .iter()
+        .filter_map(|&entity| world.get_entity(entity).ok())
+        .map(|entity| entity.archetype().components().to_vec());
+    let mut shared = component_sets.next()?;
+    for components in component_sets {
+        shared.retain(|id| components.contains(id));
+    }
+
+    let mut names: Vec<String> = shared
+        .into_iter()
+        .filter_map(|id| world.components().get_name(id))
+        .map(|name| name.shortname().to_string())
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+    names.sort();
+
+    let mut label = names
+        .iter()
+        .take(MAX_GROUP_LABEL_COMPONENTS)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if names.len() > MAX_GROUP_LABEL_COMPONENTS {
+        label.push_str(", ...");
+    }
+    Some(label)
+}
+
+/// System that syncs the entity list's labels and actions with the cache,
+/// see [`sync_entity_rows`] for the rows themselves.
+pub fn sync_entity_list(
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    strings: Res<InspectorStrings>,
+    mut labels: ParamSet<(
+        Query<&mut Text, With<GroupingStrategyLabel>>,
+        Query<&mut Text, With<SceneFilterLabel>>,
+        Query<(&mut Text, &mut TextColor), With<SearchMatchCount>>,
+        Query<&mut Text, With<BulkDespawnLabel>>,
+        Query<&mut Text, With<SearchModeLabel>>,
+        Query<&mut Text, With<ChangedFilterLabel>>,
+    )>,
+    mut bulk_rows: Query<&mut Node, With<BulkActionsRow>>,
+    mut cancel_buttons: Query<&mut Node, (With<BulkDespawnCancelButton>, Without<BulkActionsRow>)>,
+    mut chips: Query<(&FilterChipButton, &mut ButtonVariant)>,
+    hidden_checkboxes: Query<(Entity, &ShowHiddenCheckbox, Has<Checked>)>,
+    mut search_inputs: Query<&mut TextInput, With<SearchInput>>,
+    mut commands: Commands,
+) {
+    // Only update when cache or selection changes
+    if !cache.is_changed() && !state.is_changed() {
+        return;
+    }
+
+    // The search text may also be set from elsewhere, e.g. by a profile
+    for mut input in &mut search_inputs {
+        if input.text != state.filter_text {
+            input.text.clone_from(&state.filter_text);
+        }
+    }
+
+    for mut text in &mut labels.p0() {
+        let label = grouping_strategy_label(state.grouping_strategy);
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+    for mut text in &mut labels.p4() {
+        let label = search_mode_label(state.search_mode);
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+    }
+    for mut text in &mut labels.p5() {
+        let label = changed_filter_label(state.changed_within_frames, &strings);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+    for mut text in &mut labels.p1() {
+        let label = scene_filter_label(state.scene_filter.as_deref());
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+
+    let is_filtering = !state.filter_text.is_empty()
+        || !state.required_components.is_empty()
+        || !state.excluded_components.is_empty()
+        || !state.filter_chips.is_empty()
+        || state.scene_filter.is_some()
+        || state.changed_within_frames.is_some();
+    for (chip, mut variant) in &mut chips {
+        let active = if state.filter_chips.contains(&chip.0) {
+            ButtonVariant::Primary
+        } else {
+            ButtonVariant::Normal
+        };
+        if *variant != active {
+            *variant = active;
+        }
+    }
+    // Headless checkboxes don't update themselves
+    for (checkbox, kind, checked) in &hidden_checkboxes {
+        let shown = state.shown_hidden_kinds.contains(&kind.0);
+        if shown && !checked {
+            commands.entity(checkbox).insert(Checked);
+        } else if !shown && checked {
+            commands.entity(checkbox).remove::<Checked>();
+        }
+    }
+    for (mut text, mut color) in &mut labels.p2() {
+        let label_color = if cache.query_error.is_some() {
+            config.error_text_color
+        } else {
+            config.muted_text_color
+        };
+        if color.0 != label_color {
+            color.0 = label_color;
+        }
+        let mut label = match (
+            &cache.query_error,
+            is_filtering,
+            cache.filtered_entities.len(),
+        ) {
+            (Some(error), _, _) => error.clone(),
+            (None, false, _) => String::new(),
+            (None, true, 1) => format!("1 {}", strings.search_match),
+            (None, true, count) => format!("{} {}", count, strings.search_matches),
+        };
+        if let Some(notice) = &state.bulk_despawn_notice {
+            if !label.is_empty() {
+                label.push_str(" | ");
+            }
+            label.push_str(notice);
+        }
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+
+    // Bulk actions only make sense for a filtered subset of the entities
+    let confirming = state.bulk_despawn_confirmation.as_ref();
+    for mut node in &mut bulk_rows {
+        let display = if is_filtering || confirming.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    for mut node in &mut cancel_buttons {
+        let display = if confirming.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    for mut text in &mut labels.p3() {
+        let filtered = cache.filtered_entities.len();
+        let label = bulk_despawn_label(confirming.map(Vec::len), filtered, &strings);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+/// System that updates the entity list's rows to match the cache,
+/// with a header for each group of more than one entity.
+///
+/// Existing rows are diffed against [`InspectorCache::filtered_entities`]:
+/// rows of entities that are no longer listed are despawned, rows of new entities are spawned,
+/// and rows whose text is unchanged are only restyled and reordered,
+/// so the scroll position and hover state survive refreshes.
+pub fn sync_entity_rows(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    strings: Res<InspectorStrings>,
+    list_content: Query<(Entity, Option<&Children>), With<EntityListContent>>,
+    mut rows: Query<(
+        &EntityRow,
+        &EntityRowContent,
+        &mut EntityRowIndex,
+        &Hovered,
+        &mut BackgroundColor,
+        &Children,
+    )>,
+    mut headers: Query<(Entity, &mut EntityGroupHeader, &EntityGroupHeaderText)>,
+    mut row_texts: Query<&mut TextColor>,
+) {
+    if !cache.is_changed() && !state.is_changed() && !config.is_changed() {
+        return;
+    }
+    let Some((content_entity, children)) = list_content.iter().next() else {
+        return;
+    };
+
+    let mut existing_rows: HashMap<Entity, Entity> = children
+        .into_iter()
+        .flatten()
+        .filter_map(|&row_entity| {
+            let (row, ..) = rows.get(row_entity).ok()?;
+            Some((row.0, row_entity))
+        })
+        .collect();
+    let mut existing_headers: HashMap<String, Vec<Entity>> = HashMap::new();
+    for (header_entity, _, text) in &headers {
+        existing_headers
+            .entry(text.0.clone())
+            .or_default()
+            .push(header_entity);
+    }
+    let entries: HashMap<Entity, &EntityListEntry> = cache
+        .filtered_entities
+        .iter()
+        .map(|entry| (entry.entity, entry))
+        .collect();
+
+    let mut ordered = Vec::new();
+    let mut stale = Vec::new();
+    let mut index = 0;
+    for (group_index, group) in cache.groups.iter().enumerate() {
+        let has_header = group.entities.len() > 1;
+        if has_header {
+            let text = group_header_text(group);
+            let kept = existing_headers.get_mut(&text).and_then(Vec::pop);
+            let header_entity = match kept.and_then(|entity| headers.get_mut(entity).ok()) {
+                Some((header_entity, mut header, _)) => {
+                    header.0 = group_index;
+                    header_entity
+                }
+                None => {
+                    spawn_group_header(&mut commands, group_index, group, text, &config, &strings)
+                }
+            };
+            ordered.push(header_entity);
+        }
+        for entry in group
+            .entities
+            .iter()
+            .filter_map(|entity| entries.get(entity))
+        {
+            let is_selected = is_entity_selected(&state, entry.entity);
+            let content = EntityRowContent {
+                cells: entity_row_cells(entry, &config.entity_list_columns).0,
+                indented: has_header,
+                pinned: state.pinned_entities.contains(&entry.entity),
+                visibility_toggle: entry.hidden.is_some(),
+            };
+            let kept = existing_rows.remove(&entry.entity).filter(|&row_entity| {
+                let Ok((_, existing, mut row_index, hovered, mut background, row_children)) =
+                    rows.get_mut(row_entity)
+                else {
+                    return false;
+                };
+                if *existing != content {
+                    stale.push(row_entity);
+                    return false;
+                }
+                row_index.0 = index;
+                background.0 = row_background(&config, index, is_selected, hovered.0);
+                let mut colors = row_texts.iter_many_mut(row_children);
+                while let Some(mut color) = colors.fetch_next() {
+                    color.0 = row_text_color(is_selected);
+                }
+                true
+            });
+            ordered.push(kept.unwrap_or_else(|| {
+                spawn_entity_row(
+                    &mut commands,
+                    index,
+                    entry,
+                    content,
+                    is_selected,
+                    &config,
+                    &strings,
+                )
+            }));
+            index += 1;
+        }
+    }
+
+    // Reorder before despawning, so the rows that are gone are detached first
+    if children.map_or(&[][..], |children| &children[..]) != ordered.as_slice() {
+        commands.entity(content_entity).replace_children(&ordered);
+    }
+    stale.extend(existing_rows.into_values());
+    stale.extend(existing_headers.into_values().flatten());
+    for entity in stale {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Returns whether `entity` is part of the current selection.
+fn is_entity_selected(state: &InspectorState, entity: Entity) -> bool {
+    state.selected_entity == Some(entity) || state.selected_entities.contains(&entity)
+}
+
+/// Returns the label shown on the grouping strategy button.
+fn search_mode_label(mode: SearchMode) -> &'static str {
+    match mode {
+        SearchMode::Substring => "Match: Exact",
+        SearchMode::Fuzzy => "Match: Fuzzy",
+        SearchMode::Regex => "Match: Regex",
+    }
+}
+
+fn grouping_strategy_label(strategy: GroupingStrategy) -> &'static str {
+    match strategy {
+        GroupingStrategy::Hierarchy => "Group: Hierarchy",
+        GroupingStrategy::ArchetypeSimilarity => "Group: Archetype",
+    }
+}
+
+/// Returns the label shown on the change filter button.
+fn changed_filter_label(changed_within_frames: Option<u32>, strings: &InspectorStrings) -> String {
+    let label = &strings.changed_filter;
+    match changed_within_frames {
+        None => format!("{}: {}", label, strings.changed_any),
+        Some(1) => format!("{}: 1 {}", label, strings.frame),
+        Some(frames) => format!("{}: {} {}", label, frames, strings.frames),
+    }
+}
+
+/// Returns the label shown on the scene filter button.
+fn scene_filter_label(scene_filter: Option<&str>) -> String {
+    match scene_filter {
+        Some(scene) => format!("Scene: {}", truncate_middle(scene, MAX_SCENE_FILTER_CHARS)),
+        None => "Scene: All".to_string(),
+    }
+}
+
+/// Returns the text of the header of `group`,
+/// showing its entity count and total memory.
+fn group_header_text(group: &EntityListGroup) -> String {
+    format!(
+        "{} ({}) | {} comp | {}",
+        truncate_middle(&group.label, MAX_GROUP_LABEL_CHARS),
+        group.entities.len(),
+        group.component_count,
+        group.memory_size
+    )
+}
+
+/// Spawns the header row of an entity group, without a parent, returning it.
+/// Shows the header's `text` and a select-all action.
+fn spawn_group_header(
+    commands: &mut Commands,
+    group_index: usize,
+    group: &EntityListGroup,
+    text: String,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) -> Entity {
+    commands
+        .spawn((
+            Node {
+                width: Percent(100.0),
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                column_gap: config.column_gap,
+                padding: UiRect::axes(Px(6.0), Px(3.0)),
+                margin: UiRect::top(Px(4.0)),
+                border: UiRect::bottom(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(config.border_color),
+            EntityGroupHeader(group_index),
+            EntityGroupHeaderText(text.clone()),
+        ))
+        .with_children(|header| {
+            let mut text = header.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            ));
+            if group.label.chars().count() > MAX_GROUP_LABEL_CHARS {
+                text.insert(Tooltip(group.label.clone()));
+            }
+            header.spawn((
+                button(
+                    ButtonProps::default(),
+                    (),
+                    bevy::prelude::Spawn((
+                        Text::new(strings.select_all.clone()),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                    )),
+                ),
+                observe(on_group_select_all_click),
+            ));
+        })
+        .id()
+}
+
+/// Observer for a group header's "Select all" button.
+/// Selects every entity in the group, showing the first one in the detail panel.
+fn on_group_select_all_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    cache: Res<InspectorCache>,
+    headers: Query<&EntityGroupHeader>,
+    parents: Query<&ChildOf>,
+) {
+    let Some(header) = parents
+        .iter_ancestors(activate.entity)
+        .find_map(|ancestor| headers.get(ancestor).ok())
+    else {
+        return;
+    };
+    let Some(group) = cache.groups.get(header.0) else {
+        return;
+    };
+    state.selected_entity = group.entities.first().copied();
+    state.selected_entities = group.entities.clone();
+}
+
+/// Label of the [`BulkDespawnButton`], given the number of entities awaiting confirmation
+/// and the number of filtered entities.
+fn bulk_despawn_label(
+    confirming: Option<usize>,
+    filtered: usize,
+    strings: &InspectorStrings,
+) -> String {
+    match confirming {
+        Some(count) => format!("{} ({})", strings.confirm_despawn, count),
+        None => format!("{} {}", strings.despawn_all, filtered),
+    }
+}
+
+/// Observer for the "Despawn all" button.
+///
+/// The first click previews the filtered entities and asks for confirmation,
+/// the second queues despawning exactly the previewed entities as [`StructuralChange`]s.
+/// Protected entities are skipped, like with
+/// [`despawn_entities`](crate::inspector::despawn_entities).
+fn on_bulk_despawn_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    cache: Res<InspectorCache>,
+    mut pending: ResMut<PendingStructuralChanges>,
+) {
+    let Some(entities) = state.bulk_despawn_confirmation.take() else {
+        let filtered = cache
+            .filtered_entities
+            .iter()
+            .map(|entry| entry.entity)
+            .collect();
+        state.bulk_despawn_confirmation = Some(filtered);
+        state.bulk_despawn_notice = None;
+        return;
+    };
+    for &entity in &entities {
+        pending.push(StructuralChange::Despawn {
+            entity,
+            confirmed: false,
+        });
+    }
+    state.bulk_despawn_pending = entities;
+}
+
+/// Observer: reports the outcome of a confirmed "Despawn all" once its despawns are applied.
+///
+/// Entities that disappeared first, e.g. as descendants of an earlier entity, are not counted.
+pub(crate) fn report_bulk_despawn_results(
+    applied: On<StructuralChangesApplied>,
+    mut state: ResMut<InspectorState>,
+) {
+    if state.bulk_despawn_pending.is_empty() {
+        return;
+    }
+    let pending = std::mem::take(&mut state.bulk_despawn_pending);
+    let mut report = BulkDespawnReport::default();
+    for (change, result) in &applied.results {
+        let StructuralChange::Despawn { entity, .. } = *change else {
+            continue;
+        };
+        if !pending.contains(&entity) {
+            continue;
+        }
+        match result {
+            Ok(()) => report.despawned += 1,
+            Err(StructuralChangeError::Despawn(error)) => report.skipped.push(error.clone()),
+            Err(_) => {}
+        }
+    }
+
+    let mut notice = format!("Despawned {} entities", report.despawned);
+    if !report.skipped.is_empty() {
+        notice.push_str(&format!(", skipped {} protected", report.skipped.len()));
+    }
+    info!("{}", notice);
+    state.bulk_despawn_notice = Some(notice);
+}
+
+/// System that lists the soft-deleted entities of the [`Graveyard`] whenever it changes.
+pub fn sync_graveyard_row(
+    mut commands: Commands,
+    graveyard: Res<Graveyard>,
+    config: Res<InspectorConfig>,
+    mut rows: Query<(Entity, &mut Node), With<GraveyardRow>>,
+) {
+    if !graveyard.is_changed() {
+        return;
+    }
+    let grace_period = config.despawn_grace_period.unwrap_or_default();
+    for (row, mut node) in &mut rows {
+        node.display = if graveyard.entries().is_empty() {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        commands
+            .entity(row)
+            .despawn_children()
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(format!(
+                        "Recently despawned, restorable for {:.0}s:",
+                        grace_period.as_secs_f32()
+                    )),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextColor(config.muted_text_color),
+                    Node {
+                        width: Percent(100.0),
+                        ..default()
+                    },
+                ));
+                for entry in graveyard.entries().iter().rev() {
+                    row.spawn(Node {
+                        width: Percent(100.0),
+                        display: Display::Flex,
+                        align_items: AlignItems::Center,
+                        column_gap: config.column_gap,
+                        ..default()
+                    })
+                    .with_children(|entry_row| {
+                        entry_row.spawn((
+                            Text::new(truncate_middle(&entry.name, MAX_ROW_NAME_CHARS)),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                            Node {
+                                flex_grow: 1.0,
+                                ..default()
+                            },
+                        ));
+                        entry_row.spawn((
+                            button(
+                                ButtonProps::default(),
+                                GraveyardRestoreButton(entry.entity),
+                                bevy::prelude::Spawn((
+                                    Text::new("Restore"),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_graveyard_restore_click),
+                        ));
+                        entry_row.spawn((
+                            button(
+                                ButtonProps::default(),
+                                GraveyardPurgeButton(entry.entity),
+                                bevy::prelude::Spawn((
+                                    Text::new("Despawn now"),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_graveyard_purge_click),
+                        ));
+                    });
+                }
+            });
+    }
+}
+
+/// Observer: restores a soft-deleted entity and selects it.
+fn on_graveyard_restore_click(
+    activate: On<Activate>,
+    buttons: Query<&GraveyardRestoreButton>,
+    mut commands: Commands,
+) {
+    let Ok(&GraveyardRestoreButton(entity)) = buttons.get(activate.entity) else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        if restore_entity(world, entity) {
+            world.write_message(SelectionRequest::Entity(entity));
+            world.resource_mut::<InspectorCache>().stale = true;
+        }
+    });
+}
+
+/// Observer: despawns a soft-deleted entity without waiting for its grace period.
+fn on_graveyard_purge_click(
+    activate: On<Activate>,
+    buttons: Query<&GraveyardPurgeButton>,
+    mut commands: Commands,
+) {
+    let Ok(&GraveyardPurgeButton(entity)) = buttons.get(activate.entity) else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        purge_entity(world, entity);
+    });
+}
+
+/// Observer: picks the component for bulk edits matching the edited search text.
+fn on_bulk_component_search_changed(
+    changed: On<TextInputChanged>,
+    mut state: ResMut<InspectorState>,
+) {
+    state.bulk_component_search.clone_from(&changed.text);
+}
+
+/// Parses an entity id typed into the "Go to" field:
+/// either as displayed by [`Entity`], e.g. `42v3`, or as its raw bits.
+fn parse_entity_reference(text: &str) -> Option<Entity> {
+    let text = text.trim();
+    parse_entity_id(text).or_else(|| Entity::try_from_bits(text.parse().ok()?))
+}
+
+/// Observer: filters the entity list by the edited search text.
+fn on_entity_search_changed(
+    changed: On<TextInputChanged>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    state.filter_text.clone_from(&changed.text);
+    cache.stale = true;
+}
+
+/// Observer: edits the id in the "Go to" field.
+fn on_go_to_entity_changed(changed: On<TextInputChanged>, mut state: ResMut<InspectorState>) {
+    state.go_to_entity_text.clone_from(&changed.text);
+    state.go_to_entity_error = None;
+}
+
+/// Observer: selects the entity whose id was typed into the "Go to" field, if it exists.
+fn on_go_to_entity_submit(
+    submit: On<TextInputSubmitted>,
+    mut state: ResMut<InspectorState>,
+    mut inputs: Query<&mut TextInput>,
+    entities: &Entities,
+    mut requests: MessageWriter<SelectionRequest>,
+) {
+    let text = submit.text.trim();
+    state.go_to_entity_error = match parse_entity_reference(text) {
+        None => Some(format!("{:?} is not an entity id", text)),
+        Some(entity) if !entities.contains(entity) => Some(format!("{} does not exist", entity)),
+        Some(entity) => {
+            requests.write(SelectionRequest::Entity(entity));
+            state.go_to_entity_text.clear();
+            if let Ok(mut input) = inputs.get_mut(submit.entity) {
+                input.text.clear();
+            }
+            None
+        }
+    };
+}
+
+/// System that shows the id typed into the "Go to" field, or why it could not be selected.
+pub fn sync_go_to_entity_field(
+    state: Res<InspectorState>,
+    input_focus: Res<InputFocus>,
+    config: Res<InspectorConfig>,
+    strings: Res<InspectorStrings>,
+    fields: Query<Entity, With<GoToEntityField>>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<GoToEntityText>>,
+) {
+    if !state.is_changed() && !input_focus.is_changed() {
+        return;
+    }
+
+    let focused = fields.iter().any(|field| input_focus.get() == Some(field));
+    let go_to = &strings.go_to;
+    let (label, color) = match &state.go_to_entity_error {
+        Some(error) => (format!("{}: {}", go_to, error), config.error_text_color),
+        None if focused => (
+            format!("{}: {}|", go_to, state.go_to_entity_text),
+            Color::srgba(0.9, 0.9, 0.9, 1.0),
+        ),
+        None => (
+            format!("{}: {}", go_to, strings.go_to_hint),
+            config.muted_text_color,
+        ),
+    };
+    for (mut text, mut text_color) in &mut texts {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+        if text_color.0 != color {
+            text_color.0 = color;
+        }
+    }
+}
+
+/// Observer for the "Add to all" and "Remove from all" buttons.
+/// Queues the edit of every filtered entity, carried out over the next frames.
+fn on_bulk_component_click(
+    activate: On<Activate>,
+    buttons: Query<&BulkComponentButton>,
+    state: Res<InspectorState>,
+    cache: Res<InspectorCache>,
+    registry: Res<AppTypeRegistry>,
+    mut edits: ResMut<BulkComponentEdits>,
+) {
+    let Ok(BulkComponentButton(action)) = buttons.get(activate.entity) else {
+        return;
+    };
+    let Some((type_id, _)) = best_component_match(&registry.read(), &state.bulk_component_search)
+    else {
+        warn!(
+            "No reflected component matches {:?}",
+            state.bulk_component_search
+        );
+        return;
+    };
+    let entities = cache
+        .filtered_entities
+        .iter()
+        .map(|entry| entry.entity)
+        .collect();
+    edits.push(type_id, *action, entities);
+}
+
+/// System that shows the component chosen for bulk edits and the progress of those edits.
+pub fn sync_bulk_component_row(
+    state: Res<InspectorState>,
+    edits: Res<BulkComponentEdits>,
+    input_focus: Res<InputFocus>,
+    registry: Res<AppTypeRegistry>,
+    fields: Query<Entity, With<BulkComponentSearchField>>,
+    mut labels: ParamSet<(
+        Query<&mut Text, With<BulkComponentSearchText>>,
+        Query<&mut Text, With<BulkComponentStatus>>,
+    )>,
+) {
+    if !state.is_changed() && !edits.is_changed() && !input_focus.is_changed() {
+        return;
+    }
+
+    let focused = fields.iter().any(|field| input_focus.get() == Some(field));
+    let search = &state.bulk_component_search;
+    let label = match best_component_match(&registry.read(), search) {
+        _ if focused => format!("Component: {}|", search),
+        Some((_, name)) => format!("Component: {}", name),
+        None if search.is_empty() => "Component: (click to search)".to_string(),
+        None => format!("Component: no match for {:?}", search),
+    };
+    for mut text in &mut labels.p0() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+
+    let status = edits.status().unwrap_or_default();
+    for mut text in &mut labels.p1() {
+        if text.0 != status {
+            text.0 = status.to_string();
+        }
+    }
+}
+
+/// Observer for the cancel button of a pending "Despawn all" confirmation.
+fn on_bulk_despawn_cancel_click(_activate: On<Activate>, mut state: ResMut<InspectorState>) {
+    state.bulk_despawn_confirmation = None;
+}
+
+/// Observer for the scene filter button.
+/// Cycles through every scene that entities were spawned from, then back to showing all entities.
+fn on_scene_filter_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    origins: Res<SceneOrigins>,
+) {
+    let scenes = origins.scenes();
+    let next = match &state.scene_filter {
+        None => scenes.first(),
+        Some(current) => scenes
+            .iter()
+            .position(|scene| scene == current)
+            .and_then(|position| scenes.get(position + 1)),
+    };
+    state.scene_filter = next.map(|scene| scene.to_string());
+    cache.stale = true;
+}
+
+/// Observer for the filter chips. Toggles the clicked chip.
+fn on_filter_chip_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    chips: Query<&FilterChipButton>,
+) {
+    let Ok(chip) = chips.get(activate.entity) else {
+        return;
+    };
+    if let Some(position) = state
+        .filter_chips
+        .iter()
+        .position(|&active| active == chip.0)
+    {
+        state.filter_chips.remove(position);
+    } else {
+        state.filter_chips.push(chip.0);
+    }
+    cache.stale = true;
+}
+
+/// Observer for the checkboxes listing hidden kinds of entities.
+fn on_show_hidden_change(
+    change: On<ValueChange<bool>>,
+    checkboxes: Query<&ShowHiddenCheckbox>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(checkbox) = checkboxes.get(change.source) else {
+        return;
+    };
+    state.shown_hidden_kinds.retain(|&kind| kind != checkbox.0);
+    if change.value {
+        state.shown_hidden_kinds.push(checkbox.0);
+    }
+    cache.stale = true;
+}
+
+/// Returns the entity after (or before) `current` in `entities`, wrapping around at the ends.
+///
+/// If `current` is not in the list, stepping starts from the first (or last) entity.
+fn step_selection(entities: &[Entity], current: Option<Entity>, forward: bool) -> Option<Entity> {
+    let len = entities.len();
+    if len == 0 {
+        return None;
+    }
+    let position = current.and_then(|current| entities.iter().position(|&e| e == current));
+    let next = match (position, forward) {
+        (Some(position), true) => (position + 1) % len,
+        (Some(position), false) => (position + len - 1) % len,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+    Some(entities[next])
+}
+
+/// Observer for the Next/Previous buttons. Moves the selection through the filtered entities.
+fn on_search_step_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    cache: Res<InspectorCache>,
+    buttons: Query<&SearchStepButton>,
+) {
+    let Ok(step) = buttons.get(activate.entity) else {
+        return;
+    };
+    let entities: Vec<Entity> = cache.filtered_entities.iter().map(|e| e.entity).collect();
+    if let Some(entity) = step_selection(&entities, state.selected_entity, step.0) {
+        state.selected_entity = Some(entity);
+        state.selected_entities.clear();
+    }
+}
+
+/// Observer for the search mode button. Cycles through the available modes.
+fn on_search_mode_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    state.search_mode = match state.search_mode {
+        SearchMode::Substring => SearchMode::Fuzzy,
+        SearchMode::Fuzzy => SearchMode::Regex,
+        SearchMode::Regex => SearchMode::Substring,
+    };
+    cache.stale = true;
+}
+
+/// Observer for the refresh button.
+fn on_refresh_entity_list_click(_activate: On<Activate>, mut cache: ResMut<InspectorCache>) {
+    cache.stale = true;
+}
+
+/// Observer for the change filter button. Cycles through [`CHANGED_FILTER_FRAMES`].
+fn on_changed_filter_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let position = CHANGED_FILTER_FRAMES
+        .iter()
+        .position(|&frames| frames == state.changed_within_frames)
+        .unwrap_or(0);
+    let next = (position + 1) % CHANGED_FILTER_FRAMES.len();
+    state.changed_within_frames = CHANGED_FILTER_FRAMES[next];
+    cache.stale = true;
+}
+
+/// Observer for the grouping strategy button. Cycles through the available strategies.
+fn on_grouping_strategy_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    state.grouping_strategy = match state.grouping_strategy {
+        GroupingStrategy::Hierarchy => GroupingStrategy::ArchetypeSimilarity,
+        GroupingStrategy::ArchetypeSimilarity => GroupingStrategy::Hierarchy,
+    };
+    cache.stale = true;
+}
+
+/// Returns the texts of the cells of the row of `entry`: one per column of `columns`,
+/// with the name truncated, followed by the hierarchy rollup.
+/// Also returns whether the name was truncated.
+fn entity_row_cells(entry: &EntityListEntry, columns: &[EntityListColumn]) -> (Vec<String>, bool) {
+    let mut truncated = false;
+    let mut cells: Vec<String> = columns
+        .iter()
+        .map(|column| match column {
+            EntityListColumn::Name => {
+                let display_name = truncate_middle(&entry.display_name, MAX_ROW_NAME_CHARS);
+                truncated = matches!(display_name, Cow::Owned(_));
+                format!(
+                    "{} {}",
+                    entry.kind.map_or(' ', FilterChip::glyph),
+                    display_name
+                )
+            }
+            EntityListColumn::Archetype => format!("A{}", entry.archetype),
+            EntityListColumn::ComponentCount => format!("{} comp", entry.component_count),
+            EntityListColumn::Memory => entry.memory_size.to_string(),
+            EntityListColumn::ChildrenCount => format!("{} child", entry.children_count),
+            EntityListColumn::EntityId => entry.entity.to_string(),
+        })
+        .collect();
+    // Roots of a hierarchy also show what they cost with all their descendants
+    cells.push(entry.rollup.map_or_else(String::new, |rollup| {
+        format!(
+            "+{} descendants: {} comp | {}",
+            rollup.descendants, rollup.component_count, rollup.memory_size
+        )
+    }));
+    (cells, truncated)
+}
+
+/// Returns the grid track of `column` in the entity list rows.
+/// The name takes up the remaining width, the other columns fit their usual text.
+fn entity_column_track(column: EntityListColumn, font_size: f32) -> RepeatedGridTrack {
+    let chars = match column {
+        EntityListColumn::Name => return GridTrack::flex(1.0),
+        EntityListColumn::Archetype => 5.0,
+        EntityListColumn::ComponentCount => 8.0,
+        EntityListColumn::Memory => 10.0,
+        EntityListColumn::ChildrenCount => 9.0,
+        EntityListColumn::EntityId => 9.0,
+    };
+    // Roughly the advance of a character, which is about 0.6 em for most fonts
+    GridTrack::px(chars * font_size * 0.6)
+}
+
+/// Returns the color of an entity row's text.
+fn row_text_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::WHITE
+    } else {
+        Color::srgba(0.9, 0.9, 0.9, 1.0)
+    }
+}
+
+/// Spawns the row of `entry`, without a parent, returning it.
+fn spawn_entity_row(
+    commands: &mut Commands,
+    index: usize,
+    entry: &EntityListEntry,
+    content: EntityRowContent,
+    is_selected: bool,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) -> Entity {
+    let (cells, truncated) = entity_row_cells(entry, &config.entity_list_columns);
+    // Truncated names keep the full name available as a tooltip
+    let tooltip = truncated.then(|| Tooltip(entry.display_name.clone()));
+    // The columns, then the rollup, the pin toggle and the visibility toggle
+    let pinned = content.pinned;
+    let mut columns: Vec<RepeatedGridTrack> = config
+        .entity_list_columns
+        .iter()
+        .map(|&column| entity_column_track(column, config.small_font_size))
+        .collect();
+    columns.push(GridTrack::auto());
+    columns.push(GridTrack::auto());
+    columns.push(GridTrack::auto());
+
+    let mut row = commands.spawn((
+        Node {
+            width: Percent(100.0),
+            min_height: Px(config.row_min_height),
+            display: Display::Grid,
+            grid_template_columns: columns,
+            column_gap: config.column_gap,
+            align_items: AlignItems::Center,
+            padding: UiRect {
+                left: Px(if content.indented { 14.0 } else { 6.0 }),
+                ..UiRect::axes(Px(6.0), Px(3.0))
+            },
+            ..default()
+        },
+        bevy::ui_widgets::Button,
+        Hovered::default(),
+        BackgroundColor(row_background(config, index, is_selected, false)),
+        EntityRow(entry.entity),
+        EntityRowIndex(index),
+        content,
+        observe(on_entity_row_click),
+    ));
+    for cell in cells {
+        row.with_child((
+            Text::new(cell),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextColor(row_text_color(is_selected)),
+            TextLayout::new_with_no_wrap(),
+            Node {
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            Pickable::IGNORE,
+        ));
+    }
+    if let Some(tooltip) = tooltip {
+        row.insert(tooltip);
+    }
+    row.with_child((
+        Node {
+            padding: UiRect::horizontal(Px(4.0)),
+            ..default()
+        },
+        bevy::ui_widgets::Button,
+        Tooltip(strings.pin_tooltip.clone()),
+        EntityPinToggle(entry.entity),
+        observe(on_entity_pin_toggle_click),
+        children![(
+            Text::new(if pinned { &strings.unpin } else { &strings.pin }),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+            Pickable::IGNORE,
+        )],
+    ));
+    if let Some(hidden) = entry.hidden {
+        row.with_child((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                ..default()
+            },
+            bevy::ui_widgets::Button,
+            Tooltip(strings.visibility_tooltip.clone()),
+            VisibilityToggle(entry.entity),
+            observe(on_visibility_toggle_click),
+            children![(
+                Text::new(visibility_toggle_label(hidden)),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+                VisibilityToggleText,
+                Pickable::IGNORE,
+            )],
+        ));
+    }
+    row.id()
+}
+
+/// Observer for [`EntityPinToggle`] clicks. Pins the entity, or unpins it if it is pinned.
+fn on_entity_pin_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&EntityPinToggle>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
 ) {
-    // Only update when cache or selection changes
-    if !cache.is_changed() && !state.is_changed() {
-        return;
-    }
-
-    let Ok(content_entity) = list_content.iter().next().ok_or(()) else {
+    let Ok(toggle) = toggles.get(activate.entity) else {
         return;
     };
-
-    // Clear existing rows
-    for row_entity in existing_rows.iter() {
-        commands.entity(row_entity).despawn();
+    let pinned = &mut state.pinned_entities;
+    match pinned.iter().position(|&entity| entity == toggle.0) {
+        Some(index) => {
+            pinned.remove(index);
+        }
+        None => pinned.push(toggle.0),
     }
+    cache.stale = true;
+}
 
-    // Spawn new rows
-    commands.entity(content_entity).with_children(|list| {
-        for entry in &cache.filtered_entities {
-            let is_selected = state.selected_entity == Some(entry.entity);
-            spawn_entity_row(list, entry, is_selected, &config);
-        }
-    });
+/// Returns the text of a [`VisibilityToggle`]: an open eye for a shown entity,
+/// a closed one for a hidden entity.
+fn visibility_toggle_label(hidden: bool) -> &'static str {
+    if hidden { "(-)" } else { "(o)" }
 }
 
-/// Spawns a single entity row button.
-fn spawn_entity_row(
-    parent: &mut ChildSpawnerCommands<'_>,
-    entry: &EntityListEntry,
-    is_selected: bool,
-    config: &InspectorConfig,
+/// Observer for [`VisibilityToggle`] clicks.
+/// Hides the entity if it is shown and shows it otherwise, through the write-back path
+/// so the change can be undone. Shift+click applies the same visibility to its descendants.
+fn on_visibility_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&VisibilityToggle>,
+    visibilities: Query<&Visibility>,
+    children: Query<&Children>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut pending: ResMut<PendingValueChanges>,
 ) {
-    // Truncate long names
-    let display_name = if entry.display_name.len() > 20 {
-        format!("{}...", &entry.display_name[..17])
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    let Ok(visibility) = visibilities.get(toggle.0) else {
+        return;
+    };
+    let value = if *visibility == Visibility::Hidden {
+        Visibility::Visible
     } else {
-        entry.display_name.clone()
+        Visibility::Hidden
     };
-
-    let label = format!(
-        "{:20} {} comp | {}",
-        display_name, entry.component_count, entry.memory_size
+    let descendants: Vec<Entity> = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        children
+            .iter_descendants(toggle.0)
+            .filter(|&descendant| visibilities.contains(descendant))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    pending.push_batched(
+        PendingValueChange {
+            field_path: FieldPath::new::<Visibility>(toggle.0, []),
+            value: FieldValue::Reflected(Box::new(value)),
+        },
+        &descendants,
     );
+}
 
-    parent.spawn((
-        button(
-            ButtonProps::default(),
-            EntityRow(entry.entity),
-            bevy::prelude::Spawn((
-                Text::new(label),
-                TextFont {
-                    font_size: config.small_font_size,
-                    ..default()
-                },
-                TextColor(if is_selected {
-                    Color::WHITE
-                } else {
-                    Color::srgba(0.9, 0.9, 0.9, 1.0)
-                }),
-            )),
-        ),
-        observe(on_entity_row_click),
-    ));
+/// System that keeps the text of each [`VisibilityToggle`] in sync with the entity's
+/// [`Visibility`], which may change between list refreshes.
+pub fn sync_visibility_toggles(
+    toggles: Query<(&VisibilityToggle, &Children)>,
+    visibilities: Query<&Visibility>,
+    mut texts: Query<&mut Text, With<VisibilityToggleText>>,
+) {
+    for (toggle, children) in &toggles {
+        let Ok(visibility) = visibilities.get(toggle.0) else {
+            continue;
+        };
+        let label = visibility_toggle_label(*visibility == Visibility::Hidden);
+        let mut texts = texts.iter_many_mut(children);
+        while let Some(mut text) = texts.fetch_next() {
+            if text.0 != label {
+                text.0 = label.to_string();
+            }
+        }
+    }
+}
+
+/// Returns the background color of an entity row.
+/// Selection takes precedence over hover, which takes precedence over striping.
+fn row_background(
+    config: &InspectorConfig,
+    index: usize,
+    is_selected: bool,
+    is_hovered: bool,
+) -> Color {
+    if is_selected {
+        config.row_selected_color
+    } else if is_hovered {
+        config.row_hover_color
+    } else if index % 2 == 1 {
+        config.row_stripe_color
+    } else {
+        config.row_color
+    }
 }
 
 /// Observer for entity row clicks.
 /// Traverses up the parent hierarchy to find the EntityRow component.
+///
+/// Ctrl+click adds the entity to or removes it from the selection,
+/// Shift+click selects the listed entities from the selected entity up to it.
 fn on_entity_row_click(
     activate: On<Activate>,
     mut state: ResMut<InspectorState>,
+    cache: Res<InspectorCache>,
+    keys: Res<ButtonInput<KeyCode>>,
     rows: Query<&EntityRow>,
     parents: Query<&ChildOf>,
 ) {
@@ -207,7 +1949,15 @@ fn on_entity_row_click(
     let mut current = activate.entity;
     loop {
         if let Ok(row) = rows.get(current) {
-            state.selected_entity = Some(row.0);
+            if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+                let order: Vec<Entity> = cache.filtered_entities.iter().map(|e| e.entity).collect();
+                state.select_range(row.0, &order);
+            } else if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+                state.toggle_selected(row.0);
+            } else {
+                state.selected_entity = Some(row.0);
+                state.selected_entities.clear();
+            }
             return;
         }
         if let Ok(child_of) = parents.get(current) {
@@ -219,19 +1969,109 @@ fn on_entity_row_click(
     warn!("Could not find EntityRow in hierarchy!");
 }
 
-/// System that updates selection highlight without respawning rows.
-/// Note: Selection highlighting is handled during row spawning in sync_entity_list.
-/// This system is a placeholder for future improvements.
+/// System that updates row backgrounds for hover and selection without respawning rows.
 pub fn sync_selection_highlight(
-    _state: Res<InspectorState>,
-    _rows: Query<&EntityRow>,
+    mut commands: Commands,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    time: Res<Time<Real>>,
+    mut rows: Query<(
+        Entity,
+        &EntityRow,
+        &EntityRowIndex,
+        Ref<Hovered>,
+        &mut BackgroundColor,
+        Option<&mut EntityRowFlash>,
+    )>,
+) {
+    let refresh_all = state.is_changed() || config.is_changed();
+    for (row_entity, row, index, hovered, mut background, flash) in &mut rows {
+        if !refresh_all && !hovered.is_changed() && flash.is_none() {
+            continue;
+        }
+        let is_selected = is_entity_selected(&state, row.0);
+        let mut color = row_background(&config, index.0, is_selected, hovered.0);
+        if let Some(mut flash) = flash {
+            flash.0.tick(time.delta());
+            color = config.row_flash_color.mix(&color, flash.0.fraction());
+            if flash.0.is_finished() {
+                commands.entity(row_entity).remove::<EntityRowFlash>();
+            }
+        }
+        background.0 = color;
+    }
+}
+
+/// System that scrolls the entity list to the selected entity's row and flashes it,
+/// when the selection changed other than by clicking the row,
+/// e.g. through hierarchy navigation, picking or a search jump.
+///
+/// Rows of newly listed entities are only laid out a frame later, so the scroll waits
+/// up to [`SCROLL_TO_SELECTION_FRAMES`] frames for the row.
+pub fn scroll_to_selection(
+    mut commands: Commands,
+    state: Res<InspectorState>,
+    mut last_selected: Local<Option<Entity>>,
+    mut pending: Local<Option<(Entity, u32)>>,
+    mut contents: Query<
+        (&ComputedNode, &UiGlobalTransform, &mut ScrollPosition),
+        With<EntityListContent>,
+    >,
+    rows: Query<(
+        Entity,
+        &EntityRow,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &Hovered,
+    )>,
 ) {
-    // Selection highlighting is handled during row spawning in sync_entity_list.
-    // This system is a no-op placeholder for future improvements.
+    if state.selected_entity != *last_selected {
+        *last_selected = state.selected_entity;
+        *pending = state
+            .selected_entity
+            .map(|entity| (entity, SCROLL_TO_SELECTION_FRAMES));
+    }
+    let Some((target, frames_left)) = *pending else {
+        return;
+    };
+    let row = rows
+        .iter()
+        .find(|(_, row, node, ..)| row.0 == target && node.size().y > 0.0);
+    let (Some((row_entity, _, row_node, row_transform, hovered)), Ok(content)) =
+        (row, contents.single_mut())
+    else {
+        *pending = frames_left.checked_sub(1).map(|frames| (target, frames));
+        return;
+    };
+    let (node, transform, mut scroll) = content;
+    *pending = None;
+    // The row under the pointer was just clicked, and is already in view
+    if hovered.0 {
+        return;
+    }
+
+    // Offsets in physical pixels from the top of the visible part of the list
+    let row_top = row_transform.translation.y - row_node.size().y / 2.0;
+    let list_top = transform.translation.y - node.size().y / 2.0;
+    let offset = row_top - list_top;
+    if offset < 0.0 || offset + row_node.size().y > node.size().y {
+        let centered = offset + row_node.size().y / 2.0 - node.size().y / 2.0;
+        scroll.y = (scroll.y + centered * node.inverse_scale_factor()).max(0.0);
+    }
+    commands
+        .entity(row_entity)
+        .insert(EntityRowFlash(Timer::from_seconds(
+            ROW_FLASH_SECONDS,
+            TimerMode::Once,
+        )));
 }
 
 /// Spawns the entity list panel structure.
-pub fn spawn_entity_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConfig) {
+pub fn spawn_entity_list_panel(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
     parent
         .spawn((
             Node {
@@ -246,31 +2086,399 @@ pub fn spawn_entity_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
             EntityListPanel,
         ))
         .with_children(|panel| {
-            // Search bar placeholder
+            // "New entity..." dialog, overlaying the list while it is open
+            spawn_entity_wizard_dialog(panel, config, strings);
+            // "Components..." dialog, overlaying the list while it is open
+            spawn_component_filter_dialog(panel, config, strings);
+
+            // Search bar: the search field on its own line, then the search actions
             panel
                 .spawn((
                     Node {
                         width: Percent(100.0),
+                        display: Display::Flex,
+                        flex_wrap: FlexWrap::Wrap,
+                        align_items: AlignItems::Center,
+                        column_gap: config.column_gap,
+                        row_gap: config.item_gap,
                         padding: config.panel_padding,
                         border: UiRect::bottom(Px(1.0)),
                         ..default()
                     },
                     BorderColor::all(config.border_color),
-                    SearchInput,
                 ))
                 .with_children(|search| {
+                    search
+                        .spawn((
+                            Node {
+                                width: Percent(100.0),
+                                padding: UiRect::axes(Px(6.0), Px(4.0)),
+                                border: UiRect::all(Px(1.0)),
+                                ..default()
+                            },
+                            BorderColor::all(config.border_color),
+                            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                            text_input(TextInput::new(strings.search_entities.clone())),
+                            SearchInput,
+                            observe(on_entity_search_changed),
+                        ))
+                        .with_child((
+                            Text::new(strings.search_entities.clone()),
+                            TextFont {
+                                font_size: config.body_font_size,
+                                ..default()
+                            },
+                            TextInputText,
+                        ));
+                    search.spawn((
+                        button(
+                            ButtonProps::default(),
+                            RefreshEntityListButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.refresh.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_refresh_entity_list_click),
+                    ));
+                    search.spawn((
+                        button(
+                            ButtonProps::default(),
+                            GroupingStrategyButton,
+                            bevy::prelude::Spawn((
+                                Text::new(grouping_strategy_label(GroupingStrategy::default())),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                                GroupingStrategyLabel,
+                            )),
+                        ),
+                        observe(on_grouping_strategy_click),
+                    ));
+                    search.spawn((
+                        button(
+                            ButtonProps::default(),
+                            SearchModeButton,
+                            bevy::prelude::Spawn((
+                                Text::new(search_mode_label(SearchMode::default())),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                                SearchModeLabel,
+                            )),
+                        ),
+                        observe(on_search_mode_click),
+                    ));
+                    search.spawn((
+                        button(
+                            ButtonProps::default(),
+                            SceneFilterButton,
+                            bevy::prelude::Spawn((
+                                Text::new(scene_filter_label(None)),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                                SceneFilterLabel,
+                            )),
+                        ),
+                        observe(on_scene_filter_click),
+                    ));
+                    search.spawn((
+                        button(
+                            ButtonProps::default(),
+                            ChangedFilterButton,
+                            bevy::prelude::Spawn((
+                                Text::new(changed_filter_label(None, strings)),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                                ChangedFilterLabel,
+                            )),
+                        ),
+                        observe(on_changed_filter_click),
+                        Tooltip(
+                            "Only list entities with a component added or changed recently"
+                                .to_string(),
+                        ),
+                    ));
                     search.spawn((
-                        Text::new("Search entities..."),
+                        button(
+                            ButtonProps::default(),
+                            NewEntityButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.new_entity.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_new_entity_click),
+                    ));
+                });
+
+            // Match count, or why the search is invalid, and navigation through the filtered results
+            panel
+                .spawn(Node {
+                    width: Percent(100.0),
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    column_gap: config.column_gap,
+                    padding: config.panel_padding,
+                    ..default()
+                })
+                .with_children(|navigation| {
+                    navigation.spawn((
+                        Text::default(),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(config.muted_text_color),
+                        Node {
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                        SearchMatchCount,
+                    ));
+                    for (label, forward) in [(&strings.previous, false), (&strings.next, true)] {
+                        navigation.spawn((
+                            button(
+                                ButtonProps::default(),
+                                SearchStepButton(forward),
+                                bevy::prelude::Spawn((
+                                    Text::new(label.clone()),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_search_step_click),
+                        ));
+                    }
+                });
+
+            // Quick filters for common kinds of entities
+            panel
+                .spawn(Node {
+                    width: Percent(100.0),
+                    display: Display::Flex,
+                    flex_wrap: FlexWrap::Wrap,
+                    column_gap: config.column_gap,
+                    row_gap: config.item_gap,
+                    padding: config.panel_padding,
+                    ..default()
+                })
+                .with_children(|chips| {
+                    for chip in FilterChip::ALL {
+                        chips.spawn((
+                            button(
+                                ButtonProps::default(),
+                                FilterChipButton(chip),
+                                bevy::prelude::Spawn((
+                                    Text::new(chip.label()),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_filter_chip_click),
+                        ));
+                    }
+                    spawn_component_filter_button(chips, config.small_font_size, strings);
+                });
+
+            // Kinds of entities hidden by default, e.g. to debug a UI node or the window
+            panel
+                .spawn(Node {
+                    width: Percent(100.0),
+                    display: Display::Flex,
+                    flex_wrap: FlexWrap::Wrap,
+                    align_items: AlignItems::Center,
+                    column_gap: config.column_gap,
+                    row_gap: config.item_gap,
+                    padding: config.panel_padding,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(strings.show_hidden.clone()),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(config.muted_text_color),
+                    ));
+                    for kind in HiddenEntityKind::ALL {
+                        row.spawn((
+                            checkbox(
+                                ShowHiddenCheckbox(kind),
+                                bevy::prelude::Spawn((
+                                    Text::new(kind.label(strings)),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                    ThemedText,
+                                )),
+                            ),
+                            observe(on_show_hidden_change),
+                        ));
+                    }
+                });
+
+            // Selects an entity by id
+            panel
+                .spawn((
+                    Node {
+                        margin: UiRect::horizontal(Px(6.0)),
+                        padding: UiRect::axes(Px(6.0), Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    text_input(TextInput::default()),
+                    GoToEntityField,
+                    observe(on_go_to_entity_changed),
+                    observe(on_go_to_entity_submit),
+                ))
+                .with_child((
+                    Text::new(format!("{}: {}", strings.go_to, strings.go_to_hint)),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextColor(config.muted_text_color),
+                    GoToEntityText,
+                ));
+
+            // Actions on every filtered entity
+            panel
+                .spawn((
+                    Node {
+                        width: Percent(100.0),
+                        display: Display::None,
+                        flex_wrap: FlexWrap::Wrap,
+                        align_items: AlignItems::Center,
+                        column_gap: config.column_gap,
+                        row_gap: config.item_gap,
+                        padding: config.panel_padding,
+                        ..default()
+                    },
+                    BulkActionsRow,
+                ))
+                .with_children(|actions| {
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            BulkDespawnButton,
+                            bevy::prelude::Spawn((
+                                Text::new(bulk_despawn_label(None, 0, strings)),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                                BulkDespawnLabel,
+                            )),
+                        ),
+                        observe(on_bulk_despawn_click),
+                    ));
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            BulkDespawnCancelButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.cancel.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_bulk_despawn_cancel_click),
+                    ));
+
+                    // Component added to or removed from every filtered entity
+                    actions
+                        .spawn((
+                            Node {
+                                width: Percent(100.0),
+                                padding: UiRect::axes(Px(6.0), Px(4.0)),
+                                border: UiRect::all(Px(1.0)),
+                                ..default()
+                            },
+                            BorderColor::all(config.border_color),
+                            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                            text_input(TextInput::default()),
+                            BulkComponentSearchField,
+                            observe(on_bulk_component_search_changed),
+                        ))
+                        .with_child((
+                            Text::new("Component: (click to search)"),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                            BulkComponentSearchText,
+                        ));
+                    for (label, action) in [
+                        (&strings.add_to_all, BulkComponentAction::Insert),
+                        (&strings.remove_from_all, BulkComponentAction::Remove),
+                    ] {
+                        actions.spawn((
+                            button(
+                                ButtonProps::default(),
+                                BulkComponentButton(action),
+                                bevy::prelude::Spawn((
+                                    Text::new(label.clone()),
+                                    TextFont {
+                                        font_size: config.small_font_size,
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_bulk_component_click),
+                        ));
+                    }
+                    actions.spawn((
+                        Text::new(""),
                         TextFont {
-                            font_size: config.body_font_size,
+                            font_size: config.small_font_size,
                             ..default()
                         },
                         TextColor(config.muted_text_color),
+                        BulkComponentStatus,
                     ));
                 });
 
+            // Soft-deleted entities that can still be restored
+            panel.spawn((
+                Node {
+                    width: Percent(100.0),
+                    display: Display::None,
+                    flex_wrap: FlexWrap::Wrap,
+                    row_gap: config.item_gap,
+                    padding: config.panel_padding,
+                    border: UiRect::bottom(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(config.border_color),
+                GraveyardRow,
+            ));
+
             // Scrollable area with scrollbar - use Grid layout
-            let scrollbar_width = 8.0;
+            let scrollbar_width = config.scrollbar_width;
             panel
                 .spawn(Node {
                     width: Percent(100.0),
@@ -324,3 +2532,548 @@ pub fn spawn_entity_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
                 });
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::input::ButtonState;
+    use bevy::input::keyboard::{Key, KeyboardInput, NativeKeyCode};
+    use bevy::input_focus::dispatch_focused_input;
+    use bevy::window::PrimaryWindow;
+
+    use super::*;
+    use crate::entity_name_resolution::NameResolutionRegistry;
+    use crate::inspector::search_index::update_search_index;
+    use crate::inspector::widgets::apply_pending_value_changes;
+
+    /// A world with the entity list panel and the resources its search needs,
+    /// returning the world and the search field.
+    fn search_world() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorCache>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<InspectorStrings>();
+        world.init_resource::<EntityExclusions>();
+        world.init_resource::<InputFocus>();
+        world.insert_resource(NameResolutionRegistry::new());
+        let config = world.resource::<InspectorConfig>().clone();
+        let strings = world.resource::<InspectorStrings>().clone();
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|panel| spawn_entity_list_panel(panel, &config, &strings));
+        world.flush();
+        let mut inputs = world.query_filtered::<Entity, With<SearchInput>>();
+        let input = inputs.single(&world).unwrap();
+        (world, input)
+    }
+
+    /// Types `text` into the search field `input` key by key, then refreshes the entity list.
+    fn type_search(world: &mut World, input: Entity, text: &str) {
+        let window = world.spawn(PrimaryWindow).id();
+        world.init_resource::<Messages<KeyboardInput>>();
+        world.resource_mut::<InputFocus>().set(input);
+        for c in text.chars() {
+            let logical_key = match c {
+                ' ' => Key::Space,
+                c => Key::Character(c.to_string().into()),
+            };
+            world.write_message(KeyboardInput {
+                key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                logical_key,
+                state: ButtonState::Pressed,
+                text: None,
+                repeat: false,
+                window,
+            });
+        }
+        world
+            .run_system_once(dispatch_focused_input::<KeyboardInput>)
+            .unwrap();
+        world.resource_mut::<Messages<KeyboardInput>>().clear();
+        world.despawn(window);
+        world.run_system_once(refresh_entity_cache).unwrap();
+        world.run_system_once(sync_entity_list).unwrap();
+    }
+
+    /// The text of the match count below the search field.
+    fn match_count(world: &mut World) -> String {
+        let mut counts = world.query_filtered::<&Text, With<SearchMatchCount>>();
+        counts.single(world).unwrap().0.clone()
+    }
+
+    #[test]
+    fn typed_searches_filter_the_entity_list() {
+        let (mut world, input) = search_world();
+        let player = world
+            .spawn((Name::new("Player"), Transform::default()))
+            .id();
+        world.spawn(Name::new("Player marker"));
+        world.spawn((Name::new("Enemy"), Transform::default()));
+
+        type_search(&mut world, input, "has:Transform play");
+        assert_eq!(
+            world.resource::<InspectorState>().filter_text,
+            "has:Transform play"
+        );
+        let cache = world.resource::<InspectorCache>();
+        let listed: Vec<Entity> = cache.filtered_entities.iter().map(|e| e.entity).collect();
+        assert_eq!(listed, vec![player]);
+        assert_eq!(match_count(&mut world), "1 match");
+    }
+
+    #[test]
+    fn search_steps_move_through_typed_matches() {
+        let (mut world, input) = search_world();
+        world.spawn(Name::new("Enemy 1"));
+        world.spawn(Name::new("Player"));
+        world.spawn(Name::new("Enemy 2"));
+
+        type_search(&mut world, input, "enemy");
+        assert_eq!(match_count(&mut world), "2 matches");
+        let cache = world.resource::<InspectorCache>();
+        let listed: Vec<Entity> = cache.filtered_entities.iter().map(|e| e.entity).collect();
+        assert_eq!(listed.len(), 2);
+
+        let mut step_buttons = world.query::<(Entity, &SearchStepButton)>();
+        let (mut next, mut previous) = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+        for (button, step) in step_buttons.iter(&world) {
+            if step.0 {
+                next = button;
+            } else {
+                previous = button;
+            }
+        }
+        world.trigger(Activate { entity: next });
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(listed[0])
+        );
+        world.trigger(Activate { entity: next });
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(listed[1])
+        );
+        world.trigger(Activate { entity: next });
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(listed[0])
+        );
+        world.trigger(Activate { entity: previous });
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(listed[1])
+        );
+    }
+
+    #[test]
+    fn typed_fuzzy_searches_match_abbreviations() {
+        let (mut world, input) = search_world();
+        let camera = world.spawn(Name::new("Player Camera")).id();
+        world.spawn(Name::new("Player"));
+        world.spawn(Name::new("Enemy Camera"));
+        let mut mode_buttons = world.query_filtered::<Entity, With<SearchModeButton>>();
+        let mode_button = mode_buttons.single(&world).unwrap();
+        world.trigger(Activate {
+            entity: mode_button,
+        });
+        assert_eq!(
+            world.resource::<InspectorState>().search_mode,
+            SearchMode::Fuzzy
+        );
+
+        type_search(&mut world, input, "plyr cam");
+        let cache = world.resource::<InspectorCache>();
+        let listed: Vec<Entity> = cache.filtered_entities.iter().map(|e| e.entity).collect();
+        assert_eq!(listed, vec![camera]);
+        assert_eq!(match_count(&mut world), "1 match");
+    }
+
+    #[test]
+    fn typed_invalid_regexes_explain_the_error() {
+        let (mut world, input) = search_world();
+        world.spawn(Name::new("Chunk_12"));
+        world.spawn(Name::new("Chunk_x"));
+        let mut mode_buttons = world.query_filtered::<Entity, With<SearchModeButton>>();
+        let mode_button = mode_buttons.single(&world).unwrap();
+        world.trigger(Activate {
+            entity: mode_button,
+        });
+        world.trigger(Activate {
+            entity: mode_button,
+        });
+        assert_eq!(
+            world.resource::<InspectorState>().search_mode,
+            SearchMode::Regex
+        );
+
+        type_search(&mut world, input, "Chunk_(");
+        assert!(
+            world
+                .resource::<InspectorCache>()
+                .filtered_entities
+                .is_empty()
+        );
+        assert!(match_count(&mut world).starts_with("Invalid regex:"));
+        let error_color = world.resource::<InspectorConfig>().error_text_color;
+        let mut colors = world.query_filtered::<&TextColor, With<SearchMatchCount>>();
+        assert_eq!(colors.single(&world).unwrap().0, error_color);
+
+        // Closing the group makes the pattern valid again
+        type_search(&mut world, input, "\\d+)$");
+        assert_eq!(
+            world.resource::<InspectorState>().filter_text,
+            "Chunk_(\\d+)$"
+        );
+        assert_eq!(match_count(&mut world), "1 match");
+    }
+
+    fn entry(entity: Entity, name: &str, bytes: usize) -> EntityListEntry {
+        EntityListEntry {
+            entity,
+            display_name: name.to_string(),
+            component_count: 1,
+            memory_size: MemorySize::new(bytes),
+            kind: None,
+            hidden: None,
+            archetype: 0,
+            children_count: 0,
+            rollup: None,
+        }
+    }
+
+    #[test]
+    fn stepping_selection_wraps_around() {
+        let entities: Vec<Entity> = (1..=3).map(|i| Entity::from_raw_u32(i).unwrap()).collect();
+        assert_eq!(step_selection(&entities, None, true), Some(entities[0]));
+        assert_eq!(step_selection(&entities, None, false), Some(entities[2]));
+        assert_eq!(
+            step_selection(&entities, Some(entities[2]), true),
+            Some(entities[0])
+        );
+        assert_eq!(
+            step_selection(&entities, Some(entities[0]), false),
+            Some(entities[2])
+        );
+        assert_eq!(step_selection(&[], Some(entities[0]), true), None);
+    }
+
+    fn name_match_score(mode: SearchMode, search: &str, name: &str) -> Option<usize> {
+        NameMatcher::new(mode, search).unwrap().score(name)
+    }
+
+    #[test]
+    fn fuzzy_search_matches_abbreviations() {
+        assert_eq!(
+            name_match_score(SearchMode::Substring, "cam", "Player Camera"),
+            Some(0)
+        );
+        assert_eq!(
+            name_match_score(SearchMode::Substring, "plyr cam", "Player Camera"),
+            None
+        );
+        let abbreviated = name_match_score(SearchMode::Fuzzy, "plyr cam", "Player Camera");
+        let prefix = name_match_score(SearchMode::Fuzzy, "player", "Player Camera");
+        assert!(abbreviated.is_some());
+        assert!(prefix < abbreviated);
+        assert_eq!(
+            name_match_score(SearchMode::Fuzzy, "cam plyr", "Player Camera"),
+            None
+        );
+    }
+
+    #[test]
+    fn substring_search_finds_unnamed_entities() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<SearchIndex>();
+        let mut registry = NameResolutionRegistry::new();
+        registry.register_name_defining_type::<Sprite>(-10);
+        world.insert_resource(registry);
+        let camera = world.spawn(Name::new("Camera")).id();
+        let unnamed = world.spawn(Transform::default()).id();
+        // Listed by its name-defining component, as "Sprite"
+        let sprite = world.spawn(Sprite::default()).id();
+        world.run_system_once(update_search_index).unwrap();
+        let metadata_map = ComponentMetadataMap::generate(&world);
+
+        let label = entity_label(unnamed);
+        let search =
+            |world: &mut World, text: &str| entities_labelled_with(world, text, &metadata_map);
+        assert_eq!(search(&mut world, &label), Some(vec![unnamed]));
+        assert_eq!(search(&mut world, "CAMERA"), Some(vec![camera]));
+        assert_eq!(search(&mut world, "sprite"), Some(vec![sprite]));
+        assert_eq!(search(&mut world, &entity_label(sprite)), Some(vec![]));
+        // Labels follow the entity's archetype
+        world.entity_mut(unnamed).insert(Sprite::default());
+        let sprites = search(&mut world, "sprite").unwrap();
+        assert_eq!(
+            EntityHashSet::from_iter(sprites),
+            EntityHashSet::from_iter([unnamed, sprite])
+        );
+        assert_eq!(search(&mut world, &label), Some(vec![]));
+        world.remove_resource::<SearchIndex>();
+        assert_eq!(search(&mut world, &label), None);
+    }
+
+    #[test]
+    fn change_filter_finds_recently_changed_entities() {
+        let mut world = World::new();
+        let moved = world.spawn(Transform::default()).id();
+        let still = world.spawn(Transform::default()).id();
+        let since = world.read_change_tick();
+        world.increment_change_tick();
+
+        world.get_mut::<Transform>(moved).unwrap().translation.x = 1.0;
+        let this_run = world.read_change_tick();
+        assert!(changed_after(world.entity(moved), since, this_run));
+        assert!(!changed_after(world.entity(still), since, this_run));
+    }
+
+    #[test]
+    fn entity_references_parse_ids_and_bits() {
+        let entity = Entity::from_raw_u32(42).unwrap();
+        assert_eq!(parse_entity_reference(" 42v0 "), Some(entity));
+        assert_eq!(
+            parse_entity_reference(&entity.to_bits().to_string()),
+            Some(entity)
+        );
+        assert_eq!(parse_entity_reference("player"), None);
+    }
+
+    #[test]
+    fn regex_search_matches_patterns() {
+        let pattern = r"^Chunk_\d+_\d+$";
+        assert_eq!(
+            name_match_score(SearchMode::Regex, pattern, "Chunk_3_12"),
+            Some(0)
+        );
+        assert_eq!(
+            name_match_score(SearchMode::Regex, pattern, "Chunk_3_x"),
+            None
+        );
+        assert!(NameMatcher::new(SearchMode::Regex, "Chunk_(").is_err());
+
+        let query = EntityQuery::parse_pattern(r"has:Transform (?i:chunk)_[[:digit:]]+ (?:a|b)");
+        let pattern = query.unwrap().name.unwrap();
+        assert_eq!(
+            name_match_score(SearchMode::Regex, &pattern, "CHUNK_12 a"),
+            Some(0)
+        );
+        assert_eq!(
+            name_match_score(SearchMode::Regex, &pattern, "Chunk_x a"),
+            None
+        );
+    }
+
+    #[test]
+    fn ctrl_and_shift_clicks_extend_the_selection() {
+        let entities: Vec<Entity> = (1..=4).map(|i| Entity::from_raw_u32(i).unwrap()).collect();
+        let mut state = InspectorState {
+            selected_entity: Some(entities[1]),
+            ..default()
+        };
+        state.toggle_selected(entities[3]);
+        assert_eq!(state.selected_entities, vec![entities[1], entities[3]]);
+        assert_eq!(state.selected_entity, Some(entities[3]));
+        state.toggle_selected(entities[3]);
+        assert_eq!(state.selected_entities, vec![entities[1]]);
+        assert_eq!(state.selected_entity, Some(entities[1]));
+
+        state.select_range(entities[3], &entities);
+        assert_eq!(state.selected_entities, entities[1..=3]);
+        state.select_range(entities[0], &entities);
+        assert_eq!(state.selected_entities, entities[0..=1]);
+        assert_eq!(state.selected_entity, Some(entities[1]));
+
+        state.selected_entity = None;
+        state.select_range(entities[2], &entities);
+        assert_eq!(state.selected_entity, Some(entities[2]));
+        assert!(state.selected_entities.is_empty());
+    }
+
+    #[test]
+    fn hierarchy_groups_aggregate_memory() {
+        let mut world = World::new();
+        let root = world.spawn(Name::new("Level")).id();
+        let child = world.spawn(ChildOf(root)).id();
+        let loner = world.spawn(Name::new("Camera")).id();
+        let entries = [
+            entry(root, "Level", 10),
+            entry(child, "Child", 5),
+            entry(loner, "Camera", 7),
+        ];
+
+        let grouping = EntityGrouping::generate(
+            &world,
+            entries.iter().map(|e| e.entity),
+            GroupingStrategy::Hierarchy,
+        );
+        let groups =
+            build_entity_list_groups(&world, &grouping, &entries, GroupingStrategy::Hierarchy);
+
+        let level = groups.iter().find(|g| g.label == "Level").unwrap();
+        assert_eq!(level.entities, vec![root, child]);
+        assert_eq!(level.memory_size.as_bytes(), 15);
+        let camera = groups.iter().find(|g| g.label == "Camera").unwrap();
+        assert_eq!(camera.entities, vec![loner]);
+    }
+
+    #[test]
+    fn pinned_entities_are_grouped_in_the_order_pinned() {
+        let [a, b, c] = [1, 2, 3].map(|i| Entity::from_raw_u32(i).unwrap());
+        let entries = [entry(a, "A", 10), entry(b, "B", 20)];
+
+        // Pinned entities that aren't listed, e.g. filtered out, are left out
+        let group = pinned_group(&entries, &[b, c, a]).unwrap();
+        assert_eq!(group.entities, vec![b, a]);
+        assert_eq!(group.component_count, 2);
+        assert_eq!(group.memory_size.as_bytes(), 30);
+        assert!(pinned_group(&entries, &[c]).is_none());
+    }
+
+    #[test]
+    fn hierarchy_roots_roll_up_their_descendants() {
+        let mut world = World::new();
+        let root = world.spawn(Name::new("Ship")).id();
+        let turret = world.spawn(ChildOf(root)).id();
+        let barrel = world.spawn(ChildOf(turret)).id();
+        let mut entries = [
+            entry(root, "Ship", 100),
+            entry(turret, "Turret", 20),
+            entry(barrel, "Barrel", 5),
+        ];
+
+        let grouping = EntityGrouping::generate(
+            &world,
+            entries.iter().map(|e| e.entity),
+            GroupingStrategy::Hierarchy,
+        );
+        apply_hierarchy_rollups(&grouping, &mut entries);
+
+        let ship = entries[0].rollup.unwrap();
+        assert_eq!((ship.descendants, ship.component_count), (2, 3));
+        assert_eq!(ship.memory_size.as_bytes(), 125);
+        assert_eq!(entries[1].rollup.unwrap().memory_size.as_bytes(), 25);
+        assert_eq!(entries[2].rollup, None);
+    }
+
+    #[test]
+    fn unchanged_rows_are_kept_across_refreshes() {
+        let mut world = World::new();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<InspectorStrings>();
+        world.init_resource::<InspectorCache>();
+        let content = world.spawn(EntityListContent).id();
+        let [a, b, c] = [(); 3].map(|()| world.spawn_empty().id());
+        let set_entries = |world: &mut World, entries: Vec<EntityListEntry>| {
+            let mut cache = world.resource_mut::<InspectorCache>();
+            cache.groups = entries
+                .iter()
+                .map(|entry| EntityListGroup {
+                    label: entry.display_name.clone(),
+                    entities: vec![entry.entity],
+                    component_count: entry.component_count,
+                    memory_size: entry.memory_size,
+                })
+                .collect();
+            cache.filtered_entities = entries;
+        };
+        let rows = |world: &mut World| -> Vec<(Entity, Entity)> {
+            world
+                .entity(content)
+                .get::<Children>()
+                .map_or(Vec::new(), |children| {
+                    children
+                        .iter()
+                        .map(|row| (world.get::<EntityRow>(row).unwrap().0, row))
+                        .collect()
+                })
+        };
+        let mut sync = IntoSystem::into_system(sync_entity_rows);
+        sync.initialize(&mut world);
+
+        set_entries(&mut world, vec![entry(a, "A", 1), entry(b, "B", 1)]);
+        sync.run((), &mut world).unwrap();
+        let first = rows(&mut world);
+        assert_eq!(
+            first.iter().map(|(e, _)| *e).collect::<Vec<_>>(),
+            vec![a, b]
+        );
+
+        // B's row is kept and moved, A's is removed and C's is new
+        set_entries(&mut world, vec![entry(c, "C", 1), entry(b, "B", 1)]);
+        sync.run((), &mut world).unwrap();
+        let second = rows(&mut world);
+        assert_eq!(
+            second.iter().map(|(e, _)| *e).collect::<Vec<_>>(),
+            vec![c, b]
+        );
+        assert_eq!(second[1].1, first[1].1);
+        assert!(world.get_entity(first[0].1).is_err());
+        assert_eq!(world.get::<EntityRowIndex>(second[1].1).unwrap().0, 1);
+
+        // Rows whose text changes are respawned
+        set_entries(&mut world, vec![entry(c, "C", 1), entry(b, "B", 2)]);
+        sync.run((), &mut world).unwrap();
+        let third = rows(&mut world);
+        assert_eq!(third[0].1, second[0].1);
+        assert_ne!(third[1].1, second[1].1);
+    }
+
+    #[test]
+    fn visibility_toggles_hide_entities_and_their_descendants() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Visibility>();
+        world.init_resource::<PendingValueChanges>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let child = world.spawn(Visibility::Visible).id();
+        let parent = world.spawn(Visibility::Inherited).add_child(child).id();
+        let toggle = world
+            .spawn(VisibilityToggle(parent))
+            .observe(on_visibility_toggle_click)
+            .id();
+
+        world.trigger(Activate { entity: toggle });
+        apply_pending_value_changes(&mut world);
+        assert_eq!(world.get::<Visibility>(parent), Some(&Visibility::Hidden));
+        assert_eq!(world.get::<Visibility>(child), Some(&Visibility::Visible));
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+        world.trigger(Activate { entity: toggle });
+        apply_pending_value_changes(&mut world);
+        world.trigger(Activate { entity: toggle });
+        apply_pending_value_changes(&mut world);
+        assert_eq!(world.get::<Visibility>(parent), Some(&Visibility::Hidden));
+        assert_eq!(world.get::<Visibility>(child), Some(&Visibility::Hidden));
+    }
+
+    #[test]
+    fn row_cells_follow_the_configured_columns() {
+        let entity = Entity::from_raw_u32(42).unwrap();
+        let mut entry = entry(entity, "A very long entity name indeed", 2048);
+        entry.children_count = 3;
+        let columns = [
+            EntityListColumn::ChildrenCount,
+            EntityListColumn::Name,
+            EntityListColumn::EntityId,
+        ];
+        let (cells, truncated) = entity_row_cells(&entry, &columns);
+        assert!(truncated);
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells[0], "3 child");
+        assert!(cells[1].starts_with("  A very"));
+        assert_eq!(cells[2], entity.to_string());
+        // No rollup outside of hierarchy roots
+        assert_eq!(cells[3], "");
+    }
+}
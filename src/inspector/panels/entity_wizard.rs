@@ -0,0 +1,501 @@
+//! "New entity..." dialog for the entity list.
+//!
+//! Picks a set of components by fuzzy search, previews the fields each of them starts with,
+//! and spawns an entity with their default values.
+//! Only components registering both `ReflectComponent` and [`ReflectDefault`] can be picked.
+
+use core::any::TypeId;
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{ButtonProps, button};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::detail_panel::preview_field_rows;
+use crate::fuzzy_name_mapping::fuzzy_match_score;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::selection::SelectionRequest;
+use crate::inspector::semantic_names::SemanticFieldNames;
+use crate::inspector::state::{InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::{TextInput, TextInputChanged, TextInputText, text_input};
+
+/// Maximum number of search matches offered at once.
+const MAX_WIZARD_MATCHES: usize = 8;
+
+/// The components picked in the "New entity..." dialog, while it is open.
+#[derive(Clone, Debug, Default)]
+pub struct EntityWizard {
+    /// Fuzzy search text for components to add.
+    pub search: String,
+    /// Picked component types, in the order they were added.
+    pub components: Vec<TypeId>,
+}
+
+/// Button opening the "New entity..." dialog.
+#[derive(Component)]
+pub struct NewEntityButton;
+
+/// The dialog overlaying the entity list, hidden while no [`EntityWizard`] is open.
+#[derive(Component)]
+pub struct EntityWizardDialog;
+
+/// The search field of the dialog. Click it to start typing.
+#[derive(Component)]
+pub struct EntityWizardSearchField;
+
+/// The matches, picked components and their preview, rebuilt whenever the wizard changes.
+#[derive(Component)]
+pub struct EntityWizardContent;
+
+/// Button adding (`true`) or removing (`false`) a component of the wizard.
+#[derive(Component)]
+pub struct EntityWizardComponentButton {
+    /// The component type.
+    pub type_id: TypeId,
+    /// Whether the component is added or removed.
+    pub add: bool,
+}
+
+/// Button spawning the entity described by the wizard.
+#[derive(Component)]
+pub struct EntityWizardSpawnButton;
+
+/// Button closing the dialog without spawning anything.
+#[derive(Component)]
+pub struct EntityWizardCancelButton;
+
+/// Returns the components that can be spawned with default values, with their short names,
+/// keeping only those matching the fuzzy `search`, best matches first.
+pub fn template_components(registry: &TypeRegistry, search: &str) -> Vec<(TypeId, String)> {
+    let search = search.trim();
+    let mut matches: Vec<(usize, String, TypeId)> = registry
+        .iter_with_data::<ReflectComponent>()
+        .filter(|(registration, _)| registration.contains::<ReflectDefault>())
+        .filter_map(|(registration, _)| {
+            let name = ShortName(registration.type_info().type_path()).to_string();
+            let score = if search.is_empty() {
+                0
+            } else {
+                fuzzy_match_score(search, &name)?
+            };
+            Some((score, name, registration.type_id()))
+        })
+        .collect();
+    matches.sort();
+    matches
+        .into_iter()
+        .map(|(_, name, type_id)| (type_id, name))
+        .collect()
+}
+
+/// Spawns an entity with the default value of each component in `components`.
+///
+/// Components that do not register both `ReflectComponent` and [`ReflectDefault`] are skipped.
+pub fn spawn_from_template(world: &mut World, components: &[TypeId]) -> Entity {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut entity = world.spawn_empty();
+    for &type_id in components {
+        let Some(registration) = registry.get(type_id) else {
+            continue;
+        };
+        let (Some(reflect_component), Some(reflect_default)) = (
+            registration.data::<ReflectComponent>(),
+            registration.data::<ReflectDefault>(),
+        ) else {
+            warn!(
+                "Cannot spawn {} from a template: it does not register `ReflectDefault`",
+                registration.type_info().type_path()
+            );
+            continue;
+        };
+        let value = reflect_default.default();
+        reflect_component.insert(&mut entity, value.as_partial_reflect(), &registry);
+    }
+    entity.id()
+}
+
+/// Spawns the "New entity..." dialog, hidden until [`NewEntityButton`] is clicked.
+pub fn spawn_entity_wizard_dialog(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Px(0.0),
+                left: Px(0.0),
+                width: Percent(100.0),
+                height: Percent(100.0),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                row_gap: config.item_gap,
+                padding: config.panel_padding,
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.98)),
+            ZIndex(10),
+            EntityWizardDialog,
+        ))
+        .with_children(|dialog| {
+            dialog.spawn((
+                Text::new(strings.new_entity_title.clone()),
+                TextFont {
+                    font_size: config.title_font_size,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            dialog
+                .spawn((
+                    Node {
+                        width: Percent(100.0),
+                        padding: UiRect::axes(Px(6.0), Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    text_input(TextInput::new(strings.search_components.clone())),
+                    EntityWizardSearchField,
+                    observe(on_wizard_search_changed),
+                ))
+                .with_child((
+                    Text::new(strings.search_components.clone()),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextInputText,
+                ));
+
+            dialog.spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    ..default()
+                },
+                EntityWizardContent,
+            ));
+
+            dialog
+                .spawn(Node {
+                    display: Display::Flex,
+                    column_gap: config.column_gap,
+                    ..default()
+                })
+                .with_children(|actions| {
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            EntityWizardSpawnButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.spawn.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_wizard_spawn_click),
+                    ));
+                    actions.spawn((
+                        button(
+                            ButtonProps::default(),
+                            EntityWizardCancelButton,
+                            bevy::prelude::Spawn((
+                                Text::new(strings.cancel.clone()),
+                                TextFont {
+                                    font_size: config.small_font_size,
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_wizard_cancel_click),
+                    ));
+                });
+        });
+}
+
+/// Observer for the "New entity..." button. Opens an empty wizard.
+pub(crate) fn on_new_entity_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.entity_wizard = Some(EntityWizard::default());
+    commands.queue(rebuild_entity_wizard);
+}
+
+/// Observer: searches for components matching the edited search text.
+fn on_wizard_search_changed(
+    changed: On<TextInputChanged>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    let Some(wizard) = state.entity_wizard.as_mut() else {
+        return;
+    };
+    wizard.search.clone_from(&changed.text);
+    commands.queue(rebuild_entity_wizard);
+}
+
+/// Observer: adds a component to the wizard, or removes it.
+fn on_wizard_component_click(
+    activate: On<Activate>,
+    buttons: Query<&EntityWizardComponentButton>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    let (Ok(button), Some(wizard)) = (buttons.get(activate.entity), state.entity_wizard.as_mut())
+    else {
+        return;
+    };
+    if button.add {
+        if !wizard.components.contains(&button.type_id) {
+            wizard.components.push(button.type_id);
+        }
+    } else {
+        wizard
+            .components
+            .retain(|&type_id| type_id != button.type_id);
+    }
+    commands.queue(rebuild_entity_wizard);
+}
+
+/// Observer: spawns the entity, selects it and closes the dialog.
+fn on_wizard_spawn_click(_activate: On<Activate>, mut commands: Commands) {
+    commands.queue(|world: &mut World| {
+        let Some(wizard) = world.resource_mut::<InspectorState>().entity_wizard.take() else {
+            return;
+        };
+        let entity = spawn_from_template(world, &wizard.components);
+        info!(
+            "Spawned {} with {} components",
+            entity,
+            wizard.components.len()
+        );
+        world.write_message(SelectionRequest::Entity(entity));
+        world.resource_mut::<InspectorCache>().stale = true;
+        rebuild_entity_wizard(world);
+    });
+}
+
+/// Observer: closes the dialog.
+fn on_wizard_cancel_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.entity_wizard = None;
+    commands.queue(rebuild_entity_wizard);
+}
+
+/// Shows or hides the dialog, and rebuilds its content from the [`EntityWizard`],
+/// without rebuilding the search field itself, so it keeps the input focus.
+fn rebuild_entity_wizard(world: &mut World) {
+    let config = world.resource::<InspectorConfig>().clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+    let wizard = world.resource::<InspectorState>().entity_wizard.clone();
+
+    let mut dialogs = world.query_filtered::<&mut Node, With<EntityWizardDialog>>();
+    for mut node in dialogs.iter_mut(world) {
+        node.display = if wizard.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    // A reopened wizard starts with an empty search
+    let search = wizard.as_ref().map_or("", |wizard| wizard.search.as_str());
+    let mut fields = world.query_filtered::<&mut TextInput, With<EntityWizardSearchField>>();
+    for mut field in fields.iter_mut(world) {
+        if field.text != search {
+            field.text = search.to_string();
+        }
+    }
+
+    let mut contents = world.query_filtered::<Entity, With<EntityWizardContent>>();
+    let Some(content) = contents.iter(world).next() else {
+        return;
+    };
+    world.entity_mut(content).despawn_children();
+    let Some(wizard) = wizard else {
+        return;
+    };
+
+    // Collect the matches and previews first, then spawn them
+    let (matches, picked) = {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let semantic_names = world.resource::<SemanticFieldNames>();
+        let matches: Vec<(TypeId, String)> = template_components(&registry, &wizard.search)
+            .into_iter()
+            .filter(|(type_id, _)| !wizard.components.contains(type_id))
+            .take(MAX_WIZARD_MATCHES)
+            .collect();
+        let picked: Vec<(TypeId, String, Vec<(u8, String, String)>)> = wizard
+            .components
+            .iter()
+            .filter_map(|&type_id| {
+                let registration = registry.get(type_id)?;
+                let value = registration.data::<ReflectDefault>()?.default();
+                let name = ShortName(registration.type_info().type_path()).to_string();
+                let rows = preview_field_rows(value.as_partial_reflect(), semantic_names);
+                Some((type_id, name, rows))
+            })
+            .collect();
+        (matches, picked)
+    };
+
+    world.entity_mut(content).with_children(|p| {
+        let text_font = TextFont {
+            font_size: config.small_font_size,
+            ..default()
+        };
+        for (type_id, name) in matches {
+            p.spawn((
+                Node {
+                    padding: UiRect::horizontal(Px(4.0)),
+                    ..default()
+                },
+                bevy::ui_widgets::Button,
+                EntityWizardComponentButton { type_id, add: true },
+                observe(on_wizard_component_click),
+            ))
+            .with_child((
+                Text::new(format!("+ {}", name)),
+                text_font.clone(),
+                TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+            ));
+        }
+
+        p.spawn((
+            Text::new(if picked.is_empty() {
+                strings.no_components_picked.clone()
+            } else {
+                format!(
+                    "{} ({} {})",
+                    strings.preview,
+                    picked.len(),
+                    strings.components
+                )
+            }),
+            text_font.clone(),
+            TextColor(config.muted_text_color),
+            Node {
+                margin: UiRect::top(Px(8.0)),
+                ..default()
+            },
+        ));
+
+        // One card per picked component, laid out like the components tab
+        for (type_id, name, rows) in picked {
+            p.spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Px(6.0)),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(config.border_color),
+            ))
+            .with_children(|card| {
+                card.spawn(Node {
+                    display: Display::Flex,
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new(name),
+                        TextFont {
+                            font_size: config.body_font_size,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    header
+                        .spawn((
+                            bevy::ui_widgets::Button,
+                            EntityWizardComponentButton {
+                                type_id,
+                                add: false,
+                            },
+                            observe(on_wizard_component_click),
+                        ))
+                        .with_child((
+                            Text::new("x"),
+                            text_font.clone(),
+                            TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+                        ));
+                });
+                for (indent, name, value) in rows {
+                    card.spawn((
+                        Text::new(format!("{}: {}", name, value)),
+                        text_font.clone(),
+                        TextColor(config.muted_text_color),
+                        Node {
+                            margin: UiRect::left(Px(indent as f32 * 12.0)),
+                            ..default()
+                        },
+                    ));
+                }
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Target(Entity);
+
+    #[test]
+    fn templates_spawn_default_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Target>();
+            registry.register::<Name>();
+        }
+
+        let templates = template_components(&world.resource::<AppTypeRegistry>().read(), "heal");
+        assert_eq!(
+            templates.first().map(|(_, name)| name.as_str()),
+            Some("Health")
+        );
+        let all = template_components(&world.resource::<AppTypeRegistry>().read(), "");
+        assert!(
+            !all.iter()
+                .any(|(type_id, _)| *type_id == TypeId::of::<Target>())
+        );
+
+        let entity =
+            spawn_from_template(&mut world, &[TypeId::of::<Health>(), TypeId::of::<Name>()]);
+        assert_eq!(
+            world.get::<Health>(entity).map(|health| health.0),
+            Some(0.0)
+        );
+        assert!(world.get::<Name>(entity).is_some());
+    }
+}
@@ -1,7 +1,27 @@
 //! UI panels for the inspector.
 
+pub mod component_filter;
 pub mod detail_panel;
 pub mod entity_list;
+pub mod entity_wizard;
+pub mod notes;
+pub mod profile_picker;
+pub mod resources;
+pub mod states;
+pub mod systems;
+pub mod time;
+pub mod timeline;
+pub mod validation;
 
+pub use component_filter::*;
 pub use detail_panel::*;
 pub use entity_list::*;
+pub use entity_wizard::*;
+pub use notes::*;
+pub use profile_picker::*;
+pub use resources::*;
+pub use states::*;
+pub use systems::*;
+pub use time::*;
+pub use timeline::*;
+pub use validation::*;
@@ -0,0 +1,427 @@
+//! Notes tab for the detail panel.
+//! A plain text scratchpad for observations made during a debugging session,
+//! with links selecting the entities whose ids appear in the notes.
+//!
+//! Notes live in the [`SessionNotes`] resource, so they survive the inspector window
+//! being closed and reopened; set [`InspectorConfig::notes_file`] to keep them across runs.
+//! Edits are saved to the file once the notes field loses the input focus, or when the app exits.
+
+use bevy::ecs::entity::{EntityGeneration, EntityRow};
+use bevy::ecs::observer::On;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::clipboard::InspectorClipboard;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::selection::SelectionRequest;
+use crate::inspector::state::InspectorState;
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::{TextInput, TextInputChanged, TextInputText, Tooltip, text_input};
+
+/// Text of the current debugging session's notes.
+#[derive(Resource, Default, Debug)]
+pub struct SessionNotes {
+    /// The notes, as plain text.
+    pub text: String,
+    /// Whether the notes were edited since they were last saved.
+    unsaved: bool,
+}
+
+/// The notes field of the Notes tab. Click it to start typing.
+#[derive(Component)]
+pub struct NotesField;
+
+/// The container of the links to entities mentioned in the notes.
+#[derive(Component)]
+pub struct NotesEntityLinks;
+
+/// Link selecting an entity mentioned in the notes.
+#[derive(Component)]
+pub struct NotesEntityLink(pub Entity);
+
+/// Button inserting text into the notes.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotesInsertButton {
+    /// Inserts the id of the selected entity.
+    Selection,
+    /// Inserts the text last copied in the inspector, see [`InspectorClipboard`].
+    Clipboard,
+}
+
+/// Parses an entity id as displayed by [`Entity`], e.g. `12v3`.
+pub fn parse_entity_id(text: &str) -> Option<Entity> {
+    let (index, generation) = text.split_once('v')?;
+    let row = EntityRow::from_raw_u32(index.parse().ok()?)?;
+    let generation = EntityGeneration::from_bits(generation.parse().ok()?);
+    Some(Entity::from_row_and_generation(row, generation))
+}
+
+/// Returns the entities whose ids appear in `text`, in order of first appearance.
+pub fn entity_ids_in(text: &str) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    let words = text.split(|c: char| !c.is_ascii_alphanumeric());
+    for entity in words.filter_map(parse_entity_id) {
+        if !entities.contains(&entity) {
+            entities.push(entity);
+        }
+    }
+    entities
+}
+
+/// Loads the notes from [`InspectorConfig::notes_file`], if it is set and exists.
+pub(crate) fn load_session_notes(config: Res<InspectorConfig>, mut notes: ResMut<SessionNotes>) {
+    let Some(path) = &config.notes_file else {
+        return;
+    };
+    match std::fs::read_to_string(path) {
+        Ok(text) => notes.text = text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => warn!(
+            "Failed to load inspector notes from {}: {}",
+            path.display(),
+            error
+        ),
+    }
+}
+
+/// Saves edited notes to [`InspectorConfig::notes_file`], if it is set,
+/// once the notes field loses the input focus or the app exits,
+/// rather than on every key press.
+pub(crate) fn save_session_notes(
+    config: Res<InspectorConfig>,
+    input_focus: Res<InputFocus>,
+    fields: Query<(), With<NotesField>>,
+    mut exits: MessageReader<AppExit>,
+    mut notes: ResMut<SessionNotes>,
+) {
+    let exiting = exits.read().count() > 0;
+    let typing = input_focus
+        .get()
+        .is_some_and(|focused| fields.contains(focused));
+    if !notes.unsaved || (typing && !exiting) {
+        return;
+    }
+    notes.unsaved = false;
+    let Some(path) = &config.notes_file else {
+        return;
+    };
+    if let Err(error) = std::fs::write(path, &notes.text) {
+        warn!(
+            "Failed to save inspector notes to {}: {}",
+            path.display(),
+            error
+        );
+    }
+}
+
+/// Observer: stores the edited notes.
+fn on_notes_changed(
+    changed: On<TextInputChanged>,
+    mut notes: ResMut<SessionNotes>,
+    mut commands: Commands,
+) {
+    notes.text.clone_from(&changed.text);
+    notes.unsaved = true;
+    commands.queue(rebuild_notes_view);
+}
+
+/// Observer: appends the selected entity's id or the inspector clipboard to the notes.
+fn on_notes_insert_click(
+    activate: On<Activate>,
+    buttons: Query<&NotesInsertButton>,
+    state: Res<InspectorState>,
+    clipboard: Res<InspectorClipboard>,
+    mut notes: ResMut<SessionNotes>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let inserted = match button {
+        NotesInsertButton::Selection => state.selected_entity.map(|entity| entity.to_string()),
+        NotesInsertButton::Clipboard => clipboard.contents().map(str::to_string),
+    };
+    let Some(inserted) = inserted else {
+        return;
+    };
+    if !notes.text.is_empty() && !notes.text.ends_with(char::is_whitespace) {
+        notes.text.push(' ');
+    }
+    notes.text.push_str(&inserted);
+    notes.unsaved = true;
+
+    commands.queue(rebuild_notes_view);
+}
+
+/// Observer: selects an entity mentioned in the notes.
+fn on_notes_entity_link_click(
+    activate: On<Activate>,
+    links: Query<&NotesEntityLink>,
+    mut requests: MessageWriter<SelectionRequest>,
+) {
+    if let Ok(link) = links.get(activate.entity) {
+        requests.write(SelectionRequest::Entity(link.0));
+    }
+}
+
+/// Updates the notes text and the entity links, without rebuilding the notes field itself,
+/// so it keeps the input focus.
+fn rebuild_notes_view(world: &mut World) {
+    let config = world.resource::<InspectorConfig>().clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+
+    // Text inserted with the buttons is added to the notes rather than typed into the field
+    let notes = world.resource::<SessionNotes>().text.clone();
+    let mut fields = world.query_filtered::<&mut TextInput, With<NotesField>>();
+    for mut field in fields.iter_mut(world) {
+        if field.text != notes {
+            field.text.clone_from(&notes);
+        }
+    }
+
+    let mut lists = world.query_filtered::<Entity, With<NotesEntityLinks>>();
+    let Some(list) = lists.iter(world).next() else {
+        return;
+    };
+    world.entity_mut(list).despawn_children();
+    spawn_notes_entity_links(world, list, &notes, &config, &strings);
+}
+
+/// Spawns a link for each entity mentioned in `notes`, labeled with its [`Name`] if it has one.
+fn spawn_notes_entity_links(
+    world: &mut World,
+    parent: Entity,
+    notes: &str,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
+    let links: Vec<(Entity, Option<String>)> =
+        entity_ids_in(notes)
+            .into_iter()
+            .map(|entity| {
+                let label = world.get_entity(entity).ok().map(|entity_ref| {
+                    match entity_ref.get::<Name>() {
+                        Some(name) => format!("{} ({})", name, entity),
+                        None => entity.to_string(),
+                    }
+                });
+                (entity, label)
+            })
+            .collect();
+
+    world.entity_mut(parent).with_children(|p| {
+        for (entity, label) in links {
+            let Some(label) = label else {
+                p.spawn((
+                    Text::new(format!("{} ({})", entity, strings.despawned)),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    TextColor(config.muted_text_color),
+                ));
+                continue;
+            };
+            p.spawn((
+                bevy::ui_widgets::Button,
+                NotesEntityLink(entity),
+                observe(on_notes_entity_link_click),
+                Tooltip(format!("Select {}", entity)),
+            ))
+            .with_child((
+                Text::new(label),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.5, 0.7, 1.0, 1.0)),
+            ));
+        }
+    });
+}
+
+/// Spawns the Notes tab into `parent`.
+pub(crate) fn spawn_notes_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let notes = world.resource::<SessionNotes>().text.clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+
+    let mut links = Entity::PLACEHOLDER;
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(strings.notes_tab.clone()),
+            TextFont {
+                font_size: config.title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::bottom(Px(8.0)),
+                ..default()
+            },
+        ));
+
+        p.spawn(Node {
+            display: Display::Flex,
+            column_gap: config.column_gap,
+            margin: UiRect::bottom(Px(8.0)),
+            ..default()
+        })
+        .with_children(|buttons| {
+            for (label, button) in [
+                ("Insert selected entity", NotesInsertButton::Selection),
+                ("Paste copied value", NotesInsertButton::Clipboard),
+            ] {
+                buttons
+                    .spawn((
+                        Node {
+                            padding: UiRect::axes(Px(6.0), Px(2.0)),
+                            border: UiRect::all(Px(1.0)),
+                            ..default()
+                        },
+                        BorderColor::all(config.border_color),
+                        bevy::ui_widgets::Button,
+                        button,
+                        observe(on_notes_insert_click),
+                    ))
+                    .with_child((
+                        Text::new(label),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+                    ));
+            }
+        });
+
+        p.spawn((
+            Node {
+                width: Percent(100.0),
+                min_height: Px(120.0),
+                padding: UiRect::axes(Px(6.0), Px(4.0)),
+                margin: UiRect::bottom(Px(8.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(config.border_color),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            text_input(
+                TextInput::new(strings.take_notes.clone())
+                    .with_text(notes.clone())
+                    .multiline(),
+            ),
+            NotesField,
+            observe(on_notes_changed),
+        ))
+        .with_child((
+            Text::new(strings.take_notes.clone()),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextInputText,
+        ));
+
+        p.spawn((
+            Text::new(strings.entities_mentioned.clone()),
+            TextFont {
+                font_size: config.body_font_size,
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+        ));
+        links = p
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    ..default()
+                },
+                NotesEntityLinks,
+            ))
+            .id();
+    });
+
+    spawn_notes_entity_links(world, links, &notes, config, &strings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn entity_ids_are_found_in_text() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        assert_eq!(parse_entity_id(&a.to_string()), Some(a));
+
+        let notes = format!(
+            "{} jitters when {} lands, see {}.\nv2 and 3v are not ids",
+            a, b, a
+        );
+        assert_eq!(entity_ids_in(&notes), vec![a, b]);
+        assert_eq!(parse_entity_id("12"), None);
+    }
+
+    #[test]
+    fn notes_are_saved_once_the_field_loses_focus() {
+        let path = std::env::temp_dir().join("feathers_inspector_notes_test.txt");
+        let _ = std::fs::remove_file(&path);
+        let mut world = World::new();
+        world.insert_resource(InspectorConfig {
+            notes_file: Some(path.clone()),
+            ..default()
+        });
+        world.init_resource::<Messages<AppExit>>();
+        let field = world.spawn(NotesField).id();
+        world.insert_resource(InputFocus(Some(field)));
+        world.insert_resource(SessionNotes {
+            text: "boss clips through the floor".to_string(),
+            unsaved: true,
+        });
+
+        world.run_system_once(save_session_notes).unwrap();
+        assert!(!path.exists(), "notes are not written while typing");
+
+        world.resource_mut::<InputFocus>().clear();
+        world.run_system_once(save_session_notes).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "boss clips through the floor"
+        );
+        assert!(!world.resource::<SessionNotes>().unsaved);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn notes_tab_uses_the_inspector_strings() {
+        let mut world = World::new();
+        world.insert_resource(InspectorStrings {
+            notes_tab: "Notizen".to_string(),
+            despawned: "entfernt".to_string(),
+            ..default()
+        });
+        let despawned = world.spawn_empty().id();
+        world.despawn(despawned);
+        world.insert_resource(SessionNotes {
+            text: format!("{} fell through the floor", despawned),
+            unsaved: false,
+        });
+        let parent = world.spawn(Node::default()).id();
+
+        spawn_notes_tab_exclusive(&mut world, parent, &InspectorConfig::default());
+        let mut texts = world.query::<&Text>();
+        let texts: Vec<&str> = texts.iter(&world).map(|text| text.0.as_str()).collect();
+        assert!(texts.contains(&"Notizen"));
+        assert!(texts.contains(&format!("{} (entfernt)", despawned).as_str()));
+    }
+}
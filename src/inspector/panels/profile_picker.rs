@@ -0,0 +1,245 @@
+//! Profile dropdown in the title bar.
+//!
+//! Switches between the [`InspectorProfiles`], and saves the current settings
+//! to the active profile or to a new one.
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{ButtonProps, button};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::component_filter::rebuild_component_filter;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::profiles::{InspectorProfiles, apply_profile, save_profile};
+use crate::inspector::state::InspectorState;
+use crate::inspector::strings::InspectorStrings;
+
+const ACTIVE_COLOR: Color = Color::srgba(0.45, 0.7, 1.0, 1.0);
+const ACTION_COLOR: Color = Color::srgba(0.9, 0.9, 0.6, 1.0);
+
+/// Button opening and closing the profile dropdown.
+#[derive(Component)]
+pub struct ProfilePickerButton;
+
+/// The text of the [`ProfilePickerButton`], showing the active profile.
+#[derive(Component)]
+pub struct ProfilePickerLabel;
+
+/// The dropdown below the [`ProfilePickerButton`], hidden while it is closed.
+#[derive(Component)]
+pub struct ProfilePickerMenu;
+
+/// Dropdown entry applying the profile with the given name.
+#[derive(Component)]
+pub struct ProfileOptionButton(pub String);
+
+/// Dropdown entry saving the current settings, to the active profile or to a new one.
+#[derive(Component)]
+pub struct ProfileSaveButton {
+    /// Whether to save to a new profile rather than the active one.
+    pub new_profile: bool,
+}
+
+/// Returns the label of the [`ProfilePickerButton`].
+fn profile_picker_label(
+    profiles: &InspectorProfiles,
+    open: bool,
+    strings: &InspectorStrings,
+) -> String {
+    format!(
+        "{} {} {}",
+        strings.profile,
+        profiles.active.as_deref().unwrap_or(&strings.no_profile),
+        if open { "^" } else { "v" }
+    )
+}
+
+/// Spawns the profile button with its dropdown, closed.
+pub fn spawn_profile_picker(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+    profiles: &InspectorProfiles,
+) {
+    parent.spawn(Node::default()).with_children(|picker| {
+        picker.spawn((
+            button(
+                ButtonProps::default(),
+                ProfilePickerButton,
+                bevy::prelude::Spawn((
+                    Text::new(profile_picker_label(profiles, false, strings)),
+                    TextFont {
+                        font_size: config.small_font_size,
+                        ..default()
+                    },
+                    ProfilePickerLabel,
+                )),
+            ),
+            observe(on_profile_picker_click),
+        ));
+        picker.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Percent(100.0),
+                right: Px(0.0),
+                min_width: Px(200.0),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                row_gap: Px(2.0),
+                padding: config.panel_padding,
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(config.border_color),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.98)),
+            GlobalZIndex(10),
+            ProfilePickerMenu,
+        ));
+    });
+}
+
+/// Observer for the profile button. Opens or closes the dropdown.
+fn on_profile_picker_click(
+    _activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.profile_picker_open = !state.profile_picker_open;
+    commands.queue(rebuild_profile_picker);
+}
+
+/// Observer: applies a profile and closes the dropdown.
+fn on_profile_option_click(
+    activate: On<Activate>,
+    options: Query<&ProfileOptionButton>,
+    mut commands: Commands,
+) {
+    let Ok(option) = options.get(activate.entity) else {
+        return;
+    };
+    let name = option.0.clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(error) = apply_profile(world, &name) {
+            warn!("{}", error);
+        }
+        world.resource_mut::<InspectorState>().profile_picker_open = false;
+        rebuild_component_filter(world);
+        rebuild_profile_picker(world);
+    });
+}
+
+/// Observer: saves the current settings to the active profile, or to a new one.
+fn on_profile_save_click(
+    activate: On<Activate>,
+    buttons: Query<&ProfileSaveButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let new_profile = button.new_profile;
+    commands.queue(move |world: &mut World| {
+        let profiles = world.resource::<InspectorProfiles>();
+        let name = match &profiles.active {
+            Some(active) if !new_profile => active.clone(),
+            _ => profiles.unused_name(),
+        };
+        if let Err(error) = save_profile(world, &name) {
+            warn!("{}", error);
+        }
+        rebuild_profile_picker(world);
+    });
+}
+
+/// Updates the profile button's label, shows or hides the dropdown,
+/// and rebuilds its entries from the [`InspectorProfiles`].
+fn rebuild_profile_picker(world: &mut World) {
+    let config = world.resource::<InspectorConfig>().clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+    let open = world.resource::<InspectorState>().profile_picker_open;
+    let profiles = world.resource::<InspectorProfiles>().clone();
+    let label = profile_picker_label(&profiles, open, &strings);
+
+    let mut labels = world.query_filtered::<&mut Text, With<ProfilePickerLabel>>();
+    for mut text in labels.iter_mut(world) {
+        text.0.clone_from(&label);
+    }
+
+    let mut menus = world.query_filtered::<(Entity, &mut Node), With<ProfilePickerMenu>>();
+    let Some((menu, mut node)) = menus.iter_mut(world).next() else {
+        return;
+    };
+    node.display = if open { Display::Flex } else { Display::None };
+    world.entity_mut(menu).despawn_children();
+    if !open {
+        return;
+    }
+
+    world.entity_mut(menu).with_children(|p| {
+        let text_font = TextFont {
+            font_size: config.small_font_size,
+            ..default()
+        };
+        if profiles.profiles.is_empty() {
+            p.spawn((
+                Text::new(strings.no_profiles.clone()),
+                text_font.clone(),
+                TextColor(config.muted_text_color),
+            ));
+        }
+        for profile in &profiles.profiles {
+            let active = profiles.active.as_ref() == Some(&profile.name);
+            p.spawn((
+                Node {
+                    padding: UiRect::axes(Px(4.0), Px(2.0)),
+                    min_height: Px(config.row_min_height),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                bevy::ui_widgets::Button,
+                ProfileOptionButton(profile.name.clone()),
+                observe(on_profile_option_click),
+            ))
+            .with_child((
+                Text::new(profile.name.clone()),
+                text_font.clone(),
+                TextColor(if active { ACTIVE_COLOR } else { Color::WHITE }),
+            ));
+        }
+
+        let mut save_actions = vec![(true, strings.save_as_new_profile.clone())];
+        if let Some(active) = &profiles.active {
+            save_actions.insert(
+                0,
+                (false, format!("{} {}", strings.save_to_profile, active)),
+            );
+        }
+        for (new_profile, label) in save_actions {
+            p.spawn((
+                Node {
+                    padding: UiRect::axes(Px(4.0), Px(2.0)),
+                    min_height: Px(config.row_min_height),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                bevy::ui_widgets::Button,
+                ProfileSaveButton { new_profile },
+                observe(on_profile_save_click),
+            ))
+            .with_child((
+                Text::new(label),
+                text_font.clone(),
+                TextColor(ACTION_COLOR),
+            ));
+        }
+        if config.profiles_file.is_none() {
+            p.spawn((
+                Text::new(strings.profiles_not_saved.clone()),
+                text_font.clone(),
+                TextColor(config.muted_text_color),
+            ));
+        }
+    });
+}
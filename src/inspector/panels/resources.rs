@@ -0,0 +1,379 @@
+//! Resources tab for the detail panel.
+//! Lists every resource in the world, grouped by module path,
+//! with a search field that fuzzily matches resource type names.
+//! Clicking a reflected resource shows its fields above the list,
+//! edited with the same widgets as the fields of components.
+
+use core::any::TypeId;
+use std::collections::HashMap;
+
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::fuzzy_name_mapping::fuzzy_match_score;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::panels::detail_panel::spawn_resource_fields;
+use crate::inspector::state::{InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::{TextInput, TextInputChanged, TextInputText, Tooltip, text_input};
+
+/// Group label for resources whose type path has no module.
+const ROOT_MODULE: &str = "(root)";
+
+/// The search field of the Resources tab. Click it to start typing.
+#[derive(Component)]
+pub struct ResourceSearchField;
+
+/// The container of the resource groups, rebuilt whenever the search text changes.
+#[derive(Component)]
+pub struct ResourceList;
+
+/// A resource in the [`ResourceList`]. Click it to show the fields of the resource.
+#[derive(Component)]
+pub struct ResourceRow(pub TypeId);
+
+/// Resources sharing a module path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceGroup {
+    /// Module path of the resource types, e.g. `bevy_time::time`.
+    pub module: String,
+    /// Full type paths of the matching resources, best matches first.
+    pub resources: Vec<String>,
+}
+
+/// Splits a type path into its module path and short name, ignoring generic arguments,
+/// e.g. `bevy_time::time::Time<bevy_time::real::Real>` into `bevy_time::time` and `Time<Real>`.
+fn split_type_path(type_path: &str) -> (&str, String) {
+    let base = type_path.split('<').next().unwrap_or(type_path);
+    let module = base
+        .rsplit_once("::")
+        .map_or(ROOT_MODULE, |(module, _)| module);
+    (module, ShortName(type_path).to_string())
+}
+
+/// Groups resource type paths by module path, keeping only those matching the fuzzy `filter`.
+///
+/// Both the short name and the full type path are matched, see [`fuzzy_match_score`].
+/// Resources are sorted by score and then by name, and groups by their best score
+/// and then by module path, so the best matches come first.
+pub fn group_resources<'a>(
+    type_paths: impl IntoIterator<Item = &'a str>,
+    filter: &str,
+) -> Vec<ResourceGroup> {
+    let filter = filter.trim();
+    let mut groups: Vec<(usize, ResourceGroup)> = Vec::new();
+    let mut matches: Vec<(usize, String, &str, &str)> = type_paths
+        .into_iter()
+        .filter_map(|type_path| {
+            let (module, short_name) = split_type_path(type_path);
+            let score = if filter.is_empty() {
+                0
+            } else {
+                let short_score = fuzzy_match_score(filter, &short_name);
+                let path_score = fuzzy_match_score(filter, type_path);
+                short_score.into_iter().chain(path_score).min()?
+            };
+            Some((score, short_name, module, type_path))
+        })
+        .collect();
+    matches.sort();
+
+    for (score, _, module, type_path) in matches {
+        match groups.iter_mut().find(|(_, group)| group.module == module) {
+            Some((_, group)) => group.resources.push(type_path.to_string()),
+            None => groups.push((
+                score,
+                ResourceGroup {
+                    module: module.to_string(),
+                    resources: vec![type_path.to_string()],
+                },
+            )),
+        }
+    }
+
+    groups.sort_by(|(a_score, a), (b_score, b)| {
+        a_score.cmp(b_score).then_with(|| a.module.cmp(&b.module))
+    });
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Observer: shows the fields of the clicked resource, or hides them if they are shown.
+fn on_resource_row_click(
+    activate: On<Activate>,
+    rows: Query<&ResourceRow>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(row) = rows.get(activate.entity) else {
+        return;
+    };
+    state.selected_resource = if state.selected_resource == Some(row.0) {
+        None
+    } else {
+        Some(row.0)
+    };
+    cache.detail_stale = true;
+}
+
+/// Observer: filters the resources by the edited search text.
+fn on_resource_search_changed(
+    changed: On<TextInputChanged>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    state.resource_filter_text.clone_from(&changed.text);
+    commands.queue(rebuild_resource_list);
+}
+
+/// Updates the resource list, without rebuilding the search field, so it keeps the input focus.
+fn rebuild_resource_list(world: &mut World) {
+    let config = world.resource::<InspectorConfig>().clone();
+
+    let mut lists = world.query_filtered::<Entity, With<ResourceList>>();
+    let Some(list) = lists.iter(world).next() else {
+        return;
+    };
+    world.entity_mut(list).despawn_children();
+    spawn_resource_groups(world, list, &config);
+}
+
+fn spawn_resource_groups(world: &mut World, parent: Entity, config: &InspectorConfig) {
+    let type_ids: HashMap<String, Option<TypeId>> = world
+        .iter_resources()
+        .map(|(info, _)| (info.name().to_string(), info.type_id()))
+        .collect();
+    let type_paths: Vec<String> = type_ids.keys().cloned().collect();
+    let total = type_paths.len();
+    let filter = world
+        .resource::<InspectorState>()
+        .resource_filter_text
+        .clone();
+    let selected = world.resource::<InspectorState>().selected_resource;
+    let groups = group_resources(type_paths.iter().map(String::as_str), &filter);
+    let matched: usize = groups.iter().map(|group| group.resources.len()).sum();
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(if filter.trim().is_empty() {
+                format!("{} resources", total)
+            } else {
+                format!("{} of {} resources match", matched, total)
+            }),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+            Node {
+                margin: UiRect::bottom(Px(8.0)),
+                ..default()
+            },
+        ));
+
+        for group in groups {
+            p.spawn((
+                Node {
+                    width: Percent(100.0),
+                    padding: config.panel_padding,
+                    margin: UiRect::bottom(config.item_gap),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(config.border_color),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    Text::new(format!("{} ({})", group.module, group.resources.len())),
+                    TextFont {
+                        font_size: config.body_font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                for type_path in group.resources {
+                    let type_id = type_ids.get(&type_path).copied().flatten();
+                    let mut row = card.spawn((
+                        Text::new(ShortName(&type_path).to_string()),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(if type_id.is_some() && type_id == selected {
+                            Color::WHITE
+                        } else {
+                            config.muted_text_color
+                        }),
+                        Node {
+                            margin: UiRect::left(Px(12.0)),
+                            ..default()
+                        },
+                        Tooltip(type_path),
+                    ));
+                    if let Some(type_id) = type_id {
+                        row.insert((
+                            bevy::ui_widgets::Button,
+                            ResourceRow(type_id),
+                            observe(on_resource_row_click),
+                        ));
+                    }
+                }
+            });
+        }
+    });
+}
+
+pub(crate) fn spawn_resources_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let filter = world
+        .resource::<InspectorState>()
+        .resource_filter_text
+        .clone();
+    let strings = world.resource::<InspectorStrings>().clone();
+    let selected = world
+        .resource::<InspectorState>()
+        .selected_resource
+        .and_then(|type_id| {
+            world
+                .iter_resources()
+                .find(|(info, _)| info.type_id() == Some(type_id))
+                .map(|(info, _)| (type_id, info.name().to_string()))
+        });
+
+    let mut selected_card = None;
+    let mut list = Entity::PLACEHOLDER;
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(strings.resources_tab),
+            TextFont {
+                font_size: config.title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::bottom(Px(8.0)),
+                ..default()
+            },
+        ));
+
+        p.spawn((
+            Node {
+                width: Percent(100.0),
+                padding: UiRect::axes(Px(6.0), Px(4.0)),
+                margin: UiRect::bottom(Px(8.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(config.border_color),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            text_input(TextInput::new(strings.search_resources.clone()).with_text(filter.clone())),
+            ResourceSearchField,
+            observe(on_resource_search_changed),
+        ))
+        .with_child((
+            Text::new(strings.search_resources),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextInputText,
+        ));
+
+        if let Some((_, type_path)) = &selected {
+            let card = p
+                .spawn((
+                    Node {
+                        width: Percent(100.0),
+                        padding: config.panel_padding,
+                        margin: UiRect::bottom(Px(8.0)),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                ))
+                .with_child((
+                    Text::new(ShortName(type_path).to_string()),
+                    TextFont {
+                        font_size: config.body_font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::bottom(Px(4.0)),
+                        ..default()
+                    },
+                    Tooltip(type_path.clone()),
+                ))
+                .id();
+            selected_card = Some(card);
+        }
+
+        list = p
+            .spawn((
+                Node {
+                    width: Percent(100.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ResourceList,
+            ))
+            .id();
+    });
+
+    if let (Some(card), Some((type_id, _))) = (selected_card, selected) {
+        spawn_resource_fields(world, card, type_id, config);
+    }
+    spawn_resource_groups(world, list, config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resources_are_grouped_by_module_and_filtered() {
+        let type_paths = [
+            "bevy_time::time::Time<bevy_time::real::Real>",
+            "bevy_time::fixed::FixedTimestep",
+            "bevy_time::time::Time<bevy_time::virt::Virtual>",
+            "my_game::score::Score",
+            "Rootless",
+        ];
+
+        let groups = group_resources(type_paths, "");
+        let modules: Vec<&str> = groups.iter().map(|group| group.module.as_str()).collect();
+        assert_eq!(
+            modules,
+            [
+                "(root)",
+                "bevy_time::fixed",
+                "bevy_time::time",
+                "my_game::score"
+            ]
+        );
+        assert_eq!(groups[2].resources.len(), 2);
+
+        let groups = group_resources(type_paths, "score");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].module, "my_game::score");
+
+        // Matches against the full path too, and ranks exact short names first
+        let groups = group_resources(type_paths, "time");
+        assert_eq!(groups[0].module, "bevy_time::time");
+        assert!(
+            groups
+                .iter()
+                .any(|group| group.module == "bevy_time::fixed")
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! States tab for the detail panel.
+//! Shows every state with its current value, its pending transition, the states it depends on,
+//! and the systems gated on it.
+//! States that can be set through reflection get a button for each of their variants.
+
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::panels::systems::{condition_result_color, condition_result_label};
+use crate::inspector::run_conditions::RunConditions;
+use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
+use crate::inspector::states::{
+    InspectedState, InspectedStates, StateKind, gated_systems, queue_state_transition,
+};
+use crate::inspector::strings::InspectorStrings;
+
+/// How often the states tab is rebuilt while it is visible, in seconds.
+const STATES_REFRESH_SECONDS: f32 = 0.5;
+
+/// A button in the States tab that queues a transition of a state to one of its variants.
+#[derive(Component)]
+pub struct StateTransitionButton {
+    /// Full type path of the state type.
+    pub type_path: String,
+    /// Name of the variant to transition to.
+    pub variant: String,
+}
+
+/// Periodically marks the detail panel as stale while the States tab is active,
+/// so that state values and gated systems stay current.
+pub fn refresh_states_view(
+    time: Res<Time>,
+    state: Res<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut elapsed: Local<f32>,
+) {
+    if state.active_tab != DetailTab::States {
+        *elapsed = 0.0;
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+    if *elapsed >= STATES_REFRESH_SECONDS {
+        *elapsed = 0.0;
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for [`StateTransitionButton`] clicks.
+/// Writes the variant to the state's [`NextState`]; the transition happens on the next update.
+fn on_state_transition_click(
+    activate: On<Activate>,
+    buttons: Query<&StateTransitionButton>,
+    mut commands: Commands,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let type_path = button.type_path.clone();
+    let variant = button.variant.clone();
+    commands.queue(move |world: &mut World| {
+        queue_state_transition(world, &type_path, &variant);
+    });
+    cache.detail_stale = true;
+}
+
+/// Returns the label shown for a kind of state.
+fn state_kind_label(kind: StateKind) -> &'static str {
+    match kind {
+        StateKind::Root => "root",
+        StateKind::Sub => "sub-state",
+        StateKind::Computed => "computed",
+        StateKind::Unregistered => "state",
+    }
+}
+
+/// Data for a single state card.
+struct StateCard {
+    type_path: String,
+    name: String,
+    kind: StateKind,
+    current: Option<String>,
+    /// The transition queued in the state's `NextState`.
+    pending: Option<String>,
+    /// Unit variants the state can be transitioned to.
+    variants: Vec<String>,
+    /// Short names and current values of the source states.
+    sources: Vec<(String, Option<String>)>,
+    /// Gated systems as display lines, with the last result of their condition.
+    gated: Vec<(String, Option<bool>)>,
+}
+
+fn short_type_name(type_path: &str) -> String {
+    ShortName(type_path).to_string()
+}
+
+pub(crate) fn spawn_states_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let strings = world.resource::<InspectorStrings>().clone();
+    let states: Vec<InspectedState> = world
+        .get_resource::<InspectedStates>()
+        .map(|states| states.collect(world))
+        .unwrap_or_default();
+    let run_conditions = world.get_resource::<RunConditions>();
+    let records_results = run_conditions.is_some_and(RunConditions::records_results);
+
+    let cards: Vec<StateCard> = states
+        .iter()
+        .map(|state| {
+            let sources = state
+                .sources
+                .iter()
+                .map(|source| {
+                    let current = states
+                        .iter()
+                        .find(|s| &s.type_path == source)
+                        .and_then(|s| s.current(world));
+                    (short_type_name(source), current)
+                })
+                .collect();
+            let gated = run_conditions
+                .map(|run_conditions| gated_systems(run_conditions, &state.type_path))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|gated| {
+                    let line = if records_results {
+                        format!(
+                            "{}: {} ({}: {})",
+                            gated.schedule,
+                            gated.system,
+                            gated.condition,
+                            condition_result_label(gated.last_result)
+                        )
+                    } else {
+                        format!("{}: {} ({})", gated.schedule, gated.system, gated.condition)
+                    };
+                    (line, gated.last_result)
+                })
+                .collect();
+            StateCard {
+                type_path: state.type_path.clone(),
+                name: short_type_name(&state.type_path),
+                kind: state.kind,
+                current: state.current(world),
+                pending: state.pending(world),
+                variants: state.variants(world),
+                sources,
+                gated,
+            }
+        })
+        .collect();
+
+    let title_font_size = config.title_font_size;
+    let body_font_size = config.body_font_size;
+    let small_font_size = config.small_font_size;
+    let panel_padding = config.panel_padding;
+    let item_gap = config.item_gap;
+    let border_color = config.border_color;
+    let muted_text_color = config.muted_text_color;
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(format!("{} ({})", strings.states_tab, cards.len())),
+            TextFont {
+                font_size: title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::bottom(Px(12.0)),
+                ..default()
+            },
+        ));
+
+        if cards.is_empty() {
+            p.spawn((
+                Text::new(strings.no_states),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(muted_text_color),
+            ));
+            return;
+        }
+
+        for card in cards {
+            p.spawn((
+                Node {
+                    width: Percent(100.0),
+                    padding: panel_padding,
+                    margin: UiRect::bottom(item_gap),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(border_color),
+            ))
+            .with_children(|state_card| {
+                state_card.spawn((
+                    Text::new(format!(
+                        "{} = {} [{}]",
+                        card.name,
+                        card.current.as_deref().unwrap_or(&strings.inactive_state),
+                        state_kind_label(card.kind)
+                    )),
+                    TextFont {
+                        font_size: body_font_size,
+                        ..default()
+                    },
+                    TextColor(if card.current.is_some() {
+                        Color::WHITE
+                    } else {
+                        muted_text_color
+                    }),
+                ));
+
+                if let Some(pending) = &card.pending {
+                    state_card.spawn((
+                        Text::new(format!("{}: {}", strings.next_state, pending)),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                    ));
+                }
+
+                if !card.variants.is_empty() {
+                    state_card
+                        .spawn(Node {
+                            display: Display::Flex,
+                            flex_wrap: FlexWrap::Wrap,
+                            column_gap: Px(6.0),
+                            row_gap: Px(2.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            for variant in &card.variants {
+                                let is_current = card.current.as_deref() == Some(variant.as_str());
+                                buttons.spawn((
+                                    Node {
+                                        padding: UiRect::horizontal(Px(4.0)),
+                                        border: UiRect::all(Px(1.0)),
+                                        ..default()
+                                    },
+                                    BorderColor::all(border_color),
+                                    bevy::ui_widgets::Button,
+                                    StateTransitionButton {
+                                        type_path: card.type_path.clone(),
+                                        variant: variant.clone(),
+                                    },
+                                    observe(on_state_transition_click),
+                                    children![(
+                                        Text::new(variant.clone()),
+                                        TextFont {
+                                            font_size: small_font_size,
+                                            ..default()
+                                        },
+                                        TextColor(if is_current {
+                                            Color::WHITE
+                                        } else {
+                                            muted_text_color
+                                        }),
+                                        Pickable::IGNORE,
+                                    )],
+                                ));
+                            }
+                        });
+                }
+
+                if !card.sources.is_empty() {
+                    let sources: Vec<String> = card
+                        .sources
+                        .iter()
+                        .map(|(name, current)| {
+                            let current = current.as_deref().unwrap_or(&strings.inactive_state);
+                            format!("{} = {}", name, current)
+                        })
+                        .collect();
+                    state_card.spawn((
+                        Text::new(format!("{} {}", strings.depends_on, sources.join(", "))),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                    ));
+                }
+
+                if card.gated.is_empty() {
+                    state_card.spawn((
+                        Text::new(strings.no_gated_systems.clone()),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                    ));
+                } else {
+                    state_card.spawn((
+                        Text::new(format!("{} ({})", strings.gated_systems, card.gated.len())),
+                        TextFont {
+                            font_size: small_font_size,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                    ));
+                    for (line, result) in card.gated {
+                        state_card.spawn((
+                            Text::new(line),
+                            TextFont {
+                                font_size: small_font_size,
+                                ..default()
+                            },
+                            TextColor(condition_result_color(result, config)),
+                            Node {
+                                margin: UiRect::left(Px(12.0)),
+                                ..default()
+                            },
+                        ));
+                    }
+                }
+            });
+        }
+    });
+}
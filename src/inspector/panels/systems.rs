@@ -0,0 +1,215 @@
+//! Systems tab for the detail panel.
+//! Lists the systems with run conditions in each schedule, along with the result
+//! of each condition's most recent evaluation, to show why a system is not running.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::run_conditions::{ConditionedSystem, RunConditions};
+use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+
+/// How often the systems tab is rebuilt while it is visible, in seconds.
+const SYSTEMS_REFRESH_SECONDS: f32 = 0.5;
+
+const PASSED_COLOR: Color = Color::srgba(0.4, 0.8, 0.4, 1.0);
+
+/// Periodically marks the detail panel as stale while the Systems tab is active,
+/// so that condition results stay current.
+pub fn refresh_systems_view(
+    time: Res<Time>,
+    state: Res<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut elapsed: Local<f32>,
+) {
+    if state.active_tab != DetailTab::Systems {
+        *elapsed = 0.0;
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+    if *elapsed >= SYSTEMS_REFRESH_SECONDS {
+        *elapsed = 0.0;
+        cache.detail_stale = true;
+    }
+}
+
+/// Returns the label for the last result of a run condition.
+pub(crate) fn condition_result_label(result: Option<bool>) -> &'static str {
+    match result {
+        Some(true) => "true",
+        Some(false) => "false",
+        None => "not evaluated",
+    }
+}
+
+/// Returns the color for the last result of a run condition.
+pub(crate) fn condition_result_color(result: Option<bool>, config: &InspectorConfig) -> Color {
+    match result {
+        Some(true) => PASSED_COLOR,
+        Some(false) => config.error_text_color,
+        None => config.muted_text_color,
+    }
+}
+
+/// Summarizes whether a system's conditions let it run, based on their last results.
+fn system_status(system: &ConditionedSystem) -> (&'static str, Option<bool>) {
+    let results: Vec<Option<bool>> = system
+        .conditions
+        .iter()
+        .map(|condition| condition.last_result())
+        .collect();
+    if results.contains(&Some(false)) {
+        ("blocked", Some(false))
+    } else if results.iter().all(|result| *result == Some(true)) {
+        ("running", Some(true))
+    } else {
+        ("not evaluated", None)
+    }
+}
+
+pub(crate) fn spawn_systems_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let strings = world.resource::<InspectorStrings>().clone();
+    let run_conditions = world.get_resource::<RunConditions>();
+    let schedules = run_conditions
+        .map(RunConditions::schedules)
+        .unwrap_or_default();
+    let records_results = run_conditions.is_some_and(RunConditions::records_results);
+    let system_count: usize = schedules.values().map(Vec::len).sum();
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(format!(
+                "{} ({})",
+                strings.systems_with_run_conditions, system_count
+            )),
+            TextFont {
+                font_size: config.title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::bottom(Px(4.0)),
+                ..default()
+            },
+        ));
+        p.spawn((
+            Text::new(if records_results {
+                "Results are from each condition's most recent evaluation by the schedule"
+            } else {
+                "Enable InspectorWindowPlugin::with_run_condition_results to see results"
+            }),
+            TextFont {
+                font_size: config.small_font_size,
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+            Node {
+                margin: UiRect::bottom(Px(12.0)),
+                ..default()
+            },
+        ));
+
+        if system_count == 0 {
+            p.spawn((
+                Text::new(strings.no_run_conditions),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            ));
+            return;
+        }
+
+        for (schedule, systems) in &schedules {
+            if systems.is_empty() {
+                continue;
+            }
+
+            p.spawn((
+                Text::new(format!("{} ({})", schedule, systems.len())),
+                TextFont {
+                    font_size: config.body_font_size,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                Node {
+                    margin: UiRect::vertical(Px(4.0)),
+                    ..default()
+                },
+            ));
+
+            for system in systems {
+                let (status, status_result) = if records_results {
+                    system_status(system)
+                } else {
+                    ("", None)
+                };
+                p.spawn((
+                    Node {
+                        width: Percent(100.0),
+                        padding: config.panel_padding,
+                        margin: UiRect::bottom(config.item_gap),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(2.0),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                ))
+                .with_children(|system_card| {
+                    system_card.spawn((
+                        Text::new(if status.is_empty() {
+                            system.system.clone()
+                        } else {
+                            format!("{} [{}]", system.system, status)
+                        }),
+                        TextFont {
+                            font_size: config.body_font_size,
+                            ..default()
+                        },
+                        TextColor(condition_result_color(status_result, config)),
+                    ));
+
+                    for condition in &system.conditions {
+                        let result = condition.last_result();
+                        let source = condition
+                            .set
+                            .as_ref()
+                            .map(|set| format!(" (from {})", set))
+                            .unwrap_or_default();
+                        let result_label = if records_results {
+                            format!("{}: ", condition_result_label(result))
+                        } else {
+                            String::new()
+                        };
+                        system_card.spawn((
+                            Text::new(format!(
+                                "{}{}{}",
+                                result_label,
+                                condition.display_name(),
+                                source
+                            )),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                            TextColor(condition_result_color(result, config)),
+                            Node {
+                                margin: UiRect::left(Px(12.0)),
+                                ..default()
+                            },
+                        ));
+                    }
+                });
+            }
+        }
+    });
+}
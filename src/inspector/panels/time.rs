@@ -0,0 +1,265 @@
+//! Time tab for the detail panel.
+//! Shows the [`Time<Real>`], [`Time<Virtual>`] and [`Time<Fixed>`] clocks side by side,
+//! and lets the fixed timestep be changed, to make mismatched fixed-update assumptions visible.
+
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{ButtonProps, button};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+
+/// How often the time tab is rebuilt while it is visible, in seconds.
+const TIME_REFRESH_SECONDS: f32 = 0.25;
+
+/// Fixed update rates offered as presets, in Hz.
+const FIXED_RATE_PRESETS: [f64; 6] = [20.0, 30.0, 50.0, 60.0, 64.0, 120.0];
+
+/// Button that sets the fixed timestep to the given rate, in Hz.
+#[derive(Component, Clone, Copy)]
+pub struct FixedRateButton(pub f64);
+
+/// Periodically marks the detail panel as stale while the Time tab is active,
+/// so that the clocks keep ticking.
+pub fn refresh_time_view(
+    time: Res<Time>,
+    state: Res<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut elapsed: Local<f32>,
+) {
+    if state.active_tab != DetailTab::Time {
+        *elapsed = 0.0;
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+    if *elapsed >= TIME_REFRESH_SECONDS {
+        *elapsed = 0.0;
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for fixed rate preset clicks.
+fn on_fixed_rate_click(
+    activate: On<Activate>,
+    buttons: Query<&FixedRateButton>,
+    mut fixed: ResMut<Time<Fixed>>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    if let Ok(rate) = buttons.get(activate.entity) {
+        fixed.set_timestep_hz(rate.0);
+        cache.detail_stale = true;
+    }
+}
+
+/// Formats a duration in milliseconds with a fixed precision, so values don't jump around.
+fn format_millis(seconds: f64) -> String {
+    format!("{:.3} ms", seconds * 1000.0)
+}
+
+/// Returns how many fixed updates per frame the current virtual delta corresponds to.
+///
+/// Values above one mean [`FixedUpdate`] runs several times in some frames,
+/// values below one mean it is skipped in some frames.
+fn fixed_updates_per_frame(virtual_delta: f64, timestep: f64) -> f64 {
+    if timestep > 0.0 {
+        virtual_delta / timestep
+    } else {
+        0.0
+    }
+}
+
+/// A titled group of `label: value` lines.
+struct ClockSection {
+    title: &'static str,
+    lines: Vec<(&'static str, String)>,
+}
+
+pub(crate) fn spawn_time_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let strings = world.resource::<InspectorStrings>().clone();
+    let real = world.get_resource::<Time<Real>>().cloned();
+    let virt = world.get_resource::<Time<Virtual>>().cloned();
+    let fixed = world.get_resource::<Time<Fixed>>().cloned();
+
+    let mut sections = Vec::new();
+    if let Some(real) = &real {
+        sections.push(ClockSection {
+            title: "Real",
+            lines: vec![
+                ("delta", format_millis(real.delta_secs_f64())),
+                ("elapsed", format!("{:.3} s", real.elapsed_secs_f64())),
+            ],
+        });
+    }
+    if let Some(virt) = &virt {
+        sections.push(ClockSection {
+            title: "Virtual",
+            lines: vec![
+                ("delta", format_millis(virt.delta_secs_f64())),
+                ("elapsed", format!("{:.3} s", virt.elapsed_secs_f64())),
+                (
+                    "relative speed",
+                    format!("{:.2}x", virt.relative_speed_f64()),
+                ),
+                ("max delta", format_millis(virt.max_delta().as_secs_f64())),
+                ("paused", virt.is_paused().to_string()),
+            ],
+        });
+    }
+    if let Some(fixed) = &fixed {
+        let timestep = fixed.timestep().as_secs_f64();
+        let mut lines = vec![
+            (
+                "timestep",
+                format!("{} ({:.2} Hz)", format_millis(timestep), 1.0 / timestep),
+            ),
+            ("delta", format_millis(fixed.delta_secs_f64())),
+            ("elapsed", format!("{:.3} s", fixed.elapsed_secs_f64())),
+            (
+                "overstep",
+                format!(
+                    "{} ({:.0}% of a step)",
+                    format_millis(fixed.overstep().as_secs_f64()),
+                    fixed.overstep_fraction_f64() * 100.0
+                ),
+            ),
+        ];
+        if let Some(virt) = &virt {
+            lines.push((
+                "updates per frame",
+                format!(
+                    "{:.2}",
+                    fixed_updates_per_frame(virt.delta_secs_f64(), timestep)
+                ),
+            ));
+        }
+        sections.push(ClockSection {
+            title: "Fixed",
+            lines,
+        });
+    }
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(strings.time_tab),
+            TextFont {
+                font_size: config.title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::bottom(Px(12.0)),
+                ..default()
+            },
+        ));
+
+        if sections.is_empty() {
+            p.spawn((
+                Text::new(strings.no_time_resources),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            ));
+            return;
+        }
+
+        for section in sections {
+            let is_fixed = section.title == "Fixed";
+            p.spawn((
+                Node {
+                    width: Percent(100.0),
+                    padding: config.panel_padding,
+                    margin: UiRect::bottom(config.item_gap),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(config.border_color),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    Text::new(format!("Time<{}>", section.title)),
+                    TextFont {
+                        font_size: config.body_font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                for (label, value) in section.lines {
+                    card.spawn((
+                        Text::new(format!("{}: {}", label, value)),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(config.muted_text_color),
+                        Node {
+                            margin: UiRect::left(Px(12.0)),
+                            ..default()
+                        },
+                    ));
+                }
+
+                if is_fixed {
+                    card.spawn(Node {
+                        display: Display::Flex,
+                        flex_wrap: FlexWrap::Wrap,
+                        align_items: AlignItems::Center,
+                        column_gap: config.column_gap,
+                        row_gap: Px(4.0),
+                        margin: UiRect::top(Px(4.0)),
+                        ..default()
+                    })
+                    .with_children(|presets| {
+                        presets.spawn((
+                            Text::new(strings.set_rate.clone()),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                            TextColor(config.muted_text_color),
+                        ));
+                        for rate in FIXED_RATE_PRESETS {
+                            presets.spawn((
+                                button(
+                                    ButtonProps::default(),
+                                    FixedRateButton(rate),
+                                    bevy::prelude::Spawn((
+                                        Text::new(format!("{} Hz", rate)),
+                                        TextFont {
+                                            font_size: config.small_font_size,
+                                            ..default()
+                                        },
+                                    )),
+                                ),
+                                observe(on_fixed_rate_click),
+                            ));
+                        }
+                    });
+                }
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_updates_per_frame_compares_delta_to_timestep() {
+        assert_eq!(fixed_updates_per_frame(1.0 / 30.0, 1.0 / 60.0), 2.0);
+        assert_eq!(fixed_updates_per_frame(0.016, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,442 @@
+//! Timeline tab for the detail panel.
+//! Shows a horizontal strip per component with its additions, changes and removals
+//! over the recent history window of the [`ComponentLifecycleLog`],
+//! followed by the chain of archetype moves from the [`ArchetypeTransitionLog`].
+
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+
+use crate::component_inspection::ComponentMetadataMap;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::lifecycle::{
+    ArchetypeTransitionLog, ComponentLifecycleLog, LifecycleEvent, LifecycleEventKind,
+    LifecycleObservers,
+};
+use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+
+/// How often the timeline is rebuilt while it is visible, in seconds.
+const TIMELINE_REFRESH_SECONDS: f32 = 0.25;
+
+/// Height of a single component strip.
+const STRIP_HEIGHT: f32 = 10.0;
+
+/// Width of an event marker on a strip.
+const MARKER_WIDTH: f32 = 2.0;
+
+const ADDED_COLOR: Color = Color::srgba(0.4, 0.8, 0.4, 1.0);
+const CHANGED_COLOR: Color = Color::srgba(0.9, 0.8, 0.3, 1.0);
+const REMOVED_COLOR: Color = Color::srgba(0.85, 0.35, 0.35, 1.0);
+const PRESENT_COLOR: Color = Color::srgba(0.3, 0.45, 0.6, 0.6);
+const STRIP_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 1.0);
+
+/// Periodically marks the detail panel as stale while the timeline tab is active,
+/// so that the strips keep scrolling as new frames are recorded.
+pub fn refresh_timeline(
+    time: Res<Time>,
+    state: Res<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut elapsed: Local<f32>,
+) {
+    if state.active_tab != DetailTab::Timeline || state.selected_entity.is_none() {
+        *elapsed = 0.0;
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+    if *elapsed >= TIMELINE_REFRESH_SECONDS {
+        *elapsed = 0.0;
+        cache.detail_stale = true;
+    }
+}
+
+/// Computes the frame ranges during which a component was present on its entity.
+///
+/// `events` must be the events of a single component, oldest first.
+/// Ranges are clamped to the history window and returned as `(start, end)` frame pairs.
+fn presence_spans(
+    events: &[LifecycleEvent],
+    present_now: bool,
+    window_start: u64,
+    frame: u64,
+) -> Vec<(u64, u64)> {
+    // If the first structural event is a removal, the component was present when the window began
+    let first_structural = events
+        .iter()
+        .find(|event| event.kind != LifecycleEventKind::Changed)
+        .map(|event| event.kind);
+    let present_at_start = match first_structural {
+        Some(LifecycleEventKind::Removed) => true,
+        Some(_) => false,
+        None => present_now,
+    };
+
+    let mut spans = Vec::new();
+    let mut start = present_at_start.then_some(window_start);
+    for event in events {
+        match event.kind {
+            LifecycleEventKind::Added => {
+                start.get_or_insert(event.frame);
+            }
+            LifecycleEventKind::Removed => {
+                if let Some(start) = start.take() {
+                    spans.push((start, event.frame));
+                }
+            }
+            LifecycleEventKind::Changed => {}
+        }
+    }
+    if let Some(start) = start
+        && present_now
+    {
+        spans.push((start, frame));
+    }
+    spans
+}
+
+/// Describes an archetype move by its added (`+`) and removed (`-`) components,
+/// using `name` to name them.
+fn transition_edge_label(
+    added: &[ComponentId],
+    removed: &[ComponentId],
+    name: impl Fn(ComponentId) -> String,
+) -> String {
+    let mut changes: Vec<String> = added.iter().map(|&id| format!("+{}", name(id))).collect();
+    changes.extend(removed.iter().map(|&id| format!("-{}", name(id))));
+    changes.join(" ")
+}
+
+/// Data for a single timeline strip.
+struct TimelineRow {
+    name: String,
+    events: Vec<LifecycleEvent>,
+    spans: Vec<(u64, u64)>,
+}
+
+pub(crate) fn spawn_timeline_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    entity: Entity,
+    metadata_map: &ComponentMetadataMap,
+    config: &InspectorConfig,
+) {
+    let strings = world.resource::<InspectorStrings>().clone();
+    let log = world.resource::<ComponentLifecycleLog>();
+    let frame = log.frame();
+    let window_start = log.window_start();
+    let window_len = (frame - window_start).max(1) as f32;
+    let events: Vec<LifecycleEvent> = log.events_for(entity).copied().collect();
+
+    let current_components: Vec<ComponentId> = world
+        .get_entity(entity)
+        .map(|e| e.archetype().components().to_vec())
+        .unwrap_or_default();
+
+    // One row per component that is either present now or shows up in the history
+    let mut component_ids = current_components.clone();
+    for event in &events {
+        if !component_ids.contains(&event.component_id) {
+            component_ids.push(event.component_id);
+        }
+    }
+
+    let component_name = |component_id: ComponentId| {
+        metadata_map
+            .map
+            .get(&component_id)
+            .map(|m| m.name.shortname().to_string())
+            .or_else(|| {
+                world
+                    .components()
+                    .get_name(component_id)
+                    .map(|name| name.shortname().to_string())
+            })
+            .unwrap_or_else(|| "?".to_string())
+    };
+
+    // Each archetype move as the edge label and the component set it led to
+    let moves: Vec<(String, String, Color)> = world
+        .resource::<ArchetypeTransitionLog>()
+        .transitions_for(entity)
+        .map(|transition| {
+            let edge = format!(
+                "{} frames ago: {}",
+                frame.saturating_sub(transition.frame),
+                transition_edge_label(&transition.added, &transition.removed, component_name)
+            );
+            let names: Vec<String> = transition
+                .components
+                .iter()
+                .map(|&id| component_name(id))
+                .collect();
+            let color = match (transition.added.is_empty(), transition.removed.is_empty()) {
+                (false, true) => ADDED_COLOR,
+                (true, false) => REMOVED_COLOR,
+                _ => CHANGED_COLOR,
+            };
+            (edge, format!("{{{}}}", names.join(", ")), color)
+        })
+        .collect();
+
+    let mut rows: Vec<TimelineRow> = component_ids
+        .into_iter()
+        .map(|component_id| {
+            let name = component_name(component_id);
+            let component_events: Vec<LifecycleEvent> = events
+                .iter()
+                .filter(|event| event.component_id == component_id)
+                .copied()
+                .collect();
+            let spans = presence_spans(
+                &component_events,
+                current_components.contains(&component_id),
+                window_start,
+                frame,
+            );
+            TimelineRow {
+                name,
+                events: component_events,
+                spans,
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let title_font_size = config.title_font_size;
+    let small_font_size = config.small_font_size;
+    let item_gap = config.item_gap;
+    let muted_text_color = config.muted_text_color;
+    let history_frames = frame - window_start;
+    let observes_lifecycles = world.contains_resource::<LifecycleObservers>();
+
+    let percent = move |at: u64| (at.saturating_sub(window_start)) as f32 / window_len * 100.0;
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn((
+            Text::new(format!(
+                "{} {} {}",
+                strings.timeline_history, history_frames, strings.frames
+            )),
+            TextFont {
+                font_size: title_font_size,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        // Legend
+        p.spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            column_gap: Px(12.0),
+            margin: UiRect::bottom(Px(8.0)),
+            ..default()
+        })
+        .with_children(|legend| {
+            for (label, color) in [
+                (&strings.timeline_added, ADDED_COLOR),
+                (&strings.timeline_changed, CHANGED_COLOR),
+                (&strings.timeline_removed, REMOVED_COLOR),
+                (&strings.timeline_present, PRESENT_COLOR),
+            ] {
+                legend.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: small_font_size,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+
+        if !observes_lifecycles {
+            p.spawn((
+                Text::new(strings.lifecycles_not_observed),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(muted_text_color),
+            ));
+        }
+
+        if rows.is_empty() {
+            p.spawn((
+                Text::new(strings.no_timeline_changes),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(muted_text_color),
+            ));
+            return;
+        }
+
+        for row in rows {
+            p.spawn(Node {
+                width: Percent(100.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                margin: UiRect::bottom(item_gap),
+                ..default()
+            })
+            .with_children(|row_node| {
+                row_node.spawn((
+                    Text::new(row.name),
+                    TextFont {
+                        font_size: small_font_size,
+                        ..default()
+                    },
+                    TextColor(muted_text_color),
+                ));
+
+                row_node
+                    .spawn((
+                        Node {
+                            width: Percent(100.0),
+                            height: Px(STRIP_HEIGHT),
+                            ..default()
+                        },
+                        BackgroundColor(STRIP_COLOR),
+                    ))
+                    .with_children(|strip| {
+                        for (start, end) in row.spans {
+                            strip.spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Percent(percent(start)),
+                                    width: Percent(percent(end) - percent(start)),
+                                    height: Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(PRESENT_COLOR),
+                            ));
+                        }
+
+                        for event in row.events {
+                            let color = match event.kind {
+                                LifecycleEventKind::Added => ADDED_COLOR,
+                                LifecycleEventKind::Changed => CHANGED_COLOR,
+                                LifecycleEventKind::Removed => REMOVED_COLOR,
+                            };
+                            strip.spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Percent(percent(event.frame)),
+                                    width: Px(MARKER_WIDTH),
+                                    height: Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                            ));
+                        }
+                    });
+            });
+        }
+
+        if !moves.is_empty() {
+            p.spawn((
+                Text::new(format!("{} ({})", strings.archetype_moves, moves.len())),
+                TextFont {
+                    font_size: title_font_size,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::top(Px(8.0)),
+                    ..default()
+                },
+            ));
+        }
+        for (edge, components, color) in moves {
+            p.spawn((
+                Text::new(format!("| {}", edge)),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(color),
+            ));
+            p.spawn((
+                Node {
+                    padding: UiRect::horizontal(Px(4.0)),
+                    border: UiRect::all(Px(1.0)),
+                    margin: UiRect::bottom(Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(STRIP_COLOR),
+            ))
+            .with_child((
+                Text::new(components),
+                TextFont {
+                    font_size: small_font_size,
+                    ..default()
+                },
+                TextColor(muted_text_color),
+            ));
+        }
+
+        p.spawn((
+            Text::new(strings.recorded_while_selected),
+            TextFont {
+                font_size: small_font_size,
+                ..default()
+            },
+            TextColor(muted_text_color),
+            Node {
+                margin: UiRect::top(Px(8.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: LifecycleEventKind, frame: u64) -> LifecycleEvent {
+        LifecycleEvent {
+            component_id: ComponentId::new(0),
+            kind,
+            frame,
+        }
+    }
+
+    #[test]
+    fn component_present_for_whole_window() {
+        let events = [event(LifecycleEventKind::Changed, 5)];
+        assert_eq!(presence_spans(&events, true, 0, 10), vec![(0, 10)]);
+    }
+
+    #[test]
+    fn component_added_then_removed() {
+        let events = [
+            event(LifecycleEventKind::Added, 2),
+            event(LifecycleEventKind::Changed, 4),
+            event(LifecycleEventKind::Removed, 6),
+        ];
+        assert_eq!(presence_spans(&events, false, 0, 10), vec![(2, 6)]);
+    }
+
+    #[test]
+    fn moves_list_added_then_removed_components() {
+        let label = transition_edge_label(
+            &[ComponentId::new(1)],
+            &[ComponentId::new(2), ComponentId::new(3)],
+            |id| format!("C{}", id.index()),
+        );
+        assert_eq!(label, "+C1 -C2 -C3");
+    }
+
+    #[test]
+    fn component_removed_then_readded() {
+        let events = [
+            event(LifecycleEventKind::Removed, 3),
+            event(LifecycleEventKind::Added, 7),
+        ];
+        assert_eq!(presence_spans(&events, true, 1, 10), vec![(1, 3), (7, 10)]);
+    }
+}
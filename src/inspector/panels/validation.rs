@@ -0,0 +1,148 @@
+//! Validation tab for the detail panel.
+//! Lists the errors of scenes and components that failed to load or import,
+//! as collected in [`ValidationReports`].
+
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{ButtonProps, button};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::scene_validation::ValidationReports;
+use crate::inspector::state::{DetailTab, InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+use crate::inspector::widgets::selectable_text;
+
+/// Button that clears all validation reports.
+#[derive(Component)]
+pub struct ClearValidationReportsButton;
+
+/// Rebuilds the Validation tab when new reports come in while it is visible.
+pub fn refresh_validation_view(
+    state: Res<InspectorState>,
+    reports: Res<ValidationReports>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    if state.active_tab == DetailTab::Validation && reports.is_changed() {
+        cache.detail_stale = true;
+    }
+}
+
+/// Observer for the clear button.
+fn on_clear_validation_reports_click(
+    _activate: On<Activate>,
+    mut reports: ResMut<ValidationReports>,
+) {
+    reports.clear();
+}
+
+pub(crate) fn spawn_validation_tab_exclusive(
+    world: &mut World,
+    parent: Entity,
+    config: &InspectorConfig,
+) {
+    let strings = world.resource::<InspectorStrings>().clone();
+    // Newest reports first
+    let reports: Vec<(String, Vec<String>)> = world
+        .get_resource::<ValidationReports>()
+        .map(|reports| {
+            reports
+                .iter()
+                .rev()
+                .map(|report| {
+                    let errors = report.errors.iter().map(ToString::to_string).collect();
+                    (report.source.clone(), errors)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn(Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            column_gap: config.column_gap,
+            margin: UiRect::bottom(Px(12.0)),
+            ..default()
+        })
+        .with_children(|header| {
+            header.spawn((
+                Text::new(format!("{} ({})", strings.validation_errors, reports.len())),
+                TextFont {
+                    font_size: config.title_font_size,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            if !reports.is_empty() {
+                header.spawn((
+                    button(
+                        ButtonProps::default(),
+                        ClearValidationReportsButton,
+                        bevy::prelude::Spawn((
+                            Text::new(strings.clear.clone()),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                        )),
+                    ),
+                    observe(on_clear_validation_reports_click),
+                ));
+            }
+        });
+
+        if reports.is_empty() {
+            p.spawn((
+                Text::new(strings.no_validation_errors),
+                TextFont {
+                    font_size: config.small_font_size,
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            ));
+            return;
+        }
+
+        for (source, errors) in reports {
+            p.spawn((
+                Node {
+                    width: Percent(100.0),
+                    padding: config.panel_padding,
+                    margin: UiRect::bottom(config.item_gap),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(2.0),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(config.border_color),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    Text::new(format!("{} ({} {})", source, errors.len(), strings.errors)),
+                    TextFont {
+                        font_size: config.body_font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                for error in errors {
+                    card.spawn((
+                        selectable_text(error),
+                        TextFont {
+                            font_size: config.small_font_size,
+                            ..default()
+                        },
+                        TextColor(config.error_text_color),
+                        Node {
+                            margin: UiRect::left(Px(12.0)),
+                            ..default()
+                        },
+                    ));
+                }
+            });
+        }
+    });
+}
@@ -1,31 +1,89 @@
 //! Inspector window plugin and UI scaffold.
 
+use bevy::asset::AssetLoadFailedEvent;
 use bevy::camera::RenderTarget;
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::observer::On;
 use bevy::ecs::relationship::Relationship;
+use bevy::feathers::controls::{ButtonProps, button};
 use bevy::feathers::dark_theme::create_dark_theme;
 use bevy::feathers::theme::{ThemeBackgroundColor, UiTheme};
 use bevy::feathers::tokens;
-use bevy::feathers::FeathersPlugins;
+use bevy::feathers::{FeathersPlugin, FeathersPlugins};
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::input_focus::InputDispatchPlugin;
+use bevy::input_focus::tab_navigation::TabNavigationPlugin;
 use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
 use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
 use bevy::window::{WindowRef, WindowResolution};
 
+use super::baseline::EntityBaselines;
+use super::bulk_edit::{BulkComponentEdits, process_bulk_component_edits};
+use super::clipboard::{InspectorClipboard, copy_on_double_click};
+use super::component_settings::ComponentDisplayOverrides;
 use super::config::InspectorConfig;
+use super::despawn::{Graveyard, expire_graveyard};
+use super::edit_history::{EditHistory, handle_edit_history_shortcuts, record_field_edits};
+use super::exclusion::EntityExclusions;
+use super::field_access::DangerousFields;
+use super::field_flags::FieldFlags;
+use super::field_ranges::FieldRanges;
+use super::fonts::apply_inspector_fonts;
+use super::lifecycle::{
+    ArchetypeTransitionLog, ComponentInsertionSources, ComponentLifecycleLog, LifecycleObservers,
+    observe_new_components, prune_archetype_transitions, sample_component_changes,
+};
+use super::memory_trend::{MemoryTrend, sample_memory_trend, sync_memory_trend_chart};
+use super::offscreen_indicator::update_offscreen_indicator;
 use super::panels::{
-    refresh_entity_cache, spawn_detail_panel, spawn_entity_list_panel, sync_detail_panel,
-    sync_entity_list, sync_selection_highlight,
+    SerializedSizeCache, SessionNotes, auto_refresh_entity_cache, forget_edited_serialized_sizes,
+    load_session_notes, pin_component_card_headers, prune_field_display_state,
+    refresh_entity_cache, refresh_states_view, refresh_systems_view, refresh_time_view,
+    refresh_timeline, refresh_validation_view, report_bulk_despawn_results, report_despawn_results,
+    save_session_notes, scroll_to_selection, spawn_detail_panel, spawn_entity_list_panel,
+    spawn_profile_picker, sync_bulk_component_row, sync_detail_panel, sync_entity_list,
+    sync_entity_rows, sync_go_to_entity_field, sync_graveyard_row, sync_selection_highlight,
+    sync_visibility_toggles,
+};
+use super::profiles::{InspectorProfiles, load_inspector_profiles, pin_pending_entities};
+use super::run_conditions::capture_run_conditions;
+use super::scene_origin::{SceneOrigins, record_scene_origins};
+use super::scene_validation::{ValidationReports, record_scene_load_failures};
+use super::search_index::{SearchIndex, update_search_index};
+use super::selection::{
+    InspectorSelection, SelectionChanged, SelectionRequest, apply_selection_requests,
+    auto_select_spawned_entities, expire_selection_notice, follow_name_pattern,
+    navigate_hierarchy_with_keyboard, reacquire_selection, sync_inspector_selection,
 };
 use super::semantic_names::SemanticFieldNames;
 use super::state::{InspectorCache, InspectorInternal, InspectorState, InspectorWindowState};
-use super::widgets::DragValuePlugin;
+use super::states::InspectedStates;
+use super::strings::InspectorStrings;
+use super::structural_changes::{PendingStructuralChanges, apply_pending_structural_changes};
+use super::widget_registry::InspectorWidgetRegistry;
+use super::widgets::{
+    ColorPickerPlugin, DragValuePlugin, FieldSliderPlugin, FieldTweenPlugin, FlagCheckboxPlugin,
+    SelectableTextPlugin, TextInputPlugin, TooltipPlugin, VectorDragPlugin,
+};
 
 /// Marker component for the inspector window.
 #[derive(Component)]
 pub struct InspectorWindow;
 
+/// Marker component for the root node of the inspector UI.
+#[derive(Component)]
+pub(crate) struct InspectorUiRoot;
+
+/// Title bar button toggling [`InspectorConfig::auto_select_spawned`].
+#[derive(Component)]
+pub struct AutoSelectSpawnedButton;
+
+/// Marker for the text of the [`AutoSelectSpawnedButton`].
+#[derive(Component)]
+pub struct AutoSelectSpawnedLabel;
+
 /// Marker to indicate UI has been initialized.
 #[derive(Component)]
 struct InspectorUiInitialized;
@@ -42,19 +100,132 @@ pub enum InspectorSet {
 }
 
 /// Plugin that manages the inspector window lifecycle.
-pub struct InspectorWindowPlugin;
+///
+/// Adds [`FeathersPlugins`], skipping those the app already added,
+/// and installs the feathers dark theme unless the app already has a [`UiTheme`] of its own.
+#[derive(Clone, Debug)]
+pub struct InspectorWindowPlugin {
+    /// Whether to install the feathers dark theme when the app has no [`UiTheme`] with colors.
+    ///
+    /// The theme is global, so apps styling their own feathers widgets can turn this off
+    /// with [`without_theme`](Self::without_theme) to keep their theme for the inspector too.
+    pub install_theme: bool,
+    /// Whether to record the result of every run condition evaluation for the Systems tab.
+    ///
+    /// Off by default, since recording makes each condition read the whole world,
+    /// which keeps the systems it gates from running in parallel with systems that write to it.
+    /// Turned on with [`with_run_condition_results`](Self::with_run_condition_results).
+    pub record_run_condition_results: bool,
+    /// Whether to observe the additions and removals of every component type,
+    /// for the Timeline tab, [`ComponentInsertionSources`] and [`ArchetypeTransitionLog`].
+    ///
+    /// Off by default, since it spawns two observers per component type, which run on every
+    /// insertion and removal in the app. Turned on with
+    /// [`with_component_lifecycles`](Self::with_component_lifecycles).
+    pub observe_component_lifecycles: bool,
+}
+
+impl Default for InspectorWindowPlugin {
+    fn default() -> Self {
+        Self {
+            install_theme: true,
+            record_run_condition_results: false,
+            observe_component_lifecycles: false,
+        }
+    }
+}
+
+impl InspectorWindowPlugin {
+    /// Leaves the app's [`UiTheme`] alone, instead of installing the feathers dark theme.
+    pub fn without_theme(mut self) -> Self {
+        self.install_theme = false;
+        self
+    }
+
+    /// Records the result of every run condition evaluation,
+    /// to show in the Systems and States tabs whether each condition currently passes.
+    pub fn with_run_condition_results(mut self) -> Self {
+        self.record_run_condition_results = true;
+        self
+    }
+
+    /// Observes the additions and removals of every component type,
+    /// to show them in the Timeline tab and record where components were inserted.
+    pub fn with_component_lifecycles(mut self) -> Self {
+        self.observe_component_lifecycles = true;
+        self
+    }
+}
 
 impl Plugin for InspectorWindowPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(FeathersPlugins)
-            .add_plugins(DragValuePlugin)
-            .insert_resource(UiTheme(create_dark_theme()))
+        if !app.is_plugin_added::<FeathersPlugin>() {
+            let mut feathers = FeathersPlugins.build();
+            if app.is_plugin_added::<InputDispatchPlugin>() {
+                feathers = feathers.disable::<InputDispatchPlugin>();
+            }
+            if app.is_plugin_added::<TabNavigationPlugin>() {
+                feathers = feathers.disable::<TabNavigationPlugin>();
+            }
+            app.add_plugins(feathers);
+        }
+        let has_theme = app
+            .world()
+            .get_resource::<UiTheme>()
+            .is_some_and(|theme| !theme.0.color.is_empty());
+        if self.install_theme && !has_theme {
+            app.insert_resource(UiTheme(create_dark_theme()));
+        }
+
+        app.add_plugins(DragValuePlugin)
+            .add_plugins(ColorPickerPlugin)
+            .add_plugins(FieldSliderPlugin)
+            .add_plugins(FlagCheckboxPlugin)
+            .add_plugins(TooltipPlugin)
+            .add_plugins(VectorDragPlugin)
+            .add_plugins(FieldTweenPlugin)
+            .add_plugins(SelectableTextPlugin)
+            .add_plugins(TextInputPlugin)
             // State resources
             .init_resource::<InspectorState>()
+            .init_resource::<InspectorSelection>()
+            .add_message::<SelectionRequest>()
+            .add_message::<SelectionChanged>()
             .init_resource::<InspectorCache>()
             .init_resource::<InspectorConfig>()
+            .init_resource::<InspectorStrings>()
             .init_resource::<InspectorWindowState>()
             .init_resource::<SemanticFieldNames>()
+            .init_resource::<FieldRanges>()
+            .init_resource::<FieldFlags>()
+            .init_resource::<DangerousFields>()
+            .init_resource::<ComponentDisplayOverrides>()
+            .init_resource::<ComponentLifecycleLog>()
+            .init_resource::<ComponentInsertionSources>()
+            .init_resource::<ArchetypeTransitionLog>()
+            .init_resource::<SceneOrigins>()
+            .init_resource::<InspectedStates>()
+            .init_resource::<ValidationReports>()
+            .init_resource::<EntityExclusions>()
+            .init_resource::<InspectorClipboard>()
+            .init_resource::<BulkComponentEdits>()
+            .init_resource::<PendingStructuralChanges>()
+            .init_resource::<Graveyard>()
+            .init_resource::<InspectorWidgetRegistry>()
+            .init_resource::<EntityBaselines>()
+            .init_resource::<EditHistory>()
+            .init_resource::<SearchIndex>()
+            .init_resource::<SessionNotes>()
+            .init_resource::<MemoryTrend>()
+            .init_resource::<InspectorProfiles>()
+            .init_resource::<SerializedSizeCache>()
+            .register_type::<InspectorProfiles>()
+            .add_observer(record_scene_origins)
+            .add_observer(copy_on_double_click)
+            .add_observer(report_despawn_results)
+            .add_observer(report_bulk_despawn_results)
+            .add_observer(record_field_edits)
+            .add_observer(forget_edited_serialized_sizes)
             // System ordering
             .configure_sets(
                 Update,
@@ -66,37 +237,121 @@ impl Plugin for InspectorWindowPlugin {
                     .chain(),
             )
             // Startup
-            .add_systems(Startup, setup_inspector_window)
+            .add_systems(
+                Startup,
+                (
+                    setup_inspector_window,
+                    load_session_notes,
+                    load_inspector_profiles,
+                ),
+            )
+            // Lifecycle log: sample change ticks once the frame's mutations are done
+            .add_systems(
+                Last,
+                (
+                    sample_component_changes,
+                    prune_archetype_transitions,
+                    prune_field_display_state,
+                    save_session_notes,
+                ),
+            )
             // Update systems
             .add_systems(
                 Update,
                 (
                     // Input handling
-                    handle_mouse_wheel_scroll.in_set(InspectorSet::Input),
+                    (
+                        handle_mouse_wheel_scroll,
+                        // The messages only exist while the scene plugin is added
+                        record_scene_load_failures.run_if(
+                            resource_exists::<Messages<AssetLoadFailedEvent<DynamicScene>>>,
+                        ),
+                        handle_edit_history_shortcuts,
+                        (
+                            process_bulk_component_edits,
+                            apply_pending_structural_changes,
+                            expire_graveyard,
+                        )
+                            .chain(),
+                        (
+                            reacquire_selection,
+                            expire_selection_notice,
+                            follow_name_pattern,
+                            auto_select_spawned_entities,
+                            navigate_hierarchy_with_keyboard,
+                            apply_selection_requests,
+                            sync_inspector_selection,
+                        )
+                            .chain(),
+                    )
+                        .in_set(InspectorSet::Input),
                     // Cache refresh
-                    refresh_entity_cache.in_set(InspectorSet::RefreshCache),
+                    (
+                        (
+                            auto_refresh_entity_cache,
+                            pin_pending_entities,
+                            update_search_index,
+                            refresh_entity_cache,
+                        )
+                            .chain(),
+                        refresh_timeline,
+                        refresh_states_view,
+                        refresh_systems_view,
+                        refresh_time_view,
+                        refresh_validation_view,
+                        sample_memory_trend,
+                    )
+                        .in_set(InspectorSet::RefreshCache),
                     // UI sync - chain these to avoid resource conflicts
                     (
                         setup_inspector_ui,
                         sync_entity_list,
+                        sync_entity_rows,
+                        sync_bulk_component_row,
+                        sync_go_to_entity_field,
+                        sync_visibility_toggles,
+                        sync_graveyard_row,
                         sync_detail_panel,
+                        sync_memory_trend_chart,
                         sync_selection_highlight,
+                        scroll_to_selection,
+                        pin_component_card_headers,
+                        apply_inspector_fonts,
                     )
                         .chain()
                         .in_set(InspectorSet::SyncUI),
+                    update_offscreen_indicator.in_set(InspectorSet::SyncUI),
+                    sync_auto_select_spawned_label.in_set(InspectorSet::SyncUI),
+                    apply_inspector_ui_scale,
                     // Cleanup
                     handle_window_close,
                 ),
             );
+
+        // Observe newly registered components before anything else runs
+        if self.observe_component_lifecycles {
+            app.init_resource::<LifecycleObservers>()
+                .add_systems(PreStartup, observe_new_components)
+                .add_systems(First, observe_new_components);
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Schedules added by other plugins only exist once every plugin has been built
+        capture_run_conditions(app, self.record_run_condition_results);
     }
 }
 
 /// Spawns the inspector window on startup.
-fn setup_inspector_window(mut commands: Commands, mut window_state: ResMut<InspectorWindowState>) {
+fn setup_inspector_window(
+    mut commands: Commands,
+    mut window_state: ResMut<InspectorWindowState>,
+    strings: Res<InspectorStrings>,
+) {
     let window_entity = commands
         .spawn((
             Window {
-                title: "Feathers Inspector".to_string(),
+                title: strings.window_title.clone(),
                 resolution: WindowResolution::new(900, 650),
                 ..default()
             },
@@ -118,6 +373,8 @@ fn setup_inspector_ui(
     mut commands: Commands,
     window_state: Res<InspectorWindowState>,
     config: Res<InspectorConfig>,
+    strings: Res<InspectorStrings>,
+    profiles: Res<InspectorProfiles>,
     mut cache: ResMut<InspectorCache>,
     inspector_windows: Query<Entity, (With<InspectorWindow>, Without<InspectorUiInitialized>)>,
 ) {
@@ -130,7 +387,9 @@ fn setup_inspector_ui(
     }
 
     // Mark window as initialized
-    commands.entity(window_entity).insert(InspectorUiInitialized);
+    commands
+        .entity(window_entity)
+        .insert(InspectorUiInitialized);
 
     // Create camera for the inspector window (marked as internal to exclude from entity list)
     let camera_entity = commands
@@ -156,30 +415,29 @@ fn setup_inspector_ui(
             },
             ThemeBackgroundColor(tokens::WINDOW_BG),
             UiTargetCamera(camera_entity),
+            InspectorUiRoot,
         ))
         .with_children(|root| {
             // Title bar
-            spawn_title_bar(root, &config);
+            spawn_title_bar(root, &config, &strings, &profiles);
 
             // Main content area
-            root.spawn((
-                Node {
-                    width: Percent(100.0),
-                    flex_grow: 1.0,
-                    display: Display::Flex,
-                    flex_direction: FlexDirection::Row,
-                    padding: config.panel_padding,
-                    column_gap: config.column_gap,
-                    ..default()
-                },
-            ))
-            .with_children(|content| {
-                // Left panel: Entity list
-                spawn_entity_list_panel(content, &config);
+            root.spawn((Node {
+                width: Percent(100.0),
+                flex_grow: 1.0,
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                padding: config.panel_padding,
+                column_gap: config.column_gap,
+                ..default()
+            },))
+                .with_children(|content| {
+                    // Left panel: Entity list
+                    spawn_entity_list_panel(content, &config, &strings);
 
-                // Right panel: Detail view
-                spawn_detail_panel(content, &config);
-            });
+                    // Right panel: Detail view
+                    spawn_detail_panel(content, &config, &strings);
+                });
         });
 
     // Trigger initial cache refresh
@@ -188,7 +446,12 @@ fn setup_inspector_ui(
     info!("Inspector UI initialized");
 }
 
-fn spawn_title_bar(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConfig) {
+fn spawn_title_bar(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+    profiles: &InspectorProfiles,
+) {
     parent
         .spawn((
             Node {
@@ -196,6 +459,7 @@ fn spawn_title_bar(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConf
                 height: config.title_bar_height,
                 display: Display::Flex,
                 align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
                 padding: config.panel_padding,
                 border: UiRect::bottom(Px(1.0)),
                 ..default()
@@ -204,16 +468,132 @@ fn spawn_title_bar(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConf
         ))
         .with_children(|bar| {
             bar.spawn((
-                Text::new("Feathers Inspector"),
+                Text::new(strings.window_title.clone()),
                 TextFont {
                     font_size: config.title_font_size + 2.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
             ));
+            bar.spawn(Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: config.column_gap,
+                ..default()
+            })
+            .with_children(|actions| {
+                actions.spawn((
+                    button(
+                        ButtonProps::default(),
+                        AutoSelectSpawnedButton,
+                        Spawn((
+                            Text::new(auto_select_spawned_label(
+                                config.auto_select_spawned,
+                                strings,
+                            )),
+                            TextFont {
+                                font_size: config.small_font_size,
+                                ..default()
+                            },
+                            AutoSelectSpawnedLabel,
+                        )),
+                    ),
+                    observe(on_auto_select_spawned_click),
+                ));
+                spawn_profile_picker(actions, config, strings, profiles);
+            });
         });
 }
 
+/// Returns the label of the [`AutoSelectSpawnedButton`].
+fn auto_select_spawned_label(enabled: bool, strings: &InspectorStrings) -> String {
+    let state = if enabled { &strings.on } else { &strings.off };
+    format!("{}: {}", strings.select_spawns, state)
+}
+
+/// Observer for the [`AutoSelectSpawnedButton`].
+fn on_auto_select_spawned_click(_activate: On<Activate>, mut config: ResMut<InspectorConfig>) {
+    config.auto_select_spawned = !config.auto_select_spawned;
+}
+
+/// Keeps the text of the [`AutoSelectSpawnedButton`] in sync with the config,
+/// which game code can change too.
+fn sync_auto_select_spawned_label(
+    config: Res<InspectorConfig>,
+    strings: Res<InspectorStrings>,
+    mut labels: Query<&mut Text, With<AutoSelectSpawnedLabel>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    let label = auto_select_spawned_label(config.auto_select_spawned, &strings);
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0.clone_from(&label);
+        }
+    }
+}
+
+/// The scale factor override [`apply_inspector_ui_scale`] set on the inspector window,
+/// and the override the window had before, which is restored once the UI scale is back to 1.
+#[derive(Component)]
+struct UiScaleOverride {
+    original: Option<f32>,
+    applied: f32,
+}
+
+/// Applies [`InspectorConfig::ui_scale`] to the inspector window.
+///
+/// The scale is applied as a scale factor override relative to the window's own DPI scale factor,
+/// or to the override the app set, so every size in the inspector UI scales together,
+/// and stays consistent when the window moves to a monitor with a different DPI.
+fn apply_inspector_ui_scale(
+    config: Res<InspectorConfig>,
+    window_state: Res<InspectorWindowState>,
+    mut windows: Query<(&mut Window, Option<&mut UiScaleOverride>), With<InspectorWindow>>,
+    mut commands: Commands,
+) {
+    let Some(window_entity) = window_state.window_entity else {
+        return;
+    };
+    let Ok((mut window, scale_override)) = windows.get_mut(window_entity) else {
+        return;
+    };
+
+    // An override set by the app since the scale was applied replaces the remembered one
+    let current = window.resolution.scale_factor_override();
+    let original = match &scale_override {
+        Some(scale_override) if current == Some(scale_override.applied) => scale_override.original,
+        _ => current,
+    };
+    if config.ui_scale == 1.0 {
+        if scale_override.is_some() {
+            if current != original {
+                window.resolution.set_scale_factor_override(original);
+            }
+            commands.entity(window_entity).remove::<UiScaleOverride>();
+        }
+        return;
+    }
+
+    let applied = original.unwrap_or(window.resolution.base_scale_factor()) * config.ui_scale;
+    if current != Some(applied) {
+        window.resolution.set_scale_factor_override(Some(applied));
+    }
+    match scale_override {
+        Some(mut scale_override) => {
+            if scale_override.original != original || scale_override.applied != applied {
+                *scale_override = UiScaleOverride { original, applied };
+            }
+        }
+        None => {
+            commands
+                .entity(window_entity)
+                .insert(UiScaleOverride { original, applied });
+        }
+    }
+}
+
 /// Handles cleanup when the inspector window is closed.
 fn handle_window_close(
     mut window_state: ResMut<InspectorWindowState>,
@@ -251,14 +631,12 @@ fn handle_mouse_wheel_scroll(
                     if let Ok((mut scroll_pos, node, computed)) = scrollables.get_mut(current) {
                         // Found a scrollable container
                         if node.overflow.y == OverflowAxis::Scroll && delta.y != 0.0 {
-                            let max_y = (computed.content_size().y - computed.size().y)
-                                .max(0.0)
+                            let max_y = (computed.content_size().y - computed.size().y).max(0.0)
                                 * computed.inverse_scale_factor();
                             scroll_pos.y = (scroll_pos.y + delta.y).clamp(0.0, max_y);
                         }
                         if node.overflow.x == OverflowAxis::Scroll && delta.x != 0.0 {
-                            let max_x = (computed.content_size().x - computed.size().x)
-                                .max(0.0)
+                            let max_x = (computed.content_size().x - computed.size().x).max(0.0)
                                 * computed.inverse_scale_factor();
                             scroll_pos.x = (scroll_pos.x + delta.x).clamp(0.0, max_x);
                         }
@@ -276,3 +654,46 @@ fn handle_mouse_wheel_scroll(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn ui_scale_restores_the_apps_scale_factor_override() {
+        let mut world = World::new();
+        let mut resolution = WindowResolution::default();
+        resolution.set_scale_factor_override(Some(2.0));
+        let window = world
+            .spawn((
+                Window {
+                    resolution,
+                    ..default()
+                },
+                InspectorWindow,
+            ))
+            .id();
+        world.insert_resource(InspectorWindowState {
+            window_entity: Some(window),
+            ..default()
+        });
+        let scale_factor_override = |world: &World| {
+            let window = world.get::<Window>(window).unwrap();
+            window.resolution.scale_factor_override()
+        };
+
+        world.insert_resource(InspectorConfig {
+            ui_scale: 1.5,
+            ..default()
+        });
+        world.run_system_once(apply_inspector_ui_scale).unwrap();
+        world.run_system_once(apply_inspector_ui_scale).unwrap();
+        assert_eq!(scale_factor_override(&world), Some(3.0));
+
+        world.resource_mut::<InspectorConfig>().ui_scale = 1.0;
+        world.run_system_once(apply_inspector_ui_scale).unwrap();
+        assert_eq!(scale_factor_override(&world), Some(2.0));
+    }
+}
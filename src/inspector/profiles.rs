@@ -0,0 +1,418 @@
+//! Named configuration profiles for the inspector.
+//!
+//! A profile bundles the layout, entity list filters, pinned entities and pinned component cards,
+//! so a team can share consistent inspector setups for a project:
+//! set [`InspectorConfig::profiles_file`] to a RON file inside the project's repository,
+//! save profiles from the title bar's dropdown, and commit the file.
+//! Components are stored by type path and entities by [`Name`] path,
+//! so profiles stay valid across runs.
+
+use core::any::TypeId;
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeRegistry};
+use thiserror::Error;
+
+use crate::entity_grouping::GroupingStrategy;
+use crate::inspector::clipboard::reflect_to_ron;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::filter_chips::FilterChip;
+use crate::inspector::panels::EntityListPanel;
+use crate::inspector::scene_validation::{ValidationError, validate_component_ron};
+use crate::inspector::selection::{entity_name_path, find_entity_by_name_path};
+use crate::inspector::state::{InspectorCache, InspectorState};
+
+/// A named set of inspector settings.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct InspectorProfile {
+    /// Name of the profile, shown in the profile dropdown.
+    pub name: String,
+    /// Width of the entity list, see [`InspectorConfig::left_panel_width`].
+    pub left_panel_width: Val,
+    /// How entities are grouped in the entity list.
+    pub grouping_strategy: GroupingStrategy,
+    /// Search text of the entity list.
+    pub filter_text: String,
+    /// Active quick filters.
+    pub filter_chips: Vec<FilterChip>,
+    /// Scene filter: only list entities spawned from the scene with this asset path.
+    pub scene_filter: Option<String>,
+    /// Type paths of the components listed entities must have.
+    pub required_components: Vec<String>,
+    /// Type paths of the components listed entities must not have.
+    pub excluded_components: Vec<String>,
+    /// Type paths of the components whose cards are pinned, in the order pinned.
+    pub pinned_components: Vec<String>,
+    /// [`Name`] paths of the pinned entities, see [`entity_name_path`], in the order pinned.
+    pub pinned_entities: Vec<String>,
+}
+
+impl InspectorProfile {
+    /// Captures the current settings of the inspector as a profile named `name`.
+    ///
+    /// Components without a registered type and unnamed entities are left out.
+    pub fn capture(world: &World, name: impl Into<String>) -> Self {
+        let state = world.resource::<InspectorState>();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let type_path_of = |type_id: TypeId| {
+            registry
+                .get_type_info(type_id)
+                .map(|info| info.type_path().to_string())
+        };
+        let component_paths = |ids: &[bevy::ecs::component::ComponentId]| {
+            ids.iter()
+                .filter_map(|&id| world.components().get_info(id)?.type_id())
+                .filter_map(type_path_of)
+                .collect()
+        };
+
+        Self {
+            name: name.into(),
+            left_panel_width: world.resource::<InspectorConfig>().left_panel_width,
+            grouping_strategy: state.grouping_strategy,
+            filter_text: state.filter_text.clone(),
+            filter_chips: state.filter_chips.clone(),
+            scene_filter: state.scene_filter.clone(),
+            required_components: component_paths(&state.required_components),
+            excluded_components: component_paths(&state.excluded_components),
+            pinned_components: state
+                .pinned_component_cards
+                .iter()
+                .filter_map(|&type_id| type_path_of(type_id))
+                .collect(),
+            pinned_entities: state
+                .pinned_entities
+                .iter()
+                .filter_map(|&entity| entity_name_path(world, entity))
+                .chain(state.pending_pinned_paths.iter().cloned())
+                .collect(),
+        }
+    }
+
+    /// Applies the profile to the inspector, replacing its current layout, filters and pins.
+    ///
+    /// Components whose type is not registered, or not used as a component yet, are skipped.
+    /// Entities that don't exist yet are pinned once they are spawned,
+    /// see [`pin_pending_entities`].
+    pub fn apply(&self, world: &mut World) {
+        let mut pinned_entities = Vec::new();
+        let mut pending_paths = Vec::new();
+        for path in &self.pinned_entities {
+            match find_entity_by_name_path(world, path) {
+                Some(entity) => pinned_entities.push(entity),
+                None => pending_paths.push(path.clone()),
+            }
+        }
+
+        let (required, excluded, pinned) = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            let type_id_of = |path: &String| {
+                let type_id = registry.get_with_type_path(path).map(|r| r.type_id());
+                if type_id.is_none() {
+                    warn!(
+                        "Inspector profile `{}`: {} is not registered",
+                        self.name, path
+                    );
+                }
+                type_id
+            };
+            let component_ids = |paths: &[String]| {
+                paths
+                    .iter()
+                    .filter_map(type_id_of)
+                    .filter_map(|type_id| world.components().get_id(type_id))
+                    .collect::<Vec<_>>()
+            };
+            (
+                component_ids(&self.required_components),
+                component_ids(&self.excluded_components),
+                self.pinned_components
+                    .iter()
+                    .filter_map(type_id_of)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let mut state = world.resource_mut::<InspectorState>();
+        state.grouping_strategy = self.grouping_strategy;
+        state.filter_text.clone_from(&self.filter_text);
+        state.filter_chips.clone_from(&self.filter_chips);
+        state.scene_filter.clone_from(&self.scene_filter);
+        state.required_components = required;
+        state.excluded_components = excluded;
+        state.pinned_component_cards = pinned;
+        state.pinned_entities = pinned_entities;
+        state.pending_pinned_paths = pending_paths;
+
+        world.resource_mut::<InspectorConfig>().left_panel_width = self.left_panel_width;
+        let mut panels = world.query_filtered::<&mut Node, With<EntityListPanel>>();
+        for mut node in panels.iter_mut(world) {
+            node.width = self.left_panel_width;
+        }
+
+        let mut cache = world.resource_mut::<InspectorCache>();
+        cache.stale = true;
+        cache.detail_stale = true;
+    }
+}
+
+/// Exclusive system that pins the entities of [`InspectorState::pending_pinned_paths`]
+/// once entities with those [`Name`] paths exist.
+pub fn pin_pending_entities(world: &mut World) {
+    let pending = world
+        .resource::<InspectorState>()
+        .pending_pinned_paths
+        .clone();
+    let found: Vec<(String, Entity)> = pending
+        .into_iter()
+        .filter_map(|path| {
+            let entity = find_entity_by_name_path(world, &path)?;
+            Some((path, entity))
+        })
+        .collect();
+    if found.is_empty() {
+        return;
+    }
+
+    let mut state = world.resource_mut::<InspectorState>();
+    for (path, entity) in found {
+        state
+            .pending_pinned_paths
+            .retain(|pending| *pending != path);
+        if !state.pinned_entities.contains(&entity) {
+            state.pinned_entities.push(entity);
+        }
+    }
+    world.resource_mut::<InspectorCache>().stale = true;
+}
+
+/// The configuration profiles, loaded from [`InspectorConfig::profiles_file`] at startup.
+#[derive(Resource, Reflect, Clone, Debug, Default)]
+pub struct InspectorProfiles {
+    /// The profiles, in the order they were first saved.
+    pub profiles: Vec<InspectorProfile>,
+    /// Name of the profile last applied or saved.
+    ///
+    /// This is specific to the session, so it is not written to the profiles file.
+    #[reflect(ignore)]
+    pub active: Option<String>,
+}
+
+impl InspectorProfiles {
+    /// Returns the profile named `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&InspectorProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Adds `profile`, replacing the profile with the same name in place if there is one.
+    pub fn insert(&mut self, profile: InspectorProfile) {
+        match self.profiles.iter_mut().find(|p| p.name == profile.name) {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+    }
+
+    /// Returns a name that no profile has yet, such as `Profile 3`.
+    pub fn unused_name(&self) -> String {
+        (self.profiles.len() + 1..)
+            .map(|n| format!("Profile {}", n))
+            .find(|name| self.get(name).is_none())
+            .unwrap_or_default()
+    }
+
+    /// Formats the profiles as RON, the format of [`InspectorConfig::profiles_file`].
+    ///
+    /// Returns `None` if `registry` is missing the types of the profiles.
+    pub fn to_ron(&self, registry: &TypeRegistry) -> Option<String> {
+        reflect_to_ron(self, registry)
+    }
+
+    /// Parses profiles from RON written by [`Self::to_ron`].
+    pub fn from_ron(ron: &str, registry: &TypeRegistry) -> Result<Self, ValidationError> {
+        let value = validate_component_ron(registry, Self::type_path(), ron)?;
+        Self::from_reflect(value.as_ref()).ok_or_else(|| ValidationError {
+            position: None,
+            entity: None,
+            type_path: Some(Self::type_path().to_string()),
+            message: "not a list of profiles".to_string(),
+        })
+    }
+}
+
+/// An error that occurred while switching to or saving a profile.
+#[derive(Debug, Error)]
+pub enum ProfileError {
+    /// No profile has the given name.
+    #[error("No inspector profile named `{0}`")]
+    UnknownProfile(String),
+    /// The profiles could not be formatted as RON.
+    #[error("Failed to serialize the inspector profiles")]
+    Serialize,
+    /// The profiles file could not be written.
+    #[error("Failed to write the inspector profiles: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Applies the profile named `name`, making it the active profile.
+pub fn apply_profile(world: &mut World, name: &str) -> Result<(), ProfileError> {
+    let mut profiles = world.resource_mut::<InspectorProfiles>();
+    let profile = profiles
+        .get(name)
+        .cloned()
+        .ok_or_else(|| ProfileError::UnknownProfile(name.to_string()))?;
+    profiles.active = Some(profile.name.clone());
+    profile.apply(world);
+    Ok(())
+}
+
+/// Saves the current settings of the inspector as the profile named `name`,
+/// making it the active profile, and writes all profiles to
+/// [`InspectorConfig::profiles_file`] if it is set.
+pub fn save_profile(world: &mut World, name: &str) -> Result<(), ProfileError> {
+    let profile = InspectorProfile::capture(world, name);
+    let mut profiles = world.resource_mut::<InspectorProfiles>();
+    profiles.insert(profile);
+    profiles.active = Some(name.to_string());
+
+    let Some(path) = world.resource::<InspectorConfig>().profiles_file.clone() else {
+        return Ok(());
+    };
+    let ron = {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        world.resource::<InspectorProfiles>().to_ron(&registry)
+    };
+    std::fs::write(path, ron.ok_or(ProfileError::Serialize)?)?;
+    Ok(())
+}
+
+/// Reads the profiles file at `path`, returning no profiles if it doesn't exist yet.
+fn read_profiles_file(path: &Path, registry: &TypeRegistry) -> Option<InspectorProfiles> {
+    match std::fs::read_to_string(path) {
+        Ok(ron) => match InspectorProfiles::from_ron(&ron, registry) {
+            Ok(profiles) => Some(profiles),
+            Err(error) => {
+                warn!(
+                    "Invalid inspector profiles in {}: {}",
+                    path.display(),
+                    error
+                );
+                None
+            }
+        },
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+        Err(error) => {
+            warn!(
+                "Failed to load inspector profiles from {}: {}",
+                path.display(),
+                error
+            );
+            None
+        }
+    }
+}
+
+/// Loads the profiles from [`InspectorConfig::profiles_file`], if it is set and exists,
+/// and applies [`InspectorConfig::default_profile`].
+pub(crate) fn load_inspector_profiles(world: &mut World) {
+    let config = world.resource::<InspectorConfig>();
+    let (path, default_profile) = (config.profiles_file.clone(), config.default_profile.clone());
+    if let Some(path) = path {
+        let profiles = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            read_profiles_file(&path, &registry)
+        };
+        if let Some(profiles) = profiles {
+            world.insert_resource(profiles);
+        }
+    }
+    if let Some(name) = default_profile
+        && let Err(error) = apply_profile(world, &name)
+    {
+        warn!("{}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn profiles_round_trip_through_ron() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorCache>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<InspectorProfiles>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<InspectorProfiles>();
+            registry.register::<Transform>();
+        }
+        let transform = world.register_component::<Transform>();
+        let level = world.spawn(Name::new("Level")).id();
+        let boss = world.spawn((Name::new("Boss"), ChildOf(level))).id();
+        {
+            let mut state = world.resource_mut::<InspectorState>();
+            state.filter_text = "enemy".to_string();
+            state.filter_chips = vec![FilterChip::Sprites];
+            state.excluded_components = vec![transform];
+            state.pinned_component_cards = vec![TypeId::of::<Transform>()];
+            state.pinned_entities = vec![boss];
+        }
+        save_profile(&mut world, "Combat").unwrap();
+
+        let ron = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            world
+                .resource::<InspectorProfiles>()
+                .to_ron(&registry)
+                .unwrap()
+        };
+        let profiles = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            InspectorProfiles::from_ron(&ron, &registry).unwrap()
+        };
+        assert_eq!(profiles.active, None);
+        assert_eq!(
+            profiles.get("Combat").unwrap().excluded_components,
+            vec![Transform::type_path().to_string()]
+        );
+        assert_eq!(
+            profiles.get("Combat").unwrap().pinned_entities,
+            vec!["Level/Boss"]
+        );
+
+        world.insert_resource(profiles);
+        world.insert_resource(InspectorState::default());
+        apply_profile(&mut world, "Combat").unwrap();
+        let state = world.resource::<InspectorState>();
+        assert_eq!(state.filter_text, "enemy");
+        assert_eq!(state.filter_chips, vec![FilterChip::Sprites]);
+        assert_eq!(state.excluded_components, vec![transform]);
+        assert_eq!(
+            state.pinned_component_cards,
+            vec![TypeId::of::<Transform>()]
+        );
+        assert_eq!(state.pinned_entities, vec![boss]);
+        assert!(matches!(
+            apply_profile(&mut world, "Missing"),
+            Err(ProfileError::UnknownProfile(_))
+        ));
+
+        // Entities that don't exist yet are pinned once they are spawned
+        world.despawn(boss);
+        apply_profile(&mut world, "Combat").unwrap();
+        let state = world.resource::<InspectorState>();
+        assert!(state.pinned_entities.is_empty());
+        assert_eq!(state.pending_pinned_paths, vec!["Level/Boss"]);
+        let respawned = world.spawn((Name::new("Boss"), ChildOf(level))).id();
+        world.run_system_once(pin_pending_entities).unwrap();
+        let state = world.resource::<InspectorState>();
+        assert_eq!(state.pinned_entities, vec![respawned]);
+        assert!(state.pending_pinned_paths.is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+//! Captures the run conditions of every system when schedules are built.
+//!
+//! Once a schedule has been initialized, its systems and conditions are moved into the
+//! schedule's executor and can no longer be reached through the public schedule API.
+//! A [`ScheduleBuildPass`] is therefore added to each schedule, which records the conditions
+//! that apply to each system (including those inherited from system sets) whenever it is built.
+//!
+//! If enabled with [`InspectorWindowPlugin::with_run_condition_results`], the pass also wraps
+//! every condition in one that stores the result of each evaluation by the executor.
+//! Conditions are never evaluated by the inspector itself, so conditions with side effects
+//! (e.g. on their `Local`s) are evaluated exactly as often as they would be otherwise.
+//!
+//! [`InspectorWindowPlugin::with_run_condition_results`]:
+//! crate::inspector::InspectorWindowPlugin::with_run_condition_results
+
+use core::any::TypeId;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, RwLock};
+
+use bevy::ecs::schedule::graph::{DiGraph, Direction};
+use bevy::ecs::schedule::{
+    BoxedCondition, ConditionWithAccess, NodeId, ScheduleBuildError, ScheduleBuildPass,
+    ScheduleGraph, Schedules, SystemKey, SystemSetKey,
+};
+use bevy::ecs::system::ReadOnlySystem;
+use bevy::prelude::*;
+
+/// Values stored in a condition's result slot.
+const NOT_EVALUATED: u8 = 0;
+const EVALUATED_FALSE: u8 = 1;
+const EVALUATED_TRUE: u8 = 2;
+
+/// A run condition that applies to a system.
+#[derive(Clone, Debug)]
+pub struct RunConditionInfo {
+    /// Full name of the condition, e.g. `bevy_state::condition::in_state<game::AppState>::{{closure}}`.
+    pub name: String,
+    /// The system set the condition is inherited from, or `None` for the system's own conditions.
+    pub set: Option<String>,
+    /// Result of the condition's most recent evaluation.
+    result: Arc<AtomicU8>,
+}
+
+impl RunConditionInfo {
+    /// Returns the result of the most recent evaluation of the condition by the schedule's
+    /// executor, or `None` if it has not been evaluated since the schedule was built
+    /// or results are not recorded (see [`RunConditions::records_results`]).
+    ///
+    /// A condition whose parameters fail validation counts as `false`, like it does for
+    /// the executor. Conditions in schedules that stop running keep their last result.
+    pub fn last_result(&self) -> Option<bool> {
+        match self.result.load(Ordering::Relaxed) {
+            EVALUATED_FALSE => Some(false),
+            EVALUATED_TRUE => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Returns a short, readable name for the condition, e.g. `in_state<AppState>`.
+    pub fn display_name(&self) -> String {
+        let short = ShortName(&self.name).to_string();
+        short
+            .strip_suffix("::{{closure}}")
+            .map(str::to_string)
+            .unwrap_or(short)
+    }
+}
+
+/// A system together with all run conditions that apply to it.
+#[derive(Clone, Debug)]
+pub struct ConditionedSystem {
+    /// Short name of the system.
+    pub system: String,
+    /// The system's own conditions, followed by those inherited from its sets.
+    pub conditions: Vec<RunConditionInfo>,
+}
+
+/// The systems with run conditions in each schedule, as of the last time the schedule was built.
+///
+/// Schedules are only built once they first run, so systems in schedules that have not run yet
+/// (such as `OnEnter` schedules of states that were never entered) are not listed.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct RunConditions {
+    schedules: Arc<RwLock<BTreeMap<String, Vec<ConditionedSystem>>>>,
+    record_results: bool,
+}
+
+impl RunConditions {
+    /// Returns whether the results of conditions are recorded,
+    /// which is enabled with [`InspectorWindowPlugin::with_run_condition_results`].
+    ///
+    /// [`InspectorWindowPlugin::with_run_condition_results`]:
+    /// crate::inspector::InspectorWindowPlugin::with_run_condition_results
+    pub fn records_results(&self) -> bool {
+        self.record_results
+    }
+
+    /// Returns a snapshot of the systems with run conditions, per schedule name.
+    pub fn schedules(&self) -> BTreeMap<String, Vec<ConditionedSystem>> {
+        self.schedules
+            .read()
+            .map(|schedules| schedules.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Wraps `condition` in a run condition that stores the result of every evaluation in `result`,
+/// and otherwise evaluates like the condition it wraps.
+///
+/// The wrapper reads the condition's data through `&World`, so it conflicts with every system
+/// that writes to the world, which can keep the gated system from running in parallel with them.
+/// Commands queued by the wrapped condition are not applied.
+fn recorded_condition(
+    mut condition: BoxedCondition,
+    result: Arc<AtomicU8>,
+) -> impl ReadOnlySystem<In = (), Out = bool> {
+    let name = condition.name().to_string();
+    IntoSystem::into_system(move |world: &World| {
+        // Like the executor, a condition whose parameters fail validation counts as false
+        let passed = condition.run_readonly((), world).unwrap_or(false);
+        let value = if passed {
+            EVALUATED_TRUE
+        } else {
+            EVALUATED_FALSE
+        };
+        result.store(value, Ordering::Relaxed);
+        passed
+    })
+    .with_name(name)
+}
+
+/// Returns whether `condition` was wrapped by [`recorded_condition`].
+fn is_recorded(condition: &BoxedCondition) -> bool {
+    fn return_type_id<T: 'static>(_: fn(BoxedCondition, Arc<AtomicU8>) -> T) -> TypeId {
+        TypeId::of::<T>()
+    }
+    condition.type_id() == return_type_id(recorded_condition)
+}
+
+/// Build pass that records the run conditions of a schedule into [`RunConditions`].
+#[derive(Debug)]
+struct CaptureRunConditions {
+    schedule: String,
+    conditions: RunConditions,
+    /// Whether to wrap conditions to record their results.
+    record_results: bool,
+    /// Result slots of the conditions wrapped so far, keyed by the address of the wrapper,
+    /// which stays the same while the box is moved around, so slots survive rebuilds.
+    results: HashMap<usize, Arc<AtomicU8>>,
+}
+
+impl CaptureRunConditions {
+    /// Returns information about each of `conditions`,
+    /// wrapping those that are not wrapped yet if results are recorded.
+    fn capture(
+        &mut self,
+        world: &mut World,
+        conditions: &mut Vec<ConditionWithAccess>,
+        set: Option<String>,
+    ) -> Vec<RunConditionInfo> {
+        let mut infos = Vec::with_capacity(conditions.len());
+        for mut condition in core::mem::take(conditions) {
+            let name = condition.condition.name().to_string();
+            let result = if is_recorded(&condition.condition) {
+                self.results
+                    .get(&condition_address(&condition.condition))
+                    .cloned()
+                    .unwrap_or_default()
+            } else if self.record_results {
+                let result = Arc::new(AtomicU8::new(NOT_EVALUATED));
+                let mut recorded = recorded_condition(condition.condition, result.clone());
+                condition = ConditionWithAccess {
+                    access: recorded.initialize(world),
+                    condition: Box::new(recorded),
+                };
+                self.results
+                    .insert(condition_address(&condition.condition), result.clone());
+                result
+            } else {
+                Arc::new(AtomicU8::new(NOT_EVALUATED))
+            };
+            infos.push(RunConditionInfo {
+                name,
+                set: set.clone(),
+                result,
+            });
+            conditions.push(condition);
+        }
+        infos
+    }
+}
+
+/// Returns the address of a boxed condition, which stays the same while the box is moved around.
+fn condition_address(condition: &BoxedCondition) -> usize {
+    &**condition as *const dyn ReadOnlySystem<In = (), Out = bool> as *const () as usize
+}
+
+impl ScheduleBuildPass for CaptureRunConditions {
+    type EdgeOptions = ();
+
+    fn add_dependency(&mut self, _from: NodeId, _to: NodeId, _options: Option<&Self::EdgeOptions>) {
+    }
+
+    fn collapse_set(
+        &mut self,
+        _set: SystemSetKey,
+        _systems: &[SystemKey],
+        _dependency_flattening: &DiGraph<NodeId>,
+    ) -> impl Iterator<Item = (NodeId, NodeId)> {
+        core::iter::empty()
+    }
+
+    fn build(
+        &mut self,
+        world: &mut World,
+        graph: &mut ScheduleGraph,
+        _dependency_flattened: &mut DiGraph<SystemKey>,
+    ) -> Result<(), ScheduleBuildError> {
+        // Set conditions are evaluated once per set, so each set's conditions are captured
+        // once and then shared by every system in the set
+        let mut set_conditions: HashMap<SystemSetKey, Vec<RunConditionInfo>> = HashMap::new();
+        let set_keys: Vec<SystemSetKey> = graph.system_sets.iter().map(|(key, ..)| key).collect();
+        for key in set_keys {
+            let set_name = graph.system_sets.get(key).map(|set| format!("{:?}", set));
+            let conditions = graph
+                .system_sets
+                .get_conditions_mut(key)
+                .map(|conditions| self.capture(world, conditions, set_name))
+                .unwrap_or_default();
+            set_conditions.insert(key, conditions);
+        }
+
+        let system_keys: Vec<SystemKey> = graph.systems.iter().map(|(key, ..)| key).collect();
+        let mut systems = Vec::new();
+        for key in system_keys {
+            let mut conditions: Vec<RunConditionInfo> = graph
+                .systems
+                .get_conditions_mut(key)
+                .map(|conditions| self.capture(world, conditions, None))
+                .unwrap_or_default();
+
+            // Walk up the set hierarchy to collect inherited conditions
+            let mut visited = HashSet::new();
+            let mut pending = vec![NodeId::System(key)];
+            while let Some(node) = pending.pop() {
+                for parent in graph
+                    .hierarchy()
+                    .graph()
+                    .neighbors_directed(node, Direction::Incoming)
+                {
+                    let NodeId::Set(set_key) = parent else {
+                        continue;
+                    };
+                    if !visited.insert(set_key) {
+                        continue;
+                    }
+                    if let Some(inherited) = set_conditions.get(&set_key) {
+                        conditions.extend(inherited.iter().cloned());
+                    }
+                    pending.push(parent);
+                }
+            }
+
+            if !conditions.is_empty()
+                && let Some(system) = graph.systems.get(key)
+            {
+                systems.push(ConditionedSystem {
+                    system: system.name().shortname().to_string(),
+                    conditions,
+                });
+            }
+        }
+        systems.sort_by(|a, b| a.system.cmp(&b.system));
+
+        if let Ok(mut schedules) = self.conditions.schedules.write() {
+            schedules.insert(self.schedule.clone(), systems);
+        }
+        Ok(())
+    }
+}
+
+/// Adds a [`CaptureRunConditions`] pass to every schedule in the app,
+/// and inserts the [`RunConditions`] resource they record into.
+///
+/// Must run after all plugins have added their schedules, e.g. in [`Plugin::finish`].
+pub(crate) fn capture_run_conditions(app: &mut App, record_results: bool) {
+    let conditions = RunConditions {
+        record_results,
+        ..default()
+    };
+    app.insert_resource(conditions.clone());
+
+    let mut schedules = app.world_mut().resource_mut::<Schedules>();
+    for (label, schedule) in schedules.iter_mut() {
+        schedule.add_build_pass(CaptureRunConditions {
+            schedule: format!("{:?}", label),
+            conditions: conditions.clone(),
+            record_results,
+            results: HashMap::new(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::schedule::ScheduleLabel;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct GatedSet;
+
+    #[derive(Resource)]
+    struct Enabled;
+
+    fn gated_system() {}
+
+    #[test]
+    fn conditions_are_captured_with_inherited_set_conditions() {
+        let mut world = World::new();
+        let conditions = RunConditions::default();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_build_pass(CaptureRunConditions {
+            schedule: "TestSchedule".to_string(),
+            conditions: conditions.clone(),
+            record_results: false,
+            results: HashMap::new(),
+        });
+        schedule.configure_sets(GatedSet.run_if(resource_exists::<Enabled>));
+        schedule.add_systems(gated_system.in_set(GatedSet).run_if(|| true));
+        schedule.run(&mut world);
+
+        let schedules = conditions.schedules();
+        let systems = &schedules["TestSchedule"];
+        assert_eq!(systems.len(), 1);
+        assert_eq!(systems[0].system, "gated_system");
+        let sets: Vec<_> = systems[0]
+            .conditions
+            .iter()
+            .map(|c| c.set.clone())
+            .collect();
+        assert_eq!(sets, vec![None, Some("GatedSet".to_string())]);
+        assert_eq!(
+            systems[0].conditions[1].display_name(),
+            "resource_exists<Enabled>"
+        );
+
+        // Conditions are left alone unless results are recorded
+        world.insert_resource(Enabled);
+        schedule.run(&mut world);
+        let schedules = conditions.schedules();
+        assert!(
+            schedules["TestSchedule"][0]
+                .conditions
+                .iter()
+                .all(|condition| condition.last_result().is_none())
+        );
+    }
+
+    #[test]
+    fn condition_results_are_recorded_when_evaluated() {
+        let mut world = World::new();
+        let conditions = RunConditions::default();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_build_pass(CaptureRunConditions {
+            schedule: "TestSchedule".to_string(),
+            conditions: conditions.clone(),
+            record_results: true,
+            results: HashMap::new(),
+        });
+        schedule.configure_sets(GatedSet.run_if(resource_exists::<Enabled>));
+        schedule.add_systems(gated_system.in_set(GatedSet).run_if(|| true));
+
+        schedule.run(&mut world);
+        let results = |conditions: &RunConditions| -> Vec<Option<bool>> {
+            conditions.schedules()["TestSchedule"][0]
+                .conditions
+                .iter()
+                .map(RunConditionInfo::last_result)
+                .collect()
+        };
+        // Whether the system's own condition is evaluated while its set's condition is false
+        // depends on the executor
+        assert_eq!(results(&conditions)[1], Some(false));
+
+        world.insert_resource(Enabled);
+        schedule.run(&mut world);
+        assert_eq!(results(&conditions), vec![Some(true), Some(true)]);
+
+        // Rebuilding the schedule keeps the existing result slots
+        schedule.add_systems(|| {});
+        schedule.initialize(&mut world).unwrap();
+        assert_eq!(results(&conditions), vec![Some(true), Some(true)]);
+    }
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    #[test]
+    fn recorded_conditions_evaluate_like_the_conditions_they_wrap() {
+        let mut world = World::new();
+        world.init_resource::<Runs>();
+        let conditions = RunConditions::default();
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_build_pass(CaptureRunConditions {
+            schedule: "TestSchedule".to_string(),
+            conditions: conditions.clone(),
+            record_results: true,
+            results: HashMap::new(),
+        });
+        // Passes every other evaluation, so evaluating it more than once per run would show
+        let every_other = |mut evaluations: Local<u32>| {
+            *evaluations += 1;
+            *evaluations % 2 == 1
+        };
+        schedule.add_systems((|mut runs: ResMut<Runs>| runs.0 += 1).run_if(every_other));
+
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.resource::<Runs>().0, 2);
+        let schedules = conditions.schedules();
+        assert_eq!(
+            schedules["TestSchedule"][0].conditions[0].last_result(),
+            Some(false)
+        );
+    }
+}
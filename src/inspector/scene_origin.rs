@@ -0,0 +1,112 @@
+//! Tracks which scene each entity was spawned from.
+//!
+//! When a scene instance finishes spawning, every entity of the instance is recorded
+//! along with the asset path of its [`Scene`] or [`DynamicScene`], so the inspector can
+//! show where an entity came from and filter the entity list by scene.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::scene::SceneInstanceReady;
+
+use crate::inspector::state::InspectorCache;
+
+/// Label used for scene instances whose asset has no path, or that have no scene root entity.
+const UNKNOWN_SCENE: &str = "<unnamed scene>";
+
+/// The scene an entity was spawned from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SceneOrigin {
+    /// Asset path of the scene, or a placeholder if the scene has no path.
+    pub scene: String,
+    /// The entity holding the [`SceneRoot`] or [`DynamicSceneRoot`] the instance was spawned for.
+    pub root: Entity,
+}
+
+/// Maps entities spawned from scenes to their [`SceneOrigin`].
+#[derive(Resource, Default, Debug)]
+pub struct SceneOrigins {
+    origins: HashMap<Entity, SceneOrigin>,
+}
+
+impl SceneOrigins {
+    /// Returns the scene `entity` was spawned from, if any.
+    pub fn get(&self, entity: Entity) -> Option<&SceneOrigin> {
+        self.origins.get(&entity)
+    }
+
+    /// Returns the distinct scene paths that entities were spawned from, sorted.
+    pub fn scenes(&self) -> Vec<&str> {
+        let mut scenes: Vec<&str> = self
+            .origins
+            .values()
+            .map(|origin| origin.scene.as_str())
+            .collect();
+        scenes.sort_unstable();
+        scenes.dedup();
+        scenes
+    }
+}
+
+/// Observer that records the origin of every entity in a scene instance once it is ready.
+pub(crate) fn record_scene_origins(
+    ready: On<SceneInstanceReady>,
+    scene_spawner: Res<SceneSpawner>,
+    asset_server: Res<AssetServer>,
+    roots: Query<(Option<&SceneRoot>, Option<&DynamicSceneRoot>)>,
+    entities: Query<()>,
+    mut origins: ResMut<SceneOrigins>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let path = match roots.get(ready.entity) {
+        Ok((Some(scene_root), _)) => asset_server.get_path(scene_root.0.id()),
+        Ok((None, Some(dynamic_scene_root))) => asset_server.get_path(dynamic_scene_root.0.id()),
+        _ => None,
+    };
+    let scene = path
+        .map(|path| path.to_string())
+        .unwrap_or_else(|| UNKNOWN_SCENE.to_string());
+
+    // Forget entities that have been despawned since the last instance was recorded
+    origins
+        .origins
+        .retain(|&entity, _| entities.contains(entity));
+
+    for entity in scene_spawner.iter_instance_entities(ready.instance_id) {
+        origins.origins.insert(
+            entity,
+            SceneOrigin {
+                scene: scene.clone(),
+                root: ready.entity,
+            },
+        );
+    }
+    cache.stale = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenes_are_sorted_and_deduplicated() {
+        let root = Entity::PLACEHOLDER;
+        let mut origins = SceneOrigins::default();
+        for (index, scene) in ["levels/b.scn.ron", "levels/a.scn.ron", "levels/b.scn.ron"]
+            .into_iter()
+            .enumerate()
+        {
+            origins.origins.insert(
+                Entity::from_raw_u32(index as u32 + 1).unwrap(),
+                SceneOrigin {
+                    scene: scene.to_string(),
+                    root,
+                },
+            );
+        }
+        assert_eq!(
+            origins.scenes(),
+            vec!["levels/a.scn.ron", "levels/b.scn.ron"]
+        );
+    }
+}
@@ -0,0 +1,499 @@
+//! Validation of RON scenes and components, reporting every error instead of only the first.
+//!
+//! Bevy's scene deserializer stops at the first problem. [`validate_scene_ron`] instead checks
+//! each resource and component of a scene on its own, so one report lists everything that
+//! is wrong, with the line, entity and type of each error.
+//! Reports are collected in [`ValidationReports`] and shown in the inspector's Validation tab.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::scene::serde::SceneDeserializer;
+use ron::error::{SpannedError, SpannedResult};
+use ron::value::RawValue;
+use serde::de::{DeserializeSeed, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer as _};
+
+/// A single problem found while validating RON data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Line and column of the error (both starting at 1), if known.
+    pub position: Option<(usize, usize)>,
+    /// Key of the scene entity the error belongs to, if any.
+    pub entity: Option<u64>,
+    /// Type path of the component or resource the error belongs to, if any.
+    pub type_path: Option<String>,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((line, column)) = self.position {
+            write!(f, "{}:{}: ", line, column)?;
+        }
+        if let Some(entity) = self.entity {
+            write!(f, "entity {}: ", entity)?;
+        }
+        if let Some(type_path) = &self.type_path {
+            write!(f, "{}: ", ShortName(type_path))?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// The errors found in one piece of RON data, such as a scene file.
+#[derive(Clone, Debug)]
+pub struct ValidationReport {
+    /// Where the data came from, e.g. an asset path.
+    pub source: String,
+    /// All errors found in the data.
+    pub errors: Vec<ValidationError>,
+}
+
+/// The most recent validation reports with errors, newest last.
+#[derive(Resource, Debug)]
+pub struct ValidationReports {
+    /// Maximum number of reports kept.
+    pub max_reports: usize,
+    reports: VecDeque<ValidationReport>,
+}
+
+impl Default for ValidationReports {
+    fn default() -> Self {
+        Self {
+            max_reports: 16,
+            reports: VecDeque::new(),
+        }
+    }
+}
+
+impl ValidationReports {
+    /// Adds a report, dropping the oldest one if there are more than [`Self::max_reports`].
+    pub fn push(&mut self, report: ValidationReport) {
+        self.reports.push_back(report);
+        while self.reports.len() > self.max_reports {
+            self.reports.pop_front();
+        }
+    }
+
+    /// Returns the stored reports, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &ValidationReport> {
+        self.reports.iter()
+    }
+
+    /// Removes all reports.
+    pub fn clear(&mut self) {
+        self.reports.clear();
+    }
+}
+
+/// Which part of a scene a [`SceneEntry`] belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SceneSection {
+    Resources,
+    Components,
+}
+
+/// A resource or component in a scene: its type path and the RON text of its value.
+#[derive(Debug)]
+struct SceneEntry<'a> {
+    section: SceneSection,
+    entity: Option<u64>,
+    type_path: String,
+    value: &'a str,
+    /// Byte offset of `value` in the scene.
+    offset: usize,
+}
+
+/// Collects the entries of a RON map or struct in their order, leaving their values unparsed.
+struct RawEntriesVisitor<K>(PhantomData<K>);
+
+impl<'a, K: Deserialize<'a>> Visitor<'a> for RawEntriesVisitor<K> {
+    type Value = Vec<(K, &'a str)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map or struct")
+    }
+
+    fn visit_map<A: MapAccess<'a>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some((key, value)) = map.next_entry::<K, &'a RawValue>()? {
+            entries.push((key, value.trim().get_ron()));
+        }
+        Ok(entries)
+    }
+}
+
+/// Parses the entries of the RON map, or struct if `as_struct` is set, in `ron`.
+///
+/// The values are slices of `ron`, so their position in the scene is known.
+fn raw_entries<'a, K: Deserialize<'a>>(
+    ron: &'a str,
+    as_struct: bool,
+) -> SpannedResult<Vec<(K, &'a str)>> {
+    let mut deserializer = ron::de::Deserializer::from_str(ron)?;
+    let visitor = RawEntriesVisitor(PhantomData);
+    let entries = if as_struct {
+        deserializer.deserialize_struct("", &[], visitor)
+    } else {
+        deserializer.deserialize_map(visitor)
+    };
+    entries
+        .and_then(|entries| deserializer.end().map(|()| entries))
+        .map_err(|code| deserializer.span_error(code))
+}
+
+/// Returns the byte offset of `part`, a slice of `source`, in `source`.
+fn offset_in(source: &str, part: &str) -> usize {
+    part.as_ptr() as usize - source.as_ptr() as usize
+}
+
+/// Finds the resources and components of a scene in the `(resources: {..}, entities: {..})`
+/// format written by [`DynamicScene::serialize`].
+fn scene_entries<'a>(source: &'a str) -> Result<Vec<SceneEntry<'a>>, ValidationError> {
+    let error_in = |part: &str| {
+        let offset = offset_in(source, part);
+        move |spanned: SpannedError| ValidationError {
+            position: Some(scene_position(
+                source,
+                offset,
+                Some((spanned.position.line, spanned.position.col)),
+            )),
+            entity: None,
+            type_path: None,
+            message: spanned.code.to_string(),
+        }
+    };
+    let entry = |section, entity, (type_path, value): (String, &'a str)| SceneEntry {
+        section,
+        entity,
+        type_path,
+        value,
+        offset: offset_in(source, value),
+    };
+
+    let mut entries = Vec::new();
+    for (field, value) in raw_entries::<String>(source, true).map_err(error_in(source))? {
+        match field.as_str() {
+            "resources" => {
+                let resources = raw_entries(value, false).map_err(error_in(value))?;
+                entries.extend(
+                    resources
+                        .into_iter()
+                        .map(|resource| entry(SceneSection::Resources, None, resource)),
+                );
+            }
+            "entities" => {
+                for (entity, value) in raw_entries::<u64>(value, false).map_err(error_in(value))? {
+                    let fields = raw_entries::<String>(value, true).map_err(error_in(value))?;
+                    for (field, value) in fields {
+                        if field != "components" {
+                            continue;
+                        }
+                        let components = raw_entries(value, false).map_err(error_in(value))?;
+                        entries.extend(components.into_iter().map(|component| {
+                            entry(SceneSection::Components, Some(entity), component)
+                        }));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+/// Returns the line and column (both starting at 1) of a byte offset in `source`.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    (line, column)
+}
+
+/// Returns the line and column in `source` of `position`, the line and column of an error
+/// in the part of `source` starting at `offset`, or of that part itself if `None`.
+fn scene_position(source: &str, offset: usize, position: Option<(usize, usize)>) -> (usize, usize) {
+    let (line, column) = line_and_column(source, offset);
+    match position {
+        Some((1, error_column)) => (line, column + error_column - 1),
+        Some((error_line, error_column)) => (line + error_line - 1, error_column),
+        None => (line, column),
+    }
+}
+
+/// Deserializes `ron` as a value of the registered type with the given type path.
+///
+/// Error positions are relative to `ron`.
+pub fn validate_component_ron(
+    registry: &TypeRegistry,
+    type_path: &str,
+    ron: &str,
+) -> Result<Box<dyn PartialReflect>, ValidationError> {
+    let error = |position, message| ValidationError {
+        position,
+        entity: None,
+        type_path: Some(type_path.to_string()),
+        message,
+    };
+
+    let Some(registration) = registry.get_with_type_path(type_path) else {
+        return Err(error(None, "type is not registered".to_string()));
+    };
+    let mut deserializer = ron::de::Deserializer::from_str(ron).map_err(|spanned| {
+        error(
+            Some((spanned.position.line, spanned.position.col)),
+            spanned.code.to_string(),
+        )
+    })?;
+    let value = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(&mut deserializer)
+        .and_then(|value| deserializer.end().map(|()| value))
+        .map_err(|code| {
+            let spanned = deserializer.span_error(code);
+            error(
+                Some((spanned.position.line, spanned.position.col)),
+                spanned.code.to_string(),
+            )
+        })?;
+    Ok(value)
+}
+
+/// Checks every resource and component of a RON scene, returning all errors found.
+///
+/// Syntax errors stop validation, since the rest of the scene can't be read reliably.
+pub fn validate_scene_ron(registry: &TypeRegistry, ron: &str) -> Vec<ValidationError> {
+    if let Err(spanned) = ron::from_str::<ron::Value>(ron) {
+        return vec![ValidationError {
+            position: Some((spanned.position.line, spanned.position.col)),
+            entity: None,
+            type_path: None,
+            message: spanned.code.to_string(),
+        }];
+    }
+
+    let entries = match scene_entries(ron) {
+        Ok(entries) => entries,
+        Err(error) => return vec![error],
+    };
+    let mut errors = Vec::new();
+    for entry in entries {
+        let mut error = match validate_component_ron(registry, &entry.type_path, entry.value) {
+            Ok(_) => {
+                // Scenes can only hold types that reflect `Component` or `Resource`
+                let registration = registry.get_with_type_path(&entry.type_path);
+                let message = match entry.section {
+                    SceneSection::Components
+                        if registration
+                            .and_then(|r| r.data::<ReflectComponent>())
+                            .is_none() =>
+                    {
+                        "type does not reflect `Component`, add `#[reflect(Component)]`"
+                    }
+                    SceneSection::Resources
+                        if registration
+                            .and_then(|r| r.data::<ReflectResource>())
+                            .is_none() =>
+                    {
+                        "type does not reflect `Resource`, add `#[reflect(Resource)]`"
+                    }
+                    _ => continue,
+                };
+                ValidationError {
+                    position: None,
+                    entity: None,
+                    type_path: Some(entry.type_path.clone()),
+                    message: message.to_string(),
+                }
+            }
+            Err(error) => error,
+        };
+
+        // Make positions relative to the whole scene
+        error.position = Some(scene_position(ron, entry.offset, error.position));
+        error.entity = entry.entity;
+        errors.push(error);
+    }
+    errors
+}
+
+/// Validates a RON scene and spawns it into `world` if it has no errors.
+///
+/// On failure, the report is also added to [`ValidationReports`] (if present),
+/// so it shows up in the inspector.
+pub fn import_scene_ron(
+    world: &mut World,
+    source: impl Into<String>,
+    ron: &str,
+) -> Result<EntityHashMap<Entity>, ValidationReport> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let result = {
+        let registry = registry.read();
+        let errors = validate_scene_ron(&registry, ron);
+        if errors.is_empty() {
+            ron::de::Deserializer::from_str(ron)
+                .map_err(|spanned| spanned.to_string())
+                .and_then(|mut deserializer| {
+                    SceneDeserializer {
+                        type_registry: &registry,
+                    }
+                    .deserialize(&mut deserializer)
+                    .map_err(|error| error.to_string())
+                })
+                .map_err(|message| vec![message_error(message)])
+        } else {
+            Err(errors)
+        }
+    };
+
+    let errors = match result {
+        Ok(scene) => {
+            let mut entity_map = EntityHashMap::default();
+            match scene.write_to_world(world, &mut entity_map) {
+                Ok(()) => return Ok(entity_map),
+                Err(error) => vec![message_error(error.to_string())],
+            }
+        }
+        Err(errors) => errors,
+    };
+
+    let report = ValidationReport {
+        source: source.into(),
+        errors,
+    };
+    if let Some(mut reports) = world.get_resource_mut::<ValidationReports>() {
+        reports.push(report.clone());
+    }
+    Err(report)
+}
+
+fn message_error(message: String) -> ValidationError {
+    ValidationError {
+        position: None,
+        entity: None,
+        type_path: None,
+        message,
+    }
+}
+
+/// Records scenes that failed to load as validation reports,
+/// so load errors are visible in the inspector rather than only in the log.
+pub(crate) fn record_scene_load_failures(
+    mut failures: MessageReader<AssetLoadFailedEvent<DynamicScene>>,
+    mut reports: ResMut<ValidationReports>,
+) {
+    for failure in failures.read() {
+        reports.push(ValidationReport {
+            source: failure.path.to_string(),
+            errors: vec![message_error(failure.error.to_string())],
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+    }
+
+    #[derive(Reflect, Default)]
+    struct NotAComponent;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Label {
+        text: String,
+        quote: char,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry.register::<NotAComponent>();
+        registry.register::<Label>();
+        registry
+    }
+
+    #[test]
+    fn every_invalid_component_is_reported() {
+        let health = core::any::type_name::<Health>();
+        let not_a_component = core::any::type_name::<NotAComponent>();
+        let scene = format!(
+            r#"(
+  resources: {{}},
+  entities: {{
+    1: (
+      components: {{
+        "{health}": (current: 5),
+      }},
+    ),
+    2: (
+      components: {{
+        "{health}": (current: -1),
+        "game::Missing": (),
+        "{not_a_component}": (),
+      }},
+    ),
+  }},
+)"#
+        );
+
+        let errors = validate_scene_ron(&registry(), &scene);
+        let summary: Vec<_> = errors
+            .iter()
+            .map(|e| (e.position.map(|p| p.0), e.entity, e.type_path.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some(11), Some(2), Some(health)),
+                (Some(12), Some(2), Some("game::Missing")),
+                (Some(13), Some(2), Some(not_a_component)),
+            ]
+        );
+        // Points just past the invalid `-1`
+        assert_eq!(errors[0].position, Some((11, 25 + health.len())));
+    }
+
+    #[test]
+    fn quotes_in_chars_and_raw_strings_are_skipped() {
+        let health = core::any::type_name::<Health>();
+        let label = core::any::type_name::<Label>();
+        let scene = format!(
+            r##"(
+  resources: {{}},
+  entities: {{
+    1: (
+      components: {{
+        "{label}": (text: r#"say "hi", (then) }}"#, quote: '"'),
+        r#"{health}"#: (current: -1),
+      }},
+    ),
+  }},
+)"##
+        );
+
+        let errors = validate_scene_ron(&registry(), &scene);
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert_eq!(errors[0].type_path.as_deref(), Some(health));
+        assert_eq!(errors[0].position, Some((7, 28 + health.len())));
+    }
+
+    #[test]
+    fn syntax_errors_stop_validation() {
+        let errors = validate_scene_ron(&registry(), "(resources: {}, entities: {,})");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].position.map(|p| p.0), Some(1));
+    }
+}
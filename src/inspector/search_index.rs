@@ -0,0 +1,259 @@
+//! Index of entity names and component names, for searching without walking the world.
+//!
+//! [`SearchIndex`] is updated incrementally from [`Name`] changes and newly registered components,
+//! so the entity list only inspects the entities whose name matches the search,
+//! instead of inspecting and formatting every entity on each keystroke.
+//! Unnamed entities are labelled by their archetype, so their labels are indexed per archetype.
+//!
+//! Substring searches of three or more characters look names up by their trigrams,
+//! so their cost depends on the number of matching names rather than on all names.
+
+use bevy::ecs::archetype::{ArchetypeId, Archetypes};
+use bevy::ecs::component::{ComponentId, Components};
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+use crate::component_inspection::ComponentMetadataMap;
+use crate::entity_name_resolution::generated_name;
+
+/// The overlapping three-character windows of `text`.
+fn trigrams(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .windows(3)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Entities by name, unnamed entities by generated name, and component ids by short type name,
+/// all matched case-insensitively.
+#[derive(Resource, Default, Debug)]
+pub struct SearchIndex {
+    /// Entities by lowercased name.
+    names: HashMap<String, EntityHashSet>,
+    /// The lowercased name each entity is indexed under.
+    entity_names: EntityHashMap<String>,
+    /// Lowercased names by the trigrams they contain.
+    trigrams: HashMap<String, HashSet<String>>,
+    /// Component ids by lowercased short type name. Different types may share a short name.
+    components: HashMap<String, Vec<ComponentId>>,
+    /// The number of registered components already indexed.
+    indexed_components: usize,
+    /// Archetypes without a [`Name`] by the lowercased name generated for their entities.
+    archetype_labels: HashMap<String, Vec<ArchetypeId>>,
+    /// Archetypes without a [`Name`] or a name-defining component, whose entities are labelled
+    /// by their id.
+    id_labelled_archetypes: Vec<ArchetypeId>,
+    /// The number of archetypes already indexed.
+    indexed_archetypes: usize,
+}
+
+impl SearchIndex {
+    /// Indexes `entity` under `name`, replacing its previous name.
+    pub fn insert_name(&mut self, entity: Entity, name: &str) {
+        self.remove_entity(entity);
+        let name = name.to_lowercase();
+        let entities = self.names.entry(name.clone()).or_default();
+        if entities.is_empty() {
+            for trigram in trigrams(&name) {
+                self.trigrams
+                    .entry(trigram)
+                    .or_default()
+                    .insert(name.clone());
+            }
+        }
+        entities.insert(entity);
+        self.entity_names.insert(entity, name);
+    }
+
+    /// Removes `entity` from the index, e.g. when it was despawned or lost its name.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        let Some(name) = self.entity_names.remove(&entity) else {
+            return;
+        };
+        let Some(entities) = self.names.get_mut(&name) else {
+            return;
+        };
+        entities.remove(&entity);
+        if !entities.is_empty() {
+            return;
+        }
+        self.names.remove(&name);
+        for trigram in trigrams(&name) {
+            if let Some(names) = self.trigrams.get_mut(&trigram) {
+                names.remove(&name);
+                if names.is_empty() {
+                    self.trigrams.remove(&trigram);
+                }
+            }
+        }
+    }
+
+    /// The names containing `search`, ignoring case.
+    fn names_containing(&self, search: &str) -> Vec<&String> {
+        let search = search.to_lowercase();
+        // The names containing every trigram of the search are the only candidates;
+        // start from the trigram with the fewest names
+        let candidates = trigrams(&search)
+            .into_iter()
+            .map(|trigram| self.trigrams.get(&trigram))
+            .min_by_key(|names| names.map_or(0, |names| names.len()));
+        match candidates {
+            Some(None) => Vec::new(),
+            Some(Some(names)) => names.iter().filter(|name| name.contains(&search)).collect(),
+            None => self
+                .names
+                .keys()
+                .filter(|name| name.contains(&search))
+                .collect(),
+        }
+    }
+
+    /// The entities whose name contains `search`, ignoring case.
+    pub fn entities_matching(&self, search: &str) -> EntityHashSet {
+        self.names_containing(search)
+            .into_iter()
+            .filter_map(|name| self.names.get(name))
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// The unnamed entities whose generated name contains `search`, ignoring case.
+    ///
+    /// Only finds entities in the archetypes indexed by [`Self::index_archetypes`].
+    pub fn unnamed_entities_matching(&self, search: &str, archetypes: &Archetypes) -> Vec<Entity> {
+        let search = search.to_lowercase();
+        self.archetype_labels
+            .iter()
+            .filter(|(label, _)| label.contains(&search))
+            .flat_map(|(_, ids)| ids)
+            .filter_map(|&id| archetypes.get(id))
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+            .collect()
+    }
+
+    /// The unnamed entities without a name-defining component, which are labelled by their id.
+    pub fn id_labelled_entities<'a>(
+        &'a self,
+        archetypes: &'a Archetypes,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        self.id_labelled_archetypes
+            .iter()
+            .filter_map(|&id| archetypes.get(id))
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+    }
+
+    /// Indexes the labels of unnamed entities in the archetypes created since the last call.
+    ///
+    /// Archetypes never change their components, so an entity gaining or losing components
+    /// moves to an archetype that is labelled already, or is indexed by the next call.
+    pub fn index_archetypes(
+        &mut self,
+        archetypes: &Archetypes,
+        components: &Components,
+        metadata_map: &ComponentMetadataMap,
+    ) {
+        let name = components.component_id::<Name>();
+        for archetype in archetypes.iter().skip(self.indexed_archetypes) {
+            self.indexed_archetypes += 1;
+            if name.is_some_and(|name| archetype.contains(name)) {
+                continue;
+            }
+            match generated_name(archetype.components().iter().copied(), metadata_map) {
+                Some(label) => self
+                    .archetype_labels
+                    .entry(label.to_string().to_lowercase())
+                    .or_default()
+                    .push(archetype.id()),
+                None => self.id_labelled_archetypes.push(archetype.id()),
+            }
+        }
+    }
+
+    /// The components whose short type name is `name`, ignoring case.
+    pub fn components_named(&self, name: &str) -> &[ComponentId] {
+        self.components
+            .get(&name.to_lowercase())
+            .map_or(&[], |ids| ids.as_slice())
+    }
+
+    /// The components whose short type name contains `search`, ignoring case.
+    pub fn components_matching(&self, search: &str) -> Vec<ComponentId> {
+        let search = search.to_lowercase();
+        self.components
+            .iter()
+            .filter(|(name, _)| name.contains(&search))
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect()
+    }
+
+    /// Indexes the components registered since the last call.
+    pub fn index_components(&mut self, components: &Components) {
+        for info in components.iter_registered().skip(self.indexed_components) {
+            let name = info.name().shortname().to_string().to_lowercase();
+            self.components.entry(name).or_default().push(info.id());
+            self.indexed_components += 1;
+        }
+    }
+}
+
+/// Keeps the [`SearchIndex`] up to date with renamed, unnamed and despawned entities,
+/// and with newly registered components.
+pub(crate) fn update_search_index(
+    mut index: ResMut<SearchIndex>,
+    renamed: Query<(Entity, &Name), Changed<Name>>,
+    mut unnamed: RemovedComponents<Name>,
+    components: &Components,
+) {
+    for entity in unnamed.read() {
+        index.remove_entity(entity);
+    }
+    for (entity, name) in &renamed {
+        index.insert_name(entity, name.as_str());
+    }
+    index.index_components(components);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn index_follows_renames_and_despawns() {
+        let mut world = World::new();
+        world.init_resource::<SearchIndex>();
+        let goblin = world.spawn(Name::new("Goblin Archer")).id();
+        let orc = world.spawn(Name::new("Orc Archer")).id();
+        let camera = world
+            .spawn((Name::new("Camera"), Transform::default()))
+            .id();
+        world.run_system_once(update_search_index).unwrap();
+
+        let index = world.resource::<SearchIndex>();
+        assert_eq!(index.entities_matching("ARCHER").len(), 2);
+        assert_eq!(
+            index.entities_matching("ob"),
+            EntityHashSet::from_iter([goblin])
+        );
+        assert!(index.entities_matching("dragon").is_empty());
+        let transform = world.components().component_id::<Transform>().unwrap();
+        assert_eq!(index.components_named("transform"), [transform]);
+
+        world.entity_mut(orc).insert(Name::new("Orc Shaman"));
+        world.despawn(goblin);
+        world.entity_mut(camera).remove::<Name>();
+        world.run_system_once(update_search_index).unwrap();
+
+        let index = world.resource::<SearchIndex>();
+        assert!(index.entities_matching("archer").is_empty());
+        assert_eq!(
+            index.entities_matching("shaman"),
+            EntityHashSet::from_iter([orc])
+        );
+        assert!(index.entities_matching("camera").is_empty());
+    }
+}
@@ -0,0 +1,746 @@
+//! Keeps the inspector selection locked onto logical objects.
+//!
+//! Entities that are despawned and respawned (e.g. pooled entities) come back
+//! with a new generation, which invalidates the selection.
+//! The selection is therefore remembered by its [`Name`] path as well,
+//! so that it can be re-acquired once an entity with the same path appears.
+//!
+//! Alternatively, follow mode keeps the selection on whichever entity
+//! best matches a configured name pattern,
+//! and [`InspectorConfig::auto_select_spawned`] moves it to each newly spawned entity.
+//!
+//! While the inspector window is focused, the arrow keys move the selection through
+//! the hierarchy: Left/Right to the parent/first child, Up/Down among siblings.
+//!
+//! Game code and other tools read and drive the selection through [`InspectorSelection`],
+//! and are notified of changes with [`SelectionChanged`].
+//! External tools such as scene editors, which may not know entity ids,
+//! can push selections as [`SelectionRequest`] messages and read [`SelectionChanged`] messages.
+//! Game systems can select an entity the moment one of their assertions fails
+//! with [`CommandsSelectionExt`] or the [`debug_select!`](crate::debug_select) macro.
+
+use core::any::TypeId;
+use core::panic::Location;
+use core::time::Duration;
+
+use bevy::ecs::message::MessageCursor;
+use bevy::ecs::query::{SpawnDetails, Spawned};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+
+use crate::fuzzy_name_mapping::fuzzy_match_score;
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::exclusion::EntityExclusions;
+use crate::inspector::state::{
+    InspectorCache, InspectorInternal, InspectorState, InspectorWindowState,
+};
+use crate::inspector::strings::InspectorStrings;
+
+/// The entity selected in the inspector, for use by game code and other tools.
+///
+/// Reflects selections made in the inspector, and selecting an entity here
+/// shows it in the inspector. Both are picked up once per frame, in [`InspectorSet::Input`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::InspectorSelection;
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn inspect_new_player(
+///     player: Single<Entity, Added<Player>>,
+///     mut selection: ResMut<InspectorSelection>,
+/// ) {
+///     selection.select(*player);
+/// }
+/// ```
+///
+/// [`InspectorSet::Input`]: crate::inspector::InspectorSet::Input
+#[derive(Resource, Default, Debug)]
+pub struct InspectorSelection {
+    entity: Option<Entity>,
+    /// The selection as of the last sync with [`InspectorState`].
+    synced: Option<Entity>,
+}
+
+impl InspectorSelection {
+    /// Returns the selected entity, if any.
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Selects `entity` in the inspector.
+    pub fn select(&mut self, entity: Entity) {
+        self.entity = Some(entity);
+    }
+
+    /// Clears the inspector selection.
+    pub fn clear(&mut self) {
+        self.entity = None;
+    }
+}
+
+/// Event triggered when the inspector selection changes,
+/// whether from the inspector UI, through [`InspectorSelection`] or by a [`SelectionRequest`].
+///
+/// Also written as a message, for tools that poll for changes instead of observing them.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::SelectionChanged;
+/// # let mut app = App::new();
+/// app.add_observer(|changed: On<SelectionChanged>| {
+///     info!("Inspecting {:?} instead of {:?}", changed.current, changed.previous);
+/// });
+/// ```
+#[derive(Event, Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectionChanged {
+    /// The previously selected entity.
+    pub previous: Option<Entity>,
+    /// The newly selected entity.
+    pub current: Option<Entity>,
+}
+
+/// Message asking the inspector to change its selection, e.g. sent by a bridge to a scene editor.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::SelectionRequest;
+/// fn select_from_editor(mut requests: MessageWriter<SelectionRequest>) {
+///     requests.write(SelectionRequest::Path("Level/Enemies/Goblin".to_string()));
+/// }
+/// ```
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionRequest {
+    /// Selects the given entity.
+    Entity(Entity),
+    /// Selects the entity with the given [`Name`] path, see [`entity_name_path`].
+    /// Ignored if no such entity exists.
+    Path(String),
+    /// Clears the selection.
+    Clear,
+}
+
+/// Exclusive system that applies [`SelectionRequest`]s to [`InspectorSelection`].
+///
+/// If several requests arrive in the same frame, the last one that resolves wins.
+pub fn apply_selection_requests(
+    world: &mut World,
+    mut cursor: Local<MessageCursor<SelectionRequest>>,
+) {
+    let Some(messages) = world.get_resource::<Messages<SelectionRequest>>() else {
+        return;
+    };
+    let requests: Vec<SelectionRequest> = cursor.read(messages).cloned().collect();
+
+    for request in requests {
+        let target = match request {
+            SelectionRequest::Entity(entity) => Some(entity),
+            SelectionRequest::Path(path) => match find_entity_by_name_path(world, &path) {
+                Some(entity) => Some(entity),
+                None => continue,
+            },
+            SelectionRequest::Clear => None,
+        };
+        let mut selection = world.resource_mut::<InspectorSelection>();
+        match target {
+            Some(entity) => selection.select(entity),
+            None => selection.clear(),
+        }
+    }
+}
+
+/// Adds methods for [`Commands`] that select an entity in the inspector from game code,
+/// pairing a runtime assertion with immediate inspection of the offending entity.
+///
+/// The call site is logged, so several assertions can be told apart.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::CommandsSelectionExt;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn check_health(healths: Query<(Entity, &Health)>, mut commands: Commands) {
+///     for (entity, health) in &healths {
+///         if health.0 < 0.0 {
+///             commands.debug_select_and_pause(entity);
+///         }
+///     }
+/// }
+/// ```
+pub trait CommandsSelectionExt {
+    /// Selects `entity` in the inspector.
+    fn debug_select(&mut self, entity: Entity);
+
+    /// Selects `entity` in the inspector and pauses [`Time<Virtual>`],
+    /// so the entity can be inspected in the state that failed the assertion.
+    fn debug_select_and_pause(&mut self, entity: Entity);
+}
+
+impl CommandsSelectionExt for Commands<'_, '_> {
+    #[track_caller]
+    fn debug_select(&mut self, entity: Entity) {
+        let location = Location::caller();
+        self.queue(move |world: &mut World| debug_select(world, entity, false, location));
+    }
+
+    #[track_caller]
+    fn debug_select_and_pause(&mut self, entity: Entity) {
+        let location = Location::caller();
+        self.queue(move |world: &mut World| debug_select(world, entity, true, location));
+    }
+}
+
+/// Selects `entity`, pausing virtual time if `pause` is set, and logs where it was asked from.
+fn debug_select(world: &mut World, entity: Entity, pause: bool, location: &Location<'static>) {
+    let Some(mut selection) = world.get_resource_mut::<InspectorSelection>() else {
+        warn!(
+            "debug_select at {}: the inspector plugin is not added",
+            location
+        );
+        return;
+    };
+    selection.select(entity);
+    if pause && let Some(mut time) = world.get_resource_mut::<Time<Virtual>>() {
+        time.pause();
+    }
+    info!(
+        "debug_select at {}: inspecting {}{}",
+        location,
+        entity,
+        if pause { ", virtual time paused" } else { "" }
+    );
+}
+
+/// Selects an entity in the inspector from a system, e.g. when a game assertion fails,
+/// see [`CommandsSelectionExt`].
+///
+/// Add `pause` after the entity to also pause virtual time.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::debug_select;
+/// fn check_positions(transforms: Query<(Entity, &Transform)>, mut commands: Commands) {
+///     for (entity, transform) in &transforms {
+///         if !transform.translation.is_finite() {
+///             debug_select!(commands, entity, pause);
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! debug_select {
+    ($commands:expr, $entity:expr) => {
+        $crate::inspector::CommandsSelectionExt::debug_select(&mut $commands, $entity)
+    };
+    ($commands:expr, $entity:expr, pause) => {
+        $crate::inspector::CommandsSelectionExt::debug_select_and_pause(&mut $commands, $entity)
+    };
+}
+
+/// Synchronizes [`InspectorSelection`] with the inspector's own selection,
+/// and triggers [`SelectionChanged`] when either side changed it.
+///
+/// Selections made through [`InspectorSelection`] win if both sides changed in the same frame.
+pub fn sync_inspector_selection(
+    mut state: ResMut<InspectorState>,
+    mut selection: ResMut<InspectorSelection>,
+    mut changes: MessageWriter<SelectionChanged>,
+    mut commands: Commands,
+) {
+    let previous = selection.synced;
+    if selection.entity != previous {
+        // Selected by game code
+        if state.selected_entity != selection.entity {
+            state.selected_entity = selection.entity;
+            state.selected_entities.clear();
+        }
+    } else if state.selected_entity != previous {
+        // Selected in the inspector
+        selection.entity = state.selected_entity;
+    } else {
+        return;
+    }
+
+    let current = selection.entity;
+    selection.synced = current;
+    changes.write(SelectionChanged { previous, current });
+    commands.trigger(SelectionChanged { previous, current });
+}
+
+/// Placeholder used for unnamed ancestors in a name path.
+const UNNAMED_SEGMENT: &str = "<unnamed>";
+
+/// Builds the [`Name`] path of `entity`, from its root ancestor down to itself,
+/// e.g. `"Level/Enemies/Goblin"`.
+///
+/// Unnamed ancestors are written as `<unnamed>`.
+/// Returns `None` if the entity does not exist or has no [`Name`] itself,
+/// since unnamed entities cannot be told apart by path.
+pub fn entity_name_path(world: &World, entity: Entity) -> Option<String> {
+    let name = world.get_entity(entity).ok()?.get::<Name>()?;
+
+    let mut segments = vec![name.as_str()];
+    let mut current = entity;
+    while let Some(child_of) = world.get::<ChildOf>(current) {
+        current = child_of.parent();
+        segments.push(
+            world
+                .get::<Name>(current)
+                .map(Name::as_str)
+                .unwrap_or(UNNAMED_SEGMENT),
+        );
+    }
+
+    segments.reverse();
+    Some(segments.join("/"))
+}
+
+/// Finds a non-internal entity whose [`Name`] path, see [`entity_name_path`], is `path`.
+pub fn find_entity_by_name_path(world: &mut World, path: &str) -> Option<Entity> {
+    let mut query = world.query_filtered::<Entity, (With<Name>, Without<InspectorInternal>)>();
+    query
+        .iter(world)
+        .find(|&entity| entity_name_path(world, entity).as_deref() == Some(path))
+}
+
+/// How long the notice explaining a moved selection is shown.
+const SELECTION_NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// Exclusive system that remembers the name path and parent of the selected entity,
+/// and moves the selection once it is despawned.
+///
+/// With [`InspectorConfig::reacquire_selection_by_path`] enabled, an entity with the same path
+/// is selected, as soon as one exists. Otherwise the selection moves to the despawned entity's
+/// parent if it is still alive, or is cleared, with a notice in the detail panel.
+pub fn reacquire_selection(world: &mut World) {
+    let state = world.resource::<InspectorState>();
+    let Some(selected) = state.selected_entity else {
+        return;
+    };
+
+    // Keep the alias up to date while the selection is alive, as names may change
+    if world.entities().contains(selected) {
+        let path = entity_name_path(world, selected);
+        let parent = world.get::<ChildOf>(selected).map(ChildOf::parent);
+        if state.selection_alias != path || state.selection_parent != parent {
+            let mut state = world.resource_mut::<InspectorState>();
+            state.selection_alias = path;
+            state.selection_parent = parent;
+        }
+        return;
+    }
+
+    let alias = state.selection_alias.clone();
+    if world
+        .resource::<InspectorConfig>()
+        .reacquire_selection_by_path
+        && let Some(alias) = alias.clone()
+    {
+        if let Some(replacement) = find_entity_by_name_path(world, &alias) {
+            world.resource_mut::<InspectorState>().selected_entity = Some(replacement);
+        }
+        // Otherwise wait for the entity to come back, e.g. when it is pooled
+        return;
+    }
+
+    let parent = state
+        .selection_parent
+        .filter(|&parent| world.entities().contains(parent));
+    let alive: Vec<Entity> = state
+        .selected_entities
+        .iter()
+        .copied()
+        .filter(|&entity| world.entities().contains(entity))
+        .collect();
+    let name = alias.unwrap_or_else(|| selected.to_string());
+    let strings = world.resource::<InspectorStrings>();
+    let notice = match parent {
+        Some(parent) => format!(
+            "{} {}, {} {}",
+            name, strings.was_despawned, strings.selected_parent, parent
+        ),
+        None => format!("{} {}", name, strings.was_despawned),
+    };
+    let mut state = world.resource_mut::<InspectorState>();
+    state.selected_entity = parent;
+    state.selected_entities = alive;
+    state.selection_parent = None;
+    state.selection_notice = Some(notice);
+    // Drop the despawned entity's row
+    world.resource_mut::<InspectorCache>().stale = true;
+}
+
+/// Clears [`InspectorState::selection_notice`] after a few seconds.
+pub fn expire_selection_notice(
+    time: Res<Time<Real>>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+    mut shown_for: Local<Duration>,
+) {
+    if state.selection_notice.is_none() {
+        *shown_for = Duration::ZERO;
+        return;
+    }
+    *shown_for += time.delta();
+    if *shown_for >= SELECTION_NOTICE_DURATION {
+        *shown_for = Duration::ZERO;
+        state.selection_notice = None;
+        cache.detail_stale = true;
+    }
+}
+
+/// Returns the marker components of the hidden kinds of entities
+/// that the entity list shows anyway, see [`InspectorState::shown_hidden_kinds`].
+fn shown_hidden_types(state: &InspectorState) -> Vec<TypeId> {
+    state
+        .shown_hidden_kinds
+        .iter()
+        .map(|kind| kind.component_type())
+        .collect()
+}
+
+/// Exclusive system implementing follow mode:
+/// selects the entity whose [`Name`] best matches [`InspectorConfig::follow_name_pattern`],
+/// among the entities the entity list would show, see [`EntityExclusions`].
+///
+/// Ties are broken in favor of the current selection, then the lowest entity index,
+/// so the selection does not flicker between equally good matches.
+pub fn follow_name_pattern(world: &mut World) {
+    let Some(pattern) = world
+        .resource::<InspectorConfig>()
+        .follow_name_pattern
+        .clone()
+    else {
+        return;
+    };
+    let state = world.resource::<InspectorState>();
+    let selected = state.selected_entity;
+    let shown = shown_hidden_types(state);
+
+    let mut query = world.query::<(EntityRef, &Name)>();
+    let exclusions = world.resource::<EntityExclusions>();
+    let best_match = query
+        .iter(world)
+        .filter(|(entity, _)| !exclusions.is_excluded_except(*entity, &shown))
+        .filter_map(|(entity, name)| {
+            let entity = entity.id();
+            let score = fuzzy_match_score(&pattern, name.as_str())?;
+            Some((score, Some(entity) != selected, entity.index(), entity))
+        })
+        .min()
+        .map(|(.., entity)| entity);
+
+    if let Some(entity) = best_match
+        && selected != Some(entity)
+    {
+        world.resource_mut::<InspectorState>().selected_entity = Some(entity);
+    }
+}
+
+/// System implementing [`InspectorConfig::auto_select_spawned`]:
+/// selects the newest entity spawned since the last frame that the entity list would show.
+pub fn auto_select_spawned_entities(
+    config: Res<InspectorConfig>,
+    exclusions: Res<EntityExclusions>,
+    state: Res<InspectorState>,
+    spawned: Query<(EntityRef, SpawnDetails), Spawned>,
+    mut requests: MessageWriter<SelectionRequest>,
+) {
+    if !config.auto_select_spawned {
+        return;
+    }
+    let shown = shown_hidden_types(&state);
+    let newest = spawned
+        .iter()
+        .filter(|(entity, _)| !exclusions.is_excluded_except(*entity, &shown))
+        .max_by_key(|(entity, details)| (details.spawn_tick().get(), entity.id().index()))
+        .map(|(entity, _)| entity.id());
+    if let Some(entity) = newest
+        && state.selected_entity != Some(entity)
+    {
+        requests.write(SelectionRequest::Entity(entity));
+    }
+}
+
+/// Returns the entity the selection moves to when `key` is pressed with `selected` selected,
+/// or `None` if the key doesn't navigate or there is nothing in that direction.
+pub fn hierarchy_navigation_target(
+    selected: Entity,
+    key: KeyCode,
+    hierarchy: &Query<(Option<&ChildOf>, Option<&Children>)>,
+) -> Option<Entity> {
+    let (child_of, children) = hierarchy.get(selected).ok()?;
+    match key {
+        KeyCode::ArrowLeft => child_of.map(ChildOf::parent),
+        KeyCode::ArrowRight => children.and_then(|children| children.first().copied()),
+        KeyCode::ArrowUp | KeyCode::ArrowDown => {
+            let (_, siblings) = hierarchy.get(child_of?.parent()).ok()?;
+            let siblings = siblings?;
+            let index = siblings.iter().position(|sibling| sibling == selected)?;
+            if key == KeyCode::ArrowUp {
+                index.checked_sub(1).map(|index| siblings[index])
+            } else {
+                siblings.get(index + 1).copied()
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Moves the selection through the hierarchy with the arrow keys,
+/// while the inspector window is focused and no widget is capturing keyboard input.
+pub fn navigate_hierarchy_with_keyboard(
+    mut keyboard: MessageReader<KeyboardInput>,
+    window_state: Res<InspectorWindowState>,
+    input_focus: Res<InputFocus>,
+    hierarchy: Query<(Option<&ChildOf>, Option<&Children>)>,
+    mut state: ResMut<InspectorState>,
+) {
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed
+            || Some(input.window) != window_state.window_entity
+            || input_focus.0.is_some()
+        {
+            continue;
+        }
+        let Some(selected) = state.selected_entity else {
+            continue;
+        };
+        if let Some(target) = hierarchy_navigation_target(selected, input.key_code, &hierarchy) {
+            state.selected_entity = Some(target);
+            state.selected_entities.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+
+    #[test]
+    fn name_path_includes_ancestors() {
+        let mut world = World::new();
+        let root = world.spawn(Name::new("Level")).id();
+        let group = world.spawn(ChildOf(root)).id();
+        let leaf = world.spawn((Name::new("Goblin"), ChildOf(group))).id();
+
+        assert_eq!(
+            entity_name_path(&world, leaf).as_deref(),
+            Some("Level/<unnamed>/Goblin")
+        );
+        assert_eq!(entity_name_path(&world, group), None);
+    }
+
+    #[test]
+    fn selection_syncs_both_ways() {
+        #[derive(Resource, Default)]
+        struct Changes(Vec<SelectionChanged>);
+
+        let mut world = World::new();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorSelection>();
+        world.init_resource::<Messages<SelectionChanged>>();
+        world.init_resource::<Changes>();
+        world.add_observer(
+            |changed: On<SelectionChanged>, mut changes: ResMut<Changes>| {
+                changes.0.push(*changed);
+            },
+        );
+        let mut sync = IntoSystem::into_system(sync_inspector_selection);
+        sync.initialize(&mut world);
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+
+        // Selected in the inspector
+        world.resource_mut::<InspectorState>().selected_entity = Some(first);
+        sync.run((), &mut world).unwrap();
+        assert_eq!(world.resource::<InspectorSelection>().entity(), Some(first));
+
+        // Selected by game code
+        world.resource_mut::<InspectorSelection>().select(second);
+        sync.run((), &mut world).unwrap();
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(second)
+        );
+
+        // Unchanged
+        sync.run((), &mut world).unwrap();
+
+        assert_eq!(
+            world.resource::<Changes>().0,
+            [
+                SelectionChanged {
+                    previous: None,
+                    current: Some(first)
+                },
+                SelectionChanged {
+                    previous: Some(first),
+                    current: Some(second)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn despawned_selection_falls_back_to_its_parent() {
+        let mut world = World::new();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<InspectorCache>();
+        world.init_resource::<InspectorStrings>();
+        let parent = world.spawn(Name::new("Level")).id();
+        let child = world.spawn((Name::new("Goblin"), ChildOf(parent))).id();
+        world.resource_mut::<InspectorState>().selected_entity = Some(child);
+        reacquire_selection(&mut world);
+
+        world.despawn(child);
+        reacquire_selection(&mut world);
+        let state = world.resource::<InspectorState>();
+        assert_eq!(state.selected_entity, Some(parent));
+        assert!(
+            state
+                .selection_notice
+                .as_ref()
+                .unwrap()
+                .contains("Level/Goblin")
+        );
+        assert!(world.resource::<InspectorCache>().stale);
+
+        // Without a parent, the selection is cleared
+        world.despawn(parent);
+        reacquire_selection(&mut world);
+        assert_eq!(world.resource::<InspectorState>().selected_entity, None);
+    }
+
+    #[test]
+    fn newest_listed_spawn_is_auto_selected() {
+        let mut world = World::new();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<EntityExclusions>();
+        world.init_resource::<Messages<SelectionRequest>>();
+        let mut auto_select = IntoSystem::into_system(auto_select_spawned_entities);
+        auto_select.initialize(&mut world);
+        let mut requests = |world: &mut World| -> Vec<SelectionRequest> {
+            auto_select.run((), world).unwrap();
+            world
+                .resource_mut::<Messages<SelectionRequest>>()
+                .drain()
+                .collect()
+        };
+        requests(&mut world);
+
+        // Disabled by default
+        world.spawn_empty();
+        assert_eq!(requests(&mut world), []);
+
+        world.resource_mut::<InspectorConfig>().auto_select_spawned = true;
+        assert_eq!(requests(&mut world), []);
+
+        let spawned = world.spawn(Name::new("Bullet")).id();
+        world.spawn(InspectorInternal);
+        world.spawn(Node::default());
+        assert_eq!(requests(&mut world), [SelectionRequest::Entity(spawned)]);
+    }
+
+    #[test]
+    fn follow_mode_skips_hidden_entities() {
+        let mut world = World::new();
+        world.init_resource::<InspectorState>();
+        world.init_resource::<InspectorConfig>();
+        world.init_resource::<EntityExclusions>();
+        world.resource_mut::<InspectorConfig>().follow_name_pattern = Some("Player".to_string());
+        world.spawn((Name::new("Player"), Node::default()));
+        world.spawn((Name::new("Player"), InspectorInternal));
+        let player = world.spawn(Name::new("Player Ship")).id();
+
+        follow_name_pattern(&mut world);
+        assert_eq!(
+            world.resource::<InspectorState>().selected_entity,
+            Some(player)
+        );
+    }
+
+    #[test]
+    fn debug_select_selects_and_pauses() {
+        let mut world = World::new();
+        world.init_resource::<InspectorSelection>();
+        world.init_resource::<Time<Virtual>>();
+        let entity = world.spawn_empty().id();
+
+        debug_select!(world.commands(), entity);
+        world.flush();
+        assert_eq!(
+            world.resource::<InspectorSelection>().entity(),
+            Some(entity)
+        );
+        assert!(!world.resource::<Time<Virtual>>().is_paused());
+
+        world.resource_mut::<InspectorSelection>().clear();
+        debug_select!(world.commands(), entity, pause);
+        world.flush();
+        assert_eq!(
+            world.resource::<InspectorSelection>().entity(),
+            Some(entity)
+        );
+        assert!(world.resource::<Time<Virtual>>().is_paused());
+    }
+
+    #[test]
+    fn requests_select_by_entity_and_path() {
+        let mut world = World::new();
+        world.init_resource::<InspectorSelection>();
+        world.init_resource::<Messages<SelectionRequest>>();
+        let mut apply = IntoSystem::into_system(apply_selection_requests);
+        apply.initialize(&mut world);
+        let level = world.spawn(Name::new("Level")).id();
+        let goblin = world.spawn((Name::new("Goblin"), ChildOf(level))).id();
+
+        world.write_message(SelectionRequest::Entity(level));
+        apply.run((), &mut world).unwrap();
+        assert_eq!(world.resource::<InspectorSelection>().entity(), Some(level));
+
+        // Unresolved paths are ignored
+        world.write_message(SelectionRequest::Path("Level/Goblin".to_string()));
+        world.write_message(SelectionRequest::Path("Level/Orc".to_string()));
+        apply.run((), &mut world).unwrap();
+        assert_eq!(
+            world.resource::<InspectorSelection>().entity(),
+            Some(goblin)
+        );
+
+        world.write_message(SelectionRequest::Clear);
+        apply.run((), &mut world).unwrap();
+        assert_eq!(world.resource::<InspectorSelection>().entity(), None);
+    }
+
+    #[test]
+    fn arrow_keys_navigate_the_hierarchy() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let first = world.spawn(ChildOf(root)).id();
+        let second = world.spawn(ChildOf(root)).id();
+        let grandchild = world.spawn(ChildOf(first)).id();
+
+        let mut system_state =
+            SystemState::<Query<(Option<&ChildOf>, Option<&Children>)>>::new(&mut world);
+        let hierarchy = system_state.get(&world);
+        let target = |selected, key| hierarchy_navigation_target(selected, key, &hierarchy);
+
+        assert_eq!(target(first, KeyCode::ArrowLeft), Some(root));
+        assert_eq!(target(root, KeyCode::ArrowLeft), None);
+        assert_eq!(target(first, KeyCode::ArrowRight), Some(grandchild));
+        assert_eq!(target(second, KeyCode::ArrowRight), None);
+        assert_eq!(target(first, KeyCode::ArrowDown), Some(second));
+        assert_eq!(target(second, KeyCode::ArrowDown), None);
+        assert_eq!(target(second, KeyCode::ArrowUp), Some(first));
+        assert_eq!(target(root, KeyCode::ArrowUp), None);
+    }
+}
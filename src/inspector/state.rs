@@ -1,9 +1,18 @@
 //! Central UI state for the inspector.
 
+use core::any::TypeId;
+use std::collections::HashSet;
+
+use bevy::asset::UntypedAssetId;
 use bevy::ecs::component::ComponentId;
 use bevy::prelude::*;
 
 use crate::component_inspection::ComponentMetadataMap;
+use crate::entity_grouping::GroupingStrategy;
+use crate::inspector::exclusion::HiddenEntityKind;
+use crate::inspector::filter_chips::FilterChip;
+use crate::inspector::panels::{ComponentFilterPicker, EntityWizard};
+use crate::inspector::widgets::FieldPath;
 use crate::memory_size::MemorySize;
 
 /// Marker component for inspector-internal entities that should not appear in the entity list.
@@ -17,16 +26,202 @@ pub struct InspectorInternal;
 pub struct InspectorState {
     /// Currently selected entity for detail view.
     pub selected_entity: Option<Entity>,
+    /// Entities selected together, with Ctrl+click, Shift+click
+    /// or a group header's "Select all" action.
+    ///
+    /// The detail view still shows [`selected_entity`](Self::selected_entity),
+    /// limited to the components all selected entities share,
+    /// and its edits and despawns apply to every selected entity.
+    /// This is cleared whenever a single entity is selected from the list.
+    pub selected_entities: Vec<Entity>,
+    /// Asset selected from an asset handle field, for an assets view.
+    pub selected_asset: Option<UntypedAssetId>,
     /// Active tab in the detail panel.
     pub active_tab: DetailTab,
+    /// Component types whose cards were expanded or collapsed by clicking their header,
+    /// inverting their [`ComponentCardDisplay`](crate::inspector::component_settings::ComponentCardDisplay).
+    pub toggled_component_cards: HashSet<TypeId>,
+    /// Component types whose cards are shown first and always expanded, in the order pinned.
+    pub pinned_component_cards: Vec<TypeId>,
+    /// List and set fields expanded into one row per element in the detail panel.
+    pub expanded_lists: HashSet<FieldPath>,
+    /// Nested struct and enum fields expanded to show their fields in the detail panel.
+    /// They are collapsed by default, and their fields are only read once expanded.
+    pub expanded_structs: HashSet<FieldPath>,
+    /// Matrix fields showing their translation, rotation and scale below their values.
+    pub decomposed_matrices: HashSet<FieldPath>,
+    /// Unsigned integer fields displayed in hexadecimal.
+    pub hex_fields: HashSet<FieldPath>,
+    /// Dangerous components and fields unlocked for editing,
+    /// see [`DangerousFields`](crate::inspector::DangerousFields).
+    /// A path without segments unlocks the whole component.
+    pub unlocked_fields: HashSet<FieldPath>,
+    /// Vector fields whose axes are edited together, keeping their proportions,
+    /// see [`VectorDrag`](crate::inspector::widgets::VectorDrag).
+    pub linked_vectors: HashSet<FieldPath>,
+    /// Whether the detail panel highlights changes since the selected entity's baseline,
+    /// see [`EntityBaselines`](crate::inspector::baseline::EntityBaselines).
+    pub compare_to_baseline: bool,
     /// Current search/filter text for entity list.
     pub filter_text: String,
+    /// How the name part of [`filter_text`](Self::filter_text) is matched against entity names.
+    pub search_mode: SearchMode,
+    /// Fuzzy search text for the Resources tab.
+    pub resource_filter_text: String,
+    /// The resource whose fields the Resources tab shows, if any.
+    pub selected_resource: Option<TypeId>,
     /// Component filter: only show entities with these components.
     pub required_components: Vec<ComponentId>,
+    /// Component filter: only show entities without these components.
+    pub excluded_components: Vec<ComponentId>,
+    /// Quick filters: if any are active, only show entities matching one of them.
+    pub filter_chips: Vec<FilterChip>,
+    /// Kinds of entities that are hidden by default but listed anyway,
+    /// see [`EntityExclusions`](crate::inspector::EntityExclusions).
+    pub shown_hidden_kinds: Vec<HiddenEntityKind>,
+    /// Scene filter: only show entities spawned from the scene with this asset path.
+    pub scene_filter: Option<String>,
+    /// Entities listed first in the entity list, in their own group, in the order pinned.
+    pub pinned_entities: Vec<Entity>,
+    /// [`Name`] paths of pinned entities that don't exist yet, e.g. pinned by a profile
+    /// applied before the game spawned them. They are pinned as soon as they appear.
+    pub pending_pinned_paths: Vec<String>,
+    /// Change filter: only show entities with a component added or changed
+    /// within this many frames, e.g. to find what a misbehaving system is touching.
+    ///
+    /// While it is set, the entity list is refreshed every frame.
+    pub changed_within_frames: Option<u32>,
+    /// How entities are grouped in the entity list.
+    pub grouping_strategy: GroupingStrategy,
     /// Previously selected entity (for change detection).
     pub previous_selection: Option<Entity>,
     /// Previous active tab (for change detection).
     pub previous_tab: DetailTab,
+    /// [`Name`] path of the selected entity, used to re-select it after a respawn.
+    pub selection_alias: Option<String>,
+    /// Parent of the selected entity, selected instead once the selection is despawned.
+    pub selection_parent: Option<Entity>,
+    /// Short-lived message explaining why the selection moved, e.g. after it was despawned.
+    pub selection_notice: Option<String>,
+    /// Protected entity whose despawn button was clicked once and awaits confirmation.
+    pub despawn_confirmation: Option<Entity>,
+    /// Message explaining why the last despawn request for an entity was not carried out.
+    pub despawn_notice: Option<(Entity, String)>,
+    /// Filtered entities previewed by the "Despawn all" action, despawned once it is confirmed.
+    pub bulk_despawn_confirmation: Option<Vec<Entity>>,
+    /// Entities of a confirmed "Despawn all" action whose despawns are queued but not applied.
+    pub bulk_despawn_pending: Vec<Entity>,
+    /// Outcome of the last "Despawn all" action.
+    pub bulk_despawn_notice: Option<String>,
+    /// Fuzzy search text choosing the component added to or removed from every filtered entity.
+    pub bulk_component_search: String,
+    /// Id typed into the "Go to" field of the entity list, e.g. `42v3` or raw entity bits.
+    pub go_to_entity_text: String,
+    /// Why the last id entered in the "Go to" field could not be selected.
+    pub go_to_entity_error: Option<String>,
+    /// Components picked in the "New entity..." dialog, while it is open.
+    pub entity_wizard: Option<EntityWizard>,
+    /// The "Components..." dialog choosing the component filter, while it is open.
+    pub component_filter_picker: Option<ComponentFilterPicker>,
+    /// Whether the profile dropdown in the title bar is open.
+    pub profile_picker_open: bool,
+    /// The numeric field whose tween input is open,
+    /// see [`FieldTween`](crate::inspector::widgets::FieldTween).
+    pub tween_field: Option<FieldPath>,
+}
+
+impl InspectorState {
+    /// The entities an edit of a field of `entity` is written to,
+    /// or an empty slice if `entity` isn't part of a multi-entity selection.
+    pub fn batch_edit_targets(&self, entity: Entity) -> &[Entity] {
+        if self.selected_entities.len() > 1 && self.selected_entities.contains(&entity) {
+            &self.selected_entities
+        } else {
+            &[]
+        }
+    }
+
+    /// The entities an edit of the field at `field_path` is written to, or an empty slice
+    /// for a field of a resource or of an entity outside a multi-entity selection.
+    pub fn field_edit_targets(&self, field_path: &FieldPath) -> &[Entity] {
+        field_path
+            .owner
+            .entity()
+            .map_or(&[], |entity| self.batch_edit_targets(entity))
+    }
+
+    /// Forgets the expanded, toggled and linked fields whose paths `keep` rejects,
+    /// e.g. the fields of despawned entities.
+    pub fn retain_field_paths(&mut self, keep: impl Fn(&FieldPath) -> bool) {
+        for paths in [
+            &mut self.expanded_lists,
+            &mut self.expanded_structs,
+            &mut self.decomposed_matrices,
+            &mut self.hex_fields,
+            &mut self.unlocked_fields,
+            &mut self.linked_vectors,
+        ] {
+            paths.retain(&keep);
+        }
+        if self.tween_field.as_ref().is_some_and(|path| !keep(path)) {
+            self.tween_field = None;
+        }
+    }
+
+    /// Adds `entity` to the multi-entity selection, or removes it if it is already selected,
+    /// as with Ctrl+click.
+    ///
+    /// An added entity becomes the [`selected_entity`](Self::selected_entity) shown in detail.
+    pub fn toggle_selected(&mut self, entity: Entity) {
+        if self.selected_entities.is_empty()
+            && let Some(selected) = self.selected_entity
+        {
+            self.selected_entities.push(selected);
+        }
+        if let Some(index) = self.selected_entities.iter().position(|&e| e == entity) {
+            self.selected_entities.remove(index);
+            if self.selected_entity == Some(entity) {
+                self.selected_entity = self.selected_entities.last().copied();
+            }
+        } else {
+            self.selected_entities.push(entity);
+            self.selected_entity = Some(entity);
+        }
+    }
+
+    /// Selects the entities of `order` from the [`selected_entity`](Self::selected_entity)
+    /// up to `entity`, as with Shift+click.
+    ///
+    /// The selected entity stays the anchor of the range, so a later Shift+click
+    /// resizes the range instead of starting a new one.
+    /// Without a selected entity in `order`, only `entity` is selected.
+    pub fn select_range(&mut self, entity: Entity, order: &[Entity]) {
+        let anchor = self
+            .selected_entity
+            .and_then(|selected| order.iter().position(|&e| e == selected));
+        let (Some(anchor), Some(end)) = (anchor, order.iter().position(|&e| e == entity)) else {
+            self.selected_entity = Some(entity);
+            self.selected_entities.clear();
+            return;
+        };
+        self.selected_entities = order[anchor.min(end)..=anchor.max(end)].to_vec();
+    }
+}
+
+/// How the entity list matches the search text against entity names.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SearchMode {
+    /// Names containing the search text, ignoring case.
+    #[default]
+    Substring,
+    /// Names containing the characters of the search text in order,
+    /// so `plyr cam` matches `Player Camera`, ranked by how closely they match,
+    /// see [`fuzzy_match_score`](crate::fuzzy_name_mapping::fuzzy_match_score).
+    Fuzzy,
+    /// Names matching the search text as a regular expression, e.g. `Chunk_\d+_\d+`.
+    ///
+    /// Matching is case-sensitive unless the pattern starts with `(?i)`.
+    Regex,
 }
 
 /// Active tab in the detail panel.
@@ -35,21 +230,42 @@ pub enum DetailTab {
     #[default]
     Components,
     Relationships,
+    /// Per-component history of additions, changes and removals.
+    Timeline,
+    /// App-wide states, their dependencies and the systems gated on them.
+    States,
+    /// Systems with run conditions and the results of those conditions.
+    Systems,
+    /// The real, virtual and fixed clocks.
+    Time,
+    /// Errors of scenes and components that failed to load or import.
+    Validation,
+    /// All resources in the world, grouped by module.
+    Resources,
+    /// Plain text notes taken during the debugging session.
+    Notes,
 }
 
 /// Cached data for the inspector to avoid recomputation.
 #[derive(Resource, Default)]
 pub struct InspectorCache {
-    /// Cached entity list after filtering.
+    /// Cached entity list after filtering, ordered by group.
     pub filtered_entities: Vec<EntityListEntry>,
+    /// Groups of the filtered entities, according to [`InspectorState::grouping_strategy`].
+    pub groups: Vec<EntityListGroup>,
     /// Cached metadata map (reused across inspections).
     pub metadata_map: Option<ComponentMetadataMap>,
     /// Whether the cache needs to be refreshed.
     pub stale: bool,
+    /// Whether the detail panel needs to be rebuilt even though the selection and tab are unchanged.
+    pub detail_stale: bool,
+    /// Why the query typed into the search box could not be applied, if it could not.
+    pub query_error: Option<String>,
 }
 
 /// Entry for the entity list display.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityListEntry {
     /// The entity.
     pub entity: Entity,
@@ -59,6 +275,75 @@ pub struct EntityListEntry {
     pub component_count: usize,
     /// Total memory size of all components.
     pub memory_size: MemorySize,
+    /// The notable kind of entity this is, e.g. a camera or light, shown as a glyph.
+    pub kind: Option<FilterChip>,
+    /// Whether the entity is hidden, if it has a [`Visibility`].
+    pub hidden: Option<bool>,
+    /// Index of the entity's archetype.
+    pub archetype: usize,
+    /// Number of children of the entity.
+    pub children_count: usize,
+    /// Totals including the entity's descendants in the list, when grouped by hierarchy
+    /// and the entity has descendants.
+    pub rollup: Option<HierarchyRollup>,
+}
+
+/// A column of the entity list rows, see [`InspectorConfig::entity_list_columns`].
+///
+/// [`InspectorConfig::entity_list_columns`]: crate::inspector::InspectorConfig::entity_list_columns
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityListColumn {
+    /// The entity's name, preceded by the glyph of its [`FilterChip`].
+    /// Takes up the width the other columns leave.
+    Name,
+    /// The index of the entity's archetype, e.g. `A12`.
+    Archetype,
+    /// The number of components.
+    ComponentCount,
+    /// The total memory size of the components.
+    Memory,
+    /// The number of children.
+    ChildrenCount,
+    /// The entity id, e.g. `42v3`.
+    EntityId,
+}
+
+impl EntityListColumn {
+    /// Every column, e.g. to show all of them.
+    pub const ALL: [Self; 6] = [
+        Self::Name,
+        Self::Archetype,
+        Self::ComponentCount,
+        Self::Memory,
+        Self::ChildrenCount,
+        Self::EntityId,
+    ];
+}
+
+/// Totals of an entity and all of its descendants, e.g. the full cost of a prefab instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HierarchyRollup {
+    /// Number of descendants, not counting the entity itself.
+    pub descendants: usize,
+    /// Number of components on the entity and its descendants.
+    pub component_count: usize,
+    /// Total memory size of the components of the entity and its descendants.
+    pub memory_size: MemorySize,
+}
+
+/// A group of entities in the entity list.
+#[derive(Clone, Debug)]
+pub struct EntityListGroup {
+    /// Label shown in the group header.
+    pub label: String,
+    /// The entities in this group, in display order.
+    pub entities: Vec<Entity>,
+    /// Total number of components of all entities in the group.
+    pub component_count: usize,
+    /// Total memory size of all entities in the group.
+    pub memory_size: MemorySize,
 }
 
 /// Tracks the state of the inspector window.
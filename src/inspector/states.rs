@@ -0,0 +1,522 @@
+//! Collects information about [`States`] for the States tab.
+//!
+//! Root states, sub-states and computed states are registered with [`InspectStatesAppExt`],
+//! which records their kind and the source states they depend on.
+//! States that were not registered are still discovered from the world's resources,
+//! with their current value available if the state type registers [`ReflectState`].
+//!
+//! The systems gated on each state are found by scanning the [`RunConditions`]
+//! captured from the app's schedules.
+//!
+//! States registered with
+//! [`register_type_mutable_state`](AppExtStates::register_type_mutable_state)
+//! also expose their pending [`NextState`] and can be transitioned to any of their unit variants
+//! with [`queue_state_transition`].
+
+use core::any::type_name;
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::reflect::{
+    DynamicEnum, DynamicVariant, ReflectFromReflect, ReflectRef, TypeInfo, VariantInfo,
+};
+use bevy::state::reflect::{ReflectFreelyMutableState, ReflectState};
+
+use crate::inspector::run_conditions::RunConditions;
+
+/// Type path prefix of the resource holding the current value of a state.
+const STATE_RESOURCE_PREFIX: &str = "bevy_state::state::resources::State<";
+
+/// Type path prefix of the resource holding the pending transition of a state.
+const NEXT_STATE_RESOURCE_PREFIX: &str = "bevy_state::state::resources::NextState<";
+
+/// The kind of a [`States`] type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateKind {
+    /// A state that is set directly through [`NextState`].
+    Root,
+    /// A [`SubStates`] type, which only exists while its source states match.
+    Sub,
+    /// A [`ComputedStates`] type, derived from its source states.
+    Computed,
+    /// A state that was not registered with [`InspectStatesAppExt`], so its kind is unknown.
+    Unregistered,
+}
+
+/// Information about a single [`States`] type.
+#[derive(Clone, Debug)]
+pub struct InspectedState {
+    /// Full type path of the state type.
+    pub type_path: String,
+    /// The kind of state.
+    pub kind: StateKind,
+    /// Type paths of the states this state is derived from.
+    pub sources: Vec<String>,
+    /// Reads the current value of the state, if the state exists.
+    current: Option<fn(&World) -> Option<String>>,
+}
+
+impl InspectedState {
+    fn new<S: States>(kind: StateKind, sources: Vec<String>) -> Self {
+        Self {
+            type_path: type_name::<S>().to_string(),
+            kind,
+            sources,
+            current: Some(|world| {
+                world
+                    .get_resource::<State<S>>()
+                    .map(|state| format!("{:?}", state.get()))
+            }),
+        }
+    }
+
+    /// Returns the current value of the state, formatted with its [`Debug`] implementation.
+    ///
+    /// Returns `None` if the state does not currently exist (e.g. an inactive sub-state)
+    /// or its value cannot be read.
+    pub fn current(&self, world: &World) -> Option<String> {
+        if let Some(current) = self.current {
+            return current(world);
+        }
+
+        // Unregistered states can only be read through reflection
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let registration = registry.get_with_type_path(&self.type_path)?;
+        let value = registration.data::<ReflectState>()?.reflect(world)?;
+        Some(format!("{:?}", value))
+    }
+
+    /// Returns the transition queued in the state's [`NextState`], formatted with its [`Debug`]
+    /// implementation.
+    ///
+    /// Returns `None` if no transition is pending or `NextState` is not registered for reflection.
+    pub fn pending(&self, world: &World) -> Option<String> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get_with_type_path(&format!("{NEXT_STATE_RESOURCE_PREFIX}{}>", self.type_path))?;
+        let next_state = registration
+            .data::<ReflectResource>()?
+            .reflect(world)
+            .ok()?;
+        let ReflectRef::Enum(next_state) = next_state.reflect_ref() else {
+            return None;
+        };
+        next_state.field_at(0).map(|value| format!("{:?}", value))
+    }
+
+    /// Returns the names of the unit variants the state can be transitioned to
+    /// with [`queue_state_transition`].
+    ///
+    /// Empty unless the state is an enum that registers [`ReflectFreelyMutableState`],
+    /// which computed states never do.
+    pub fn variants(&self, world: &World) -> Vec<String> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let Some(registration) = registry.get_with_type_path(&self.type_path) else {
+            return Vec::new();
+        };
+        if registration.data::<ReflectFreelyMutableState>().is_none() {
+            return Vec::new();
+        }
+        let TypeInfo::Enum(info) = registration.type_info() else {
+            return Vec::new();
+        };
+        info.iter()
+            .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+            .map(|variant| variant.name().to_string())
+            .collect()
+    }
+}
+
+/// Queues a transition of the state with the given type path to one of its unit variants,
+/// by writing its [`NextState`] through reflection.
+///
+/// Returns `false` if the state does not register [`ReflectFreelyMutableState`]
+/// or has no unit variant with that name.
+pub fn queue_state_transition(world: &mut World, type_path: &str, variant: &str) -> bool {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let Some(registration) = registry.get_with_type_path(type_path) else {
+        return false;
+    };
+    let (Some(set_next_state), Some(from_reflect)) = (
+        registration.data::<ReflectFreelyMutableState>(),
+        registration.data::<ReflectFromReflect>(),
+    ) else {
+        return false;
+    };
+    let TypeInfo::Enum(info) = registration.type_info() else {
+        return false;
+    };
+    if !matches!(info.variant(variant), Some(VariantInfo::Unit(_))) {
+        return false;
+    }
+
+    let mut value = DynamicEnum::new(variant, DynamicVariant::Unit);
+    value.set_represented_type(Some(registration.type_info()));
+    let Some(value) = from_reflect.from_reflect(&value) else {
+        return false;
+    };
+    set_next_state.set_next_state(world, value.as_ref(), &registry);
+    true
+}
+
+/// The states registered through [`InspectStatesAppExt`].
+#[derive(Resource, Default, Debug)]
+pub struct InspectedStates {
+    states: Vec<InspectedState>,
+}
+
+impl InspectedStates {
+    fn register(&mut self, state: InspectedState) {
+        if !self.states.iter().any(|s| s.type_path == state.type_path) {
+            self.states.push(state);
+        }
+    }
+
+    /// Returns all registered states, followed by any other states found in `world`.
+    ///
+    /// States are ordered so that every state comes after the states it depends on.
+    pub fn collect(&self, world: &World) -> Vec<InspectedState> {
+        let mut states = self.states.clone();
+        for info in world.components().iter_registered() {
+            let name = info.name().to_string();
+            let Some(type_path) = name
+                .strip_prefix(STATE_RESOURCE_PREFIX)
+                .and_then(|rest| rest.strip_suffix('>'))
+            else {
+                continue;
+            };
+            if world.contains_resource_by_id(info.id())
+                && !states.iter().any(|s| s.type_path == type_path)
+            {
+                states.push(InspectedState {
+                    type_path: type_path.to_string(),
+                    kind: StateKind::Unregistered,
+                    sources: Vec::new(),
+                    current: None,
+                });
+            }
+        }
+
+        let depths = dependency_depths(&states);
+        states.sort_by(|a, b| {
+            depths[&a.type_path]
+                .cmp(&depths[&b.type_path])
+                .then_with(|| a.type_path.cmp(&b.type_path))
+        });
+        states
+    }
+}
+
+/// Computes how many layers of source states each state depends on.
+fn dependency_depths(states: &[InspectedState]) -> HashMap<String, usize> {
+    fn depth(
+        type_path: &str,
+        states: &[InspectedState],
+        depths: &mut HashMap<String, usize>,
+        visiting: &mut Vec<String>,
+    ) -> usize {
+        if let Some(&depth) = depths.get(type_path) {
+            return depth;
+        }
+        // Guard against malformed dependency information
+        if visiting.iter().any(|v| v == type_path) {
+            return 0;
+        }
+        visiting.push(type_path.to_string());
+
+        let sources = states
+            .iter()
+            .find(|s| s.type_path == type_path)
+            .map(|s| s.sources.clone())
+            .unwrap_or_default();
+        let result = sources
+            .iter()
+            .map(|source| depth(source, states, depths, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+
+        visiting.pop();
+        depths.insert(type_path.to_string(), result);
+        result
+    }
+
+    let mut depths = HashMap::new();
+    for state in states {
+        depth(&state.type_path, states, &mut depths, &mut Vec::new());
+    }
+    depths
+}
+
+/// Splits the type name of a [`StateSet`](bevy::state::state::StateSet) into the type paths
+/// of its states, unwrapping optional sources.
+///
+/// For example `(game::AppState, core::option::Option<game::Paused>)`
+/// becomes `["game::AppState", "game::Paused"]`.
+fn state_set_type_paths(state_set: &str) -> Vec<String> {
+    let inner = state_set
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(state_set);
+
+    let mut paths = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                paths.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    paths.push(&inner[start..]);
+
+    paths
+        .into_iter()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(|path| {
+            path.strip_prefix("core::option::Option<")
+                .and_then(|rest| rest.strip_suffix('>'))
+                .unwrap_or(path)
+                .to_string()
+        })
+        .collect()
+}
+
+/// Extension methods for registering states with the inspector's States tab.
+///
+/// Registration records the kind of each state and the states it depends on.
+/// It does not add the state to the app; that is still done with
+/// [`init_state`](AppExtStates::init_state), [`add_sub_state`](AppExtStates::add_sub_state)
+/// or [`add_computed_state`](AppExtStates::add_computed_state).
+pub trait InspectStatesAppExt {
+    /// Registers a root state, which is set directly through [`NextState`].
+    fn inspect_state<S: States>(&mut self) -> &mut Self;
+    /// Registers a [`SubStates`] type, along with its source states.
+    fn inspect_sub_state<S: SubStates>(&mut self) -> &mut Self;
+    /// Registers a [`ComputedStates`] type, along with its source states.
+    fn inspect_computed_state<S: ComputedStates>(&mut self) -> &mut Self;
+}
+
+impl InspectStatesAppExt for App {
+    fn inspect_state<S: States>(&mut self) -> &mut Self {
+        self.init_resource::<InspectedStates>();
+        self.world_mut()
+            .resource_mut::<InspectedStates>()
+            .register(InspectedState::new::<S>(StateKind::Root, Vec::new()));
+        self
+    }
+
+    fn inspect_sub_state<S: SubStates>(&mut self) -> &mut Self {
+        let sources = state_set_type_paths(type_name::<S::SourceStates>());
+        self.init_resource::<InspectedStates>();
+        self.world_mut()
+            .resource_mut::<InspectedStates>()
+            .register(InspectedState::new::<S>(StateKind::Sub, sources));
+        self
+    }
+
+    fn inspect_computed_state<S: ComputedStates>(&mut self) -> &mut Self {
+        let sources = state_set_type_paths(type_name::<S::SourceStates>());
+        self.init_resource::<InspectedStates>();
+        self.world_mut()
+            .resource_mut::<InspectedStates>()
+            .register(InspectedState::new::<S>(StateKind::Computed, sources));
+        self
+    }
+}
+
+/// A system whose run conditions (or whose sets' run conditions) read a state.
+#[derive(Clone, Debug)]
+pub struct GatedSystem {
+    /// The schedule the system runs in.
+    pub schedule: String,
+    /// Short name of the system.
+    pub system: String,
+    /// Short name of the run condition that reads the state.
+    pub condition: String,
+    /// Result of the condition's most recent evaluation, or `None` if it was not evaluated yet.
+    pub last_result: Option<bool>,
+}
+
+/// Returns the systems with a run condition that reads the state with the given type path,
+/// such as `in_state` or `state_changed`.
+pub fn gated_systems(run_conditions: &RunConditions, state_type_path: &str) -> Vec<GatedSystem> {
+    let mut gated = Vec::new();
+    for (schedule, systems) in run_conditions.schedules() {
+        for system in systems {
+            for condition in &system.conditions {
+                if outer_generic_arguments(&condition.name).contains(&state_type_path) {
+                    gated.push(GatedSystem {
+                        schedule: schedule.clone(),
+                        system: system.system.clone(),
+                        condition: condition.display_name(),
+                        last_result: condition.last_result(),
+                    });
+                }
+            }
+        }
+    }
+    gated
+}
+
+/// Returns the outermost generic arguments in a function or type name.
+///
+/// For example `bevy_state::condition::in_state<game::AppState>::{{closure}}`
+/// gives `["game::AppState"]`, and `a<game::A> && b<game::B<u8>, u16>`
+/// gives `["game::A", "game::B<u8>", "u16"]`.
+fn outer_generic_arguments(name: &str) -> Vec<&str> {
+    let mut arguments = Vec::new();
+    let mut depth = 0;
+    let mut parens = 0;
+    let mut start = 0;
+    for (index, c) in name.char_indices() {
+        match c {
+            '<' => {
+                depth += 1;
+                if depth == 1 {
+                    start = index + 1;
+                }
+            }
+            '>' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    arguments.push(name[start..index].trim());
+                }
+            }
+            '(' if depth > 0 => parens += 1,
+            ')' if depth > 0 => parens -= 1,
+            ',' if depth == 1 && parens == 0 => {
+                arguments.push(name[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    arguments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::run_conditions::capture_run_conditions;
+
+    #[test]
+    fn state_set_type_names_are_split() {
+        assert_eq!(
+            state_set_type_paths("game::AppState"),
+            vec!["game::AppState"]
+        );
+        assert_eq!(
+            state_set_type_paths("(game::AppState, core::option::Option<game::Menu<u8>>)"),
+            vec!["game::AppState", "game::Menu<u8>"]
+        );
+    }
+
+    #[test]
+    fn gated_systems_match_the_exact_state() {
+        #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+        enum Mode {
+            #[default]
+            Play,
+        }
+        #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+        enum ModeOverlay {
+            #[default]
+            Hidden,
+        }
+        fn play() {}
+        fn show_overlay() {}
+
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<Mode>()
+            .init_state::<ModeOverlay>()
+            .add_systems(Update, play.run_if(in_state(Mode::Play)))
+            .add_systems(Update, show_overlay.run_if(in_state(ModeOverlay::Hidden)));
+        capture_run_conditions(&mut app, false);
+        app.update();
+
+        let run_conditions = app.world().resource::<RunConditions>();
+        let systems = |state_type_path: &str| -> Vec<String> {
+            gated_systems(run_conditions, state_type_path)
+                .into_iter()
+                .map(|gated| gated.system)
+                .collect()
+        };
+        assert_eq!(systems(type_name::<Mode>()), ["play"]);
+        assert_eq!(systems(type_name::<ModeOverlay>()), ["show_overlay"]);
+        assert_eq!(
+            outer_generic_arguments("a<game::A> && b<game::B<u8>, (u8, u16)>"),
+            ["game::A", "game::B<u8>", "(u8, u16)"]
+        );
+    }
+
+    #[test]
+    fn states_are_ordered_by_dependency_depth() {
+        #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+        enum AppState {
+            #[default]
+            Menu,
+        }
+
+        let state = |type_path: &str, sources: &[&str]| InspectedState {
+            type_path: type_path.to_string(),
+            kind: StateKind::Computed,
+            sources: sources.iter().map(|s| s.to_string()).collect(),
+            current: None,
+        };
+        let states = [
+            state("c", &["b"]),
+            state("b", &["a"]),
+            state("a", &[]),
+            InspectedState::new::<AppState>(StateKind::Root, Vec::new()),
+        ];
+
+        let depths = dependency_depths(&states);
+        assert_eq!(depths["a"], 0);
+        assert_eq!(depths["b"], 1);
+        assert_eq!(depths["c"], 2);
+        assert_eq!(depths[type_name::<AppState>()], 0);
+    }
+
+    // Declared outside the test so its type name matches its reflected type path
+    #[derive(States, Reflect, Default, Clone, PartialEq, Eq, Hash, Debug)]
+    enum TransitionState {
+        #[default]
+        Menu,
+        InGame,
+        Level(u8),
+    }
+
+    #[test]
+    fn transitions_are_queued_through_reflection() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<TransitionState>()
+            .register_type_mutable_state::<TransitionState>()
+            .inspect_state::<TransitionState>();
+
+        let states = app
+            .world()
+            .resource::<InspectedStates>()
+            .collect(app.world());
+        let state = &states[0];
+        assert_eq!(state.variants(app.world()), vec!["Menu", "InGame"]);
+        assert_eq!(state.pending(app.world()), None);
+
+        let type_path = type_name::<TransitionState>();
+        assert!(!queue_state_transition(app.world_mut(), type_path, "Level"));
+        assert!(queue_state_transition(app.world_mut(), type_path, "InGame"));
+        assert_eq!(state.pending(app.world()).as_deref(), Some("InGame"));
+
+        app.update();
+        assert_eq!(state.current(app.world()).as_deref(), Some("InGame"));
+        assert_eq!(state.pending(app.world()), None);
+    }
+}
@@ -0,0 +1,423 @@
+//! User-facing strings of the inspector UI.
+//!
+//! The titles, tab labels, buttons and empty state messages of the inspector's panels are read
+//! from the [`InspectorStrings`] resource, so the inspector can be localized or re-worded
+//! without forking it.
+
+use bevy::prelude::*;
+
+/// The labels, titles and messages shown in the inspector UI.
+///
+/// Insert this resource before adding the
+/// [`InspectorWindowPlugin`](crate::inspector::InspectorWindowPlugin)
+/// to override the English defaults:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::InspectorStrings;
+/// # let mut app = App::new();
+/// app.insert_resource(InspectorStrings {
+///     components_tab: "Komponenten".to_string(),
+///     no_parent: "Kein Elternteil (Wurzel)".to_string(),
+///     ..default()
+/// });
+/// ```
+///
+/// Changes are picked up the next time a panel is rebuilt; the window title, title bar and tab
+/// bar are only built once, when the inspector window opens.
+#[derive(Resource, Clone, Debug)]
+pub struct InspectorStrings {
+    // Window
+    /// Title of the inspector window and its title bar.
+    pub window_title: String,
+
+    // Tabs
+    /// Label of the Components tab.
+    pub components_tab: String,
+    /// Label of the Relationships tab.
+    pub relationships_tab: String,
+    /// Label of the Timeline tab.
+    pub timeline_tab: String,
+    /// Label of the States tab, also used as its title.
+    pub states_tab: String,
+    /// Label of the Systems tab.
+    pub systems_tab: String,
+    /// Label of the Time tab, also used as its title.
+    pub time_tab: String,
+    /// Label of the Validation tab.
+    pub validation_tab: String,
+    /// Label of the Resources tab, also used as its title.
+    pub resources_tab: String,
+    /// Label of the Notes tab, also used as its title.
+    pub notes_tab: String,
+
+    // Entity list
+    /// Placeholder of the entity search field.
+    pub search_entities: String,
+    /// Unit of the entity list's match count, when a single entity matches the search.
+    pub search_match: String,
+    /// Unit of the entity list's match count, when several entities match the search.
+    pub search_matches: String,
+    /// Label of the button selecting the previous match of the search.
+    pub previous: String,
+    /// Label of the button selecting the next match of the search.
+    pub next: String,
+    /// Shown instead of the match count when a regex search is invalid, followed by the cause.
+    pub invalid_regex: String,
+    /// Label of the button refreshing the entity list.
+    pub refresh: String,
+    /// Label of the button opening the new entity dialog.
+    pub new_entity: String,
+    /// Label of the button selecting every entity of a group.
+    pub select_all: String,
+    /// Label of buttons cancelling a dialog or pending action.
+    pub cancel: String,
+    /// Label of the button toggling whether spawned entities are selected,
+    /// followed by [`on`](Self::on) or [`off`](Self::off).
+    pub select_spawns: String,
+    /// Shown after the label of a setting that is enabled.
+    pub on: String,
+    /// Shown after the label of a setting that is disabled.
+    pub off: String,
+    /// Label of the change filter button, followed by the number of frames.
+    pub changed_filter: String,
+    /// Shown on the change filter button while it lists entities changed at any time.
+    pub changed_any: String,
+    /// Unit of the change filter button while it lists entities changed within a single frame.
+    pub frame: String,
+    /// Unit of frame counts, e.g. on the change filter button.
+    pub frames: String,
+    /// Label of the checkboxes showing hidden entities.
+    pub show_hidden: String,
+    /// Label of the checkbox showing UI nodes.
+    pub ui_nodes: String,
+    /// Label of the checkbox showing windows.
+    pub windows: String,
+    /// Label of the checkbox showing the inspector's own entities.
+    pub inspector_entities: String,
+    /// Label of the field selecting an entity by its id.
+    pub go_to: String,
+    /// Shown in the go to field while it is empty and unfocused.
+    pub go_to_hint: String,
+    /// Label of the button despawning the filtered entities, followed by their number.
+    pub despawn_all: String,
+    /// Label of the button adding a component to the filtered entities.
+    pub add_to_all: String,
+    /// Label of the button removing a component from the filtered entities.
+    pub remove_from_all: String,
+    /// Label of the button showing an entity first in the list.
+    pub pin: String,
+    /// Label of the button no longer showing an entity first in the list.
+    pub unpin: String,
+    /// Tooltip of the pin button.
+    pub pin_tooltip: String,
+    /// Tooltip of the button showing or hiding an entity.
+    pub visibility_tooltip: String,
+    /// Shown while a component is added to the filtered entities, followed by its name.
+    pub bulk_adding: String,
+    /// Shown once a component was added to the filtered entities, followed by its name.
+    pub bulk_added: String,
+    /// Shown while a component is removed from the filtered entities, followed by its name.
+    pub bulk_removing: String,
+    /// Shown once a component was removed from the filtered entities, followed by its name.
+    pub bulk_removed: String,
+    /// Shown after the name of a component that can't be added without a default value.
+    pub no_default_value: String,
+    /// Unit of entity counts.
+    pub entities: String,
+
+    // Dialogs
+    /// Title of the new entity dialog.
+    pub new_entity_title: String,
+    /// Label of the button spawning the entity of the new entity dialog.
+    pub spawn: String,
+    /// Shown in the new entity dialog while no component is picked.
+    pub no_components_picked: String,
+    /// Title of the picked components in the new entity dialog, followed by their number.
+    pub preview: String,
+    /// Unit of component counts.
+    pub components: String,
+    /// Placeholder of the component search fields.
+    pub search_components: String,
+    /// Title of the component filter dialog.
+    pub component_filter_title: String,
+    /// Label of the button opening the component filter dialog,
+    /// followed by the number of filtered components.
+    pub component_filter: String,
+    /// Label of the button clearing the component filter.
+    pub clear_all: String,
+    /// Label of the button closing the component filter dialog.
+    pub done: String,
+
+    // Detail panel
+    /// Shown in the detail panel while no entity is selected.
+    pub no_selection: String,
+    /// Shown in the detail panel when the selected entity has been despawned.
+    pub selection_despawned: String,
+    /// Label of the button despawning the selected entity.
+    pub despawn: String,
+    /// Label of the despawn button while it awaits confirmation.
+    pub confirm_despawn: String,
+    /// Label of the button selecting the asset referenced by a handle field.
+    pub select: String,
+    /// Label of the button capturing a baseline of the selected entity.
+    pub capture_baseline: String,
+    /// Label of the button logging the inspection of the selected entity.
+    pub dump_to_log: String,
+    /// Label of the button highlighting changes since the baseline.
+    pub compare_to_baseline: String,
+    /// Label of the button that stops highlighting changes since the baseline.
+    pub stop_comparing: String,
+    /// Tooltip of the marker on fields that differ from the component's default.
+    pub differs_from_default: String,
+    /// Label of the button setting a non-finite number to zero.
+    pub set_to_zero: String,
+    /// Tooltip of the button opening the tween input of a numeric field.
+    pub start_tween: String,
+    /// Tooltip of the button stopping the tween of a numeric field.
+    pub stop_tween: String,
+    /// Label of the button stopping the tween of a numeric field.
+    pub stop: String,
+    /// Placeholder of the tween input.
+    pub tween_placeholder: String,
+    /// Tooltip of the tween input.
+    pub tween_input_tooltip: String,
+    /// Shown in a component without reflected fields.
+    pub no_reflected_data: String,
+    /// Shown in a component whose values are hidden.
+    pub values_hidden: String,
+    /// Label of the scene the selected entity was spawned from, followed by its path.
+    pub scene_origin: String,
+    /// Label of the buttons copying a value to the clipboard.
+    pub copy: String,
+    /// Tooltip of the button copying a field value.
+    pub copy_value: String,
+    /// Tooltip of the button copying a component as RON.
+    pub copy_component_ron: String,
+    /// Shown after the name of a selected entity that was despawned.
+    pub was_despawned: String,
+    /// Shown after [`was_despawned`](Self::was_despawned) when its parent was selected instead,
+    /// followed by the parent.
+    pub selected_parent: String,
+
+    // Relationships tab
+    /// Title of the parent section.
+    pub parent: String,
+    /// Shown when the selected entity has no parent.
+    pub no_parent: String,
+    /// Title of the children section, followed by the number of children.
+    pub children: String,
+    /// Shown when the selected entity has no children.
+    pub no_children: String,
+
+    // Other tabs
+    /// Shown in the States tab when no states are inspected.
+    pub no_states: String,
+    /// Shown in the Systems tab when no system has run conditions.
+    pub no_run_conditions: String,
+    /// Shown in the Time tab when the app has no time resources.
+    pub no_time_resources: String,
+    /// Shown in the Validation tab when nothing failed to validate.
+    pub no_validation_errors: String,
+    /// Shown in the Timeline tab when no component changes were recorded.
+    pub no_timeline_changes: String,
+    /// Shown in the Timeline tab while component additions and removals aren't observed.
+    pub lifecycles_not_observed: String,
+    /// Title of the Timeline tab, followed by the number of recorded [`frames`](Self::frames).
+    pub timeline_history: String,
+    /// Legend of the Timeline tab's component additions.
+    pub timeline_added: String,
+    /// Legend of the Timeline tab's component changes.
+    pub timeline_changed: String,
+    /// Legend of the Timeline tab's component removals.
+    pub timeline_removed: String,
+    /// Legend of the Timeline tab's spans while a component is present.
+    pub timeline_present: String,
+    /// Title of the archetype moves in the Timeline tab, followed by their number.
+    pub archetype_moves: String,
+    /// Shown below the Timeline tab's changes.
+    pub recorded_while_selected: String,
+    /// Title of the Validation tab, followed by the number of reports.
+    pub validation_errors: String,
+    /// Label of the button clearing the validation reports.
+    pub clear: String,
+    /// Unit of error counts.
+    pub errors: String,
+    /// Shown in place of the value of a state that doesn't exist.
+    pub inactive_state: String,
+    /// Label of the states a computed or sub state depends on, followed by them.
+    pub depends_on: String,
+    /// Shown when no systems run depending on a state.
+    pub no_gated_systems: String,
+    /// Title of the systems running depending on a state, followed by their number.
+    pub gated_systems: String,
+    /// Title of the Systems tab, followed by the number of systems.
+    pub systems_with_run_conditions: String,
+    /// Label of the buttons setting the relative speed of a clock.
+    pub set_rate: String,
+    /// Label of the profile menu button, followed by the active profile.
+    pub profile: String,
+    /// Shown on the profile menu button while no profile is active.
+    pub no_profile: String,
+    /// Label of the button saving the settings as a new profile.
+    pub save_as_new_profile: String,
+    /// Label of the button saving the settings to the active profile, followed by its name.
+    pub save_to_profile: String,
+    /// Shown in the profile menu while there are no saved profiles.
+    pub no_profiles: String,
+    /// Shown in the profile menu while profiles are not saved to a file.
+    pub profiles_not_saved: String,
+    /// Shown after a link to a despawned entity in the notes.
+    pub despawned: String,
+    /// Title of the list of entities mentioned in the notes.
+    pub entities_mentioned: String,
+    /// Shown in the notes while they are empty and unfocused.
+    pub take_notes: String,
+    /// Placeholder of the resource search field.
+    pub search_resources: String,
+    /// Label of the state a state will transition to, followed by that state.
+    pub next_state: String,
+    /// Label of the button resuming a timer.
+    pub resume: String,
+    /// Label of the button pausing a timer.
+    pub pause: String,
+    /// Label of the button resetting a timer.
+    pub reset: String,
+}
+
+impl Default for InspectorStrings {
+    fn default() -> Self {
+        Self {
+            window_title: "Feathers Inspector".to_string(),
+
+            components_tab: "Components".to_string(),
+            relationships_tab: "Relationships".to_string(),
+            timeline_tab: "Timeline".to_string(),
+            states_tab: "States".to_string(),
+            systems_tab: "Systems".to_string(),
+            time_tab: "Time".to_string(),
+            validation_tab: "Validation".to_string(),
+            resources_tab: "Resources".to_string(),
+            notes_tab: "Notes".to_string(),
+
+            search_entities: "Search entities...".to_string(),
+            search_match: "match".to_string(),
+            search_matches: "matches".to_string(),
+            previous: "Previous".to_string(),
+            next: "Next".to_string(),
+            invalid_regex: "Invalid regex:".to_string(),
+            refresh: "Refresh".to_string(),
+            new_entity: "New entity...".to_string(),
+            select_all: "Select all".to_string(),
+            cancel: "Cancel".to_string(),
+            select_spawns: "Select spawns".to_string(),
+            on: "On".to_string(),
+            off: "Off".to_string(),
+            changed_filter: "Changed".to_string(),
+            changed_any: "Any".to_string(),
+            frame: "frame".to_string(),
+            frames: "frames".to_string(),
+            show_hidden: "Show:".to_string(),
+            ui_nodes: "UI nodes".to_string(),
+            windows: "Windows".to_string(),
+            inspector_entities: "Inspector".to_string(),
+            go_to: "Go to".to_string(),
+            go_to_hint: "(click to type an id)".to_string(),
+            despawn_all: "Despawn all".to_string(),
+            add_to_all: "Add to all".to_string(),
+            remove_from_all: "Remove from all".to_string(),
+            pin: "pin".to_string(),
+            unpin: "unpin".to_string(),
+            pin_tooltip: "Show this entity first in the list".to_string(),
+            visibility_tooltip: "Show or hide the entity. Shift+click includes its descendants"
+                .to_string(),
+            bulk_adding: "Adding".to_string(),
+            bulk_added: "Added".to_string(),
+            bulk_removing: "Removing".to_string(),
+            bulk_removed: "Removed".to_string(),
+            no_default_value: "has no default value".to_string(),
+            entities: "entities".to_string(),
+
+            new_entity_title: "New entity".to_string(),
+            spawn: "Spawn".to_string(),
+            no_components_picked: "No components picked; the entity will be empty".to_string(),
+            preview: "Preview".to_string(),
+            components: "components".to_string(),
+            search_components: "Search components...".to_string(),
+            component_filter_title: "Component filter".to_string(),
+            component_filter: "Components".to_string(),
+            clear_all: "Clear all".to_string(),
+            done: "Done".to_string(),
+
+            no_selection: "Select an entity to view details".to_string(),
+            selection_despawned: "Selected entity no longer exists".to_string(),
+            despawn: "Despawn".to_string(),
+            confirm_despawn: "Confirm despawn".to_string(),
+            select: "Select".to_string(),
+            capture_baseline: "Capture baseline".to_string(),
+            dump_to_log: "Dump to log".to_string(),
+            compare_to_baseline: "Compare to baseline".to_string(),
+            stop_comparing: "Stop comparing".to_string(),
+            differs_from_default: "Differs from default".to_string(),
+            set_to_zero: "set to 0".to_string(),
+            start_tween: "Tween to a value".to_string(),
+            stop_tween: "Stop the tween".to_string(),
+            stop: "stop".to_string(),
+            tween_placeholder: "to secs".to_string(),
+            tween_input_tooltip: "Type a target value and seconds, e.g. 10 2.5, and press Enter"
+                .to_string(),
+            no_reflected_data: "<no reflected data>".to_string(),
+            values_hidden: "<values hidden>".to_string(),
+            scene_origin: "Scene:".to_string(),
+            copy: "copy".to_string(),
+            copy_value: "Copy value".to_string(),
+            copy_component_ron: "Copy component as RON".to_string(),
+            was_despawned: "was despawned".to_string(),
+            selected_parent: "selected its parent".to_string(),
+
+            parent: "Parent".to_string(),
+            no_parent: "No parent (root entity)".to_string(),
+            children: "Children".to_string(),
+            no_children: "No children".to_string(),
+
+            no_states: "No states found".to_string(),
+            no_run_conditions: "No systems with run conditions found".to_string(),
+            no_time_resources: "No time resources found".to_string(),
+            no_validation_errors: "No scene or component errors".to_string(),
+            no_timeline_changes: "No components recorded".to_string(),
+            lifecycles_not_observed:
+                "Additions and removals aren't observed, see with_component_lifecycles".to_string(),
+            timeline_history: "Last".to_string(),
+            timeline_added: "added".to_string(),
+            timeline_changed: "changed".to_string(),
+            timeline_removed: "removed".to_string(),
+            timeline_present: "present".to_string(),
+            archetype_moves: "Archetype moves".to_string(),
+            recorded_while_selected: "Changes are only recorded while the entity is selected"
+                .to_string(),
+            validation_errors: "Validation errors".to_string(),
+            clear: "Clear".to_string(),
+            errors: "errors".to_string(),
+            inactive_state: "<inactive>".to_string(),
+            depends_on: "Depends on:".to_string(),
+            no_gated_systems: "No gated systems".to_string(),
+            gated_systems: "Gated systems".to_string(),
+            systems_with_run_conditions: "Systems with run conditions".to_string(),
+            set_rate: "Set rate:".to_string(),
+            profile: "Profile:".to_string(),
+            no_profile: "none".to_string(),
+            save_as_new_profile: "Save as new profile".to_string(),
+            save_to_profile: "Save to".to_string(),
+            no_profiles: "No profiles yet".to_string(),
+            profiles_not_saved: "Set InspectorConfig::profiles_file to keep profiles".to_string(),
+            despawned: "despawned".to_string(),
+            entities_mentioned: "Entities mentioned".to_string(),
+            take_notes: "Click to take notes...".to_string(),
+            search_resources: "Search resources...".to_string(),
+            next_state: "Next".to_string(),
+            resume: "Resume".to_string(),
+            pause: "Pause".to_string(),
+            reset: "Reset".to_string(),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Queue of structural changes requested from the inspector UI.
+//!
+//! Inserting or removing components and despawning entities move entities between archetypes,
+//! so instead of being carried out wherever they are requested, they are queued in
+//! [`PendingStructuralChanges`] and applied together by [`apply_pending_structural_changes`],
+//! like field edits are applied by
+//! [`apply_pending_value_changes`](crate::inspector::widgets::apply_pending_value_changes).
+//!
+//! Each change is checked against the world as it is when the queue is applied,
+//! so a change targeting an entity that disappeared in the meantime (e.g. despawned by the app,
+//! or by an earlier change in the queue) is reported as a conflict instead of being lost silently.
+//! The outcome of every change is reported with a [`StructuralChangesApplied`] event.
+
+use core::any::TypeId;
+
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::inspector::despawn::{DespawnError, despawn_entity};
+use crate::inspector::state::InspectorCache;
+
+/// A structural change to an entity, see [`PendingStructuralChanges`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StructuralChange {
+    /// Inserts the default value of a component, replacing any existing value.
+    /// The component must register `ReflectComponent` and `ReflectDefault`.
+    Insert {
+        /// The entity to insert the component into.
+        entity: Entity,
+        /// The type of the component.
+        component_type_id: TypeId,
+    },
+    /// Removes a component. The component must register `ReflectComponent`.
+    Remove {
+        /// The entity to remove the component from.
+        entity: Entity,
+        /// The type of the component.
+        component_type_id: TypeId,
+    },
+    /// Despawns an entity and its descendants, see [`despawn_entity`].
+    Despawn {
+        /// The entity to despawn.
+        entity: Entity,
+        /// Whether despawning a protected entity was confirmed.
+        confirmed: bool,
+    },
+}
+
+impl StructuralChange {
+    /// The entity the change targets.
+    pub fn entity(&self) -> Entity {
+        match *self {
+            Self::Insert { entity, .. }
+            | Self::Remove { entity, .. }
+            | Self::Despawn { entity, .. } => entity,
+        }
+    }
+}
+
+/// Reasons a [`StructuralChange`] was not applied.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum StructuralChangeError {
+    #[error("{0} no longer exists")]
+    EntityNotFound(Entity),
+    #[error("Component {0:?} is not registered with `ReflectComponent`")]
+    NotRegistered(TypeId),
+    #[error("{0} does not register `ReflectDefault`")]
+    NoDefault(String),
+    #[error(transparent)]
+    Despawn(DespawnError),
+}
+
+/// Queue of structural changes, applied in order by [`apply_pending_structural_changes`].
+#[derive(Resource, Default)]
+pub struct PendingStructuralChanges {
+    pub changes: Vec<StructuralChange>,
+}
+
+impl PendingStructuralChanges {
+    /// Queues `change`, unless the same change is already queued.
+    pub fn push(&mut self, change: StructuralChange) {
+        if !self.changes.contains(&change) {
+            self.changes.push(change);
+        }
+    }
+}
+
+/// Event triggered after [`apply_pending_structural_changes`] applied a non-empty queue,
+/// with the outcome of each change in the order they were queued.
+#[derive(Event, Debug)]
+pub struct StructuralChangesApplied {
+    pub results: Vec<(StructuralChange, Result<(), StructuralChangeError>)>,
+}
+
+/// Applies a single structural change to the world.
+fn apply_structural_change(
+    world: &mut World,
+    change: &StructuralChange,
+) -> Result<(), StructuralChangeError> {
+    let entity = change.entity();
+    if !world.entities().contains(entity) {
+        return Err(StructuralChangeError::EntityNotFound(entity));
+    }
+
+    let (component_type_id, insert) = match *change {
+        StructuralChange::Insert {
+            component_type_id, ..
+        } => (component_type_id, true),
+        StructuralChange::Remove {
+            component_type_id, ..
+        } => (component_type_id, false),
+        StructuralChange::Despawn { confirmed, .. } => {
+            return despawn_entity(world, entity, confirmed).map_err(|error| match error {
+                DespawnError::EntityNotFound(entity) => {
+                    StructuralChangeError::EntityNotFound(entity)
+                }
+                error => StructuralChangeError::Despawn(error),
+            });
+        }
+    };
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let Some(registration) = registry.get(component_type_id) else {
+        return Err(StructuralChangeError::NotRegistered(component_type_id));
+    };
+    let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+        return Err(StructuralChangeError::NotRegistered(component_type_id));
+    };
+    let mut entity_mut = world.entity_mut(entity);
+    if insert {
+        let Some(reflect_default) = registration.data::<ReflectDefault>() else {
+            let name = ShortName(registration.type_info().type_path()).to_string();
+            return Err(StructuralChangeError::NoDefault(name));
+        };
+        let value = reflect_default.default();
+        reflect_component.insert(&mut entity_mut, value.as_partial_reflect(), &registry);
+    } else {
+        reflect_component.remove(&mut entity_mut);
+    }
+    Ok(())
+}
+
+/// Exclusive system that applies the queued [`PendingStructuralChanges`],
+/// then triggers [`StructuralChangesApplied`] and marks the inspector's cache stale.
+///
+/// Failed insertions and removals are logged; failed despawns are left to observers,
+/// since protected entities are skipped on purpose.
+pub fn apply_pending_structural_changes(world: &mut World) {
+    let changes = {
+        let mut pending = world.resource_mut::<PendingStructuralChanges>();
+        std::mem::take(&mut pending.changes)
+    };
+    if changes.is_empty() {
+        return;
+    }
+
+    let mut results = Vec::with_capacity(changes.len());
+    for change in changes {
+        let result = apply_structural_change(world, &change);
+        if let Err(error) = &result
+            && !matches!(change, StructuralChange::Despawn { .. })
+        {
+            warn!("Failed to apply {:?}: {}", change, error);
+        }
+        results.push((change, result));
+    }
+
+    if let Some(mut cache) = world.get_resource_mut::<InspectorCache>() {
+        cache.stale = true;
+        cache.detail_stale = true;
+    }
+    world.trigger(StructuralChangesApplied { results });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Wireframe;
+
+    #[derive(Resource, Default)]
+    struct Results(Vec<Result<(), StructuralChangeError>>);
+
+    #[test]
+    fn changes_to_vanished_entities_are_conflicts() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<PendingStructuralChanges>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Wireframe>();
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(parent)).id();
+        let other = world.spawn_empty().id();
+
+        let component_type_id = TypeId::of::<Wireframe>();
+        let mut pending = world.resource_mut::<PendingStructuralChanges>();
+        pending.push(StructuralChange::Insert {
+            entity: other,
+            component_type_id,
+        });
+        pending.push(StructuralChange::Despawn {
+            entity: parent,
+            confirmed: false,
+        });
+        pending.push(StructuralChange::Insert {
+            entity: child,
+            component_type_id,
+        });
+        assert_eq!(pending.changes.len(), 3);
+        // Nothing changes until the queue is applied
+        assert!(world.entities().contains(parent));
+
+        world.init_resource::<Results>();
+        world.add_observer(
+            |applied: On<StructuralChangesApplied>, mut results: ResMut<Results>| {
+                results.0 = applied
+                    .results
+                    .iter()
+                    .map(|(_, result)| result.clone())
+                    .collect();
+            },
+        );
+        apply_pending_structural_changes(&mut world);
+
+        assert_eq!(
+            world.resource::<Results>().0,
+            [
+                Ok(()),
+                Ok(()),
+                Err(StructuralChangeError::EntityNotFound(child))
+            ]
+        );
+
+        assert!(world.get::<Wireframe>(other).is_some());
+        assert!(!world.entities().contains(child));
+        assert!(
+            world
+                .resource::<PendingStructuralChanges>()
+                .changes
+                .is_empty()
+        );
+    }
+}
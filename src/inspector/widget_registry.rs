@@ -0,0 +1,115 @@
+//! Custom editors for specific types.
+//!
+//! The detail panel shows every reflected value as generic rows of fields.
+//! Types registered in [`InspectorWidgetRegistry`] are drawn by their own widget instead,
+//! both when they are a whole component and when they are a field of another component.
+
+use core::any::TypeId;
+use std::sync::Arc;
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::inspector::widgets::FieldPath;
+
+/// A function spawning the editor of a value into the detail panel.
+///
+/// It is given the spawner of the row (or component card) the editor belongs in,
+/// the path of the value, and a copy of the value as it was when the panel was built.
+/// The world is available through [`ChildSpawner::world_mut`], e.g. to read other components
+/// of the entity in `field_path.owner`. Edits are written back like any other field edit,
+/// e.g. by pushing to [`PendingValueChanges`](crate::inspector::widgets::PendingValueChanges).
+pub type InspectorWidgetFn =
+    Arc<dyn Fn(&mut ChildSpawner, &FieldPath, &dyn PartialReflect) + Send + Sync>;
+
+/// Registry of custom editors, keyed by the type they edit.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::InspectorWidgetRegistry;
+/// #[derive(Component, Reflect)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// let mut widgets = InspectorWidgetRegistry::default();
+/// widgets.register::<Health>(|parent, _field_path, value| {
+///     let Some(health) = value.try_downcast_ref::<Health>() else {
+///         return;
+///     };
+///     parent.spawn(Text::new(format!("{:.0} / {:.0} HP", health.current, health.max)));
+/// });
+/// ```
+///
+/// Values whose type is registered are not expanded into fields, so their editor is responsible
+/// for all of them.
+#[derive(Resource, Default, Clone)]
+pub struct InspectorWidgetRegistry {
+    widgets: HashMap<TypeId, InspectorWidgetFn>,
+}
+
+impl InspectorWidgetRegistry {
+    /// Registers `widget` as the editor of values of type `T`, replacing any previous editor.
+    pub fn register<T: 'static>(
+        &mut self,
+        widget: impl Fn(&mut ChildSpawner, &FieldPath, &dyn PartialReflect) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_by_id(TypeId::of::<T>(), widget)
+    }
+
+    /// Registers `widget` as the editor of values of the type `type_id`.
+    pub fn register_by_id(
+        &mut self,
+        type_id: TypeId,
+        widget: impl Fn(&mut ChildSpawner, &FieldPath, &dyn PartialReflect) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.widgets.insert(type_id, Arc::new(widget));
+        self
+    }
+
+    /// Removes the editor of values of type `T`, so they are shown as fields again.
+    pub fn unregister<T: 'static>(&mut self) -> &mut Self {
+        self.widgets.remove(&TypeId::of::<T>());
+        self
+    }
+
+    /// Returns the editor of values of the type `type_id`, if one is registered.
+    pub fn get(&self, type_id: TypeId) -> Option<&InspectorWidgetFn> {
+        self.widgets.get(&type_id)
+    }
+
+    /// Returns the editor of `value`'s type, if one is registered.
+    pub fn get_for(&self, value: &dyn PartialReflect) -> Option<&InspectorWidgetFn> {
+        let type_info = value.get_represented_type_info()?;
+        self.get(type_info.type_id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Default)]
+    struct Inventory {
+        slots: Vec<u32>,
+    }
+
+    #[test]
+    fn widgets_are_found_by_the_type_of_a_value() {
+        let mut widgets = InspectorWidgetRegistry::default();
+        widgets.register::<Inventory>(|parent, _, _| {
+            parent.spawn(Text::new("inventory"));
+        });
+
+        let inventory = Inventory::default();
+        assert!(widgets.get_for(&inventory).is_some());
+        assert!(widgets.get_for(&inventory.slots).is_none());
+        // Dynamic values are looked up by the type they represent
+        assert!(widgets.get_for(inventory.to_dynamic().as_ref()).is_some());
+
+        widgets.unregister::<Inventory>();
+        assert!(widgets.get_for(&inventory).is_none());
+    }
+}
@@ -0,0 +1,319 @@
+//! Color picker widget - an inline swatch plus one slider per RGBA channel.
+//!
+//! Edits fields of type [`Color`], [`Srgba`] and [`LinearRgba`].
+//! The sliders always work on sRGB channels, which is how colors are usually picked,
+//! but the written value keeps the field's type and, for [`Color`], its color space.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{SliderProps, slider};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{SliderPrecision, SliderStep, SliderValue, ValueChange};
+
+use super::drag_value::{FieldPath, FieldValue, PendingValueChange, PendingValueChanges};
+use crate::inspector::fonts::ValueText;
+use crate::inspector::state::InspectorState;
+
+/// Width of the picker, which the sliders stretch to fill.
+const PICKER_WIDTH: f32 = 180.0;
+const SWATCH_SIZE: f32 = 14.0;
+
+/// The reflected type of an edited color field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorFieldType {
+    Color,
+    Srgba,
+    LinearRgba,
+}
+
+impl ColorFieldType {
+    /// Converts `color` into a value of this type, ready to be applied to the field.
+    fn to_reflect(self, color: Color) -> Box<dyn PartialReflect> {
+        match self {
+            ColorFieldType::Color => Box::new(color),
+            ColorFieldType::Srgba => Box::new(color.to_srgba()),
+            ColorFieldType::LinearRgba => Box::new(color.to_linear()),
+        }
+    }
+}
+
+/// Reads a color from a reflected [`Color`], [`Srgba`] or [`LinearRgba`] value.
+pub fn color_from_reflect(reflected: &dyn PartialReflect) -> Option<(Color, ColorFieldType)> {
+    if let Some(color) = reflected.try_downcast_ref::<Color>() {
+        return Some((*color, ColorFieldType::Color));
+    }
+    if let Some(srgba) = reflected.try_downcast_ref::<Srgba>() {
+        return Some(((*srgba).into(), ColorFieldType::Srgba));
+    }
+    if let Some(linear) = reflected.try_downcast_ref::<LinearRgba>() {
+        return Some(((*linear).into(), ColorFieldType::LinearRgba));
+    }
+    None
+}
+
+/// One of the channels edited by a [`ColorPicker`] slider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChannel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl ColorChannel {
+    const ALL: [ColorChannel; 4] = [
+        ColorChannel::Red,
+        ColorChannel::Green,
+        ColorChannel::Blue,
+        ColorChannel::Alpha,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ColorChannel::Red => "R",
+            ColorChannel::Green => "G",
+            ColorChannel::Blue => "B",
+            ColorChannel::Alpha => "A",
+        }
+    }
+
+    /// Returns the sRGB value of this channel.
+    fn get(self, color: Color) -> f32 {
+        let srgba = color.to_srgba();
+        match self {
+            ColorChannel::Red => srgba.red,
+            ColorChannel::Green => srgba.green,
+            ColorChannel::Blue => srgba.blue,
+            ColorChannel::Alpha => srgba.alpha,
+        }
+    }
+}
+
+/// Returns `color` with the sRGB `channel` set to `value`, keeping the color space of `color`.
+pub fn with_color_channel(color: Color, channel: ColorChannel, value: f32) -> Color {
+    let mut srgba = color.to_srgba();
+    match channel {
+        ColorChannel::Red => srgba.red = value,
+        ColorChannel::Green => srgba.green = value,
+        ColorChannel::Blue => srgba.blue = value,
+        ColorChannel::Alpha => srgba.alpha = value,
+    }
+    match color {
+        Color::Srgba(_) => Color::Srgba(srgba),
+        Color::LinearRgba(_) => Color::LinearRgba(srgba.into()),
+        Color::Hsla(_) => Color::Hsla(srgba.into()),
+        Color::Hsva(_) => Color::Hsva(srgba.into()),
+        Color::Hwba(_) => Color::Hwba(srgba.into()),
+        Color::Laba(_) => Color::Laba(srgba.into()),
+        Color::Lcha(_) => Color::Lcha(srgba.into()),
+        Color::Oklaba(_) => Color::Oklaba(srgba.into()),
+        Color::Oklcha(_) => Color::Oklcha(srgba.into()),
+        Color::Xyza(_) => Color::Xyza(srgba.into()),
+    }
+}
+
+/// A color editing widget. Holds the field path for write-back and the current color.
+#[derive(Component, Clone, Debug)]
+pub struct ColorPicker {
+    /// The field path for write-back.
+    pub field_path: FieldPath,
+    /// The color currently shown.
+    pub color: Color,
+    /// The type of the edited field.
+    pub field_type: ColorFieldType,
+}
+
+/// A slider editing one channel of the given [`ColorPicker`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ColorChannelSlider {
+    pub picker: Entity,
+    pub channel: ColorChannel,
+}
+
+/// The swatch showing the color of the given [`ColorPicker`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ColorSwatch(pub Entity);
+
+/// The hex label showing the color of the given [`ColorPicker`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ColorHexLabel(pub Entity);
+
+/// Spawns a color picker for `color` as a child of `parent`.
+pub fn spawn_color_picker(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    color: Color,
+    field_type: ColorFieldType,
+    font_size: f32,
+) {
+    let mut picker = parent.spawn((
+        Node {
+            width: Px(PICKER_WIDTH),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(2.0),
+            ..default()
+        },
+        ColorPicker {
+            field_path,
+            color,
+            field_type,
+        },
+    ));
+    let picker_entity = picker.id();
+
+    picker.with_children(|picker| {
+        // Swatch and hex value
+        picker
+            .spawn(Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Px(6.0),
+                ..default()
+            })
+            .with_children(|header| {
+                header.spawn((
+                    Node {
+                        width: Px(SWATCH_SIZE),
+                        height: Px(SWATCH_SIZE),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(0.5, 0.5, 0.5, 1.0)),
+                    BackgroundColor(color),
+                    ColorSwatch(picker_entity),
+                ));
+                header.spawn((
+                    Text::new(color.to_srgba().to_hex()),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+                    ColorHexLabel(picker_entity),
+                    ValueText,
+                ));
+            });
+
+        for channel in ColorChannel::ALL {
+            let value = channel.get(color);
+            picker
+                .spawn(Node {
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    column_gap: Px(4.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(channel.label()),
+                        TextFont {
+                            font_size,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.6, 0.6, 0.6, 1.0)),
+                    ));
+                    row.spawn(slider(
+                        SliderProps {
+                            value,
+                            min: 0.0,
+                            // Keep HDR values in range instead of clamping them on display
+                            max: value.max(1.0),
+                        },
+                        (
+                            ColorChannelSlider {
+                                picker: picker_entity,
+                                channel,
+                            },
+                            SliderStep(0.01),
+                            SliderPrecision(3),
+                        ),
+                    ));
+                });
+        }
+    });
+}
+
+/// Observer: applies slider changes to the picker, its swatch and label, and queues the write-back.
+fn color_channel_on_change(
+    change: On<ValueChange<f32>>,
+    sliders: Query<&ColorChannelSlider>,
+    mut pickers: Query<&mut ColorPicker>,
+    mut swatches: Query<(&ColorSwatch, &mut BackgroundColor)>,
+    mut labels: Query<(&ColorHexLabel, &mut Text)>,
+    mut pending: ResMut<PendingValueChanges>,
+    state: Option<Res<InspectorState>>,
+    mut commands: Commands,
+) {
+    let Ok(slider) = sliders.get(change.source) else {
+        return;
+    };
+    let Ok(mut picker) = pickers.get_mut(slider.picker) else {
+        return;
+    };
+
+    // Headless sliders don't update themselves
+    commands
+        .entity(change.source)
+        .insert(SliderValue(change.value));
+
+    picker.color = with_color_channel(picker.color, slider.channel, change.value);
+    let color = picker.color;
+    for (swatch, mut background) in &mut swatches {
+        if swatch.0 == slider.picker {
+            background.0 = color;
+        }
+    }
+    for (label, mut text) in &mut labels {
+        if label.0 == slider.picker {
+            text.0 = color.to_srgba().to_hex();
+        }
+    }
+
+    let edit = PendingValueChange {
+        field_path: picker.field_path.clone(),
+        value: FieldValue::Reflected(picker.field_type.to_reflect(color)),
+    };
+    let targets = state.as_ref().map_or(&[][..], |state| {
+        state.field_edit_targets(&picker.field_path)
+    });
+    pending.push_batched(edit, targets);
+}
+
+/// Plugin that adds the ColorPicker widget observers.
+///
+/// Requires [`DragValuePlugin`](super::DragValuePlugin), which writes the queued changes back.
+pub struct ColorPickerPlugin;
+
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(color_channel_on_change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_edits_keep_the_color_space() {
+        let color = Color::linear_rgba(1.0, 0.0, 0.0, 1.0);
+        let edited = with_color_channel(color, ColorChannel::Alpha, 0.5);
+        assert!(matches!(edited, Color::LinearRgba(_)));
+        assert_eq!(edited.alpha(), 0.5);
+
+        let edited = with_color_channel(Color::srgb(0.2, 0.4, 0.6), ColorChannel::Green, 0.8);
+        assert_eq!(edited, Color::srgb(0.2, 0.8, 0.6));
+    }
+
+    #[test]
+    fn color_fields_keep_their_type() {
+        let (color, field_type) = color_from_reflect(&Srgba::RED).unwrap();
+        assert_eq!(field_type, ColorFieldType::Srgba);
+        let written = field_type.to_reflect(color);
+        assert_eq!(written.try_downcast_ref::<Srgba>(), Some(&Srgba::RED));
+
+        assert!(color_from_reflect(&1.0_f32).is_none());
+    }
+}
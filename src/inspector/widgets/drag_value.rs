@@ -2,43 +2,208 @@
 //!
 //! This widget allows editing numeric values by:
 //! 1. Horizontal dragging to increment/decrement the value
+//!    (hold Shift to drag faster, Ctrl to drag slower)
 //! 2. Double-clicking to enter text input mode for direct value entry
+//!    (move the caret with Left/Right/Home/End, hold Shift to select, Ctrl+A to select all)
+//! 3. Clicking to focus it, then nudging the value with the Up/Down arrow keys
+//!    (hold Shift for larger steps, Ctrl for smaller ones)
+//!
+//! If [`DragValue::snap`] is set, holding Alt while dragging snaps the value to its multiples.
 
 use bevy::ecs::entity::Entity;
 use bevy::ecs::event::Event;
 use bevy::ecs::observer::On;
-use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::ecs::world::DeferredWorld;
 use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::input_focus::{FocusedInput, InputFocus};
 use bevy::picking::events::{Click, Drag, DragEnd, DragStart, Pointer};
 use bevy::prelude::*;
-use bevy::reflect::ReflectMut;
+use bevy::reflect::{Access, ParsedPath, ReflectMut, ReflectRef};
 use core::any::TypeId;
+use core::fmt;
+use core::ops::Range;
 use std::time::{Duration, Instant};
+use thiserror::Error;
 
-use crate::reflection_tools::get_reflected_component_mut;
+use crate::inspector::component_settings::ComponentDisplayOverrides;
+use crate::inspector::opaque_math::{opaque_math_field_mut, opaque_math_fields};
+use crate::inspector::state::InspectorState;
+use crate::inspector::widgets::vector_drag::{EulerAngleAxis, quat_euler_degrees};
+use crate::reflection_tools::{
+    get_reflected_component_mut, get_reflected_component_ref, get_reflected_resource_mut,
+    get_reflected_resource_ref,
+};
 
 /// Double-click detection threshold (in milliseconds)
-const DOUBLE_CLICK_THRESHOLD_MS: u64 = 300;
+pub(crate) const DOUBLE_CLICK_THRESHOLD_MS: u64 = 300;
+
+/// Drag speed multiplier while Shift is held, for drags and arrow key nudges.
+const FAST_MULTIPLIER: f64 = 10.0;
+/// Drag speed multiplier while Ctrl is held, for drags and arrow key nudges.
+const SLOW_MULTIPLIER: f64 = 0.1;
+
+/// Border of a drag value, and of the drag value that has the input focus.
+const BORDER_COLOR: Color = Color::srgba(0.3, 0.3, 0.3, 1.0);
+const FOCUSED_BORDER_COLOR: Color = Color::srgba(0.35, 0.55, 0.85, 1.0);
+
+/// What owns an inspected field: a component of an entity, or a resource.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FieldOwner {
+    /// A component of this entity.
+    Entity(Entity),
+    /// A resource.
+    Resource,
+}
 
-/// Describes how to locate a field within a component for write-back.
-#[derive(Clone, Debug)]
+impl FieldOwner {
+    /// Returns the entity owning the field, or `None` for a resource.
+    pub fn entity(self) -> Option<Entity> {
+        match self {
+            Self::Entity(entity) => Some(entity),
+            Self::Resource => None,
+        }
+    }
+}
+
+/// Describes how to locate a field within a component or resource for write-back.
+///
+/// Paths to fields of a resource, created with [`FieldPath::resource`],
+/// have [`FieldOwner::Resource`] as their owner and the resource's type as their component.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FieldPath {
-    /// The entity containing the component.
-    pub entity: Entity,
-    /// The TypeId of the component.
+    /// The entity containing the component, or [`FieldOwner::Resource`] for a resource.
+    pub owner: FieldOwner,
+    /// The TypeId of the component or resource.
     pub component_type_id: TypeId,
     /// The path segments to navigate to the field.
     pub path: Vec<FieldPathSegment>,
 }
 
+impl FieldPath {
+    /// Creates a path to a field of component `C` on `entity`.
+    pub fn new<C: Component>(
+        entity: Entity,
+        path: impl IntoIterator<Item = FieldPathSegment>,
+    ) -> Self {
+        Self {
+            owner: FieldOwner::Entity(entity),
+            component_type_id: TypeId::of::<C>(),
+            path: path.into_iter().collect(),
+        }
+    }
+
+    /// Creates a path to a field of the resource with type `resource_type_id`.
+    pub fn resource(
+        resource_type_id: TypeId,
+        path: impl IntoIterator<Item = FieldPathSegment>,
+    ) -> Self {
+        Self {
+            owner: FieldOwner::Resource,
+            component_type_id: resource_type_id,
+            path: path.into_iter().collect(),
+        }
+    }
+
+    /// Returns whether this is the path to a field of a resource rather than a component.
+    pub fn is_resource(&self) -> bool {
+        self.owner == FieldOwner::Resource
+    }
+
+    /// Returns the same path on the component of another entity.
+    pub fn with_entity(&self, entity: Entity) -> Self {
+        Self {
+            owner: FieldOwner::Entity(entity),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the path to another field of the same component or resource.
+    pub fn with_path(&self, path: Vec<FieldPathSegment>) -> Self {
+        Self {
+            path,
+            ..self.clone()
+        }
+    }
+
+    /// Converts the path segments into a bevy_reflect [`ParsedPath`],
+    /// which can be used with [`GetPath`](bevy::reflect::GetPath) on the component.
+    pub fn parsed_path(&self) -> ParsedPath {
+        ParsedPath::from(
+            self.path
+                .iter()
+                .map(FieldPathSegment::to_access)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
 /// A segment in a field path.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FieldPathSegment {
     /// Named struct field: e.g., "translation"
     Named(String),
     /// Indexed tuple/array field: e.g., 0, 1, 2
     Index(usize),
+    /// Indexed list or array element: e.g., `[2]`
+    ListIndex(usize),
+}
+
+impl FieldPathSegment {
+    /// Converts this segment into the equivalent bevy_reflect [`Access`].
+    pub fn to_access(&self) -> Access<'static> {
+        match self {
+            FieldPathSegment::Named(name) => Access::Field(name.clone().into()),
+            FieldPathSegment::Index(index) => Access::TupleIndex(*index),
+            FieldPathSegment::ListIndex(index) => Access::ListIndex(*index),
+        }
+    }
+}
+
+impl fmt::Display for FieldPathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_access().fmt(f)
+    }
+}
+
+/// An error that can occur when parsing a field path string.
+#[derive(Debug, Error)]
+pub enum FieldPathParseError {
+    /// The string is not valid bevy_reflect path syntax.
+    #[error("{0}")]
+    InvalidSyntax(String),
+    /// Field index access (`#0`) cannot be represented by a [`FieldPathSegment`].
+    #[error("Field index access `#{0}` is not supported in field paths")]
+    FieldIndexUnsupported(usize),
+}
+
+/// Formats path segments using bevy_reflect path syntax, e.g. `translation.x` or `[2].value`.
+///
+/// The output can be parsed back with [`parse_field_path`].
+pub fn format_field_path(path: &[FieldPathSegment]) -> String {
+    let formatted: String = path.iter().map(ToString::to_string).collect();
+    // A leading named field does not need its dot
+    match path.first() {
+        Some(FieldPathSegment::Named(_)) => formatted[1..].to_string(),
+        _ => formatted,
+    }
+}
+
+/// Parses path segments from bevy_reflect path syntax, e.g. `translation.x` or `[2].value`.
+pub fn parse_field_path(path: &str) -> Result<Vec<FieldPathSegment>, FieldPathParseError> {
+    let parsed = ParsedPath::parse(path)
+        .map_err(|err| FieldPathParseError::InvalidSyntax(err.to_string()))?;
+
+    parsed
+        .0
+        .into_iter()
+        .map(|offset_access| match offset_access.access {
+            Access::Field(name) => Ok(FieldPathSegment::Named(name.into_owned())),
+            Access::TupleIndex(index) => Ok(FieldPathSegment::Index(index)),
+            Access::ListIndex(index) => Ok(FieldPathSegment::ListIndex(index)),
+            Access::FieldIndex(index) => Err(FieldPathParseError::FieldIndexUnsupported(index)),
+        })
+        .collect()
 }
 
 /// Props for spawning a DragValue widget.
@@ -55,13 +220,17 @@ pub struct DragValueProps {
     pub min: Option<f64>,
     /// Maximum value (optional).
     pub max: Option<f64>,
+    /// Increment the value snaps to while Alt is held during a drag (optional).
+    pub snap: Option<f64>,
+    /// How the value is displayed and stepped.
+    pub format: DragValueFormat,
 }
 
 impl Default for DragValueProps {
     fn default() -> Self {
         Self {
             field_path: FieldPath {
-                entity: Entity::PLACEHOLDER,
+                owner: FieldOwner::Resource,
                 component_type_id: TypeId::of::<()>(),
                 path: vec![],
             },
@@ -70,6 +239,8 @@ impl Default for DragValueProps {
             precision: 2,
             min: None,
             max: None,
+            snap: None,
+            format: DragValueFormat::Float,
         }
     }
 }
@@ -89,6 +260,80 @@ pub struct DragValue {
     pub min: Option<f64>,
     /// Maximum allowed value.
     pub max: Option<f64>,
+    /// Increment the value snaps to while Alt is held during a drag,
+    /// e.g. `0.5` to place things on a half-unit grid.
+    pub snap: Option<f64>,
+    /// How the value is displayed and stepped.
+    pub format: DragValueFormat,
+}
+
+/// How a [`DragValue`] displays its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DragValueFormat {
+    /// A decimal number with [`DragValue::precision`] decimal places.
+    #[default]
+    Float,
+    /// A whole number; dragging and nudging step by whole units.
+    Integer,
+    /// A whole number in hexadecimal, e.g. `0xFF`, for bitfield-style values.
+    Hex,
+}
+
+impl DragValue {
+    /// Formats `value` for display.
+    pub fn format_value(&self, value: f64) -> String {
+        match self.format {
+            DragValueFormat::Float => format!("{:.prec$}", value, prec = self.precision),
+            // Adding zero turns -0 into 0
+            DragValueFormat::Integer => format!("{}", value.round() + 0.0),
+            DragValueFormat::Hex => format!("0x{:X}", value.round().max(0.0) as u64),
+        }
+    }
+
+    /// Rounds `value` to a whole number if the format is an integer one.
+    pub fn round_to_format(&self, value: f64) -> f64 {
+        match self.format {
+            DragValueFormat::Float => value,
+            DragValueFormat::Integer | DragValueFormat::Hex => value.round(),
+        }
+    }
+
+    /// Formats the whole number `value` for display, exactly even beyond 2^53,
+    /// where [`format_value`](Self::format_value) would round it.
+    pub fn format_integer(&self, value: i128) -> String {
+        match self.format {
+            DragValueFormat::Float | DragValueFormat::Integer => value.to_string(),
+            DragValueFormat::Hex => format!("0x{:X}", value.max(0)),
+        }
+    }
+
+    /// Clamps the whole number `value` to [`min`](Self::min) and [`max`](Self::max).
+    fn clamp_integer(&self, mut value: i128) -> i128 {
+        if let Some(min) = self.min {
+            value = value.max(min.ceil() as i128);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max.floor() as i128);
+        }
+        value
+    }
+}
+
+/// Parses a displayed drag value, either a decimal number or a `0x` prefixed hexadecimal one.
+pub(crate) fn parse_drag_value(text: &str) -> Option<f64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok().map(|value| value as f64),
+        None => text.parse().ok(),
+    }
+}
+
+/// Parses a displayed whole number like [`parse_drag_value`], but without going through f64,
+/// so 64-bit integers beyond 2^53 keep their exact value.
+pub(crate) fn parse_drag_integer(text: &str) -> Option<i128> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok().map(i128::from),
+        None => text.parse().ok(),
+    }
 }
 
 /// Tracks the drag state of a DragValue widget.
@@ -98,14 +343,27 @@ pub struct DragValueDragState {
     pub dragging: bool,
     /// The value when dragging started.
     pub start_value: f64,
+    /// The value reached by the current drag, before snapping and clamping.
+    pub drag_value: f64,
+    /// The value to write when the drag ends, for components edited on release
+    /// (see [`ComponentDisplayOverrides`]).
+    pub release_value: Option<f64>,
     /// Whether in text editing mode.
     pub editing: bool,
     /// Text buffer for editing mode.
     pub edit_buffer: String,
+    /// Byte index of the caret in [`edit_buffer`](Self::edit_buffer).
+    pub caret: usize,
+    /// Where the selection in [`edit_buffer`](Self::edit_buffer) started;
+    /// the text between it and the caret is selected.
+    pub selection_anchor: Option<usize>,
     /// Last click time for double-click detection.
     pub last_click_time: Option<Instant>,
     /// The original value before editing (for cancellation).
     pub original_value: f64,
+    /// The exact original value of an integer field before editing,
+    /// which [`original_value`](Self::original_value) may have rounded.
+    pub original_integer: Option<i128>,
 }
 
 impl Default for DragValueDragState {
@@ -113,10 +371,121 @@ impl Default for DragValueDragState {
         Self {
             dragging: false,
             start_value: 0.0,
+            drag_value: 0.0,
+            release_value: None,
             editing: false,
             edit_buffer: String::new(),
+            caret: 0,
+            selection_anchor: None,
             last_click_time: None,
             original_value: 0.0,
+            original_integer: None,
+        }
+    }
+}
+
+impl DragValueDragState {
+    /// The text showing the value from before editing, to restore when the edit is cancelled.
+    fn original_text(&self, drag_value: &DragValue) -> String {
+        match self.original_integer {
+            Some(integer) if drag_value.format != DragValueFormat::Float => {
+                drag_value.format_integer(integer)
+            }
+            _ => drag_value.format_value(self.original_value),
+        }
+    }
+
+    /// Starts editing `text`, with the caret at its end.
+    fn start_editing(&mut self, text: String) {
+        self.editing = true;
+        self.caret = text.len();
+        self.selection_anchor = None;
+        self.edit_buffer = text;
+    }
+
+    /// The selected part of the edit buffer, if any text is selected.
+    fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        let range = anchor.min(self.caret)..anchor.max(self.caret);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Removes the selected text. Returns whether any text was selected.
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.selection_anchor = None;
+        let Some(range) = selection else {
+            return false;
+        };
+        self.caret = range.start;
+        self.edit_buffer.replace_range(range, "");
+        true
+    }
+
+    /// Inserts `text` at the caret, replacing the selected text.
+    fn insert(&mut self, text: &str) {
+        self.delete_selection();
+        self.edit_buffer.insert_str(self.caret, text);
+        self.caret += text.len();
+    }
+
+    /// Removes the selected text, or else the character before (or after) the caret.
+    fn delete(&mut self, forward: bool) {
+        if self.delete_selection() {
+            return;
+        }
+        if forward && self.caret < self.edit_buffer.len() {
+            self.edit_buffer.remove(self.caret);
+        } else if !forward && self.caret > 0 {
+            self.caret -= 1;
+            self.edit_buffer.remove(self.caret);
+        }
+    }
+
+    /// Moves the caret to `position`, extending the selection if `extend` is set.
+    fn move_caret(&mut self, position: usize, extend: bool) {
+        if extend {
+            self.selection_anchor.get_or_insert(self.caret);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.caret = position.min(self.edit_buffer.len());
+    }
+
+    /// Moves the caret one character left (`-1`) or right (`1`).
+    /// Without `extend`, a selection collapses to its start or end instead.
+    fn step_caret(&mut self, direction: isize, extend: bool) {
+        let position = match self.selection() {
+            Some(range) if !extend => {
+                if direction < 0 {
+                    range.start
+                } else {
+                    range.end
+                }
+            }
+            _ => self.caret.saturating_add_signed(direction),
+        };
+        self.move_caret(position, extend);
+    }
+
+    /// Selects the whole edit buffer.
+    fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.caret = self.edit_buffer.len();
+    }
+
+    /// The edit buffer as displayed while editing: the selection in brackets,
+    /// or else a `|` at the caret.
+    fn edit_display(&self) -> String {
+        let buffer = &self.edit_buffer;
+        match self.selection() {
+            Some(range) => format!(
+                "{}[{}]{}",
+                &buffer[..range.start],
+                &buffer[range.clone()],
+                &buffer[range.end..]
+            ),
+            None => format!("{}|{}", &buffer[..self.caret], &buffer[self.caret..]),
         }
     }
 }
@@ -131,58 +500,95 @@ pub struct DragValueChanged {
     pub field_path: FieldPath,
     /// The new value.
     pub new_value: f64,
+    /// The exact new value of a whole number typed in or nudged, which `new_value`
+    /// only approximates for 64-bit integers beyond 2^53.
+    pub exact_integer: Option<i128>,
+    /// Whether this change follows the change of a linked field, such as another axis
+    /// of a linked vector, rather than an edit of this field. It isn't passed on to linked fields.
+    pub linked: bool,
 }
 
-// Observer: handle click for double-click detection
-fn drag_value_on_click(
-    mut click: On<Pointer<Click>>,
-    mut q_drag_value: Query<(&DragValue, &mut DragValueDragState, &Children)>,
-    q_text: Query<&Text>,
-    mut input_focus: ResMut<InputFocus>,
-    mut commands: Commands,
-) {
-    if let Ok((drag_value, mut drag_state, children)) = q_drag_value.get_mut(click.entity) {
-        click.propagate(false);
+/// Returns the current value of the field edited by the [`DragValue`] `entity`,
+/// read from its component or resource rather than parsed from its rounded text.
+///
+/// The [`EulerAngleAxis`] drags of a quaternion read the angle around their axis, in degrees.
+fn drag_field_value(world: &World, entity: Entity) -> Option<f64> {
+    let field_path = &world.get::<DragValue>(entity)?.field_path;
+    let field =
+        reflected_root(world, field_path).and_then(|root| field_at_path(root, &field_path.path))?;
+    match world.get::<EulerAngleAxis>(entity) {
+        Some(&EulerAngleAxis(axis)) => {
+            quat_euler_degrees(field).map(|degrees| f64::from(degrees[axis]))
+        }
+        None => try_extract_numeric(field),
+    }
+}
 
-        let now = Instant::now();
+/// Returns the exact current value of the integer field edited by the [`DragValue`] `entity`,
+/// if it has an integer format, see [`drag_field_value`].
+fn drag_field_integer(world: &World, entity: Entity) -> Option<i128> {
+    let drag_value = world.get::<DragValue>(entity)?;
+    if drag_value.format == DragValueFormat::Float || world.get::<EulerAngleAxis>(entity).is_some()
+    {
+        return None;
+    }
+    let field_path = &drag_value.field_path;
+    let field =
+        reflected_root(world, field_path).and_then(|root| field_at_path(root, &field_path.path))?;
+    try_extract_exact_integer(field)
+}
 
-        // Check for double-click
-        let is_double_click = drag_state
-            .last_click_time
-            .map(|last| now.duration_since(last) < Duration::from_millis(DOUBLE_CLICK_THRESHOLD_MS))
-            .unwrap_or(false);
+// Observer: handle click for double-click detection
+fn drag_value_on_click(mut click: On<Pointer<Click>>, mut world: DeferredWorld) {
+    let entity = click.entity;
+    let (Some(drag_value), Some(drag_state)) = (
+        world.get::<DragValue>(entity),
+        world.get::<DragValueDragState>(entity),
+    ) else {
+        return;
+    };
+    click.propagate(false);
 
-        if is_double_click && !drag_state.editing {
-            // Enter edit mode
-            drag_state.editing = true;
+    let now = Instant::now();
 
-            // Get current value and populate edit buffer
-            let current_value = children
-                .iter()
-                .find_map(|child| {
-                    q_text
-                        .get(child)
-                        .ok()
-                        .and_then(|text| text.0.parse::<f64>().ok())
-                })
-                .unwrap_or(0.0);
+    // Check for double-click
+    let is_double_click = drag_state
+        .last_click_time
+        .map(|last| now.duration_since(last) < Duration::from_millis(DOUBLE_CLICK_THRESHOLD_MS))
+        .unwrap_or(false);
 
+    if is_double_click && !drag_state.editing {
+        // Enter edit mode, with the current value in the edit buffer
+        let current_value = drag_field_value(&world, entity).unwrap_or(0.0);
+        let current_integer = drag_field_integer(&world, entity);
+        let text = match current_integer {
+            Some(integer) => drag_value.format_integer(integer),
+            None => drag_value.format_value(current_value),
+        };
+        if let Some(mut drag_state) = world.get_mut::<DragValueDragState>(entity) {
             drag_state.original_value = current_value;
-            drag_state.edit_buffer = format!("{:.prec$}", current_value, prec = drag_value.precision);
-
-            // Set input focus to this widget
-            input_focus.set(click.entity);
+            drag_state.original_integer = current_integer;
+            drag_state.start_editing(text);
+            drag_state.last_click_time = None; // Reset to prevent triple-click
+        }
 
-            // Trigger a visual update to show the edit buffer (with cursor indicator)
-            commands.trigger(DragValueEditModeChanged {
-                entity: click.entity,
-                editing: true,
-            });
+        // Set input focus to this widget
+        world.resource_mut::<InputFocus>().set(entity);
 
-            drag_state.last_click_time = None; // Reset to prevent triple-click
-        } else {
+        // Trigger a visual update to show the edit buffer (with cursor indicator)
+        world.commands().trigger(DragValueEditModeChanged {
+            entity,
+            editing: true,
+        });
+    } else {
+        let editing = drag_state.editing;
+        if let Some(mut drag_state) = world.get_mut::<DragValueDragState>(entity) {
             drag_state.last_click_time = Some(now);
         }
+        // Focus for arrow key nudging
+        if !editing {
+            world.resource_mut::<InputFocus>().set(entity);
+        }
     }
 }
 
@@ -194,65 +600,87 @@ pub struct DragValueEditModeChanged {
 }
 
 // Observer: handle drag start (skip if in edit mode)
-fn drag_value_on_drag_start(
-    mut drag_start: On<Pointer<DragStart>>,
-    mut q_drag_value: Query<(&DragValue, &mut DragValueDragState, &Children)>,
-    q_text: Query<&Text>,
-) {
-    if let Ok((_drag_value, mut drag_state, children)) = q_drag_value.get_mut(drag_start.entity) {
-        // Skip dragging if in edit mode
-        if drag_state.editing {
-            return;
-        }
-
-        drag_start.propagate(false);
+fn drag_value_on_drag_start(mut drag_start: On<Pointer<DragStart>>, mut world: DeferredWorld) {
+    let entity = drag_start.entity;
+    let Some(drag_state) = world.get::<DragValueDragState>(entity) else {
+        return;
+    };
+    // Skip dragging if in edit mode
+    if drag_state.editing || !world.entity(entity).contains::<DragValue>() {
+        return;
+    }
 
-        // Get current value from Text child
-        let current_value = children
-            .iter()
-            .find_map(|child| {
-                q_text
-                    .get(child)
-                    .ok()
-                    .and_then(|text| text.0.parse::<f64>().ok())
-            })
-            .unwrap_or(0.0);
+    drag_start.propagate(false);
 
+    let current_value = drag_field_value(&world, entity).unwrap_or(0.0);
+    if let Some(mut drag_state) = world.get_mut::<DragValueDragState>(entity) {
         drag_state.dragging = true;
         drag_state.start_value = current_value;
+        drag_state.drag_value = current_value;
     }
 }
 
+/// Rounds `value` to the nearest multiple of `increment`.
+/// Non-positive increments leave the value unchanged.
+fn snap_value(value: f64, increment: f64) -> f64 {
+    if increment > 0.0 {
+        (value / increment).round() * increment
+    } else {
+        value
+    }
+}
+
+/// Adds `delta_x` pixels of horizontal drag movement to the drag,
+/// and returns the value it reaches, rounded to the format and clamped to the range.
+///
+/// The movement is faster while Shift is held and slower while Ctrl is held.
+/// While Alt is held, the value snaps to multiples of [`DragValue::snap`], if it has one.
+fn apply_drag(
+    drag_value: &DragValue,
+    drag_state: &mut DragValueDragState,
+    delta_x: f32,
+    keys: &ButtonInput<KeyCode>,
+) -> f64 {
+    // Accumulating the movement keeps the value from jumping when a modifier is pressed mid-drag
+    drag_state.drag_value += delta_x as f64 * drag_value.drag_speed * speed_multiplier(keys);
+    let mut new_value = drag_state.drag_value;
+
+    let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    if let Some(snap) = drag_value.snap.filter(|_| alt) {
+        new_value = snap_value(new_value, snap);
+    }
+    new_value = drag_value.round_to_format(new_value);
+
+    // Apply constraints
+    if let Some(min) = drag_value.min {
+        new_value = new_value.max(min);
+    }
+    if let Some(max) = drag_value.max {
+        new_value = new_value.min(max);
+    }
+    new_value
+}
+
 // Observer: handle drag
 fn drag_value_on_drag(
     mut drag: On<Pointer<Drag>>,
-    q_drag_value: Query<(&DragValue, &DragValueDragState)>,
+    mut q_drag_value: Query<(&DragValue, &mut DragValueDragState)>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
-    if let Ok((drag_value, drag_state)) = q_drag_value.get(drag.entity) {
+    if let Ok((drag_value, mut drag_state)) = q_drag_value.get_mut(drag.entity) {
         drag.propagate(false);
 
         if drag_state.dragging {
-            // Horizontal drag distance in pixels
-            let delta_x = drag.distance.x as f64;
-
-            // Calculate new value
-            let delta_value = delta_x * drag_value.drag_speed;
-            let mut new_value = drag_state.start_value + delta_value;
-
-            // Apply constraints
-            if let Some(min) = drag_value.min {
-                new_value = new_value.max(min);
-            }
-            if let Some(max) = drag_value.max {
-                new_value = new_value.min(max);
-            }
+            let new_value = apply_drag(drag_value, &mut drag_state, drag.delta.x, &keys);
 
             // Emit change event
             commands.trigger(DragValueChanged {
                 source: drag.entity,
                 field_path: drag_value.field_path.clone(),
                 new_value,
+                exact_integer: None,
+                linked: false,
             });
         }
     }
@@ -261,11 +689,23 @@ fn drag_value_on_drag(
 // Observer: handle drag end
 fn drag_value_on_drag_end(
     mut drag_end: On<Pointer<DragEnd>>,
-    mut q_drag_value: Query<&mut DragValueDragState>,
+    mut q_drag_value: Query<(&DragValue, &mut DragValueDragState)>,
+    mut commands: Commands,
 ) {
-    if let Ok(mut drag_state) = q_drag_value.get_mut(drag_end.entity) {
+    if let Ok((drag_value, mut drag_state)) = q_drag_value.get_mut(drag_end.entity) {
         drag_end.propagate(false);
         drag_state.dragging = false;
+
+        // Write the value held back during the drag
+        if let Some(new_value) = drag_state.release_value.take() {
+            commands.trigger(DragValueChanged {
+                source: drag_end.entity,
+                field_path: drag_value.field_path.clone(),
+                new_value,
+                exact_integer: None,
+                linked: false,
+            });
+        }
     }
 }
 
@@ -279,53 +719,164 @@ fn update_drag_value_display(
         // Find and update the Text child
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = format!("{:.prec$}", trigger.new_value, prec = drag_value.precision);
+                text.0 = match trigger.exact_integer {
+                    Some(integer) => drag_value.format_integer(integer),
+                    None => drag_value.format_value(trigger.new_value),
+                };
             }
         }
     }
 }
 
-/// Navigates a field path and sets the value using reflection.
-/// Returns true on success, false on failure.
-fn set_field_value_recursive(
-    reflected: &mut dyn PartialReflect,
+/// Navigates a field path and returns the reflected field it points to.
+/// Returns `None` if any segment of the path does not exist.
+pub(crate) fn field_at_path<'a>(
+    reflected: &'a dyn PartialReflect,
     path: &[FieldPathSegment],
-    new_value: f64,
-) -> bool {
-    if path.is_empty() {
-        // At the target field - try to set the value
-        return apply_value_to_partial_reflect(reflected, new_value);
+) -> Option<&'a dyn PartialReflect> {
+    let Some((segment, remaining)) = path.split_first() else {
+        return Some(reflected);
+    };
+
+    let field = match (reflected.reflect_ref(), segment) {
+        (ReflectRef::Struct(s), FieldPathSegment::Named(name)) => s.field(name),
+        (ReflectRef::TupleStruct(ts), FieldPathSegment::Index(idx)) => ts.field(*idx),
+        (ReflectRef::Tuple(t), FieldPathSegment::Index(idx)) => t.field(*idx),
+        (ReflectRef::List(l), FieldPathSegment::ListIndex(idx)) => l.get(*idx),
+        (ReflectRef::Array(a), FieldPathSegment::ListIndex(idx)) => a.get(*idx),
+        (ReflectRef::Enum(e), FieldPathSegment::Named(name)) => e.field(name),
+        (ReflectRef::Enum(e), FieldPathSegment::Index(idx)) => e.field_at(*idx),
+        (ReflectRef::Opaque(_), FieldPathSegment::Named(name)) => opaque_math_fields(reflected)?
+            .into_iter()
+            .find_map(|(field_name, value)| (field_name == name).then_some(value)),
+        _ => None,
+    }?;
+
+    field_at_path(field, remaining)
+}
+
+/// Mutable version of [`field_at_path`].
+fn field_at_path_mut<'a>(
+    reflected: &'a mut dyn PartialReflect,
+    path: &[FieldPathSegment],
+) -> Option<&'a mut dyn PartialReflect> {
+    let Some((segment, remaining)) = path.split_first() else {
+        return Some(reflected);
+    };
+
+    let field = match (reflected.reflect_mut(), segment) {
+        (ReflectMut::Struct(s), FieldPathSegment::Named(name)) => s.field_mut(name),
+        (ReflectMut::TupleStruct(ts), FieldPathSegment::Index(idx)) => ts.field_mut(*idx),
+        (ReflectMut::Tuple(t), FieldPathSegment::Index(idx)) => t.field_mut(*idx),
+        (ReflectMut::List(l), FieldPathSegment::ListIndex(idx)) => l.get_mut(*idx),
+        (ReflectMut::Array(a), FieldPathSegment::ListIndex(idx)) => a.get_mut(*idx),
+        (ReflectMut::Enum(e), FieldPathSegment::Named(name)) => e.field_mut(name),
+        (ReflectMut::Enum(e), FieldPathSegment::Index(idx)) => e.field_at_mut(*idx),
+        (ReflectMut::Opaque(o), FieldPathSegment::Named(name)) => opaque_math_field_mut(o, name),
+        _ => None,
+    }?;
+
+    field_at_path_mut(field, remaining)
+}
+
+/// Clones a reflected value into a box, preferring a concrete clone
+/// and falling back to a dynamic representation for types that cannot be cloned.
+pub(crate) fn clone_reflected(reflected: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    reflected
+        .reflect_clone()
+        .map(|value| value.into_partial_reflect())
+        .unwrap_or_else(|_| reflected.to_dynamic())
+}
+
+/// Returns the [`DragValueFormat`] a numeric reflected type is displayed with by default.
+pub(crate) fn numeric_format(reflected: &dyn PartialReflect) -> DragValueFormat {
+    if try_extract_integer(reflected).is_some() {
+        DragValueFormat::Integer
+    } else {
+        DragValueFormat::Float
     }
+}
 
-    let segment = &path[0];
-    let remaining = &path[1..];
+/// Returns whether the reflected type can be shown in hexadecimal,
+/// which is offered for unsigned integers since they often hold bitfields.
+pub(crate) fn supports_hex_display(reflected: &dyn PartialReflect) -> bool {
+    reflected.represents::<u8>()
+        || reflected.represents::<u16>()
+        || reflected.represents::<u32>()
+        || reflected.represents::<u64>()
+        || reflected.represents::<usize>()
+}
 
-    match reflected.reflect_mut() {
-        ReflectMut::Struct(s) => {
-            if let FieldPathSegment::Named(name) = segment {
-                if let Some(field) = s.field_mut(name) {
-                    return set_field_value_recursive(field, remaining, new_value);
-                }
+/// Returns the value of `$reflected` as `$out` if it is one of the listed integer types.
+macro_rules! extract_integer {
+    ($reflected:expr, $out:ty; $($int:ty),*) => {
+        $(
+            if let Some(val) = $reflected.try_downcast_ref::<$int>() {
+                return Some(*val as $out);
             }
-        }
-        ReflectMut::TupleStruct(ts) => {
-            if let FieldPathSegment::Index(idx) = segment {
-                if let Some(field) = ts.field_mut(*idx) {
-                    return set_field_value_recursive(field, remaining, new_value);
-                }
+        )*
+    };
+}
+
+/// Writes `$value` to `$reflected`, rounded, if it is one of the listed integer types.
+/// Float to integer casts saturate, so out-of-range values clamp to the type's bounds.
+macro_rules! apply_integer {
+    ($reflected:expr, $value:expr, $($int:ty),*) => {
+        $(
+            if let Some(val) = $reflected.try_downcast_mut::<$int>() {
+                *val = $value.round() as $int;
+                return true;
             }
-        }
-        ReflectMut::Tuple(t) => {
-            if let FieldPathSegment::Index(idx) = segment {
-                if let Some(field) = t.field_mut(*idx) {
-                    return set_field_value_recursive(field, remaining, new_value);
-                }
+        )*
+    };
+}
+
+/// Writes `$value`, an i128, exactly to `$reflected` if it is one of the listed integer types.
+/// Out-of-range values clamp to the type's bounds.
+macro_rules! apply_exact_integer {
+    ($reflected:expr, $value:expr, $($int:ty),*) => {
+        $(
+            if let Some(val) = $reflected.try_downcast_mut::<$int>() {
+                *val = <$int>::try_from($value)
+                    .unwrap_or(if $value < 0 { <$int>::MIN } else { <$int>::MAX });
+                return true;
             }
-        }
-        _ => {}
-    }
+        )*
+    };
+}
 
-    false
+/// Tries to extract the value of a reflected integer of any width as f64.
+fn try_extract_integer(reflected: &dyn PartialReflect) -> Option<f64> {
+    extract_integer!(reflected, f64; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+/// Tries to extract the exact value of a reflected integer of any width.
+pub(crate) fn try_extract_exact_integer(reflected: &dyn PartialReflect) -> Option<i128> {
+    extract_integer!(reflected, i128; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+/// Tries to extract a numeric value from a reflected type.
+/// Returns the value as f64 if it's a supported numeric type.
+pub(crate) fn try_extract_numeric(reflected: &dyn PartialReflect) -> Option<f64> {
+    // Try f32
+    if let Some(val) = reflected.try_downcast_ref::<f32>() {
+        return Some(*val as f64);
+    }
+    // Try f64
+    if let Some(val) = reflected.try_downcast_ref::<f64>() {
+        return Some(*val);
+    }
+    // Try integers, from i8 to usize
+    if let Some(val) = try_extract_integer(reflected) {
+        return Some(val);
+    }
+    // Durations are edited in seconds
+    if let Some(val) = reflected.try_downcast_ref::<Duration>() {
+        return Some(val.as_secs_f64());
+    }
+    None
 }
 
 /// Applies a numeric value to a reflected field.
@@ -342,50 +893,418 @@ fn apply_value_to_partial_reflect(reflected: &mut dyn PartialReflect, new_value:
         return true;
     }
 
-    // Try to apply to i32
-    if let Some(i32_val) = reflected.try_downcast_mut::<i32>() {
-        *i32_val = new_value as i32;
+    // Try to apply to Duration, in seconds
+    if let Some(duration) = reflected.try_downcast_mut::<Duration>() {
+        *duration = Duration::try_from_secs_f64(new_value.max(0.0)).unwrap_or(Duration::MAX);
         return true;
     }
 
-    // Try to apply to i64
-    if let Some(i64_val) = reflected.try_downcast_mut::<i64>() {
-        *i64_val = new_value as i64;
-        return true;
+    // Try to apply to an integer of any width
+    apply_integer!(
+        reflected, new_value, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+    );
+
+    false
+}
+
+/// Applies a whole number to a reflected field, exactly if the field is an integer.
+fn apply_integer_to_partial_reflect(reflected: &mut dyn PartialReflect, new_value: i128) -> bool {
+    apply_exact_integer!(
+        reflected, new_value, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+    );
+    apply_value_to_partial_reflect(reflected, new_value as f64)
+}
+
+/// Event triggered after the inspector successfully wrote a new value to a component field.
+///
+/// Observe this to react to inspector edits, e.g. to recompute derived caches:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::inspector::InspectorFieldEdited;
+/// # let mut app = App::new();
+/// app.add_observer(|edit: On<InspectorFieldEdited>| {
+///     info!("{:?} changed from {:?} to {:?}", edit.path, edit.old_value, edit.new_value);
+/// });
+/// ```
+#[derive(Event, Debug)]
+pub struct InspectorFieldEdited {
+    /// The entity containing the edited component, or [`FieldOwner::Resource`] for a resource.
+    pub owner: FieldOwner,
+    /// The TypeId of the edited component or resource.
+    pub component_type_id: TypeId,
+    /// The path segments to the edited field.
+    pub path: Vec<FieldPathSegment>,
+    /// The value of the field before the edit.
+    pub old_value: Box<dyn PartialReflect>,
+    /// The value of the field after the edit.
+    pub new_value: Box<dyn PartialReflect>,
+}
+
+/// A value waiting to be written to a component field.
+#[derive(Debug)]
+pub enum FieldValue {
+    /// A number, converted to the field's numeric type on write.
+    Numeric(f64),
+    /// A whole number, written exactly to integer fields, unlike [`FieldValue::Numeric`]
+    /// which rounds 64-bit integers beyond 2^53.
+    Integer(i128),
+    /// An arbitrary reflected value, applied with [`PartialReflect::try_apply`].
+    Reflected(Box<dyn PartialReflect>),
+    /// An earlier value of the field, replacing it entirely.
+    /// Unlike [`FieldValue::Reflected`], this also shrinks lists and maps back to their old length.
+    Restore(Box<dyn PartialReflect>),
+    /// A structural change to a list field.
+    ListEdit(ListEdit),
+    /// A member inserted into or removed from a set field.
+    SetEdit(SetEdit),
+    /// Sets (or clears) the bits of `mask` in an integer field, leaving its other bits as they are.
+    Flags { mask: u64, set: bool },
+}
+
+impl FieldValue {
+    /// Copies the value, e.g. to write it to the same field of several entities.
+    fn duplicate(&self) -> Self {
+        match self {
+            Self::Numeric(value) => Self::Numeric(*value),
+            Self::Integer(value) => Self::Integer(*value),
+            Self::Reflected(value) => Self::Reflected(clone_reflected(value.as_ref())),
+            Self::Restore(value) => Self::Restore(clone_reflected(value.as_ref())),
+            Self::ListEdit(ListEdit::Push(element)) => {
+                Self::ListEdit(ListEdit::Push(clone_reflected(element.as_ref())))
+            }
+            Self::ListEdit(ListEdit::Remove(index)) => Self::ListEdit(ListEdit::Remove(*index)),
+            Self::ListEdit(ListEdit::Move { from, to }) => Self::ListEdit(ListEdit::Move {
+                from: *from,
+                to: *to,
+            }),
+            Self::SetEdit(SetEdit::Insert(member)) => {
+                Self::SetEdit(SetEdit::Insert(clone_reflected(member.as_ref())))
+            }
+            Self::SetEdit(SetEdit::Remove(member)) => {
+                Self::SetEdit(SetEdit::Remove(clone_reflected(member.as_ref())))
+            }
+            Self::Flags { mask, set } => Self::Flags {
+                mask: *mask,
+                set: *set,
+            },
+        }
     }
+}
 
-    // Try to apply to u32
-    if let Some(u32_val) = reflected.try_downcast_mut::<u32>() {
-        *u32_val = new_value.max(0.0) as u32;
-        return true;
+/// Returns the bits of an integer field, or `None` if it is not an integer.
+pub(crate) fn try_extract_bits(reflected: &dyn PartialReflect) -> Option<u64> {
+    if let Some(value) = reflected.try_downcast_ref::<u8>() {
+        return Some(*value as u64);
     }
+    if let Some(value) = reflected.try_downcast_ref::<u16>() {
+        return Some(*value as u64);
+    }
+    if let Some(value) = reflected.try_downcast_ref::<u32>() {
+        return Some(*value as u64);
+    }
+    if let Some(value) = reflected.try_downcast_ref::<u64>() {
+        return Some(*value);
+    }
+    if let Some(value) = reflected.try_downcast_ref::<usize>() {
+        return Some(*value as u64);
+    }
+    if let Some(value) = reflected.try_downcast_ref::<i32>() {
+        return Some(*value as u32 as u64);
+    }
+    if let Some(value) = reflected.try_downcast_ref::<i64>() {
+        return Some(*value as u64);
+    }
+    None
+}
 
-    // Try to apply to u64
-    if let Some(u64_val) = reflected.try_downcast_mut::<u64>() {
-        *u64_val = new_value.max(0.0) as u64;
-        return true;
+/// Sets or clears the bits of `mask` in the integer `field`. Returns whether it is an integer.
+fn apply_flags(field: &mut dyn PartialReflect, mask: u64, set: bool) -> bool {
+    let Some(bits) = try_extract_bits(field) else {
+        return false;
+    };
+    let bits = if set { bits | mask } else { bits & !mask };
+    if let Some(value) = field.try_downcast_mut::<u8>() {
+        *value = bits as u8;
+    } else if let Some(value) = field.try_downcast_mut::<u16>() {
+        *value = bits as u16;
+    } else if let Some(value) = field.try_downcast_mut::<u32>() {
+        *value = bits as u32;
+    } else if let Some(value) = field.try_downcast_mut::<u64>() {
+        *value = bits;
+    } else if let Some(value) = field.try_downcast_mut::<usize>() {
+        *value = bits as usize;
+    } else if let Some(value) = field.try_downcast_mut::<i32>() {
+        *value = bits as u32 as i32;
+    } else if let Some(value) = field.try_downcast_mut::<i64>() {
+        *value = bits as i64;
     }
+    true
+}
 
-    false
+/// Replaces `field` with `value`, falling back to [`PartialReflect::try_apply`]
+/// for values that can't be cloned into a concrete type. Returns whether it was replaced.
+fn restore_value(field: &mut dyn PartialReflect, value: &dyn PartialReflect) -> bool {
+    if let Some(field) = field.try_as_reflect_mut()
+        && let Ok(value) = value.reflect_clone()
+    {
+        return field.set(value).is_ok();
+    }
+    field.try_apply(value).is_ok()
+}
+
+/// A structural change to a list field, such as a [`Vec`].
+#[derive(Debug)]
+pub enum ListEdit {
+    /// Appends an element to the end of the list.
+    Push(Box<dyn PartialReflect>),
+    /// Removes the element at the given index.
+    Remove(usize),
+    /// Moves the element at index `from` to index `to`, shifting the elements in between.
+    Move { from: usize, to: usize },
+}
+
+/// Applies `edit` to `field` if it is a list and the indices are in bounds.
+/// Returns whether the edit was applied.
+fn apply_list_edit(field: &mut dyn PartialReflect, edit: &ListEdit) -> bool {
+    let ReflectMut::List(list) = field.reflect_mut() else {
+        return false;
+    };
+    match edit {
+        ListEdit::Push(element) => list.push(element.to_dynamic()),
+        ListEdit::Remove(index) if *index < list.len() => {
+            list.remove(*index);
+        }
+        ListEdit::Move { from, to } if *from < list.len() && *to < list.len() => {
+            let element = list.remove(*from);
+            list.insert(*to, element);
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// A structural change to a set field, such as a [`HashSet`](std::collections::HashSet).
+#[derive(Debug)]
+pub enum SetEdit {
+    /// Inserts a member, leaving the set unchanged if it is already present.
+    Insert(Box<dyn PartialReflect>),
+    /// Removes a member.
+    Remove(Box<dyn PartialReflect>),
+}
+
+/// Applies `edit` to `field` if it is a set.
+/// Returns whether the edit was applied, which for removals means the member was present.
+fn apply_set_edit(field: &mut dyn PartialReflect, edit: &SetEdit) -> bool {
+    let ReflectMut::Set(set) = field.reflect_mut() else {
+        return false;
+    };
+    match edit {
+        SetEdit::Insert(member) => {
+            set.insert_boxed(clone_reflected(member.as_ref()));
+            true
+        }
+        SetEdit::Remove(member) => set.remove(member.as_ref()),
+    }
+}
+
+/// A queued write to a component field.
+#[derive(Debug)]
+pub struct PendingValueChange {
+    /// The field to write to.
+    pub field_path: FieldPath,
+    /// The value to write.
+    pub value: FieldValue,
 }
 
 /// Resource to queue value changes for the write-back system
 #[derive(Resource, Default)]
 pub struct PendingValueChanges {
-    pub changes: Vec<DragValueChanged>,
+    pub changes: Vec<PendingValueChange>,
+    /// Copies of changes for the other entities of a batch edit, see [`Self::push_batched`].
+    batched: Vec<PendingValueChange>,
 }
 
-/// Observer that queues value changes for later processing
-fn queue_value_change(trigger: On<DragValueChanged>, mut pending: ResMut<PendingValueChanges>) {
-    pending.changes.push(trigger.event().clone());
+/// Pushes `change` to `changes`, replacing a queued change it supersedes,
+/// see [`PendingValueChanges::push`].
+fn push_coalesced(changes: &mut Vec<PendingValueChange>, change: PendingValueChange) {
+    let replaces = |queued: &FieldValue| match (&change.value, queued) {
+        (
+            FieldValue::Numeric(_) | FieldValue::Integer(_),
+            FieldValue::Numeric(_) | FieldValue::Integer(_),
+        ) => true,
+        (FieldValue::Reflected(new), FieldValue::Reflected(queued))
+        | (FieldValue::Restore(new), FieldValue::Restore(queued)) => {
+            new.reflect_type_path() == queued.reflect_type_path()
+        }
+        _ => false,
+    };
+    if let Some(index) = changes
+        .iter()
+        .rposition(|queued| queued.field_path == change.field_path)
+        && replaces(&changes[index].value)
+    {
+        // Move the write to the end, so it still follows other queued writes
+        changes.remove(index);
+    }
+    changes.push(change);
 }
 
-/// Observer: handle keyboard input during text edit mode
+impl PendingValueChanges {
+    /// Queues `change`, replacing any queued value of the same kind for the same field,
+    /// so dragging a value only writes it once per frame.
+    ///
+    /// Relative changes ([`FieldValue::ListEdit`], [`FieldValue::SetEdit`]
+    /// and [`FieldValue::Flags`]) depend on the changes before them, so they are never coalesced.
+    /// Neither are reflected values of different types, since either may fail to apply.
+    pub fn push(&mut self, change: PendingValueChange) {
+        push_coalesced(&mut self.changes, change);
+    }
+
+    /// Queues `change`, and a copy of it for each of `entities`,
+    /// so a field shared by several selected entities is edited on all of them at once.
+    ///
+    /// The copies are only written to entities whose component has the same field;
+    /// the others are skipped without a warning.
+    pub fn push_batched(&mut self, change: PendingValueChange, entities: &[Entity]) {
+        for &entity in entities {
+            if FieldOwner::Entity(entity) != change.field_path.owner {
+                let copy = PendingValueChange {
+                    field_path: change.field_path.with_entity(entity),
+                    value: change.value.duplicate(),
+                };
+                push_coalesced(&mut self.batched, copy);
+            }
+        }
+        self.push(change);
+    }
+}
+
+/// Returns the component or resource containing the field at `field_path`,
+/// if it exists and is reflected.
+pub fn reflected_root<'w>(
+    world: &'w World,
+    field_path: &FieldPath,
+) -> Option<&'w dyn PartialReflect> {
+    match field_path.owner {
+        FieldOwner::Resource => {
+            get_reflected_resource_ref(world, field_path.component_type_id).ok()
+        }
+        FieldOwner::Entity(entity) if world.entities().contains(entity) => {
+            get_reflected_component_ref(world, entity, field_path.component_type_id).ok()
+        }
+        FieldOwner::Entity(_) => None,
+    }
+}
+
+/// Returns mutable access to the component or resource containing the field at `field_path`,
+/// if it exists and is reflected.
+pub fn reflected_root_mut<'w>(
+    world: &'w mut World,
+    field_path: &FieldPath,
+) -> Option<Mut<'w, dyn Reflect>> {
+    match field_path.owner {
+        FieldOwner::Resource => {
+            get_reflected_resource_mut(world, field_path.component_type_id).ok()
+        }
+        FieldOwner::Entity(entity) if world.entities().contains(entity) => {
+            get_reflected_component_mut(world, entity, field_path.component_type_id).ok()
+        }
+        FieldOwner::Entity(_) => None,
+    }
+}
+
+/// Whether the field at `field_path` exists.
+fn has_field(world: &World, field_path: &FieldPath) -> bool {
+    reflected_root(world, field_path)
+        .and_then(|root| field_at_path(root, &field_path.path))
+        .is_some()
+}
+
+/// Observer that queues value changes for later processing,
+/// for every selected entity if the edited entity is part of a multi-entity selection.
+///
+/// While dragging a field of a component whose [`ComponentDisplayOverrides`] enable
+/// `commit_on_release`, the value is only written when the drag ends.
+fn queue_value_change(
+    trigger: On<DragValueChanged>,
+    mut pending: ResMut<PendingValueChanges>,
+    mut drag_states: Query<&mut DragValueDragState>,
+    euler_angles: Query<(), With<EulerAngleAxis>>,
+    overrides: Option<Res<ComponentDisplayOverrides>>,
+    state: Option<Res<InspectorState>>,
+) {
+    // Euler angles are written as whole quaternions, see `spawn_euler_angle_drag`
+    if euler_angles.contains(trigger.source) {
+        return;
+    }
+    let commit_on_release = overrides.is_some_and(|overrides| {
+        overrides
+            .get(trigger.field_path.component_type_id)
+            .commit_on_release
+    });
+    if commit_on_release
+        && let Ok(mut drag_state) = drag_states.get_mut(trigger.source)
+        && drag_state.dragging
+    {
+        drag_state.release_value = Some(trigger.new_value);
+        return;
+    }
+
+    let edit = PendingValueChange {
+        field_path: trigger.field_path.clone(),
+        value: trigger
+            .exact_integer
+            .map_or(FieldValue::Numeric(trigger.new_value), FieldValue::Integer),
+    };
+    let targets = state.as_ref().map_or(&[][..], |state| {
+        state.field_edit_targets(&trigger.field_path)
+    });
+    pending.push_batched(edit, targets);
+}
+
+/// The drag speed multiplier of the held modifier keys: faster with Shift, slower with Ctrl.
+fn speed_multiplier(keys: &ButtonInput<KeyCode>) -> f64 {
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        FAST_MULTIPLIER
+    } else if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        SLOW_MULTIPLIER
+    } else {
+        1.0
+    }
+}
+
+/// Returns `value` nudged by one drag step in `direction` (`1.0` or `-1.0`),
+/// scaled by `multiplier` and clamped to the drag value's range.
+fn nudge_value(drag_value: &DragValue, value: f64, direction: f64, multiplier: f64) -> f64 {
+    let mut step = drag_value.drag_speed * multiplier;
+    if drag_value.format != DragValueFormat::Float {
+        // Integers move by at least one per key press
+        step = step.round().max(1.0);
+    }
+    let mut nudged = drag_value.round_to_format(value + direction * step);
+    if let Some(min) = drag_value.min {
+        nudged = nudged.max(min);
+    }
+    if let Some(max) = drag_value.max {
+        nudged = nudged.min(max);
+    }
+    nudged
+}
+
+/// Nudges the whole number `value` like [`nudge_value`], without going through f64.
+fn nudge_integer(drag_value: &DragValue, value: i128, direction: f64, multiplier: f64) -> i128 {
+    let step = (drag_value.drag_speed * multiplier).round().max(1.0) as i128;
+    drag_value.clamp_integer(value.saturating_add(direction as i128 * step))
+}
+
+/// Observer: handle keyboard input while focused, for nudging and text edit mode
 fn drag_value_on_keyboard_input(
     trigger: On<FocusedInput<KeyboardInput>>,
     mut q_drag_value: Query<(&DragValue, &mut DragValueDragState, &Children)>,
     mut q_text: Query<&mut Text>,
     mut input_focus: ResMut<InputFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
     // Only process key presses
@@ -393,19 +1312,49 @@ fn drag_value_on_keyboard_input(
         return;
     }
 
-    // Check if the focused entity is a DragValue in edit mode
+    // Check if the focused entity is a DragValue
     let entity = trigger.focused_entity;
     if let Ok((drag_value, mut drag_state, children)) = q_drag_value.get_mut(entity) {
+        // Outside of edit mode, the arrow keys nudge the value
         if !drag_state.editing {
+            let direction = match &trigger.input.logical_key {
+                Key::ArrowUp => 1.0,
+                Key::ArrowDown => -1.0,
+                Key::Escape => {
+                    input_focus.clear();
+                    return;
+                }
+                _ => return,
+            };
+            let multiplier = speed_multiplier(&keys);
+            let drag_value = drag_value.clone();
+            commands.queue(move |world: &mut World| {
+                let Some(current_value) = drag_field_value(world, entity) else {
+                    return;
+                };
+                let exact_integer = drag_field_integer(world, entity)
+                    .map(|current| nudge_integer(&drag_value, current, direction, multiplier));
+                world.trigger(DragValueChanged {
+                    source: entity,
+                    field_path: drag_value.field_path.clone(),
+                    new_value: nudge_value(&drag_value, current_value, direction, multiplier),
+                    exact_integer,
+                    linked: false,
+                });
+            });
             return;
         }
 
         match &trigger.input.logical_key {
             Key::Enter => {
-                // Commit the value
-                if let Ok(new_value) = drag_state.edit_buffer.parse::<f64>() {
+                // Commit the value, parsing whole numbers exactly
+                let exact_integer = (drag_value.format != DragValueFormat::Float)
+                    .then(|| parse_drag_integer(&drag_state.edit_buffer))
+                    .flatten()
+                    .map(|integer| drag_value.clamp_integer(integer));
+                if let Some(new_value) = parse_drag_value(&drag_state.edit_buffer) {
                     // Apply min/max constraints
-                    let mut constrained_value = new_value;
+                    let mut constrained_value = drag_value.round_to_format(new_value);
                     if let Some(min) = drag_value.min {
                         constrained_value = constrained_value.max(min);
                     }
@@ -418,6 +1367,8 @@ fn drag_value_on_keyboard_input(
                         source: entity,
                         field_path: drag_value.field_path.clone(),
                         new_value: constrained_value,
+                        exact_integer,
+                        linked: false,
                     });
                 }
 
@@ -426,23 +1377,36 @@ fn drag_value_on_keyboard_input(
             }
             Key::Escape => {
                 // Revert to original value
+                let original_text = drag_state.original_text(drag_value);
                 for child in children.iter() {
                     if let Ok(mut text) = q_text.get_mut(child) {
-                        text.0 = format!(
-                            "{:.prec$}",
-                            drag_state.original_value,
-                            prec = drag_value.precision
-                        );
+                        text.0 = original_text.clone();
                     }
                 }
 
                 // Exit edit mode
                 exit_edit_mode(&mut drag_state, &mut input_focus, entity, &mut commands);
             }
-            Key::Backspace => {
-                // Remove last character
-                drag_state.edit_buffer.pop();
-                update_edit_display(&drag_state.edit_buffer, &children, &mut q_text);
+            Key::Backspace | Key::Delete => {
+                drag_state.delete(trigger.input.logical_key == Key::Delete);
+                update_edit_display(&drag_state, children, &mut q_text);
+            }
+            Key::ArrowLeft | Key::ArrowRight | Key::Home | Key::End => {
+                let extend = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+                match &trigger.input.logical_key {
+                    Key::ArrowLeft => drag_state.step_caret(-1, extend),
+                    Key::ArrowRight => drag_state.step_caret(1, extend),
+                    Key::Home => drag_state.move_caret(0, extend),
+                    _ => drag_state.move_caret(usize::MAX, extend),
+                }
+                update_edit_display(&drag_state, children, &mut q_text);
+            }
+            Key::Character(c)
+                if c.eq_ignore_ascii_case("a")
+                    && keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) =>
+            {
+                drag_state.select_all();
+                update_edit_display(&drag_state, children, &mut q_text);
             }
             Key::Character(c) => {
                 // Only allow numeric characters, decimal point, and minus sign
@@ -453,10 +1417,12 @@ fn drag_value_on_keyboard_input(
                         || ch == 'e'
                         || ch == 'E'
                         || ch == '+'
+                        || (drag_value.format == DragValueFormat::Hex
+                            && (ch.is_ascii_hexdigit() || ch == 'x' || ch == 'X'))
                 });
                 if valid {
-                    drag_state.edit_buffer.push_str(c);
-                    update_edit_display(&drag_state.edit_buffer, &children, &mut q_text);
+                    drag_state.insert(c);
+                    update_edit_display(&drag_state, children, &mut q_text);
                 }
             }
             _ => {}
@@ -473,6 +1439,8 @@ fn exit_edit_mode(
 ) {
     drag_state.editing = false;
     drag_state.edit_buffer.clear();
+    drag_state.caret = 0;
+    drag_state.selection_anchor = None;
     input_focus.clear();
     commands.trigger(DragValueEditModeChanged {
         entity,
@@ -481,65 +1449,117 @@ fn exit_edit_mode(
 }
 
 /// Helper: update the text display during editing
-fn update_edit_display(buffer: &str, children: &Children, q_text: &mut Query<&mut Text>) {
+fn update_edit_display(
+    drag_state: &DragValueDragState,
+    children: &Children,
+    q_text: &mut Query<&mut Text>,
+) {
     for child in children.iter() {
         if let Ok(mut text) = q_text.get_mut(child) {
-            // Show edit buffer with cursor indicator
-            text.0 = format!("{}|", buffer);
+            // Show edit buffer with caret or selection
+            text.0 = drag_state.edit_display();
         }
     }
 }
 
-/// Observer: handle edit mode visual changes
+/// Observer: show the edit buffer with its caret when edit mode starts.
+///
+/// Leaving edit mode needs no update here: [`update_drag_value_display`] shows a committed
+/// value, and a cancelled edit restores the original text itself.
 fn update_edit_mode_display(
     trigger: On<DragValueEditModeChanged>,
-    q_drag_value: Query<(&DragValue, &DragValueDragState, &Children)>,
+    q_drag_value: Query<(&DragValueDragState, &Children)>,
     mut q_text: Query<&mut Text>,
 ) {
-    if let Ok((drag_value, drag_state, children)) = q_drag_value.get(trigger.entity) {
-        for child in children.iter() {
-            if let Ok(mut text) = q_text.get_mut(child) {
-                if trigger.editing {
-                    // Show edit buffer with cursor
-                    text.0 = format!("{}|", drag_state.edit_buffer);
-                } else {
-                    // Show formatted value
-                    if let Ok(val) = drag_state.edit_buffer.parse::<f64>() {
-                        text.0 = format!("{:.prec$}", val, prec = drag_value.precision);
-                    }
-                }
-            }
-        }
+    if !trigger.editing {
+        return;
+    }
+    if let Ok((drag_state, children)) = q_drag_value.get(trigger.entity) {
+        update_edit_display(drag_state, children, &mut q_text);
+    }
+}
+
+/// Highlights the border of the drag value that has the input focus.
+fn highlight_focused_drag_value(
+    input_focus: Res<InputFocus>,
+    mut q_drag_value: Query<(Entity, &mut BorderColor), With<DragValue>>,
+) {
+    if !input_focus.is_changed() {
+        return;
+    }
+    for (entity, mut border) in &mut q_drag_value {
+        let color = if input_focus.get() == Some(entity) {
+            FOCUSED_BORDER_COLOR
+        } else {
+            BORDER_COLOR
+        };
+        *border = BorderColor::all(color);
     }
 }
 
 /// Exclusive system that writes queued value changes back to ECS components.
 pub fn apply_pending_value_changes(world: &mut World) {
     // Take pending changes to avoid borrow issues
-    let changes = {
+    let (mut changes, batched) = {
         let mut pending = world.resource_mut::<PendingValueChanges>();
-        std::mem::take(&mut pending.changes)
+        (
+            std::mem::take(&mut pending.changes),
+            std::mem::take(&mut pending.batched),
+        )
     };
+    changes.extend(
+        batched
+            .into_iter()
+            .filter(|change| has_field(world, &change.field_path)),
+    );
 
     for change in changes {
-        let field_path = &change.field_path;
-
-        // Get mutable access to the component and apply the change
-        if let Ok(mut reflected) =
-            get_reflected_component_mut(world, field_path.entity, field_path.component_type_id)
+        let field_path = change.field_path;
+        if let FieldOwner::Entity(entity) = field_path.owner
+            && !world.entities().contains(entity)
         {
-            let success = set_field_value_recursive(
-                reflected.bypass_change_detection().as_partial_reflect_mut(),
-                &field_path.path,
-                change.new_value,
+            warn!(
+                "Failed to set field value at path `{}`: entity {:?} no longer exists",
+                format_field_path(&field_path.path),
+                entity
             );
-            if !success {
-                warn!(
-                    "Failed to set field value at path {:?} for entity {:?}",
-                    field_path.path, field_path.entity
-                );
-            }
+            continue;
         }
+
+        // Get mutable access to the component or resource and apply the change,
+        // capturing the field's value before and after the edit
+        let edited = reflected_root_mut(world, &field_path).and_then(|mut reflected| {
+            let component = reflected.bypass_change_detection().as_partial_reflect_mut();
+            let field = field_at_path_mut(component, &field_path.path)?;
+            let old_value = clone_reflected(field);
+            let applied = match &change.value {
+                FieldValue::Numeric(value) => apply_value_to_partial_reflect(field, *value),
+                FieldValue::Integer(value) => apply_integer_to_partial_reflect(field, *value),
+                FieldValue::Reflected(value) => field.try_apply(value.as_ref()).is_ok(),
+                FieldValue::Restore(value) => restore_value(field, value.as_ref()),
+                FieldValue::ListEdit(edit) => apply_list_edit(field, edit),
+                FieldValue::SetEdit(edit) => apply_set_edit(field, edit),
+                FieldValue::Flags { mask, set } => apply_flags(field, *mask, *set),
+            };
+            applied.then(|| (old_value, clone_reflected(field)))
+        });
+
+        let Some((old_value, new_value)) = edited else {
+            warn!(
+                "Failed to set field value at path `{}` of {:?}",
+                format_field_path(&field_path.path),
+                field_path.owner
+            );
+            continue;
+        };
+
+        world.trigger(InspectorFieldEdited {
+            owner: field_path.owner,
+            component_type_id: field_path.component_type_id,
+            path: field_path.path,
+            old_value,
+            new_value,
+        });
     }
 }
 
@@ -562,6 +1582,469 @@ impl Plugin for DragValuePlugin {
             .add_observer(update_edit_mode_display)
             // Value change processing
             .add_observer(queue_value_change)
-            .add_systems(Update, apply_pending_value_changes);
+            .add_systems(
+                Update,
+                (highlight_focused_drag_value, apply_pending_value_changes),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_path_round_trip() {
+        for path in ["translation.x", "[2].value", ".0.1", "points[3].0"] {
+            let segments = parse_field_path(path).unwrap();
+            let formatted = format_field_path(&segments);
+            assert_eq!(parse_field_path(&formatted).unwrap(), segments);
+        }
+
+        assert_eq!(
+            parse_field_path("translation.x").unwrap(),
+            vec![
+                FieldPathSegment::Named("translation".to_string()),
+                FieldPathSegment::Named("x".to_string()),
+            ]
+        );
+        assert_eq!(
+            format_field_path(&[
+                FieldPathSegment::ListIndex(2),
+                FieldPathSegment::Named("value".to_string()),
+            ]),
+            "[2].value"
+        );
+        assert!(matches!(
+            parse_field_path("bar#0"),
+            Err(FieldPathParseError::FieldIndexUnsupported(0))
+        ));
+    }
+
+    #[test]
+    fn parsed_path_reads_field() {
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let field_path = FieldPath::new::<Transform>(
+            Entity::PLACEHOLDER,
+            parse_field_path("translation.y").unwrap(),
+        );
+        let parsed_path = field_path.parsed_path();
+        assert_eq!(parsed_path.element::<f32>(&transform).ok(), Some(&2.0));
+    }
+
+    #[test]
+    fn nudging_steps_by_drag_speed_within_range() {
+        let drag_value = DragValue {
+            field_path: DragValueProps::default().field_path,
+            drag_speed: 0.5,
+            precision: 2,
+            min: Some(0.0),
+            max: Some(10.0),
+            snap: None,
+            format: DragValueFormat::Float,
+        };
+        assert_eq!(nudge_value(&drag_value, 1.0, 1.0, 1.0), 1.5);
+        assert_eq!(nudge_value(&drag_value, 1.0, -1.0, FAST_MULTIPLIER), 0.0);
+        assert_eq!(nudge_value(&drag_value, 9.9, 1.0, 1.0), 10.0);
+        assert_eq!(nudge_value(&drag_value, 1.0, 1.0, SLOW_MULTIPLIER), 1.05);
+    }
+
+    #[test]
+    fn ctrl_slows_drags_and_alt_snaps_them() {
+        let mut drag_value = DragValue {
+            field_path: DragValueProps::default().field_path,
+            drag_speed: 0.1,
+            precision: 2,
+            min: None,
+            max: None,
+            snap: crate::inspector::InspectorConfig::default().drag_snap_increment,
+            format: DragValueFormat::Float,
+        };
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::ControlLeft);
+        let mut drag_state = DragValueDragState::default();
+        let value = apply_drag(&drag_value, &mut drag_state, 10.0, &keys);
+        assert!((value - 10.0 * 0.1 * SLOW_MULTIPLIER).abs() < 1e-9);
+
+        // Ctrl still slows the drag when the value has a snap increment
+        drag_value.snap = Some(0.5);
+        let mut drag_state = DragValueDragState::default();
+        let value = apply_drag(&drag_value, &mut drag_state, 10.0, &keys);
+        assert!((value - 10.0 * 0.1 * SLOW_MULTIPLIER).abs() < 1e-9);
+
+        keys.release(KeyCode::ControlLeft);
+        keys.press(KeyCode::AltLeft);
+        let mut drag_state = DragValueDragState::default();
+        assert_eq!(apply_drag(&drag_value, &mut drag_state, 4.0, &keys), 0.5);
+        assert_eq!(apply_drag(&drag_value, &mut drag_state, 4.0, &keys), 1.0);
+    }
+
+    #[test]
+    fn drags_read_the_field_instead_of_its_rounded_text() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+        let rotation = Quat::from_rotation_y(30f32.to_radians());
+        let transform = Transform::from_xyz(1.23456, 0.0, 0.0).with_rotation(rotation);
+        let target = world.spawn(transform).id();
+        let drag = |path: &str| DragValue {
+            field_path: FieldPath::new::<Transform>(target, parse_field_path(path).unwrap()),
+            drag_speed: 0.1,
+            precision: 1,
+            min: None,
+            max: None,
+            snap: None,
+            format: DragValueFormat::Float,
+        };
+        let x = world.spawn((drag("translation.x"), Text::new("1.2"))).id();
+        let yaw = world.spawn((drag("rotation"), EulerAngleAxis(1))).id();
+
+        assert_eq!(drag_field_value(&world, x), Some(f64::from(1.23456f32)));
+        let degrees = drag_field_value(&world, yaw).unwrap();
+        assert!((degrees - 30.0).abs() < 1e-4);
+        world.despawn(target);
+        assert_eq!(drag_field_value(&world, x), None);
+    }
+
+    #[test]
+    fn integers_step_whole_units_and_display_in_hex() {
+        let mut drag_value = DragValue {
+            field_path: DragValueProps::default().field_path,
+            drag_speed: 0.1,
+            precision: 2,
+            min: None,
+            max: None,
+            snap: None,
+            format: numeric_format(&42_i32),
+        };
+        assert_eq!(drag_value.format, DragValueFormat::Integer);
+        assert_eq!(drag_value.format_value(42.0), "42");
+        assert_eq!(drag_value.format_value(-0.4), "0");
+        assert_eq!(nudge_value(&drag_value, 42.0, 1.0, 1.0), 43.0);
+        assert_eq!(nudge_value(&drag_value, 42.0, -1.0, FAST_MULTIPLIER), 41.0);
+
+        assert!(supports_hex_display(&255_u32));
+        assert!(!supports_hex_display(&255_i32));
+        drag_value.format = DragValueFormat::Hex;
+        assert_eq!(drag_value.format_value(255.0), "0xFF");
+        assert_eq!(parse_drag_value("0xff"), Some(255.0));
+        assert_eq!(parse_drag_value("1.5"), Some(1.5));
+        assert_eq!(parse_drag_value("0xZZ"), None);
+        assert_eq!(
+            drag_value.format_integer(u64::MAX.into()),
+            "0xFFFFFFFFFFFFFFFF"
+        );
+
+        // Cancelling an edit restores the value in the field's own format
+        let mut drag_state = DragValueDragState {
+            original_value: 255.0,
+            original_integer: Some(255),
+            ..default()
+        };
+        assert_eq!(drag_state.original_text(&drag_value), "0xFF");
+        drag_value.format = DragValueFormat::Integer;
+        assert_eq!(drag_state.original_text(&drag_value), "255");
+        drag_state.original_integer = None;
+        drag_value.format = DragValueFormat::Float;
+        assert_eq!(drag_state.original_text(&drag_value), "255.00");
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Seed {
+        value: u64,
+    }
+
+    #[test]
+    fn large_integers_are_edited_exactly() {
+        // 2^53 + 1 is the first integer an f64 can't represent
+        let typed = parse_drag_integer("9007199254740993").unwrap();
+        assert_eq!(typed, (1 << 53) + 1);
+        assert_eq!(
+            parse_drag_integer("0xFFFFFFFFFFFFFFFF"),
+            Some(u64::MAX.into())
+        );
+        assert_eq!(parse_drag_integer("1.5"), None);
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Seed>();
+        world.init_resource::<PendingValueChanges>();
+        world.init_resource::<Seed>();
+        world.add_observer(queue_value_change);
+        let field_path = FieldPath::resource(
+            TypeId::of::<Seed>(),
+            [FieldPathSegment::Named("value".to_string())],
+        );
+        let source = world.spawn_empty().id();
+        world.trigger(DragValueChanged {
+            source,
+            field_path,
+            new_value: typed as f64,
+            exact_integer: Some(typed),
+            linked: false,
+        });
+        apply_pending_value_changes(&mut world);
+        let value = world.resource::<Seed>().value;
+        assert_eq!(value, (1 << 53) + 1);
+        assert_eq!(try_extract_exact_integer(&value), Some(typed));
+
+        let drag_value = DragValue {
+            field_path: FieldPath::resource(TypeId::of::<Seed>(), []),
+            drag_speed: 0.1,
+            precision: 2,
+            min: None,
+            max: None,
+            snap: None,
+            format: DragValueFormat::Integer,
+        };
+        assert_eq!(nudge_integer(&drag_value, typed, 1.0, 1.0), typed + 1);
+        assert_eq!(drag_value.format_integer(typed), "9007199254740993");
+
+        // Whole numbers beyond the field's type saturate
+        let mut index = 3_u8;
+        assert!(apply_integer_to_partial_reflect(&mut index, -1));
+        assert_eq!(index, 0);
+        let mut offset = 0_i64;
+        assert!(apply_integer_to_partial_reflect(&mut offset, i128::MAX));
+        assert_eq!(offset, i64::MAX);
+    }
+
+    #[test]
+    fn pending_changes_coalesce_per_field() {
+        let field = |name: &str| FieldPath {
+            owner: FieldOwner::Entity(Entity::PLACEHOLDER),
+            component_type_id: TypeId::of::<Transform>(),
+            path: parse_field_path(name).unwrap(),
+        };
+        let numeric = |name: &str, value| PendingValueChange {
+            field_path: field(name),
+            value: FieldValue::Numeric(value),
+        };
+
+        let mut pending = PendingValueChanges::default();
+        pending.push(numeric("translation.x", 1.0));
+        pending.push(numeric("translation.y", 2.0));
+        pending.push(numeric("translation.x", 3.0));
+        pending.push(PendingValueChange {
+            field_path: field("points"),
+            value: FieldValue::ListEdit(ListEdit::Remove(0)),
+        });
+        pending.push(PendingValueChange {
+            field_path: field("points"),
+            value: FieldValue::ListEdit(ListEdit::Remove(0)),
+        });
+
+        let queued: Vec<_> = pending
+            .changes
+            .iter()
+            .map(|change| (format_field_path(&change.field_path.path), &change.value))
+            .collect();
+        assert_eq!(queued.len(), 4);
+        assert_eq!(queued[0].0, "translation.y");
+        assert_eq!(queued[1].0, "translation.x");
+        assert!(matches!(queued[1].1, FieldValue::Numeric(3.0)));
+        assert_eq!(queued[2].0, "points");
+        assert_eq!(queued[3].0, "points");
+    }
+
+    #[test]
+    fn batched_changes_are_written_to_every_entity_with_the_field() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+        world.init_resource::<PendingValueChanges>();
+        let first = world.spawn(Transform::default()).id();
+        let second = world.spawn(Transform::default()).id();
+        let without = world.spawn(Name::new("No transform")).id();
+
+        let path = parse_field_path("translation.x").unwrap();
+        let edit = |value| PendingValueChange {
+            field_path: FieldPath::new::<Transform>(first, path.clone()),
+            value: FieldValue::Numeric(value),
+        };
+        let mut pending = world.resource_mut::<PendingValueChanges>();
+        pending.push_batched(edit(1.0), &[first, second, without]);
+        // Dragging replaces the queued values of every entity
+        pending.push_batched(edit(2.0), &[first, second, without]);
+        assert_eq!(pending.changes.len(), 1);
+        apply_pending_value_changes(&mut world);
+
+        for entity in [first, second] {
+            assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 2.0);
+        }
+        assert!(world.resource::<PendingValueChanges>().batched.is_empty());
+    }
+
+    #[test]
+    fn text_is_edited_at_the_caret() {
+        let mut state = DragValueDragState::default();
+        state.start_editing("1.5".to_string());
+        assert_eq!(state.edit_display(), "1.5|");
+
+        state.move_caret(0, false);
+        state.insert("-");
+        state.step_caret(1, false);
+        state.delete(true);
+        assert_eq!(state.edit_display(), "-1|5");
+        state.delete(false);
+        assert_eq!(state.edit_display(), "-|5");
+
+        // Typing replaces the selection
+        state.step_caret(1, true);
+        assert_eq!(state.edit_display(), "-[5]");
+        state.insert("42");
+        state.select_all();
+        assert_eq!(state.edit_display(), "[-42]");
+        state.step_caret(-1, false);
+        assert_eq!(state.edit_display(), "|-42");
+        state.select_all();
+        state.delete(false);
+        assert_eq!(state.edit_display(), "|");
+    }
+
+    #[test]
+    fn modifier_keys_scale_drag_speed() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        assert_eq!(speed_multiplier(&keys), 1.0);
+        keys.press(KeyCode::ControlRight);
+        assert_eq!(speed_multiplier(&keys), SLOW_MULTIPLIER);
+        keys.press(KeyCode::ShiftLeft);
+        assert_eq!(speed_multiplier(&keys), FAST_MULTIPLIER);
+    }
+
+    #[test]
+    fn snapping_rounds_to_increment() {
+        assert_eq!(snap_value(1.26, 0.5), 1.5);
+        assert_eq!(snap_value(-0.2, 0.5), 0.0);
+        assert_eq!(snap_value(37.0, 15.0), 30.0);
+        assert_eq!(snap_value(1.26, 0.0), 1.26);
+    }
+
+    #[test]
+    fn field_at_path_reads_nested_field() {
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let path = parse_field_path("translation.z").unwrap();
+        let field = field_at_path(&transform, &path).unwrap();
+        assert_eq!(field.try_downcast_ref::<f32>(), Some(&3.0));
+
+        let missing = parse_field_path("translation.w").unwrap();
+        assert!(field_at_path(&transform, &missing).is_none());
+    }
+
+    #[test]
+    fn list_edits_push_remove_and_move() {
+        let mut list = vec![1, 2, 3];
+        assert!(apply_list_edit(&mut list, &ListEdit::Push(Box::new(4))));
+        assert!(apply_list_edit(
+            &mut list,
+            &ListEdit::Move { from: 0, to: 2 }
+        ));
+        assert_eq!(list, [2, 3, 1, 4]);
+        assert!(apply_list_edit(&mut list, &ListEdit::Remove(1)));
+        assert_eq!(list, [2, 1, 4]);
+
+        // Out of bounds or not a list
+        assert!(!apply_list_edit(&mut list, &ListEdit::Remove(3)));
+        assert!(!apply_list_edit(
+            &mut list,
+            &ListEdit::Move { from: 0, to: 3 }
+        ));
+        assert!(!apply_list_edit(&mut 1.0_f32, &ListEdit::Remove(0)));
+    }
+
+    #[test]
+    fn flags_set_and_clear_only_their_bits() {
+        let mut layers: u32 = 0b1010;
+        assert!(apply_flags(&mut layers, 0b0001, true));
+        assert_eq!(layers, 0b1011);
+        assert!(apply_flags(&mut layers, 0b1000, false));
+        assert_eq!(layers, 0b0011);
+
+        let mut signed: i32 = -1;
+        assert!(apply_flags(&mut signed, 1 << 31, false));
+        assert_eq!(signed, i32::MAX);
+        assert!(!apply_flags(&mut 1.0_f32, 1, true));
+    }
+
+    #[test]
+    fn durations_are_edited_in_seconds() {
+        let mut duration = Duration::from_millis(1500);
+        assert_eq!(try_extract_numeric(&duration), Some(1.5));
+        assert!(apply_value_to_partial_reflect(&mut duration, 0.25));
+        assert_eq!(duration, Duration::from_millis(250));
+        assert!(apply_value_to_partial_reflect(&mut duration, -1.0));
+        assert_eq!(duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn narrow_integers_are_edited_with_saturation() {
+        let mut index = 3_u8;
+        assert_eq!(try_extract_numeric(&index), Some(3.0));
+        assert_eq!(numeric_format(&index), DragValueFormat::Integer);
+        assert!(apply_value_to_partial_reflect(&mut index, 300.0));
+        assert_eq!(index, u8::MAX);
+        assert!(apply_value_to_partial_reflect(&mut index, -5.0));
+        assert_eq!(index, 0);
+
+        let mut layer = -2_i16;
+        assert!(apply_value_to_partial_reflect(&mut layer, 4.6));
+        assert_eq!(layer, 5);
+
+        let mut len = 7_usize;
+        assert!(supports_hex_display(&len));
+        assert!(apply_value_to_partial_reflect(&mut len, 8.0));
+        assert_eq!(len, 8);
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Gravity {
+        strength: f32,
+    }
+
+    #[test]
+    fn resource_fields_are_written_back() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Gravity>();
+        world.init_resource::<PendingValueChanges>();
+        world.init_resource::<Gravity>();
+        #[derive(Resource, Default)]
+        struct EditedOwners(Vec<FieldOwner>);
+        world.init_resource::<EditedOwners>();
+        world.add_observer(
+            |edit: On<InspectorFieldEdited>, mut owners: ResMut<EditedOwners>| {
+                owners.0.push(edit.owner);
+            },
+        );
+
+        let field_path = FieldPath::resource(
+            TypeId::of::<Gravity>(),
+            [FieldPathSegment::Named("strength".to_string())],
+        );
+        assert!(field_path.is_resource());
+        assert!(has_field(&world, &field_path));
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push(PendingValueChange {
+                field_path,
+                value: FieldValue::Numeric(9.8),
+            });
+        apply_pending_value_changes(&mut world);
+        assert_eq!(world.resource::<Gravity>().strength, 9.8);
+        assert_eq!(world.resource::<EditedOwners>().0, [FieldOwner::Resource]);
     }
 }
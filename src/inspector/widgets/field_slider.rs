@@ -0,0 +1,87 @@
+//! Field slider widget - a slider for numeric fields with a known range.
+//!
+//! Used instead of a [`DragValue`](super::DragValue) for fields whose range is known
+//! from [`FieldRanges`](crate::inspector::field_ranges::FieldRanges),
+//! so the value can't be dragged out of bounds.
+
+use core::ops::RangeInclusive;
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::{SliderProps, slider};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{SliderPrecision, SliderValue, ValueChange};
+
+use super::drag_value::{FieldPath, FieldValue, PendingValueChange, PendingValueChanges};
+use crate::inspector::state::InspectorState;
+
+const SLIDER_WIDTH: f32 = 140.0;
+
+/// A slider editing the numeric field at the given path.
+#[derive(Component, Clone, Debug)]
+pub struct FieldSlider {
+    /// The field path for write-back.
+    pub field_path: FieldPath,
+}
+
+/// Spawns a slider for the numeric field at `field_path`, clamped to `range`.
+pub fn spawn_field_slider(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    value: f64,
+    range: RangeInclusive<f64>,
+) {
+    parent.spawn((
+        Node {
+            width: Px(SLIDER_WIDTH),
+            ..default()
+        },
+        children![slider(
+            SliderProps {
+                value: value as f32,
+                min: *range.start() as f32,
+                max: *range.end() as f32,
+            },
+            (FieldSlider { field_path }, SliderPrecision(2)),
+        )],
+    ));
+}
+
+/// Observer: applies slider changes and queues the write-back.
+fn field_slider_on_change(
+    change: On<ValueChange<f32>>,
+    sliders: Query<&FieldSlider>,
+    mut pending: ResMut<PendingValueChanges>,
+    state: Option<Res<InspectorState>>,
+    mut commands: Commands,
+) {
+    let Ok(slider) = sliders.get(change.source) else {
+        return;
+    };
+
+    // Headless sliders don't update themselves
+    commands
+        .entity(change.source)
+        .insert(SliderValue(change.value));
+
+    let edit = PendingValueChange {
+        field_path: slider.field_path.clone(),
+        value: FieldValue::Numeric(change.value as f64),
+    };
+    let targets = state.as_ref().map_or(&[][..], |state| {
+        state.field_edit_targets(&slider.field_path)
+    });
+    pending.push_batched(edit, targets);
+}
+
+/// Plugin that adds the FieldSlider widget observers.
+///
+/// Requires [`DragValuePlugin`](super::DragValuePlugin), which writes the queued changes back.
+pub struct FieldSliderPlugin;
+
+impl Plugin for FieldSliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(field_slider_on_change);
+    }
+}
@@ -0,0 +1,109 @@
+//! Flag checkboxes widget - one checkbox per named bit of an integer bitflags field.
+//!
+//! Used instead of a [`DragValue`](super::DragValue) for fields whose bits are named
+//! in [`FieldFlags`](crate::inspector::field_flags::FieldFlags).
+//! Checking a box sets its bits in the field, unchecking clears them,
+//! leaving all other bits untouched.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::feathers::controls::checkbox;
+use bevy::feathers::theme::ThemedText;
+use bevy::prelude::*;
+use bevy::ui::{Checked, Val::*};
+use bevy::ui_widgets::ValueChange;
+
+use super::drag_value::{FieldPath, FieldValue, PendingValueChange, PendingValueChanges};
+use crate::inspector::field_flags::FlagBit;
+use crate::inspector::state::InspectorState;
+
+/// A checkbox setting or clearing `mask` in the integer field at the given path.
+#[derive(Component, Clone, Debug)]
+pub struct FlagCheckbox {
+    /// The field path for write-back.
+    pub field_path: FieldPath,
+    /// The bits toggled by this checkbox.
+    pub mask: u64,
+}
+
+/// Spawns a column of checkboxes, one per flag, for the integer field at `field_path`
+/// whose current value is `value`.
+pub fn spawn_flag_checkboxes(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    value: u64,
+    flags: &[FlagBit],
+    font_size: f32,
+) {
+    parent
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(2.0),
+            ..default()
+        })
+        .with_children(|column| {
+            for flag in flags {
+                let mut checkbox_entity = column.spawn(checkbox(
+                    FlagCheckbox {
+                        field_path: field_path.clone(),
+                        mask: flag.mask,
+                    },
+                    Spawn((
+                        Text::new(flag.name.clone()),
+                        TextFont {
+                            font_size,
+                            ..default()
+                        },
+                        ThemedText,
+                    )),
+                ));
+                if flag.mask != 0 && value & flag.mask == flag.mask {
+                    checkbox_entity.insert(Checked);
+                }
+            }
+        });
+}
+
+/// Observer: applies checkbox changes and queues the write-back.
+fn flag_checkbox_on_change(
+    change: On<ValueChange<bool>>,
+    checkboxes: Query<&FlagCheckbox>,
+    mut pending: ResMut<PendingValueChanges>,
+    state: Option<Res<InspectorState>>,
+    mut commands: Commands,
+) {
+    let Ok(checkbox) = checkboxes.get(change.source) else {
+        return;
+    };
+
+    // Headless checkboxes don't update themselves
+    if change.value {
+        commands.entity(change.source).insert(Checked);
+    } else {
+        commands.entity(change.source).remove::<Checked>();
+    }
+
+    let edit = PendingValueChange {
+        field_path: checkbox.field_path.clone(),
+        value: FieldValue::Flags {
+            mask: checkbox.mask,
+            set: change.value,
+        },
+    };
+    let targets = state.as_ref().map_or(&[][..], |state| {
+        state.field_edit_targets(&checkbox.field_path)
+    });
+    pending.push_batched(edit, targets);
+}
+
+/// Plugin that adds the flag checkbox observers.
+///
+/// Requires [`DragValuePlugin`](super::DragValuePlugin), which writes the queued changes back.
+pub struct FlagCheckboxPlugin;
+
+impl Plugin for FlagCheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(flag_checkbox_on_change);
+    }
+}
@@ -0,0 +1,250 @@
+//! List editor widget - structural editing of list fields such as [`Vec`].
+//!
+//! A list field shows its length with a toggle that expands it into one row per element,
+//! and a button that appends the default value of the element type,
+//! which therefore has to register [`ReflectDefault`].
+//! Each element row gets buttons that move it up or down, or remove it.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::reflect::{TypeInfo, TypeRegistry};
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::Tooltip;
+use super::drag_value::{
+    FieldPath, FieldValue, ListEdit, PendingValueChange, PendingValueChanges,
+    apply_pending_value_changes, field_at_path, reflected_root,
+};
+use crate::inspector::state::{InspectorCache, InspectorState};
+
+const BUTTON_TEXT_COLOR: Color = Color::srgba(0.9, 0.9, 0.6, 1.0);
+
+/// A structural change requested by a [`ListEditButton`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListAction {
+    /// Append the default value of the element type.
+    Push,
+    /// Remove the element at the given index.
+    Remove(usize),
+    /// Swap the element at the given index with the one before it.
+    MoveUp(usize),
+    /// Swap the element at the given index with the one after it.
+    MoveDown(usize),
+}
+
+/// A button applying a [`ListAction`] to the list field at the given path.
+#[derive(Component, Clone, Debug)]
+pub struct ListEditButton {
+    /// The path of the list field.
+    pub field_path: FieldPath,
+    /// What the button does.
+    pub action: ListAction,
+}
+
+/// A toggle expanding or collapsing the list or set field at the given path,
+/// see [`InspectorState::expanded_lists`].
+#[derive(Component, Clone, Debug)]
+pub struct ListExpandToggle {
+    /// The path of the list or set field.
+    pub field_path: FieldPath,
+}
+
+/// Returns the default value of the element type of the list `reflected`,
+/// if it is a list whose element type registers [`ReflectDefault`].
+pub fn default_list_element(
+    reflected: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    let TypeInfo::List(list_info) = reflected.get_represented_type_info()? else {
+        return None;
+    };
+    let reflect_default = registry.get_type_data::<ReflectDefault>(list_info.item_ty().id())?;
+    Some(reflect_default.default().into_partial_reflect())
+}
+
+pub(super) fn spawn_list_button(
+    parent: &mut ChildSpawner,
+    label: &str,
+    tooltip: &str,
+    button: impl Bundle,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                margin: UiRect::left(Px(4.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            bevy::ui_widgets::Button,
+            Tooltip(tooltip.to_string()),
+            button,
+        ))
+        .with_child((
+            Text::new(label),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(BUTTON_TEXT_COLOR),
+        ));
+}
+
+/// Spawns the expand toggle, showing the length of the list, and the button appending an element.
+pub fn spawn_list_header(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    len: usize,
+    expanded: bool,
+    font_size: f32,
+) {
+    let label = format!("{} [{} items]", if expanded { "v" } else { ">" }, len);
+    spawn_list_button(
+        parent,
+        &label,
+        if expanded { "Collapse" } else { "Expand" },
+        (
+            ListExpandToggle {
+                field_path: field_path.clone(),
+            },
+            observe(on_list_expand_toggle_click),
+        ),
+        font_size,
+    );
+    spawn_list_button(
+        parent,
+        "+",
+        "Add element",
+        (
+            ListEditButton {
+                field_path,
+                action: ListAction::Push,
+            },
+            observe(on_list_edit_click),
+        ),
+        font_size,
+    );
+}
+
+/// Spawns the move up, move down and remove buttons of the element at `index`
+/// of the list at `field_path`.
+pub fn spawn_list_item_buttons(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    index: usize,
+    len: usize,
+    font_size: f32,
+) {
+    let mut buttons = Vec::new();
+    if index > 0 {
+        buttons.push(("^", "Move up", ListAction::MoveUp(index)));
+    }
+    if index + 1 < len {
+        buttons.push(("v", "Move down", ListAction::MoveDown(index)));
+    }
+    buttons.push(("x", "Remove", ListAction::Remove(index)));
+
+    for (label, tooltip, action) in buttons {
+        spawn_list_button(
+            parent,
+            label,
+            tooltip,
+            (
+                ListEditButton {
+                    field_path: field_path.clone(),
+                    action,
+                },
+                observe(on_list_edit_click),
+            ),
+            font_size,
+        );
+    }
+}
+
+/// Observer: expands or collapses a list or set field.
+pub(super) fn on_list_expand_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&ListExpandToggle>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    if !state.expanded_lists.remove(&toggle.field_path) {
+        state.expanded_lists.insert(toggle.field_path.clone());
+    }
+    cache.detail_stale = true;
+}
+
+/// Observer: applies a list edit and rebuilds the detail panel to show the new elements.
+fn on_list_edit_click(
+    activate: On<Activate>,
+    buttons: Query<&ListEditButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let ListEditButton { field_path, action } = button.clone();
+
+    commands.queue(move |world: &mut World| {
+        let edit = match action {
+            ListAction::Push => {
+                let registry = world.resource::<AppTypeRegistry>().read();
+                let element = reflected_root(world, &field_path)
+                    .and_then(|root| field_at_path(root, &field_path.path))
+                    .and_then(|list| default_list_element(list, &registry));
+                let Some(element) = element else {
+                    warn!(
+                        "Cannot add an element to list field of {:?}: \
+                         the element type does not register `ReflectDefault`",
+                        field_path.owner
+                    );
+                    return;
+                };
+                ListEdit::Push(element)
+            }
+            ListAction::Remove(index) => ListEdit::Remove(index),
+            ListAction::MoveUp(index) => ListEdit::Move {
+                from: index,
+                to: index.saturating_sub(1),
+            },
+            ListAction::MoveDown(index) => ListEdit::Move {
+                from: index,
+                to: index + 1,
+            },
+        };
+
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push(PendingValueChange {
+                field_path,
+                value: FieldValue::ListEdit(edit),
+            });
+        apply_pending_value_changes(world);
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_elements_come_from_the_element_type() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<f32>();
+
+        let element = default_list_element(&vec![1.0_f32, 2.0], &registry).unwrap();
+        assert_eq!(element.try_downcast_ref::<f32>(), Some(&0.0));
+
+        assert!(default_list_element(&vec![Entity::PLACEHOLDER], &registry).is_none());
+        assert!(default_list_element(&1.0_f32, &registry).is_none());
+    }
+}
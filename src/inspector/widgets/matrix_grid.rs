@@ -0,0 +1,357 @@
+//! Matrix grid widget - an aligned grid of [`DragValue`]s for matrices such as [`Mat4`].
+//!
+//! Matrices are stored column-major, so each grid column is one axis of the matrix
+//! (`X`, `Y`, `Z`, `W`, or `T` for the translation of affine transforms)
+//! and each grid row one component of those axes.
+//! Transform matrices can also show their translation, rotation and scale, read-only.
+
+use core::any::TypeId;
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::math::{Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, Mat2, Mat3A};
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::drag_value::{
+    DragValue, DragValueDragState, DragValueFormat, FieldPath, field_at_path, try_extract_numeric,
+};
+use super::{FieldPathSegment, Tooltip};
+use crate::inspector::fonts::ValueText;
+use crate::inspector::state::{InspectorCache, InspectorState};
+
+const LABEL_COLOR: Color = Color::srgba(0.7, 0.7, 0.7, 1.0);
+const VALUE_COLOR: Color = Color::srgba(0.9, 0.9, 0.6, 1.0);
+
+/// Labels of the rows, i.e. of the components of each axis.
+const ROW_LABELS: [&str; 4] = ["x", "y", "z", "w"];
+
+/// One value of a matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixCell {
+    /// The path from the matrix to this value, e.g. `.x_axis.y`.
+    pub path: Vec<FieldPathSegment>,
+    /// The current value.
+    pub value: f64,
+}
+
+/// The values of a matrix laid out as a grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixGrid {
+    /// Labels of the columns, e.g. `X`, `Y`, `Z`, `T`.
+    pub column_labels: Vec<&'static str>,
+    /// The values, row by row.
+    pub rows: Vec<Vec<MatrixCell>>,
+    /// The translation, rotation and scale, for matrices that represent 3D transforms.
+    pub decomposition: Option<String>,
+}
+
+/// A toggle showing or hiding the decomposition of the matrix at the given path,
+/// see [`InspectorState::decomposed_matrices`].
+#[derive(Component, Clone, Debug)]
+pub struct MatrixDecompositionToggle {
+    /// The path of the matrix field.
+    pub field_path: FieldPath,
+}
+
+/// Returns the columns of a matrix type, as labels and paths to the axis vectors,
+/// and its number of rows.
+fn matrix_layout(
+    reflected: &dyn PartialReflect,
+) -> Option<(Vec<(&'static str, Vec<&'static str>)>, usize)> {
+    let axes = |matrix: Option<&'static str>, count: usize| {
+        [
+            ("X", "x_axis"),
+            ("Y", "y_axis"),
+            ("Z", "z_axis"),
+            ("W", "w_axis"),
+        ]
+        .into_iter()
+        .take(count)
+        .map(|(label, axis)| (label, matrix.into_iter().chain([axis]).collect()))
+        .collect::<Vec<_>>()
+    };
+    let affine = |matrix: &'static str, count: usize| {
+        let mut columns = axes(Some(matrix), count);
+        columns.push(("T", vec!["translation"]));
+        columns
+    };
+
+    let is = |type_ids: &[TypeId]| {
+        reflected
+            .get_represented_type_info()
+            .is_some_and(|info| type_ids.contains(&info.type_id()))
+    };
+    if is(&[TypeId::of::<Mat2>(), TypeId::of::<DMat2>()]) {
+        Some((axes(None, 2), 2))
+    } else if is(&[
+        TypeId::of::<Mat3>(),
+        TypeId::of::<Mat3A>(),
+        TypeId::of::<DMat3>(),
+    ]) {
+        Some((axes(None, 3), 3))
+    } else if is(&[TypeId::of::<Mat4>(), TypeId::of::<DMat4>()]) {
+        Some((axes(None, 4), 4))
+    } else if is(&[TypeId::of::<Affine2>(), TypeId::of::<DAffine2>()]) {
+        Some((affine("matrix2", 2), 2))
+    } else if is(&[TypeId::of::<Affine3A>(), TypeId::of::<DAffine3>()]) {
+        Some((affine("matrix3", 3), 3))
+    } else {
+        None
+    }
+}
+
+/// Formats the translation, rotation (as Euler angles in degrees) and scale of a transform.
+fn format_decomposition(scale: Vec3, rotation: Quat, translation: Vec3) -> String {
+    let (y, x, z) = rotation.to_euler(EulerRot::YXZ);
+    // Adding zero turns -0.0 into 0.0, so unrotated axes don't show as "-0.0"
+    let degrees = |angle: f32| angle.to_degrees() + 0.0;
+    format!(
+        "T ({:.2}, {:.2}, {:.2})  R ({:.1}, {:.1}, {:.1}) deg  S ({:.2}, {:.2}, {:.2})",
+        translation.x,
+        translation.y,
+        translation.z,
+        degrees(x),
+        degrees(y),
+        degrees(z),
+        scale.x,
+        scale.y,
+        scale.z
+    )
+}
+
+/// Returns the translation, rotation and scale of a 3D transform matrix, formatted.
+fn decomposition(reflected: &dyn PartialReflect) -> Option<String> {
+    let (scale, rotation, translation) = if let Some(m) = reflected.try_downcast_ref::<Mat4>() {
+        m.to_scale_rotation_translation()
+    } else if let Some(m) = reflected.try_downcast_ref::<DMat4>() {
+        m.as_mat4().to_scale_rotation_translation()
+    } else if let Some(a) = reflected.try_downcast_ref::<Affine3A>() {
+        a.to_scale_rotation_translation()
+    } else if let Some(a) = reflected.try_downcast_ref::<DAffine3>() {
+        let (scale, rotation, translation) = a.to_scale_rotation_translation();
+        (scale.as_vec3(), rotation.as_quat(), translation.as_vec3())
+    } else {
+        return None;
+    };
+    Some(format_decomposition(scale, rotation, translation))
+}
+
+/// Returns the values of `reflected` laid out as a grid, if it is a matrix or affine transform.
+///
+/// Works for matrices reflected as structs as well as opaque values.
+pub fn matrix_grid(reflected: &dyn PartialReflect) -> Option<MatrixGrid> {
+    let (columns, row_count) = matrix_layout(reflected)?;
+    let rows = ROW_LABELS[..row_count]
+        .iter()
+        .map(|&row| {
+            columns
+                .iter()
+                .map(|(_, column)| {
+                    let path: Vec<FieldPathSegment> = column
+                        .iter()
+                        .chain([&row])
+                        .map(|name| FieldPathSegment::Named(name.to_string()))
+                        .collect();
+                    let value = try_extract_numeric(field_at_path(reflected, &path)?)?;
+                    Some(MatrixCell { path, value })
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(MatrixGrid {
+        column_labels: columns.iter().map(|(label, _)| *label).collect(),
+        rows,
+        decomposition: decomposition(reflected),
+    })
+}
+
+fn spawn_label(parent: &mut ChildSpawner, label: &str, font_size: f32) {
+    parent.spawn((
+        Text::new(label),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(LABEL_COLOR),
+        Node {
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+    ));
+}
+
+/// Spawns a grid of [`DragValue`]s, with row and column labels, for the matrix at `field_path`,
+/// followed by a toggle for its decomposition if it has one.
+/// Each [`DragValue`] is at least `min_height` tall.
+///
+/// The values never snap: rounding them one by one would skew and rescale the transform.
+pub fn spawn_matrix_grid(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    grid: &MatrixGrid,
+    decomposed: bool,
+    min_height: f32,
+    font_size: f32,
+) {
+    parent
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(2.0),
+            ..default()
+        })
+        .with_children(|column| {
+            column
+                .spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: std::iter::once(GridTrack::auto())
+                        .chain(grid.column_labels.iter().map(|_| GridTrack::px(52.0)))
+                        .collect(),
+                    column_gap: Px(2.0),
+                    row_gap: Px(2.0),
+                    ..default()
+                })
+                .with_children(|cells| {
+                    spawn_label(cells, "", font_size);
+                    for label in &grid.column_labels {
+                        spawn_label(cells, label, font_size);
+                    }
+                    for (row, row_label) in grid.rows.iter().zip(ROW_LABELS) {
+                        spawn_label(cells, row_label, font_size);
+                        for cell in row {
+                            let mut cell_path = field_path.clone();
+                            cell_path.path.extend(cell.path.iter().cloned());
+                            cells
+                                .spawn((
+                                    Node {
+                                        min_height: Px(min_height),
+                                        align_items: AlignItems::Center,
+                                        padding: UiRect::horizontal(Px(4.0)),
+                                        border: UiRect::all(Px(1.0)),
+                                        ..default()
+                                    },
+                                    BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                                    DragValue {
+                                        field_path: cell_path,
+                                        drag_speed: 0.01,
+                                        precision: 3,
+                                        min: None,
+                                        max: None,
+                                        snap: None,
+                                        format: DragValueFormat::Float,
+                                    },
+                                    DragValueDragState::default(),
+                                    Interaction::default(),
+                                ))
+                                .with_child((
+                                    Text::new(format!("{:.3}", cell.value)),
+                                    TextFont {
+                                        font_size,
+                                        ..default()
+                                    },
+                                    TextColor(VALUE_COLOR),
+                                    ValueText,
+                                ));
+                        }
+                    }
+                });
+
+            let Some(decomposition) = &grid.decomposition else {
+                return;
+            };
+            column
+                .spawn((
+                    Node {
+                        align_self: AlignSelf::FlexStart,
+                        padding: UiRect::horizontal(Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    bevy::ui_widgets::Button,
+                    Tooltip("Show translation, rotation and scale".to_string()),
+                    MatrixDecompositionToggle { field_path },
+                    observe(on_matrix_decomposition_toggle_click),
+                ))
+                .with_child((
+                    Text::new(if decomposed { "v TRS" } else { "> TRS" }),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(VALUE_COLOR),
+                ));
+            if decomposed {
+                column.spawn((
+                    Text::new(decomposition.clone()),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(LABEL_COLOR),
+                ));
+            }
+        });
+}
+
+/// Observer: shows or hides the decomposition of a matrix.
+fn on_matrix_decomposition_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&MatrixDecompositionToggle>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    if !state.decomposed_matrices.remove(&toggle.field_path) {
+        state.decomposed_matrices.insert(toggle.field_path.clone());
+    }
+    cache.detail_stale = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrices_are_laid_out_by_axis() {
+        let matrix = Mat4::from_scale_rotation_translation(
+            Vec3::splat(2.0),
+            Quat::IDENTITY,
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let grid = matrix_grid(&matrix).unwrap();
+        assert_eq!(grid.column_labels, ["X", "Y", "Z", "W"]);
+        assert_eq!(grid.rows.len(), 4);
+        // The translation is the `w_axis` column
+        assert_eq!(grid.rows[1][3].value, 2.0);
+        assert_eq!(
+            grid.rows[1][3].path,
+            [
+                FieldPathSegment::Named("w_axis".to_string()),
+                FieldPathSegment::Named("y".to_string()),
+            ]
+        );
+        assert_eq!(
+            grid.decomposition.as_deref(),
+            Some("T (1.00, 2.00, 3.00)  R (0.0, 0.0, 0.0) deg  S (2.00, 2.00, 2.00)")
+        );
+
+        let affine = matrix_grid(&Affine3A::from_translation(Vec3::X)).unwrap();
+        assert_eq!(affine.column_labels, ["X", "Y", "Z", "T"]);
+        assert_eq!(affine.rows[0][3].value, 1.0);
+        assert!(
+            matrix_grid(&Mat2::IDENTITY)
+                .unwrap()
+                .decomposition
+                .is_none()
+        );
+        assert!(matrix_grid(&Vec3::ZERO).is_none());
+    }
+}
@@ -4,11 +4,66 @@
 //! - DragValue: A draggable number input (like ImGui's DragFloat)
 //!   - Drag horizontally to change value
 //!   - Double-click to enter text input mode
+//! - ColorPicker: An inline swatch plus RGBA sliders for color fields
+//! - VectorDrag: A single row of DragValues for the axes of a math vector, optionally linked
+//! - EulerAngleAxis: DragValues editing a quaternion through its Euler angles, in degrees
+//! - MatrixGrid: A grid of DragValues for matrices and affine transforms
+//! - FieldSlider: A slider for numeric fields with a known range
+//! - FlagCheckbox: One checkbox per named bit of an integer bitflags field
+//! - OptionToggle: A Some/None toggle for `Option` fields
+//! - ListEditButton: Add, remove and reorder buttons for list fields
+//! - SetInsertInput: Insert and remove members of sets of primitives
+//! - TimerControlButton: Pause and reset buttons for `Timer` and `Stopwatch` fields
+//! - TweenToggle: Animates a numeric field to a typed target value over a few seconds
+//! - SelectableText: Read-only text that can be partially selected and copied
+//! - TextInput: Text typed into while focused, submitted with Enter
+//! - Tooltip: Shows the full text of a truncated label while it is hovered
 
+pub mod color_picker;
 pub mod drag_value;
+pub mod field_slider;
+pub mod flag_checkboxes;
+pub mod list_editor;
+pub mod matrix_grid;
+pub mod option_toggle;
+pub mod selectable_text;
+pub mod set_editor;
+pub mod text_input;
+pub mod timer_controls;
+pub mod tooltip;
+pub mod tween;
+pub mod vector_drag;
 
+pub use color_picker::{
+    ColorChannel, ColorChannelSlider, ColorFieldType, ColorHexLabel, ColorPicker,
+    ColorPickerPlugin, ColorSwatch, color_from_reflect, with_color_channel,
+};
 pub use drag_value::{
-    apply_pending_value_changes, DragValue, DragValueChanged, DragValueDragState,
-    DragValueEditModeChanged, DragValuePlugin, DragValueProps, FieldPath, FieldPathSegment,
-    PendingValueChanges,
+    DragValue, DragValueChanged, DragValueDragState, DragValueEditModeChanged, DragValueFormat,
+    DragValuePlugin, DragValueProps, FieldOwner, FieldPath, FieldPathParseError, FieldPathSegment,
+    FieldValue, InspectorFieldEdited, ListEdit, PendingValueChange, PendingValueChanges, SetEdit,
+    apply_pending_value_changes, format_field_path, parse_field_path,
+};
+pub use field_slider::{FieldSlider, FieldSliderPlugin};
+pub use flag_checkboxes::{FlagCheckbox, FlagCheckboxPlugin};
+pub use list_editor::{ListAction, ListEditButton, ListExpandToggle, default_list_element};
+pub use matrix_grid::{MatrixCell, MatrixDecompositionToggle, MatrixGrid, matrix_grid};
+pub use option_toggle::{OptionToggle, option_is_some, toggled_option};
+pub use selectable_text::{SelectableText, SelectableTextPlugin, selectable_text};
+pub use set_editor::{
+    SetInsertInput, SetMemberRemoveButton, parse_set_member, set_has_primitive_members,
+};
+pub use text_input::{
+    TextInput, TextInputCancelled, TextInputChanged, TextInputPlugin, TextInputSubmitted,
+    TextInputText, text_input,
+};
+pub use timer_controls::{TimerAction, TimerControlButton, apply_timer_action, timer_summary};
+pub use tooltip::{Tooltip, TooltipPlugin, TooltipPopup, truncate_middle};
+pub use tween::{
+    FieldTween, FieldTweenPlugin, FieldTweens, TweenInput, TweenToggle, parse_tween_input,
+    start_field_tween,
+};
+pub use vector_drag::{
+    EulerAngleAxis, VectorAxis, VectorDrag, VectorDragPlugin, VectorLinkToggle, linked_axis_values,
+    quat_euler_degrees, vector_axes, with_euler_angle,
 };
@@ -0,0 +1,166 @@
+//! Option toggle widget - switches an [`Option`] field between `Some` and `None`.
+//!
+//! Switching to `Some` constructs the default value of the inner type,
+//! which therefore has to register [`ReflectDefault`].
+//! While the field is `Some`, the inner value is shown and edited below the toggle.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::reflect::{
+    DynamicEnum, DynamicTuple, DynamicVariant, ReflectRef, TypeInfo, TypeRegistry, VariantInfo,
+};
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::drag_value::{
+    FieldPath, FieldValue, PendingValueChange, PendingValueChanges, apply_pending_value_changes,
+    field_at_path, reflected_root,
+};
+use crate::inspector::state::InspectorCache;
+
+/// Type path prefix shared by all [`Option`] types.
+const OPTION_TYPE_PATH_PREFIX: &str = "core::option::Option<";
+
+/// A toggle switching the [`Option`] field at the given path between `Some` and `None`.
+#[derive(Component, Clone, Debug)]
+pub struct OptionToggle {
+    /// The field path for write-back.
+    pub field_path: FieldPath,
+}
+
+/// Returns whether `reflected` is `Some`, or `None` if it is not an [`Option`].
+pub fn option_is_some(reflected: &dyn PartialReflect) -> Option<bool> {
+    let type_info = reflected.get_represented_type_info()?;
+    if !type_info.type_path().starts_with(OPTION_TYPE_PATH_PREFIX) {
+        return None;
+    }
+    match reflected.reflect_ref() {
+        ReflectRef::Enum(e) => Some(e.variant_name() == "Some"),
+        _ => None,
+    }
+}
+
+/// Returns the opposite of the [`Option`] `reflected`:
+/// `None` for `Some`, and `Some` of the inner type's default value for `None`.
+///
+/// Returns `None` if `reflected` is not an [`Option`],
+/// or if the inner type does not register [`ReflectDefault`].
+pub fn toggled_option(
+    reflected: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    if option_is_some(reflected)? {
+        return Some(Box::new(DynamicEnum::new("None", DynamicVariant::Unit)));
+    }
+
+    let TypeInfo::Enum(enum_info) = reflected.get_represented_type_info()? else {
+        return None;
+    };
+    let VariantInfo::Tuple(some_info) = enum_info.variant("Some")? else {
+        return None;
+    };
+    let inner_type_id = some_info.field_at(0)?.type_id();
+    let default = registry
+        .get_type_data::<ReflectDefault>(inner_type_id)?
+        .default();
+
+    let mut inner = DynamicTuple::default();
+    inner.insert_boxed(default.into_partial_reflect());
+    Some(Box::new(DynamicEnum::new(
+        "Some",
+        DynamicVariant::Tuple(inner),
+    )))
+}
+
+/// Spawns a toggle for the [`Option`] field at `field_path`, labeled with its current variant.
+pub fn spawn_option_toggle(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    is_some: bool,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(6.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+            BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+            bevy::ui_widgets::Button,
+            OptionToggle { field_path },
+            observe(on_option_toggle_click),
+        ))
+        .with_child((
+            Text::new(if is_some { "Some" } else { "None" }),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+        ));
+}
+
+/// Observer: toggles the option and rebuilds the detail panel to show or hide the inner value.
+fn on_option_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&OptionToggle>,
+    mut commands: Commands,
+) {
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    let field_path = toggle.field_path.clone();
+
+    commands.queue(move |world: &mut World| {
+        let toggled = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            reflected_root(world, &field_path)
+                .and_then(|root| field_at_path(root, &field_path.path))
+                .and_then(|option| toggled_option(option, &registry))
+        };
+        let Some(value) = toggled else {
+            warn!(
+                "Cannot switch option field of {:?} to `Some`: \
+                 the inner type does not register `ReflectDefault`",
+                field_path.owner
+            );
+            return;
+        };
+
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push(PendingValueChange {
+                field_path,
+                value: FieldValue::Reflected(value),
+            });
+        apply_pending_value_changes(world);
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_toggle_between_none_and_default() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<f32>();
+
+        let mut value: Option<f32> = None;
+        assert_eq!(option_is_some(&value), Some(false));
+        value.apply(toggled_option(&value, &registry).unwrap().as_ref());
+        assert_eq!(value, Some(0.0));
+
+        value = Some(2.5);
+        value.apply(toggled_option(&value, &registry).unwrap().as_ref());
+        assert_eq!(value, None);
+
+        // Not an option
+        assert_eq!(option_is_some(&2.5_f32), None);
+        assert!(toggled_option(&2.5_f32, &registry).is_none());
+    }
+}
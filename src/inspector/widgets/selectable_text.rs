@@ -0,0 +1,355 @@
+//! Selectable text widget - read-only text whose characters can be selected and copied.
+//!
+//! Dragging over a [`SelectableText`] selects part of it, highlighted in place,
+//! and Ctrl+C copies the selection through [`copy_to_clipboard`] while the text is focused.
+//! Ctrl+A selects all of it, and clicking elsewhere clears the selection.
+//!
+//! The text is drawn as the root [`Text`] followed by two [`TextSpan`]s,
+//! the highlighted selection and the text after it, so glyph positions stay those
+//! of the whole text and can be mapped back to characters.
+
+use core::ops::Range;
+
+use bevy::ecs::observer::On;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input_focus::{FocusedInput, InputFocus};
+use bevy::picking::events::{Drag, Pointer, Press};
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::ui::UiGlobalTransform;
+use bevy::ui_widgets::observe;
+
+use crate::inspector::clipboard::copy_to_clipboard;
+
+const SELECTION_COLOR: Color = Color::srgba(0.25, 0.4, 0.7, 0.8);
+
+/// Read-only text whose characters can be selected with the pointer and copied.
+///
+/// Spawn it with [`selectable_text`], which adds the spans drawing the selection.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SelectableText {
+    text: String,
+    anchor: usize,
+    cursor: usize,
+}
+
+impl SelectableText {
+    /// Creates a selectable `text`, with nothing selected.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            anchor: 0,
+            cursor: 0,
+        }
+    }
+
+    /// The whole text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The byte range of the selection, empty if nothing is selected.
+    pub fn selection(&self) -> Range<usize> {
+        self.anchor.min(self.cursor)..self.anchor.max(self.cursor)
+    }
+
+    /// The selected part of the text.
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selection()]
+    }
+
+    /// Selects from byte `anchor` to byte `cursor`, moved back to character boundaries.
+    pub fn select(&mut self, anchor: usize, cursor: usize) {
+        self.anchor = self.char_boundary(anchor);
+        self.cursor = self.char_boundary(cursor);
+    }
+
+    /// Clears the selection.
+    pub fn clear_selection(&mut self) {
+        self.anchor = 0;
+        self.cursor = 0;
+    }
+
+    /// The closest character boundary at or before byte `index`.
+    fn char_boundary(&self, index: usize) -> usize {
+        let mut index = index.min(self.text.len());
+        while !self.text.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
+    }
+}
+
+/// The bundle of a [`SelectableText`] showing `text`.
+///
+/// Add [`TextFont`] and [`TextColor`] as for a [`Text`]; the spans follow them.
+pub fn selectable_text(text: impl Into<String>) -> impl Bundle {
+    let text = text.into();
+    (
+        Text::new(text.clone()),
+        SelectableText::new(text),
+        children![
+            (TextSpan::default(), TextBackgroundColor(SELECTION_COLOR)),
+            TextSpan::default(),
+        ],
+        observe(on_selectable_text_press),
+        observe(on_selectable_text_drag),
+        observe(on_selectable_text_key),
+    )
+}
+
+/// The laid out box of a glyph, in pixels from the top left of its text.
+#[derive(Clone, Copy, Debug)]
+struct GlyphBox {
+    center: Vec2,
+    size: Vec2,
+    /// Index of the line of the text, as separated by `\n`.
+    line: usize,
+    /// Byte index of the glyph within its line.
+    byte_index: usize,
+    byte_length: usize,
+}
+
+/// Returns the byte index of `text` closest to `point`, given the boxes of its glyphs.
+///
+/// The closest glyph is the one on the nearest line, then the nearest along it,
+/// and the index is before or after it depending on which half `point` is in.
+fn caret_at(text: &str, glyphs: impl IntoIterator<Item = GlyphBox>, point: Vec2) -> usize {
+    let line_starts: Vec<usize> = core::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let outside = |offset: f32, half_extent: f32| (offset.abs() - half_extent).max(0.0);
+    let closest = glyphs.into_iter().min_by(|a, b| {
+        let distance = |glyph: &GlyphBox| {
+            let offset = point - glyph.center;
+            (
+                outside(offset.y, glyph.size.y / 2.0),
+                outside(offset.x, glyph.size.x / 2.0),
+            )
+        };
+        distance(a)
+            .partial_cmp(&distance(b))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    let Some(glyph) = closest else {
+        return 0;
+    };
+    let start = line_starts.get(glyph.line).copied().unwrap_or(0) + glyph.byte_index;
+    let caret = if point.x > glyph.center.x {
+        start + glyph.byte_length
+    } else {
+        start
+    };
+    caret.min(text.len())
+}
+
+/// Returns the byte index of the selectable text under the pointer at `position`,
+/// in logical pixels of the window.
+fn caret_under_pointer(
+    selectable: &SelectableText,
+    node: &ComputedNode,
+    transform: &UiGlobalTransform,
+    layout: &TextLayoutInfo,
+    position: Vec2,
+) -> Option<usize> {
+    let local = transform
+        .try_inverse()?
+        .transform_point2(position / node.inverse_scale_factor())
+        + node.size() / 2.0;
+    let glyphs = layout.glyphs.iter().map(|glyph| GlyphBox {
+        center: glyph.position,
+        size: glyph.size,
+        line: glyph.line_index,
+        byte_index: glyph.byte_index,
+        byte_length: glyph.byte_length,
+    });
+    Some(caret_at(selectable.text(), glyphs, local))
+}
+
+/// Observer: starts selecting at the pressed character, and focuses the text.
+fn on_selectable_text_press(
+    mut press: On<Pointer<Press>>,
+    mut texts: Query<(
+        &mut SelectableText,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &TextLayoutInfo,
+    )>,
+    mut input_focus: ResMut<InputFocus>,
+) {
+    let Ok((mut selectable, node, transform, layout)) = texts.get_mut(press.entity) else {
+        return;
+    };
+    press.propagate(false);
+    let position = press.pointer_location.position;
+    if let Some(caret) = caret_under_pointer(&selectable, node, transform, layout, position) {
+        selectable.select(caret, caret);
+    }
+    input_focus.set(press.entity);
+}
+
+/// Observer: extends the selection to the character under the dragged pointer.
+fn on_selectable_text_drag(
+    mut drag: On<Pointer<Drag>>,
+    mut texts: Query<(
+        &mut SelectableText,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &TextLayoutInfo,
+    )>,
+) {
+    let Ok((mut selectable, node, transform, layout)) = texts.get_mut(drag.entity) else {
+        return;
+    };
+    drag.propagate(false);
+    let position = drag.pointer_location.position;
+    if let Some(caret) = caret_under_pointer(&selectable, node, transform, layout, position) {
+        let anchor = selectable.anchor;
+        selectable.select(anchor, caret);
+    }
+}
+
+/// Observer: copies the selection on Ctrl+C and selects everything on Ctrl+A.
+fn on_selectable_text_key(
+    mut input: On<FocusedInput<KeyboardInput>>,
+    mut texts: Query<&mut SelectableText>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut input_focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    if input.input.state != ButtonState::Pressed {
+        return;
+    }
+    let Ok(mut selectable) = texts.get_mut(input.focused_entity) else {
+        return;
+    };
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    match &input.input.logical_key {
+        Key::Character(c) if ctrl && c.eq_ignore_ascii_case("c") => {
+            let selected = selectable.selected_text().to_string();
+            if !selected.is_empty() {
+                commands.queue(move |world: &mut World| copy_to_clipboard(world, selected));
+            }
+        }
+        Key::Character(c) if ctrl && c.eq_ignore_ascii_case("a") => {
+            let len = selectable.text().len();
+            selectable.select(0, len);
+        }
+        Key::Escape => {
+            selectable.clear_selection();
+            input_focus.clear();
+        }
+        _ => return,
+    }
+    input.propagate(false);
+}
+
+/// Splits each changed [`SelectableText`] into the text before its selection,
+/// the highlighted selection and the text after it, styled like the root text.
+fn sync_selectable_text_spans(
+    mut texts: Query<
+        (&SelectableText, &mut Text, &TextFont, &TextColor, &Children),
+        Or<(
+            Changed<SelectableText>,
+            Changed<TextFont>,
+            Changed<TextColor>,
+        )>,
+    >,
+    mut spans: Query<(&mut TextSpan, &mut TextFont, &mut TextColor), Without<SelectableText>>,
+) {
+    for (selectable, mut text, text_font, text_color, children) in &mut texts {
+        let selection = selectable.selection();
+        let parts = [
+            &selectable.text()[selection.clone()],
+            &selectable.text()[selection.end..],
+        ];
+        let before = &selectable.text()[..selection.start];
+        if text.0 != before {
+            text.0 = before.to_string();
+        }
+        let mut span_entities = spans.iter_many_mut(children.iter());
+        let mut parts = parts.into_iter();
+        while let Some((mut span, mut span_font, mut span_color)) = span_entities.fetch_next() {
+            let part = parts.next().unwrap_or_default();
+            if span.0 != part {
+                span.0 = part.to_string();
+            }
+            if *span_font != *text_font {
+                *span_font = text_font.clone();
+            }
+            if *span_color != *text_color {
+                *span_color = *text_color;
+            }
+        }
+    }
+}
+
+/// Clears the selections of texts that lost focus.
+fn clear_unfocused_selections(
+    input_focus: Res<InputFocus>,
+    mut texts: Query<(Entity, &mut SelectableText)>,
+) {
+    if !input_focus.is_changed() {
+        return;
+    }
+    for (entity, mut selectable) in &mut texts {
+        if input_focus.0 != Some(entity) && !selectable.selection().is_empty() {
+            selectable.clear_selection();
+        }
+    }
+}
+
+/// Plugin that draws and clears the selections of [`SelectableText`]s.
+pub struct SelectableTextPlugin;
+
+impl Plugin for SelectableTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (clear_unfocused_selections, sync_selectable_text_spans).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Boxes of a monospace text, 10 pixels per character and 20 per line.
+    fn monospace_glyphs(text: &str) -> Vec<GlyphBox> {
+        text.split('\n')
+            .enumerate()
+            .flat_map(|(line, line_text)| {
+                line_text
+                    .char_indices()
+                    .enumerate()
+                    .map(move |(column, (index, c))| GlyphBox {
+                        center: Vec2::new(column as f32 * 10.0 + 5.0, line as f32 * 20.0 + 10.0),
+                        size: Vec2::new(10.0, 20.0),
+                        line,
+                        byte_index: index,
+                        byte_length: c.len_utf8(),
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn carets_map_points_to_characters() {
+        let text = "abc\ndéf";
+        let glyphs = monospace_glyphs(text);
+        assert_eq!(caret_at(text, glyphs.clone(), Vec2::new(12.0, 10.0)), 1);
+        assert_eq!(caret_at(text, glyphs.clone(), Vec2::new(18.0, 10.0)), 2);
+        // Past the end of a line, and on the second line after the two-byte `é`
+        assert_eq!(caret_at(text, glyphs.clone(), Vec2::new(200.0, 10.0)), 3);
+        assert_eq!(caret_at(text, glyphs.clone(), Vec2::new(16.0, 30.0)), 7);
+        assert_eq!(caret_at(text, Vec::new(), Vec2::ZERO), 0);
+
+        let mut selectable = SelectableText::new(text);
+        selectable.select(7, 5);
+        assert_eq!(selectable.selected_text(), "é");
+        selectable.select(1, 6);
+        assert_eq!(selectable.selected_text(), "bc\nd");
+    }
+}
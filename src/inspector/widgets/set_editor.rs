@@ -0,0 +1,254 @@
+//! Set editor widget - structural editing of set fields such as [`HashSet`].
+//!
+//! A set field shows its size with a toggle that expands it into one read-only row per member,
+//! sharing [`InspectorState::expanded_lists`] with list fields.
+//! Sets of primitives, such as numbers and strings, also get an input that inserts
+//! the typed RON value on Enter, and a button on each member row that removes it.
+//!
+//! [`HashSet`]: std::collections::HashSet
+//! [`InspectorState::expanded_lists`]: crate::inspector::state::InspectorState::expanded_lists
+
+use core::any::TypeId;
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::reflect::{TypeInfo, TypeRegistry};
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::Tooltip;
+use super::drag_value::{
+    FieldPath, FieldValue, PendingValueChange, PendingValueChanges, SetEdit,
+    apply_pending_value_changes, clone_reflected, field_at_path, reflected_root,
+};
+use super::list_editor::{ListExpandToggle, on_list_expand_toggle_click, spawn_list_button};
+use super::text_input::{TextInput, TextInputSubmitted, TextInputText, text_input};
+use crate::inspector::scene_validation::validate_component_ron;
+use crate::inspector::state::InspectorCache;
+
+const PLACEHOLDER: &str = "+ add";
+
+/// A button removing a member from the set field at the given path.
+#[derive(Component, Debug)]
+pub struct SetMemberRemoveButton {
+    /// The path of the set field.
+    pub field_path: FieldPath,
+    /// The member to remove.
+    pub member: Box<dyn PartialReflect>,
+}
+
+/// A [`TextInput`] inserting its RON value into the set field at the given path on Enter.
+#[derive(Component, Clone, Debug)]
+pub struct SetInsertInput {
+    /// The path of the set field.
+    pub field_path: FieldPath,
+}
+
+/// Returns whether the members of the set `reflected` are primitives,
+/// i.e. booleans, characters, integers or strings, which can be typed in and removed.
+pub fn set_has_primitive_members(reflected: &dyn PartialReflect) -> bool {
+    let Some(TypeInfo::Set(set_info)) = reflected.get_represented_type_info() else {
+        return false;
+    };
+    let primitives = [
+        TypeId::of::<bool>(),
+        TypeId::of::<char>(),
+        TypeId::of::<u8>(),
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+        TypeId::of::<u128>(),
+        TypeId::of::<usize>(),
+        TypeId::of::<i8>(),
+        TypeId::of::<i16>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<i64>(),
+        TypeId::of::<i128>(),
+        TypeId::of::<isize>(),
+        TypeId::of::<String>(),
+    ];
+    primitives.contains(&set_info.value_ty().id())
+}
+
+/// Parses `text` as a member of the set `reflected`.
+///
+/// The text is read as RON of the member type, except that strings may also be typed unquoted.
+pub fn parse_set_member(
+    reflected: &dyn PartialReflect,
+    text: &str,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    let Some(TypeInfo::Set(set_info)) = reflected.get_represented_type_info() else {
+        return None;
+    };
+    let member_type = set_info.value_ty();
+    match validate_component_ron(registry, member_type.path(), text.trim()) {
+        Ok(member) => Some(member),
+        Err(_) if member_type.is::<String>() => Some(Box::new(text.to_string())),
+        Err(_) => None,
+    }
+}
+
+/// Spawns the expand toggle, showing the size of the set,
+/// and if `editable`, the input inserting a member.
+pub fn spawn_set_header(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    len: usize,
+    expanded: bool,
+    editable: bool,
+    font_size: f32,
+) {
+    let label = format!("{} {{{} items}}", if expanded { "v" } else { ">" }, len);
+    spawn_list_button(
+        parent,
+        &label,
+        if expanded { "Collapse" } else { "Expand" },
+        (
+            ListExpandToggle {
+                field_path: field_path.clone(),
+            },
+            observe(on_list_expand_toggle_click),
+        ),
+        font_size,
+    );
+    if !editable {
+        return;
+    }
+    parent.spawn((
+        Node {
+            min_width: Px(48.0),
+            padding: UiRect::horizontal(Px(4.0)),
+            margin: UiRect::left(Px(4.0)),
+            border: UiRect::all(Px(1.0)),
+            ..default()
+        },
+        BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+        BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 1.0)),
+        text_input(TextInput::new(PLACEHOLDER)),
+        Tooltip("Type a member and press Enter to insert it".to_string()),
+        SetInsertInput { field_path },
+        Text::new(PLACEHOLDER),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextInputText,
+        observe(on_set_insert_input_submit),
+    ));
+}
+
+/// Spawns the button removing `member` from the set at `field_path`.
+pub fn spawn_set_member_remove_button(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    member: Box<dyn PartialReflect>,
+    font_size: f32,
+) {
+    spawn_list_button(
+        parent,
+        "x",
+        "Remove",
+        (
+            SetMemberRemoveButton { field_path, member },
+            observe(on_set_member_remove_click),
+        ),
+        font_size,
+    );
+}
+
+/// Queues `edit` of the set at `field_path`, applies it,
+/// and rebuilds the detail panel to show the new members.
+fn queue_set_edit(world: &mut World, field_path: FieldPath, edit: SetEdit) {
+    world
+        .resource_mut::<PendingValueChanges>()
+        .push(PendingValueChange {
+            field_path,
+            value: FieldValue::SetEdit(edit),
+        });
+    apply_pending_value_changes(world);
+    world.resource_mut::<InspectorCache>().detail_stale = true;
+}
+
+/// Observer: inserts the value typed into a set insert input.
+fn on_set_insert_input_submit(
+    submit: On<TextInputSubmitted>,
+    inputs: Query<&SetInsertInput>,
+    mut commands: Commands,
+) {
+    let Ok(insert_input) = inputs.get(submit.entity) else {
+        return;
+    };
+    let field_path = insert_input.field_path.clone();
+    let typed = submit.text.clone();
+    commands.queue(move |world: &mut World| {
+        let member = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            reflected_root(world, &field_path)
+                .and_then(|root| field_at_path(root, &field_path.path))
+                .and_then(|set| parse_set_member(set, &typed, &registry))
+        };
+        let Some(member) = member else {
+            warn!(
+                "Cannot insert {:?} into set field: not a valid member",
+                typed
+            );
+            return;
+        };
+        queue_set_edit(world, field_path, SetEdit::Insert(member));
+    });
+}
+
+/// Observer: removes a member from a set field.
+fn on_set_member_remove_click(
+    activate: On<Activate>,
+    buttons: Query<&SetMemberRemoveButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let field_path = button.field_path.clone();
+    let member = clone_reflected(button.member.as_ref());
+
+    commands.queue(move |world: &mut World| {
+        queue_set_edit(world, field_path, SetEdit::Remove(member));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn members_are_parsed_as_the_member_type() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<String>();
+
+        let numbers: HashSet<u32> = HashSet::from([1, 2]);
+        assert!(set_has_primitive_members(&numbers));
+        let member = parse_set_member(&numbers, " 7 ", &registry).unwrap();
+        assert_eq!(member.try_downcast_ref::<u32>(), Some(&7));
+        assert!(parse_set_member(&numbers, "seven", &registry).is_none());
+
+        let names: HashSet<String> = HashSet::new();
+        let quoted = parse_set_member(&names, "\"a b\"", &registry).unwrap();
+        assert_eq!(
+            quoted.try_downcast_ref::<String>(),
+            Some(&"a b".to_string())
+        );
+        let unquoted = parse_set_member(&names, "a b", &registry).unwrap();
+        assert_eq!(
+            unquoted.try_downcast_ref::<String>(),
+            Some(&"a b".to_string())
+        );
+
+        let entities: HashSet<Entity> = HashSet::new();
+        assert!(!set_has_primitive_members(&entities));
+        assert!(!set_has_primitive_members(&vec![1_u32]));
+    }
+}
@@ -0,0 +1,251 @@
+//! Text input widget - text typed into while the input is focused.
+//!
+//! Clicking a [`TextInput`] focuses it. While it is focused, typed characters and Space are
+//! appended to its text and Backspace deletes the last character. Enter submits the text
+//! and Escape cancels the edit, both ending it; multiline inputs start a new line on Enter.
+//!
+//! Every edit triggers [`TextInputChanged`] on the input, Enter triggers [`TextInputSubmitted`]
+//! and Escape [`TextInputCancelled`], so the owner of an input can keep its own copy of the text.
+//! A [`TextInputText`] on the input or one of its children shows its text with a caret
+//! while it is focused, or its placeholder while it is empty and unfocused.
+
+use bevy::ecs::observer::On;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input_focus::{FocusedInput, InputFocus};
+use bevy::prelude::*;
+use bevy::ui_widgets::{Activate, observe};
+
+/// Color of the typed text.
+const INPUT_TEXT_COLOR: Color = Color::srgba(0.9, 0.9, 0.9, 1.0);
+
+/// Color of the placeholder shown while an input is empty.
+const PLACEHOLDER_TEXT_COLOR: Color = Color::srgba(0.5, 0.5, 0.5, 1.0);
+
+/// Text typed into while the entity is focused.
+///
+/// Spawn it with [`text_input`], which makes it focusable by clicking it.
+#[derive(Component, Clone, Debug, Default)]
+pub struct TextInput {
+    /// The text typed so far.
+    pub text: String,
+    /// Shown while the text is empty and the input isn't focused.
+    pub placeholder: String,
+    /// Whether Enter starts a new line instead of submitting the text.
+    pub multiline: bool,
+}
+
+impl TextInput {
+    /// Creates an input showing `placeholder` while it is empty.
+    pub fn new(placeholder: impl Into<String>) -> Self {
+        Self {
+            placeholder: placeholder.into(),
+            ..default()
+        }
+    }
+
+    /// Starts the input with `text`.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Makes Enter start a new line instead of submitting the text.
+    pub fn multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
+    /// The text to show in the input, with a caret while it is `focused`.
+    pub fn display_text(&self, focused: bool) -> String {
+        if focused {
+            format!("{}|", self.text)
+        } else if self.text.is_empty() {
+            self.placeholder.clone()
+        } else {
+            self.text.clone()
+        }
+    }
+
+    /// The color to show the [`display_text`](Self::display_text) in.
+    pub fn display_color(&self, focused: bool) -> Color {
+        if focused || !self.text.is_empty() {
+            INPUT_TEXT_COLOR
+        } else {
+            PLACEHOLDER_TEXT_COLOR
+        }
+    }
+
+    /// Applies a pressed `key` to the text, returning whether it changed.
+    fn edit(&mut self, key: &Key) -> bool {
+        match key {
+            Key::Backspace => self.text.pop().is_some(),
+            Key::Space => {
+                self.text.push(' ');
+                true
+            }
+            Key::Enter if self.multiline => {
+                self.text.push('\n');
+                true
+            }
+            Key::Character(c) => {
+                self.text.push_str(c);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The text of a [`TextInput`], on the input itself or one of its children,
+/// showing its text or placeholder.
+#[derive(Component)]
+pub struct TextInputText;
+
+/// Triggered on a [`TextInput`] whenever its text is edited.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TextInputChanged {
+    /// The input entity.
+    pub entity: Entity,
+    /// The new text.
+    pub text: String,
+}
+
+/// Triggered on a single line [`TextInput`] when Enter is pressed.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TextInputSubmitted {
+    /// The input entity.
+    pub entity: Entity,
+    /// The submitted text.
+    pub text: String,
+}
+
+/// Triggered on a [`TextInput`] when Escape is pressed.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TextInputCancelled {
+    /// The input entity.
+    pub entity: Entity,
+}
+
+/// The bundle of a focusable `input`.
+///
+/// Add a [`Node`] to style it, and a [`Text`] with [`TextInputText`] to show its text,
+/// either to the input or to a child of it.
+pub fn text_input(input: TextInput) -> impl Bundle {
+    (
+        input,
+        bevy::ui_widgets::Button,
+        observe(on_text_input_click),
+        observe(on_text_input_key),
+    )
+}
+
+/// Observer: focuses a text input when it is clicked.
+fn on_text_input_click(activate: On<Activate>, mut input_focus: ResMut<InputFocus>) {
+    input_focus.set(activate.entity);
+}
+
+/// Observer: edits the text of a focused text input, submitting it on Enter
+/// and cancelling the edit on Escape.
+fn on_text_input_key(
+    mut input: On<FocusedInput<KeyboardInput>>,
+    mut inputs: Query<&mut TextInput>,
+    mut input_focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    if input.input.state != ButtonState::Pressed {
+        return;
+    }
+    let entity = input.focused_entity;
+    let Ok(mut text_input) = inputs.get_mut(entity) else {
+        return;
+    };
+    input.propagate(false);
+
+    let key = &input.input.logical_key;
+    match key {
+        Key::Enter if !text_input.multiline => {
+            input_focus.clear();
+            commands.trigger(TextInputSubmitted {
+                entity,
+                text: text_input.text.clone(),
+            });
+        }
+        Key::Escape => {
+            input_focus.clear();
+            commands.trigger(TextInputCancelled { entity });
+        }
+        _ => {
+            if text_input.edit(key) {
+                commands.trigger(TextInputChanged {
+                    entity,
+                    text: text_input.text.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// System that shows the text of each input, or its placeholder, in its [`TextInputText`].
+fn sync_text_input_text(
+    input_focus: Res<InputFocus>,
+    inputs: Query<(Entity, Ref<TextInput>, Option<&Children>)>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<TextInputText>>,
+) {
+    for (entity, input, children) in &inputs {
+        if !input.is_changed() && !input_focus.is_changed() {
+            continue;
+        }
+        let focused = input_focus.get() == Some(entity);
+        let display = input.display_text(focused);
+        let color = input.display_color(focused);
+        let children = children.into_iter().flat_map(|children| children.iter());
+        let mut texts = texts.iter_many_mut(core::iter::once(entity).chain(children));
+        while let Some((mut text, mut text_color)) = texts.fetch_next() {
+            if text.0 != display {
+                text.0.clone_from(&display);
+            }
+            if text_color.0 != color {
+                text_color.0 = color;
+            }
+        }
+    }
+}
+
+/// Plugin that shows the text of [`TextInput`]s.
+pub struct TextInputPlugin;
+
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_text_input_text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_edit_the_text() {
+        let mut input = TextInput::new("Search...");
+        assert_eq!(input.display_text(false), "Search...");
+        for key in [
+            Key::Character("a".into()),
+            Key::Space,
+            Key::Character("b".into()),
+            Key::Character("c".into()),
+            Key::Backspace,
+        ] {
+            assert!(input.edit(&key));
+        }
+        assert_eq!(input.text, "a b");
+        assert_eq!(input.display_text(true), "a b|");
+        assert!(!input.edit(&Key::Enter));
+        assert!(!input.edit(&Key::ArrowLeft));
+
+        let mut notes = TextInput::default().multiline();
+        assert!(!notes.edit(&Key::Backspace));
+        assert!(notes.edit(&Key::Enter));
+        assert_eq!(notes.text, "\n");
+    }
+}
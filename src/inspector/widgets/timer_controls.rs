@@ -0,0 +1,197 @@
+//! Timer controls widget - pause and reset buttons for [`Timer`] and [`Stopwatch`] fields.
+//!
+//! Timer fields show their elapsed and remaining time instead of their internal fields,
+//! and are edited through the timer methods, which keep the `finished` state consistent.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::time::Stopwatch;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::Tooltip;
+use super::drag_value::{
+    FieldPath, FieldValue, PendingValueChange, PendingValueChanges, apply_pending_value_changes,
+    field_at_path, reflected_root,
+};
+use crate::inspector::state::InspectorCache;
+use crate::inspector::strings::InspectorStrings;
+
+/// A change requested by a [`TimerControlButton`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerAction {
+    /// Pause a running timer, or resume a paused one.
+    TogglePause,
+    /// Reset the elapsed time to zero.
+    Reset,
+}
+
+/// A button applying a [`TimerAction`] to the [`Timer`] or [`Stopwatch`] field at the given path.
+#[derive(Component, Clone, Debug)]
+pub struct TimerControlButton {
+    /// The path of the timer field.
+    pub field_path: FieldPath,
+    /// What the button does.
+    pub action: TimerAction,
+}
+
+/// Returns a summary of the [`Timer`] or [`Stopwatch`] `reflected`, and whether it is paused,
+/// e.g. `0.50s / 2.00s (1.50s left)` for a timer.
+pub fn timer_summary(reflected: &dyn PartialReflect) -> Option<(String, bool)> {
+    let (mut summary, paused) = if let Some(timer) = reflected.try_downcast_ref::<Timer>() {
+        let summary = format!(
+            "{:.2}s / {:.2}s ({:.2}s left)",
+            timer.elapsed_secs(),
+            timer.duration().as_secs_f32(),
+            timer.remaining_secs()
+        );
+        (summary, timer.is_paused())
+    } else {
+        let stopwatch = reflected.try_downcast_ref::<Stopwatch>()?;
+        (
+            format!("{:.2}s elapsed", stopwatch.elapsed_secs()),
+            stopwatch.is_paused(),
+        )
+    };
+    if paused {
+        summary.push_str(", paused");
+    }
+    Some((summary, paused))
+}
+
+/// Returns a copy of the [`Timer`] or [`Stopwatch`] `reflected` with `action` applied.
+pub fn apply_timer_action(
+    reflected: &dyn PartialReflect,
+    action: TimerAction,
+) -> Option<Box<dyn PartialReflect>> {
+    if let Some(timer) = reflected.try_downcast_ref::<Timer>() {
+        let mut timer = timer.clone();
+        match action {
+            TimerAction::TogglePause if timer.is_paused() => timer.unpause(),
+            TimerAction::TogglePause => timer.pause(),
+            TimerAction::Reset => timer.reset(),
+        }
+        return Some(Box::new(timer));
+    }
+
+    let mut stopwatch = reflected.try_downcast_ref::<Stopwatch>()?.clone();
+    match action {
+        TimerAction::TogglePause if stopwatch.is_paused() => stopwatch.unpause(),
+        TimerAction::TogglePause => stopwatch.pause(),
+        TimerAction::Reset => stopwatch.reset(),
+    }
+    Some(Box::new(stopwatch))
+}
+
+/// Spawns the pause (or resume) and reset buttons of the timer field at `field_path`.
+pub fn spawn_timer_controls(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    paused: bool,
+    font_size: f32,
+    strings: &InspectorStrings,
+) {
+    let buttons = [
+        (
+            if paused {
+                &strings.resume
+            } else {
+                &strings.pause
+            },
+            TimerAction::TogglePause,
+        ),
+        (&strings.reset, TimerAction::Reset),
+    ];
+    for (label, action) in buttons {
+        parent
+            .spawn((
+                Node {
+                    padding: UiRect::horizontal(Px(4.0)),
+                    margin: UiRect::left(Px(4.0)),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                bevy::ui_widgets::Button,
+                Tooltip(format!("{} timer", label)),
+                TimerControlButton {
+                    field_path: field_path.clone(),
+                    action,
+                },
+                observe(on_timer_control_click),
+            ))
+            .with_child((
+                Text::new(label.clone()),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+            ));
+    }
+}
+
+/// Observer: applies a timer action and rebuilds the detail panel to show the new state.
+fn on_timer_control_click(
+    activate: On<Activate>,
+    buttons: Query<&TimerControlButton>,
+    mut commands: Commands,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let TimerControlButton { field_path, action } = button.clone();
+
+    commands.queue(move |world: &mut World| {
+        let value = reflected_root(world, &field_path)
+            .and_then(|root| field_at_path(root, &field_path.path))
+            .and_then(|timer| apply_timer_action(timer, action));
+        let Some(value) = value else {
+            return;
+        };
+
+        world
+            .resource_mut::<PendingValueChanges>()
+            .push(PendingValueChange {
+                field_path,
+                value: FieldValue::Reflected(value),
+            });
+        apply_pending_value_changes(world);
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn timers_are_summarized_and_controlled() {
+        let mut timer = Timer::from_seconds(2.0, TimerMode::Once);
+        timer.tick(Duration::from_millis(500));
+        let (summary, paused) = timer_summary(&timer).unwrap();
+        assert_eq!(summary, "0.50s / 2.00s (1.50s left)");
+        assert!(!paused);
+
+        let paused = apply_timer_action(&timer, TimerAction::TogglePause).unwrap();
+        let paused = paused.try_downcast_ref::<Timer>().unwrap();
+        assert!(paused.is_paused());
+        assert_eq!(
+            timer_summary(paused).unwrap().0,
+            "0.50s / 2.00s (1.50s left), paused"
+        );
+
+        let reset = apply_timer_action(paused, TimerAction::Reset).unwrap();
+        let reset = reset.try_downcast_ref::<Timer>().unwrap();
+        assert_eq!(reset.elapsed_secs(), 0.0);
+        assert!(reset.is_paused());
+
+        let stopwatch = Stopwatch::new();
+        assert_eq!(timer_summary(&stopwatch).unwrap().0, "0.00s elapsed");
+        assert!(timer_summary(&1.0_f32).is_none());
+    }
+}
@@ -0,0 +1,175 @@
+//! Hover tooltips and text truncation.
+//!
+//! Long labels are shortened with [`truncate_middle`], which keeps both the start
+//! and the (often more interesting) end of the text.
+//! Adding a [`Tooltip`] to a node shows its full text in a popup while the node is hovered.
+
+use std::borrow::Cow;
+
+use bevy::picking::hover::Hovered;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui::{ComputedUiTargetCamera, UiGlobalTransform};
+
+/// How long a node must be hovered before its tooltip appears, in seconds.
+const TOOLTIP_DELAY: f32 = 0.4;
+
+/// Gap between a node and its tooltip popup.
+const TOOLTIP_OFFSET: f32 = 4.0;
+
+const TOOLTIP_FONT_SIZE: f32 = 12.0;
+const TOOLTIP_BACKGROUND: Color = Color::srgba(0.08, 0.08, 0.08, 0.95);
+const TOOLTIP_BORDER: Color = Color::srgba(0.4, 0.4, 0.4, 1.0);
+
+/// Marks the removed middle of a truncated text.
+/// Plain ASCII, since the default UI font lacks a dedicated ellipsis glyph.
+const ELLIPSIS: &str = "...";
+
+/// Shortens `text` to at most `max_chars` characters by replacing its middle with `...`.
+///
+/// Operates on characters rather than bytes, so it never splits a multi-byte character.
+/// The end of the text gets the extra character when the remainder can't be split evenly.
+pub fn truncate_middle(text: &str, max_chars: usize) -> Cow<'_, str> {
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return Cow::Borrowed(text);
+    }
+    if max_chars <= ELLIPSIS.len() {
+        return Cow::Owned(text.chars().take(max_chars).collect());
+    }
+
+    let kept = max_chars - ELLIPSIS.len();
+    let head = kept / 2;
+    let tail = kept - head;
+    let mut truncated: String = text.chars().take(head).collect();
+    truncated.push_str(ELLIPSIS);
+    truncated.extend(text.chars().skip(char_count - tail));
+    Cow::Owned(truncated)
+}
+
+/// Shows the given text in a popup while the node is hovered.
+#[derive(Component, Clone, Debug)]
+#[require(Hovered)]
+pub struct Tooltip(pub String);
+
+/// The popup currently showing a tooltip. Stores the node the tooltip belongs to.
+#[derive(Component)]
+pub struct TooltipPopup(pub Entity);
+
+/// Plugin that shows [`Tooltip`] popups.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_tooltips);
+    }
+}
+
+/// Shows the tooltip of the hovered node after [`TOOLTIP_DELAY`], and hides it once the hover ends.
+///
+/// When nested nodes are both hovered, the smallest (innermost) one wins.
+fn update_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    tooltips: Query<(
+        Entity,
+        &Tooltip,
+        &Hovered,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &ComputedUiTargetCamera,
+    )>,
+    popups: Query<(Entity, &TooltipPopup)>,
+    mut hover: Local<Option<(Entity, f32)>>,
+) {
+    let hovered = tooltips
+        .iter()
+        .filter(|(_, _, hovered, ..)| hovered.get())
+        .min_by(|(.., a, _, _), (.., b, _, _)| {
+            let (a, b) = (a.size().element_product(), b.size().element_product());
+            a.total_cmp(&b)
+        });
+
+    let Some((entity, tooltip, _, computed, transform, target_camera)) = hovered else {
+        *hover = None;
+        for (popup, _) in &popups {
+            commands.entity(popup).despawn();
+        }
+        return;
+    };
+
+    // Restart the delay whenever the hovered node changes
+    let elapsed = match *hover {
+        Some((hovered_entity, elapsed)) if hovered_entity == entity => elapsed + time.delta_secs(),
+        _ => 0.0,
+    };
+    *hover = Some((entity, elapsed));
+
+    let mut showing = false;
+    for (popup, owner) in &popups {
+        if owner.0 == entity {
+            showing = true;
+        } else {
+            commands.entity(popup).despawn();
+        }
+    }
+    if showing || elapsed < TOOLTIP_DELAY {
+        return;
+    }
+    let Some(camera) = target_camera.get() else {
+        return;
+    };
+
+    // Place the popup just below the hovered node, converting from physical to logical pixels
+    let scale = computed.inverse_scale_factor();
+    let top_left = (transform.translation - computed.size() / 2.0) * scale;
+    let height = computed.size().y * scale;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Px(top_left.x),
+            top: Px(top_left.y + height + TOOLTIP_OFFSET),
+            padding: UiRect::axes(Px(6.0), Px(3.0)),
+            border: UiRect::all(Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(TOOLTIP_BACKGROUND),
+        BorderColor::all(TOOLTIP_BORDER),
+        GlobalZIndex(i32::MAX),
+        UiTargetCamera(camera),
+        Pickable::IGNORE,
+        TooltipPopup(entity),
+        children![(
+            Text::new(tooltip.0.clone()),
+            TextFont {
+                font_size: TOOLTIP_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_text_is_unchanged() {
+        assert_eq!(truncate_middle("Player", 20), "Player");
+    }
+
+    #[test]
+    fn middle_is_replaced_with_ellipsis() {
+        let truncated = truncate_middle("EnemySpawner_Level3_West", 12);
+        assert_eq!(truncated, "Enem..._West");
+        assert_eq!(truncated.chars().count(), 12);
+    }
+
+    #[test]
+    fn multi_byte_characters_are_not_split() {
+        assert_eq!(truncate_middle("ネームド・エンティティ", 7), "ネー...ティ");
+    }
+}
@@ -0,0 +1,378 @@
+//! Tween widget - animates a numeric field to a target value over time.
+//!
+//! The `~` button next to a numeric field opens an input taking a target value
+//! and a duration in seconds, e.g. `10 2.5` (the duration defaults to one second).
+//! On Enter, a [`FieldTween`] writes the interpolated value through [`PendingValueChanges`]
+//! every frame, so motion or fades can be previewed without writing code.
+//! The tween runs on real time, so it also plays while virtual time is paused,
+//! and its writes are merged into a single step of the edit history.
+
+use core::time::Duration;
+
+use bevy::ecs::entity::Entities;
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::Tooltip;
+use super::drag_value::{
+    FieldPath, FieldValue, PendingValueChange, PendingValueChanges, apply_pending_value_changes,
+    field_at_path, parse_drag_value, reflected_root, try_extract_numeric,
+};
+use super::text_input::{
+    TextInput, TextInputCancelled, TextInputSubmitted, TextInputText, text_input,
+};
+use crate::inspector::config::InspectorConfig;
+use crate::inspector::state::{InspectorCache, InspectorState};
+use crate::inspector::strings::InspectorStrings;
+
+/// Duration of a tween whose input doesn't give one.
+const DEFAULT_TWEEN_DURATION: Duration = Duration::from_secs(1);
+
+/// An animation of a numeric field from one value to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldTween {
+    /// The animated field.
+    pub field_path: FieldPath,
+    /// The value at the start of the tween.
+    pub from: f64,
+    /// The value at the end of the tween.
+    pub to: f64,
+    /// How long the tween takes.
+    pub duration: Duration,
+    /// How the value moves from `from` to `to`.
+    pub ease: EaseFunction,
+    /// Time since the tween started.
+    pub elapsed: Duration,
+}
+
+impl FieldTween {
+    /// Creates a linear tween of the field at `field_path`.
+    pub fn new(field_path: FieldPath, from: f64, to: f64, duration: Duration) -> Self {
+        Self {
+            field_path,
+            from,
+            to,
+            duration,
+            ease: EaseFunction::Linear,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns the tween with the given easing function.
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Whether the tween has reached its target value.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The value of the field at the current point of the tween.
+    pub fn value(&self) -> f64 {
+        let t = if self.duration.is_zero() {
+            1.0
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        };
+        let progress = self.ease.sample_clamped(t) as f64;
+        self.from + (self.to - self.from) * progress
+    }
+}
+
+/// The running field tweens, advanced once per frame.
+#[derive(Resource, Default, Debug)]
+pub struct FieldTweens {
+    /// The tweens, at most one per field.
+    pub tweens: Vec<FieldTween>,
+}
+
+impl FieldTweens {
+    /// Starts `tween`, replacing any tween of the same field.
+    pub fn start(&mut self, tween: FieldTween) {
+        self.stop(&tween.field_path);
+        self.tweens.push(tween);
+    }
+
+    /// Stops the tween of the field at `field_path`, leaving the field at its current value.
+    pub fn stop(&mut self, field_path: &FieldPath) {
+        self.tweens.retain(|tween| tween.field_path != *field_path);
+    }
+
+    /// Whether the field at `field_path` is being tweened.
+    pub fn is_tweening(&self, field_path: &FieldPath) -> bool {
+        self.tweens
+            .iter()
+            .any(|tween| tween.field_path == *field_path)
+    }
+}
+
+/// A button opening the tween input of the numeric field at the given path,
+/// or stopping its tween if it is being tweened.
+#[derive(Component, Debug)]
+pub struct TweenToggle(pub FieldPath);
+
+/// A [`TextInput`] starting a tween of the field at the given path on Enter.
+#[derive(Component, Clone, Debug)]
+pub struct TweenInput {
+    /// The path of the tweened field.
+    pub field_path: FieldPath,
+}
+
+/// Parses the text of a [`TweenInput`], a target value followed by an optional duration
+/// in seconds, such as `10`, `0.5 2` or `0x10 1.5s`.
+pub fn parse_tween_input(text: &str) -> Option<(f64, Duration)> {
+    let mut words = text.split_whitespace();
+    let target = parse_drag_value(words.next()?)?;
+    let duration = match words.next() {
+        Some(seconds) => {
+            let seconds: f64 = seconds.strip_suffix('s').unwrap_or(seconds).parse().ok()?;
+            Duration::try_from_secs_f64(seconds).ok()?
+        }
+        None => DEFAULT_TWEEN_DURATION,
+    };
+    if words.next().is_some() || !target.is_finite() {
+        return None;
+    }
+    Some((target, duration))
+}
+
+/// Starts tweening the numeric field at `field_path` from its current value to `to`.
+///
+/// Returns `false` if the field doesn't exist or isn't numeric.
+pub fn start_field_tween(
+    world: &mut World,
+    field_path: FieldPath,
+    to: f64,
+    duration: Duration,
+) -> bool {
+    let from = reflected_root(world, &field_path)
+        .and_then(|root| field_at_path(root, &field_path.path))
+        .and_then(try_extract_numeric);
+    let Some(from) = from else {
+        return false;
+    };
+    world
+        .resource_mut::<FieldTweens>()
+        .start(FieldTween::new(field_path, from, to, duration));
+    true
+}
+
+/// Advances the running tweens, queuing their values as [`PendingValueChange`]s.
+///
+/// Tweens of despawned entities are dropped, and finished tweens are removed
+/// after writing their target value, refreshing the detail panel.
+pub fn advance_field_tweens(
+    time: Res<Time<Real>>,
+    entities: &Entities,
+    mut tweens: ResMut<FieldTweens>,
+    mut pending: ResMut<PendingValueChanges>,
+    cache: Option<ResMut<InspectorCache>>,
+) {
+    if tweens.tweens.is_empty() {
+        return;
+    }
+    tweens.tweens.retain(|tween| {
+        let owner = tween.field_path.owner.entity();
+        owner.is_none_or(|entity| entities.contains(entity))
+    });
+    for tween in &mut tweens.tweens {
+        tween.elapsed = (tween.elapsed + time.delta()).min(tween.duration);
+        pending.push(PendingValueChange {
+            field_path: tween.field_path.clone(),
+            value: FieldValue::Numeric(tween.value()),
+        });
+    }
+    let running = tweens.tweens.len();
+    tweens.tweens.retain(|tween| !tween.is_finished());
+    if tweens.tweens.len() != running
+        && let Some(mut cache) = cache
+    {
+        cache.detail_stale = true;
+    }
+}
+
+/// Spawns the button opening the tween input of the field at `field_path`,
+/// followed by the input itself while it is `open`.
+pub fn spawn_tween_controls(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    tweening: bool,
+    open: bool,
+    config: &InspectorConfig,
+    strings: &InspectorStrings,
+) {
+    let font_size = config.small_font_size;
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::horizontal(Px(4.0)),
+                margin: UiRect::left(Px(4.0)),
+                border: UiRect::all(Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(config.border_color),
+            BackgroundColor(config.control_background_color),
+            bevy::ui_widgets::Button,
+            Tooltip(if tweening {
+                strings.stop_tween.clone()
+            } else {
+                strings.start_tween.clone()
+            }),
+            TweenToggle(field_path.clone()),
+            observe(on_tween_toggle_click),
+        ))
+        .with_child((
+            Text::new(if tweening { strings.stop.as_str() } else { "~" }),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(config.action_text_color),
+        ));
+    if !open {
+        return;
+    }
+    parent.spawn((
+        Node {
+            min_width: Px(56.0),
+            padding: UiRect::horizontal(Px(4.0)),
+            margin: UiRect::left(Px(4.0)),
+            border: UiRect::all(Px(1.0)),
+            ..default()
+        },
+        BorderColor::all(config.border_color),
+        BackgroundColor(config.input_background_color),
+        text_input(TextInput::new(strings.tween_placeholder.clone())),
+        Tooltip(strings.tween_input_tooltip.clone()),
+        TweenInput { field_path },
+        Text::new(strings.tween_placeholder.clone()),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextInputText,
+        observe(on_tween_input_submit),
+        observe(on_tween_input_cancel),
+    ));
+}
+
+/// Observer: stops the tween of a field, or opens or closes its tween input.
+fn on_tween_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&TweenToggle>,
+    mut tweens: ResMut<FieldTweens>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    if tweens.is_tweening(&toggle.0) {
+        tweens.stop(&toggle.0);
+    } else if state.tween_field.as_ref() == Some(&toggle.0) {
+        state.tween_field = None;
+    } else {
+        state.tween_field = Some(toggle.0.clone());
+    }
+    cache.detail_stale = true;
+}
+
+/// Observer: starts the tween typed into a tween input.
+fn on_tween_input_submit(
+    submit: On<TextInputSubmitted>,
+    inputs: Query<&TweenInput>,
+    mut commands: Commands,
+) {
+    let Ok(tween_input) = inputs.get(submit.entity) else {
+        return;
+    };
+    let field_path = tween_input.field_path.clone();
+    let Some((to, duration)) = parse_tween_input(&submit.text) else {
+        warn!(
+            "Cannot tween to {:?}: expected a value and seconds",
+            submit.text
+        );
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        // Apply earlier edits first, so the tween starts from the latest value
+        apply_pending_value_changes(world);
+        if !start_field_tween(world, field_path, to, duration) {
+            warn!("Cannot tween the field: it no longer exists or isn't numeric");
+        }
+        world.resource_mut::<InspectorState>().tween_field = None;
+        world.resource_mut::<InspectorCache>().detail_stale = true;
+    });
+}
+
+/// Observer: closes a tween input when its edit is cancelled.
+fn on_tween_input_cancel(
+    _cancel: On<TextInputCancelled>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    state.tween_field = None;
+    cache.detail_stale = true;
+}
+
+/// Plugin that advances [`FieldTweens`] before the inspector's writes are applied.
+pub struct FieldTweenPlugin;
+
+impl Plugin for FieldTweenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FieldTweens>().add_systems(
+            Update,
+            advance_field_tweens.before(apply_pending_value_changes),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::widgets::parse_field_path;
+
+    #[test]
+    fn tween_input_takes_a_target_and_seconds() {
+        assert_eq!(
+            parse_tween_input("10"),
+            Some((10.0, DEFAULT_TWEEN_DURATION))
+        );
+        assert_eq!(
+            parse_tween_input(" -0.5  2.5s "),
+            Some((-0.5, Duration::from_secs_f64(2.5)))
+        );
+        assert_eq!(parse_tween_input("0x10 0"), Some((16.0, Duration::ZERO)));
+        assert_eq!(parse_tween_input(""), None);
+        assert_eq!(parse_tween_input("10 -1"), None);
+        assert_eq!(parse_tween_input("10 2 3"), None);
+    }
+
+    #[test]
+    fn tweens_interpolate_and_finish() {
+        let mut tween = FieldTween::new(
+            FieldPath::new::<Transform>(
+                Entity::PLACEHOLDER,
+                parse_field_path("translation.x").unwrap(),
+            ),
+            2.0,
+            6.0,
+            Duration::from_secs(2),
+        );
+        assert_eq!(tween.value(), 2.0);
+        tween.elapsed = Duration::from_millis(500);
+        assert_eq!(tween.value(), 3.0);
+        assert!(!tween.is_finished());
+        tween.elapsed = tween.duration;
+        assert_eq!(tween.value(), 6.0);
+        assert!(tween.is_finished());
+
+        let instant = FieldTween::new(tween.field_path.clone(), 0.0, 1.0, Duration::ZERO);
+        assert_eq!(instant.value(), 1.0);
+    }
+}
@@ -0,0 +1,555 @@
+//! Vector drag widget - one compact row of [`DragValue`]s for math vectors such as [`Vec3`].
+//!
+//! Instead of one indented row per axis, the axes are edited side by side,
+//! each labeled with its [`SemanticFieldNames`] name (`x`, `y`, `z`, ...).
+//!
+//! The axes of a vector can be linked, e.g. to scale a [`Transform`] uniformly:
+//! editing one axis then scales the others by the same factor, in the same batch of writes.
+//!
+//! Quaternions are also edited through their Euler angles, in degrees,
+//! which is where rotations snap to an angle increment.
+
+use bevy::ecs::hierarchy::ChildSpawner;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+use bevy::reflect::ReflectRef;
+use bevy::ui::Val::*;
+use bevy::ui_widgets::{Activate, observe};
+
+use super::drag_value::{
+    DragValue, DragValueChanged, DragValueDragState, DragValueFormat, FieldPath, FieldValue,
+    PendingValueChange, PendingValueChanges, field_at_path, numeric_format, reflected_root,
+    try_extract_numeric,
+};
+use super::{FieldPathSegment, Tooltip};
+use crate::inspector::fonts::ValueText;
+use crate::inspector::opaque_math::opaque_math_fields;
+use crate::inspector::semantic_names::SemanticFieldNames;
+use crate::inspector::state::{InspectorCache, InspectorState};
+
+/// Label colors for the first axes, in the usual x/y/z = red/green/blue convention.
+const AXIS_COLORS: [Color; 3] = [
+    Color::srgba(0.9, 0.45, 0.45, 1.0),
+    Color::srgba(0.5, 0.85, 0.5, 1.0),
+    Color::srgba(0.5, 0.65, 0.95, 1.0),
+];
+const OTHER_AXIS_COLOR: Color = Color::srgba(0.7, 0.7, 0.7, 1.0);
+
+/// The order of the Euler angles rotations are edited in, as in matrix decompositions:
+/// yaw around y, then pitch around x, then roll around z.
+const EULER_ROT: EulerRot = EulerRot::YXZ;
+
+/// One axis of a vector, e.g. the `y` of a [`Vec3`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorAxis {
+    /// The semantic name shown next to the value.
+    pub label: &'static str,
+    /// The path segment leading from the vector to this axis.
+    pub segment: FieldPathSegment,
+    /// The current value.
+    pub value: f64,
+    /// How the value is displayed, e.g. without decimals for an [`IVec3`].
+    pub format: DragValueFormat,
+}
+
+/// Returns the axes of `reflected` if it is a vector:
+/// a type with [`SemanticFieldNames`] whose fields are all numeric.
+pub fn vector_axes(
+    reflected: &dyn PartialReflect,
+    semantic_names: &SemanticFieldNames,
+) -> Option<Vec<VectorAxis>> {
+    let type_id = reflected.get_represented_type_info()?.type_id();
+    if !semantic_names.has_override(type_id) {
+        return None;
+    }
+
+    let fields: Vec<(FieldPathSegment, &dyn PartialReflect)> =
+        if let Some(math_fields) = opaque_math_fields(reflected) {
+            math_fields
+                .into_iter()
+                .map(|(name, value)| (FieldPathSegment::Named(name.to_string()), value))
+                .collect()
+        } else {
+            match reflected.reflect_ref() {
+                ReflectRef::Struct(s) => (0..s.field_len())
+                    .map(|i| {
+                        let name = s.name_at(i)?.to_string();
+                        Some((FieldPathSegment::Named(name), s.field_at(i)?))
+                    })
+                    .collect::<Option<_>>()?,
+                ReflectRef::TupleStruct(ts) => (0..ts.field_len())
+                    .map(|i| Some((FieldPathSegment::Index(i), ts.field(i)?)))
+                    .collect::<Option<_>>()?,
+                _ => return None,
+            }
+        };
+
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, (segment, value))| {
+            Some(VectorAxis {
+                label: semantic_names.get_field_name(type_id, i)?,
+                segment,
+                value: try_extract_numeric(value)?,
+                format: numeric_format(value),
+            })
+        })
+        .collect()
+}
+
+/// The values of `axes` after setting the axis at `index` to `new_value` while they are linked:
+/// the other axes are scaled by the same factor.
+///
+/// If the axis was zero, no factor leads to the new value, so only that axis changes.
+pub fn linked_axis_values(axes: &[f64], index: usize, new_value: f64) -> Vec<f64> {
+    let old_value = axes[index];
+    axes.iter()
+        .enumerate()
+        .map(|(i, &value)| match i == index {
+            true => new_value,
+            false if old_value == 0.0 => value,
+            false => value * new_value / old_value,
+        })
+        .collect()
+}
+
+/// Returns the Euler angles of `reflected` around the x, y and z axes, in degrees,
+/// if it is a [`Quat`].
+pub fn quat_euler_degrees(reflected: &dyn PartialReflect) -> Option<Vec3> {
+    let (y, x, z) = reflected.try_downcast_ref::<Quat>()?.to_euler(EULER_ROT);
+    Some(Vec3::new(x, y, z).map(f32::to_degrees))
+}
+
+/// Returns `rotation` with its Euler angle around `axis` (0, 1 or 2 for x, y or z)
+/// set to `degrees`, keeping the other two angles.
+pub fn with_euler_angle(rotation: Quat, axis: usize, degrees: f32) -> Quat {
+    let (y, x, z) = rotation.to_euler(EULER_ROT);
+    let mut angles = Vec3::new(x, y, z);
+    angles[axis] = degrees.to_radians();
+    Quat::from_euler(EULER_ROT, angles.y, angles.x, angles.z)
+}
+
+/// Marker for the row of a vector drag widget. Holds the path of the vector itself.
+#[derive(Component, Clone, Debug)]
+pub struct VectorDrag {
+    pub field_path: FieldPath,
+    /// Whether editing one axis scales the others, see [`InspectorState::linked_vectors`].
+    pub linked: bool,
+}
+
+/// A toggle linking the axes of the vector at the given path,
+/// see [`InspectorState::linked_vectors`].
+#[derive(Component, Clone, Debug)]
+pub struct VectorLinkToggle {
+    /// The path of the vector field.
+    pub field_path: FieldPath,
+}
+
+/// Spawns a row with one labeled [`DragValue`] per axis of the vector at `field_path`.
+///
+/// Vectors of several floating point axes also get a toggle linking their axes.
+/// Each [`DragValue`] is at least `min_height` tall.
+pub fn spawn_vector_drag(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    axes: &[VectorAxis],
+    linked: bool,
+    snap: Option<f64>,
+    min_height: f32,
+    font_size: f32,
+) {
+    parent
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Px(4.0),
+                ..default()
+            },
+            VectorDrag {
+                field_path: field_path.clone(),
+                linked,
+            },
+        ))
+        .with_children(|row| {
+            for (i, axis) in axes.iter().enumerate() {
+                row.spawn((
+                    Text::new(axis.label),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(AXIS_COLORS.get(i).copied().unwrap_or(OTHER_AXIS_COLOR)),
+                ));
+
+                let mut axis_path = field_path.clone();
+                axis_path.path.push(axis.segment.clone());
+                let drag_value = DragValue {
+                    field_path: axis_path,
+                    drag_speed: 0.1,
+                    precision: 2,
+                    min: None,
+                    max: None,
+                    snap,
+                    format: axis.format,
+                };
+                let value_text = drag_value.format_value(axis.value);
+                row.spawn((
+                    Node {
+                        min_width: Px(48.0),
+                        min_height: Px(min_height),
+                        align_items: AlignItems::Center,
+                        padding: UiRect::horizontal(Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    drag_value,
+                    DragValueDragState::default(),
+                    Interaction::default(),
+                ))
+                .with_child((
+                    Text::new(value_text),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+                    ValueText,
+                ));
+            }
+
+            let linkable = axes.len() > 1
+                && axes
+                    .iter()
+                    .all(|axis| axis.format == DragValueFormat::Float);
+            if !linkable {
+                return;
+            }
+            row.spawn((
+                Node {
+                    padding: UiRect::horizontal(Px(4.0)),
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                bevy::ui_widgets::Button,
+                Tooltip("Scale all axes together".to_string()),
+                VectorLinkToggle {
+                    field_path: field_path.clone(),
+                },
+                observe(on_vector_link_toggle_click),
+            ))
+            .with_child((
+                Text::new(if linked { "linked" } else { "link" }),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(if linked {
+                    Color::srgba(0.9, 0.9, 0.6, 1.0)
+                } else {
+                    Color::srgba(0.5, 0.5, 0.5, 1.0)
+                }),
+            ));
+        });
+}
+
+/// One Euler angle of the quaternion a [`DragValue`]'s field path points to,
+/// edited in degrees; the value is the index of the axis (0, 1 or 2 for x, y or z).
+///
+/// Changes are written by rebuilding the quaternion rather than as a number,
+/// see [`spawn_euler_angle_drag`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct EulerAngleAxis(pub usize);
+
+/// Spawns a row with one [`DragValue`] per Euler angle of the quaternion at `field_path`,
+/// in degrees, which snap to multiples of `snap` while Alt is held.
+/// Each [`DragValue`] is at least `min_height` tall.
+pub fn spawn_euler_angle_drag(
+    parent: &mut ChildSpawner,
+    field_path: FieldPath,
+    degrees: Vec3,
+    snap: Option<f64>,
+    min_height: f32,
+    font_size: f32,
+) {
+    parent
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Px(4.0),
+            ..default()
+        })
+        .with_children(|row| {
+            for (axis, label) in ["x", "y", "z"].into_iter().enumerate() {
+                row.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(AXIS_COLORS[axis]),
+                ));
+
+                let drag_value = DragValue {
+                    field_path: field_path.clone(),
+                    drag_speed: 1.0,
+                    precision: 1,
+                    min: None,
+                    max: None,
+                    snap,
+                    format: DragValueFormat::Float,
+                };
+                let value_text = drag_value.format_value(degrees[axis] as f64);
+                row.spawn((
+                    Node {
+                        min_width: Px(48.0),
+                        min_height: Px(min_height),
+                        align_items: AlignItems::Center,
+                        padding: UiRect::horizontal(Px(4.0)),
+                        border: UiRect::all(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
+                    BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 1.0)),
+                    drag_value,
+                    DragValueDragState::default(),
+                    EulerAngleAxis(axis),
+                    Interaction::default(),
+                ))
+                .with_child((
+                    Text::new(value_text),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)),
+                    ValueText,
+                ));
+            }
+        });
+}
+
+/// Observer: writes the quaternion with the edited Euler angle,
+/// to every selected entity if the edited entity is part of a multi-entity selection.
+///
+/// Each entity keeps its own angles around the other two axes.
+fn write_euler_angle(
+    changed: On<DragValueChanged>,
+    axes: Query<&EulerAngleAxis>,
+    state: Option<Res<InspectorState>>,
+    mut commands: Commands,
+) {
+    let Ok(&EulerAngleAxis(axis)) = axes.get(changed.source) else {
+        return;
+    };
+    let field_path = changed.field_path.clone();
+    let mut targets: Vec<FieldPath> = state.as_ref().map_or_else(Vec::new, |state| {
+        let entities = state.field_edit_targets(&field_path);
+        entities
+            .iter()
+            .map(|&entity| field_path.with_entity(entity))
+            .collect()
+    });
+    if !targets.contains(&field_path) {
+        targets.push(field_path);
+    }
+    let degrees = changed.new_value as f32;
+    commands.queue(move |world: &mut World| {
+        let changes: Vec<PendingValueChange> = targets
+            .into_iter()
+            .filter_map(|field_path| {
+                let root = reflected_root(world, &field_path)?;
+                let rotation =
+                    *field_at_path(root, &field_path.path)?.try_downcast_ref::<Quat>()?;
+                Some(PendingValueChange {
+                    field_path,
+                    value: FieldValue::Reflected(Box::new(with_euler_angle(
+                        rotation, axis, degrees,
+                    ))),
+                })
+            })
+            .collect();
+        let mut pending = world.resource_mut::<PendingValueChanges>();
+        for change in changes {
+            pending.push(change);
+        }
+    });
+}
+
+/// Observer: links or unlinks the axes of a vector.
+fn on_vector_link_toggle_click(
+    activate: On<Activate>,
+    toggles: Query<&VectorLinkToggle>,
+    mut state: ResMut<InspectorState>,
+    mut cache: ResMut<InspectorCache>,
+) {
+    let Ok(toggle) = toggles.get(activate.entity) else {
+        return;
+    };
+    if !state.linked_vectors.remove(&toggle.field_path) {
+        state.linked_vectors.insert(toggle.field_path.clone());
+    }
+    cache.detail_stale = true;
+}
+
+/// The current values of the axes of the vector at `field_path`.
+fn current_axis_values(world: &World, field_path: &FieldPath) -> Option<Vec<f64>> {
+    let root = reflected_root(world, field_path)?;
+    let vector = field_at_path(root, &field_path.path)?;
+    let axes = vector_axes(vector, world.get_resource::<SemanticFieldNames>()?)?;
+    Some(axes.iter().map(|axis| axis.value).collect())
+}
+
+/// Observer: when an axis of a linked vector changes, changes the other axes by the same factor.
+///
+/// The other axes get [`DragValueChanged`] events of their own, marked as linked,
+/// so their displays update and their values are queued with the edited axis.
+fn scale_linked_axes(
+    changed: On<DragValueChanged>,
+    parents: Query<&ChildOf>,
+    rows: Query<(&VectorDrag, &Children)>,
+    drag_values: Query<&DragValue>,
+    mut commands: Commands,
+) {
+    if changed.linked {
+        return;
+    }
+    let Ok(child_of) = parents.get(changed.source) else {
+        return;
+    };
+    let Ok((row, children)) = rows.get(child_of.parent()) else {
+        return;
+    };
+    if !row.linked {
+        return;
+    }
+
+    let axes: Vec<(Entity, DragValue)> = children
+        .iter()
+        .filter_map(|child| Some((child, drag_values.get(child).ok()?.clone())))
+        .collect();
+    let vector_path = row.field_path.clone();
+    let source = changed.source;
+    let new_value = changed.new_value;
+    // Read the values once the edited axis' own event was handled, but before it is written
+    commands.queue(move |world: &mut World| {
+        let Some(values) = current_axis_values(world, &vector_path) else {
+            return;
+        };
+        let Some(index) = axes.iter().position(|(entity, _)| *entity == source) else {
+            return;
+        };
+        if values.len() != axes.len() {
+            return;
+        }
+        let linked = linked_axis_values(&values, index, new_value);
+        for (i, (entity, drag_value)) in axes.into_iter().enumerate() {
+            if i == index || linked[i] == values[i] {
+                continue;
+            }
+            let new_value = drag_value.round_to_format(linked[i]);
+            world.trigger(DragValueChanged {
+                source: entity,
+                field_path: drag_value.field_path,
+                new_value,
+                exact_integer: None,
+                linked: true,
+            });
+        }
+    });
+}
+
+/// Plugin that adds the observers linking the axes of vector drag widgets.
+pub struct VectorDragPlugin;
+
+impl Plugin for VectorDragPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(scale_linked_axes)
+            .add_observer(write_euler_angle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::widgets::drag_value::apply_pending_value_changes;
+
+    #[test]
+    fn vectors_expose_labeled_axes() {
+        let semantic_names = SemanticFieldNames::default();
+
+        let axes = vector_axes(&Vec3::new(1.0, 2.0, 3.0), &semantic_names).unwrap();
+        let labels: Vec<_> = axes.iter().map(|axis| axis.label).collect();
+        assert_eq!(labels, ["x", "y", "z"]);
+        assert_eq!(axes[1].segment, FieldPathSegment::Named("y".to_string()));
+        assert_eq!(axes[2].value, 3.0);
+        assert_eq!(axes[2].format, DragValueFormat::Float);
+
+        let axes = vector_axes(&IVec2::new(4, -2), &semantic_names).unwrap();
+        assert_eq!(axes[1].format, DragValueFormat::Integer);
+
+        assert!(vector_axes(&Transform::IDENTITY, &semantic_names).is_none());
+    }
+
+    #[test]
+    fn euler_angles_are_edited_one_at_a_time() {
+        let rotation = Quat::from_euler(EULER_ROT, 30f32.to_radians(), 15f32.to_radians(), 0.0);
+        let degrees = quat_euler_degrees(&rotation).unwrap();
+        assert!((degrees - Vec3::new(15.0, 30.0, 0.0)).abs().max_element() < 1e-3);
+
+        let rotated = with_euler_angle(rotation, 2, 45.0);
+        let degrees = quat_euler_degrees(&rotated).unwrap();
+        assert!((degrees - Vec3::new(15.0, 30.0, 45.0)).abs().max_element() < 1e-3);
+
+        assert!(quat_euler_degrees(&Vec4::ONE).is_none());
+    }
+
+    #[test]
+    fn euler_angle_edits_write_the_whole_quaternion() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+        world.init_resource::<PendingValueChanges>();
+        world.add_observer(write_euler_angle);
+
+        let entity = world.spawn(Transform::default()).id();
+        let field_path =
+            FieldPath::new::<Transform>(entity, [FieldPathSegment::Named("rotation".to_string())]);
+        let source = world.spawn(EulerAngleAxis(1)).id();
+        world.trigger(DragValueChanged {
+            source,
+            field_path,
+            new_value: 90.0,
+            exact_integer: None,
+            linked: false,
+        });
+        world.flush();
+        apply_pending_value_changes(&mut world);
+
+        let rotation = world.get::<Transform>(entity).unwrap().rotation;
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_y(90f32.to_radians()), 1e-5));
+    }
+
+    #[test]
+    fn linked_axes_scale_together() {
+        assert_eq!(
+            linked_axis_values(&[1.0, 2.0, 0.5], 1, 4.0),
+            [2.0, 4.0, 1.0]
+        );
+        // A zero axis gives no factor to scale the others by
+        assert_eq!(
+            linked_axis_values(&[0.0, 2.0, 3.0], 0, 1.0),
+            [1.0, 2.0, 3.0]
+        );
+    }
+}
@@ -10,11 +10,15 @@ pub mod entity_name_resolution;
 pub mod extension_methods;
 pub mod fuzzy_name_mapping;
 pub mod hierarchy_grouping;
+#[cfg(feature = "http_dashboard")]
+pub mod http_dashboard;
 pub mod inspectable;
 pub mod inspector;
 pub mod memory_size;
 pub mod reflection_tools;
 pub mod resource_inspection;
+#[cfg(feature = "serde")]
+mod serde_adapters;
 
 // Re-export the main plugin for convenience
 pub use inspector::{InspectorConfig, InspectorWindowPlugin};
@@ -1,9 +1,14 @@
 //! Types for representing the size of objects in memory.
+//!
+//! Also estimates the size of values once serialized, see [`serialized_ron_size`].
 
+use bevy::reflect::serde::ReflectSerializer;
+use bevy::reflect::{PartialReflect, TypeRegistry};
 use core::fmt::Display;
 
 /// The size of an object in memory, in bytes.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemorySize(pub usize);
 
 impl MemorySize {
@@ -89,3 +94,45 @@ pub enum MemoryUnit {
     /// 1 terabyte = 1024 gigabytes
     Terabytes,
 }
+
+/// Estimates the size of `value` when serialized as compact RON, as in scene files,
+/// e.g. to budget save files or network replication payloads.
+///
+/// Like in scenes, the value is keyed by its type path.
+/// Returns `None` if the value cannot be serialized,
+/// e.g. because a type it contains is not registered.
+pub fn serialized_ron_size(
+    value: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> Option<MemorySize> {
+    let serializer = ReflectSerializer::new(value, registry);
+    let ron = ron::to_string(&serializer).ok()?;
+    Some(MemorySize::new(ron.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn serialized_size_counts_ron_bytes() {
+        #[derive(Reflect)]
+        struct Health(f32);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+
+        let size = serialized_ron_size(&Health(1.5), &registry).unwrap();
+        let expected = format!("{{\"{}\":(1.5)}}", Health::type_path());
+        assert_eq!(size.as_bytes(), expected.len());
+
+        // Opaque values are only serializable if their type registers `ReflectSerialize`
+        #[derive(Reflect)]
+        struct SpawnedAt(std::time::Instant);
+        registry.register::<SpawnedAt>();
+        let spawned_at = SpawnedAt(std::time::Instant::now());
+        assert!(serialized_ron_size(&spawned_at, &registry).is_none());
+    }
+}
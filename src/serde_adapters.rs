@@ -0,0 +1,128 @@
+//! `serde` adapters for the Bevy types in inspection results
+//! that don't implement `Serialize` and `Deserialize` themselves.
+//!
+//! Used with `#[serde(with = "...")]` when the `serde` feature is enabled.
+
+/// Serializes a [`DebugName`](bevy::utils::prelude::DebugName) as its string.
+pub(crate) mod debug_name {
+    use bevy::utils::prelude::DebugName;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        name: &DebugName,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(name)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DebugName, D::Error> {
+        String::deserialize(deserializer).map(DebugName::owned)
+    }
+}
+
+/// Serializes a [`ComponentId`](bevy::ecs::component::ComponentId) as its index.
+pub(crate) mod component_id {
+    use bevy::ecs::component::ComponentId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        id: &ComponentId,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        id.index().serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ComponentId, D::Error> {
+        usize::deserialize(deserializer).map(ComponentId::new)
+    }
+}
+
+/// Serializes a list of [`ComponentId`](bevy::ecs::component::ComponentId)s as their indices.
+pub(crate) mod component_ids {
+    use bevy::ecs::component::ComponentId;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        ids: &[ComponentId],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(ids.iter().map(|id| id.index()))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<ComponentId>, D::Error> {
+        let indices = Vec::<usize>::deserialize(deserializer)?;
+        Ok(indices.into_iter().map(ComponentId::new).collect())
+    }
+}
+
+/// Serializes a [`StorageType`](bevy::ecs::component::StorageType) as its variant name.
+pub(crate) mod storage_type {
+    use bevy::ecs::component::StorageType;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        storage_type: &StorageType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match storage_type {
+            StorageType::Table => "Table",
+            StorageType::SparseSet => "SparseSet",
+        })
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<StorageType, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "Table" => Ok(StorageType::Table),
+            "SparseSet" => Ok(StorageType::SparseSet),
+            other => Err(D::Error::unknown_variant(other, &["Table", "SparseSet"])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::entity_inspection::{EntityInspection, EntityInspectionSettings};
+    use crate::entity_name_resolution::NameResolutionRegistry;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+
+    #[test]
+    fn entity_inspection_round_trips_through_ron() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.insert_resource(NameResolutionRegistry::new());
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+        let entity = world
+            .spawn((Name::new("Player"), Transform::default()))
+            .id();
+        let inspection = world
+            .inspect(entity, EntityInspectionSettings::default())
+            .unwrap();
+
+        let serialized = ron::to_string(&inspection).unwrap();
+        let deserialized: EntityInspection = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.entity, entity);
+        assert_eq!(deserialized.name, inspection.name);
+        assert_eq!(deserialized.total_memory_size, inspection.total_memory_size);
+        let components = deserialized.components.unwrap();
+        assert_eq!(components.len(), inspection.components.unwrap().len());
+        let transform = components
+            .iter()
+            .find(|component| component.name.shortname().to_string() == "Transform")
+            .unwrap();
+        assert!(transform.value.is_some());
+    }
+}
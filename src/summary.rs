@@ -1,12 +1,19 @@
-//! Statistical summaries about the [`World`].
+//! Statistical summaries about the [`World`],
+//! and audits of the world for common problems, see [`WorldAudit`].
 
 use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 use bevy::{
-    ecs::{archetype::ArchetypeId, component::ComponentId},
+    ecs::{archetype::ArchetypeId, component::ComponentId, entity_disabling::DefaultQueryFilters},
     prelude::*,
+    reflect::ReflectRef,
 };
 
+use crate::inspector::exclusion::EntityExclusions;
+use crate::memory_size::{MemorySize, serialized_ron_size};
+
 /// Settings for [`WorldSummary`].
 #[derive(Clone, Debug)]
 pub struct SummarySettings {
@@ -132,10 +139,198 @@ impl std::fmt::Display for WorldSummary {
     }
 }
 
+/// Settings for [`WorldAudit`].
+#[derive(Clone, Debug)]
+pub struct AuditSettings {
+    /// How many entities without a [`Name`] are tolerated before they are reported.
+    pub unnamed_entity_threshold: usize,
+    /// The size from which a component value is reported as huge,
+    /// measured as its serialized RON size, or its stack size if it cannot be serialized.
+    pub huge_component_size: MemorySize,
+    /// Optional output limit for the findings of each check.
+    pub max_rows_per_check: Option<usize>,
+}
+
+impl Default for AuditSettings {
+    fn default() -> Self {
+        const DEFAULT_ROWS_PER_CHECK: usize = 10;
+        Self {
+            unnamed_entity_threshold: 100,
+            huge_component_size: MemorySize::new(64 * 1024),
+            max_rows_per_check: Some(DEFAULT_ROWS_PER_CHECK),
+        }
+    }
+}
+
+/// A component type in use that is not reflected,
+/// so its values cannot be inspected, edited or saved in scenes.
+#[derive(Clone, Debug)]
+pub struct UnreflectedComponent {
+    /// The id of the component type.
+    pub component_id: ComponentId,
+    /// The name of the component type.
+    pub name: DebugName,
+    /// How many entities have the component.
+    pub entity_count: usize,
+}
+
+/// An [`Entity`] stored in a component that no longer exists.
+#[derive(Clone, Debug)]
+pub struct DanglingReference {
+    /// The entity holding the reference.
+    pub entity: Entity,
+    /// The name of the component holding the reference.
+    pub component_name: DebugName,
+    /// The entity that no longer exists.
+    pub target: Entity,
+}
+
+/// The largest value of a component type with values
+/// exceeding [`AuditSettings::huge_component_size`].
+#[derive(Clone, Debug)]
+pub struct HugeComponent {
+    /// The entity holding the largest value.
+    pub entity: Entity,
+    /// The name of the component type.
+    pub component_name: DebugName,
+    /// The size of the largest value.
+    pub size: MemorySize,
+    /// How many values of the type exceed the size limit.
+    pub count: usize,
+}
+
+/// [`World`] audit result, listing the problems found by each check.
+#[derive(Clone, Debug)]
+pub struct WorldAudit {
+    /// Component types in use that are not reflected, most used first.
+    pub unreflected_components: Vec<UnreflectedComponent>,
+    /// The number of entities without a [`Name`],
+    /// if it exceeds [`AuditSettings::unnamed_entity_threshold`].
+    ///
+    /// Disabled entities, internal entities such as observers,
+    /// and entities hidden by the [`EntityExclusions`] are not counted.
+    pub unnamed_entities: Option<usize>,
+    /// Reflected [`Entity`] fields pointing at entities that no longer exist.
+    pub dangling_references: Vec<DanglingReference>,
+    /// Component types with huge values, largest first.
+    pub huge_components: Vec<HugeComponent>,
+    /// Limit of displayed findings per check.
+    max_rows_per_check: Option<usize>,
+}
+
+impl WorldAudit {
+    /// Returns true if no check found a problem.
+    pub fn is_clean(&self) -> bool {
+        self.unreflected_components.is_empty()
+            && self.unnamed_entities.is_none()
+            && self.dangling_references.is_empty()
+            && self.huge_components.is_empty()
+    }
+}
+
+/// Appends the rows of one audit check to `display`, up to `limit` of them.
+fn push_audit_rows(display: &mut String, title: &str, rows: Vec<String>, limit: Option<usize>) {
+    let count = rows.len();
+    display.push_str(&format!("{title}: {count}"));
+    display.push('\n');
+    let limit = limit.unwrap_or(usize::MAX);
+    for row in rows.iter().take(limit) {
+        display.push_str(&format!("- {row}"));
+        display.push('\n');
+    }
+    if count > limit {
+        let remaining = count - limit;
+        display.push_str(&format!("... and {remaining} more."));
+        display.push('\n');
+    }
+}
+
+impl std::fmt::Display for WorldAudit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut display = String::new();
+        display.push_str("Audit:");
+        display.push('\n');
+        if self.is_clean() {
+            display.push_str("No problems found.");
+            return write!(f, "{display}");
+        }
+        let limit = self.max_rows_per_check;
+        let unreflected = self
+            .unreflected_components
+            .iter()
+            .map(|component| {
+                let name = component.name.shortname();
+                let entity_count = component.entity_count;
+                format!("{name} ({entity_count} entities)")
+            })
+            .collect();
+        push_audit_rows(&mut display, "Unreflected components", unreflected, limit);
+        if let Some(unnamed) = self.unnamed_entities {
+            display.push_str(&format!("Entities without a name: {unnamed}"));
+            display.push('\n');
+        }
+        let dangling = self
+            .dangling_references
+            .iter()
+            .map(|reference| {
+                let entity = reference.entity;
+                let component = reference.component_name.shortname();
+                let target = reference.target;
+                format!("{entity} {component} -> {target}")
+            })
+            .collect();
+        push_audit_rows(&mut display, "Dangling entity references", dangling, limit);
+        let huge = self
+            .huge_components
+            .iter()
+            .map(|component| {
+                let name = component.component_name.shortname();
+                let size = component.size;
+                let entity = component.entity;
+                let count = component.count;
+                format!("{name}: {size} on {entity} ({count} values over the limit)")
+            })
+            .collect();
+        push_audit_rows(&mut display, "Huge components", huge, limit);
+        write!(f, "{display}")
+    }
+}
+
+/// Collects the [`Entity`] values anywhere inside `value`.
+fn collect_entities(value: &dyn PartialReflect, entities: &mut Vec<Entity>) {
+    if let Some(entity) = value.try_downcast_ref::<Entity>() {
+        entities.push(*entity);
+        return;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => s.iter_fields().for_each(|f| collect_entities(f, entities)),
+        ReflectRef::TupleStruct(s) => s.iter_fields().for_each(|f| collect_entities(f, entities)),
+        ReflectRef::Tuple(t) => t.iter_fields().for_each(|f| collect_entities(f, entities)),
+        ReflectRef::List(l) => l.iter().for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Array(a) => a.iter().for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Map(m) => m.iter().for_each(|(key, value)| {
+            collect_entities(key, entities);
+            collect_entities(value, entities);
+        }),
+        ReflectRef::Set(s) => s.iter().for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Enum(e) => e
+            .iter_fields()
+            .for_each(|field| collect_entities(field.value(), entities)),
+        _ => {}
+    }
+}
+
 /// Adds summary methods to [`World`].
 pub trait WorldSummaryExt {
     /// Summarizes data about this [`World`].
     fn summarize(&self, settings: SummarySettings) -> WorldSummary;
+
+    /// Audits this [`World`] for unreflected components in use, many unnamed entities,
+    /// dangling entity references and huge components.
+    ///
+    /// Every reflected component of every entity is read,
+    /// so this is meant to be run once, e.g. at startup, rather than every frame.
+    fn audit(&self, settings: AuditSettings) -> WorldAudit;
 }
 
 impl WorldSummaryExt for World {
@@ -195,12 +390,137 @@ impl WorldSummaryExt for World {
             max_archetype_rows: settings.max_archetype_rows,
         }
     }
+
+    fn audit(&self, settings: AuditSettings) -> WorldAudit {
+        let registry = self
+            .get_resource::<AppTypeRegistry>()
+            .map(|registry| registry.read());
+        let component_name = |component_id: ComponentId| {
+            self.components().get_name(component_id).unwrap_or_else(|| {
+                let component_index = component_id.index();
+                DebugName::owned(format!("Component #{component_index}"))
+            })
+        };
+        let name_id = self.components().component_id::<Name>();
+        // Entities that are disabled (e.g. by `Disabled`) or internal (e.g. observers),
+        // or hidden from the inspector, are not expected to be named
+        let disabling_ids: Vec<ComponentId> = self
+            .get_resource::<DefaultQueryFilters>()
+            .map(|filters| filters.disabling_ids().collect())
+            .unwrap_or_default();
+        let exclusions = self.get_resource::<EntityExclusions>();
+        let is_hidden = |entity: Entity| {
+            exclusions.is_some_and(|exclusions| exclusions.is_excluded(self.entity(entity)))
+        };
+
+        let mut unreflected: HashMap<ComponentId, usize> = HashMap::new();
+        let mut unnamed_entities = 0;
+        let mut dangling_references = Vec::new();
+        let mut huge: HashMap<ComponentId, HugeComponent> = HashMap::new();
+        for archetype in self.archetypes().iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            if !name_id.is_some_and(|id| archetype.contains(id))
+                && !disabling_ids.iter().any(|&id| archetype.contains(id))
+            {
+                unnamed_entities += archetype
+                    .entities()
+                    .iter()
+                    .filter(|archetype_entity| !is_hidden(archetype_entity.id()))
+                    .count();
+            }
+            for &component_id in archetype.components() {
+                let reflect_component = self
+                    .components()
+                    .get_info(component_id)
+                    .and_then(|info| info.type_id())
+                    .zip(registry.as_ref())
+                    .and_then(|(type_id, registry)| {
+                        registry.get_type_data::<ReflectComponent>(type_id)
+                    });
+                let (Some(reflect_component), Some(registry)) = (reflect_component, &registry)
+                else {
+                    *unreflected.entry(component_id).or_default() += archetype.entities().len();
+                    continue;
+                };
+                for archetype_entity in archetype.entities() {
+                    let entity = archetype_entity.id();
+                    let Some(value) = reflect_component.reflect(self.entity(entity)) else {
+                        continue;
+                    };
+
+                    let mut referenced = Vec::new();
+                    collect_entities(value.as_partial_reflect(), &mut referenced);
+                    for target in referenced {
+                        if target != Entity::PLACEHOLDER && !self.entities().contains(target) {
+                            dangling_references.push(DanglingReference {
+                                entity,
+                                component_name: component_name(component_id),
+                                target,
+                            });
+                        }
+                    }
+
+                    let size = serialized_ron_size(value.as_partial_reflect(), registry)
+                        .unwrap_or_else(|| {
+                            let info = self.components().get_info(component_id);
+                            MemorySize::new(info.map_or(0, |info| info.layout().size()))
+                        });
+                    if size.as_bytes() < settings.huge_component_size.as_bytes() {
+                        continue;
+                    }
+                    let largest = huge.entry(component_id).or_insert(HugeComponent {
+                        entity,
+                        component_name: component_name(component_id),
+                        size,
+                        count: 0,
+                    });
+                    largest.count += 1;
+                    if size.as_bytes() > largest.size.as_bytes() {
+                        largest.entity = entity;
+                        largest.size = size;
+                    }
+                }
+            }
+        }
+
+        let mut unreflected_components: Vec<UnreflectedComponent> = unreflected
+            .into_iter()
+            .map(|(component_id, entity_count)| UnreflectedComponent {
+                component_id,
+                name: component_name(component_id),
+                entity_count,
+            })
+            .collect();
+        unreflected_components.sort_by_key(|component| {
+            (
+                Reverse(component.entity_count),
+                component.component_id.index(),
+            )
+        });
+        let mut huge_components: Vec<HugeComponent> = huge.into_values().collect();
+        huge_components.sort_by_key(|component| Reverse(component.size.as_bytes()));
+        dangling_references.sort_by_key(|reference| reference.entity);
+
+        WorldAudit {
+            unreflected_components,
+            unnamed_entities: (unnamed_entities > settings.unnamed_entity_threshold)
+                .then_some(unnamed_entities),
+            dangling_references,
+            huge_components,
+            max_rows_per_check: settings.max_rows_per_check,
+        }
+    }
 }
 
 /// Adds summary methods for [`Commands`].
 pub trait CommandsSummaryExt {
     /// Summarizes data about the [`World`].
     fn summarize(&mut self, settings: SummarySettings);
+
+    /// Audits the [`World`] and logs the report, see [`WorldSummaryExt::audit`].
+    fn audit(&mut self, settings: AuditSettings);
 }
 
 impl CommandsSummaryExt for Commands<'_, '_> {
@@ -210,4 +530,100 @@ impl CommandsSummaryExt for Commands<'_, '_> {
             info!("{inspection_summary}");
         });
     }
+
+    fn audit(&mut self, settings: AuditSettings) {
+        self.queue(move |world: &mut World| log_audit(&world.audit(settings)));
+    }
+}
+
+/// Logs `audit`, as a warning if it found problems.
+fn log_audit(audit: &WorldAudit) {
+    if audit.is_clean() {
+        info!("{audit}");
+    } else {
+        warn!("{audit}");
+    }
+}
+
+/// Plugin that audits the [`World`] once, after startup, see [`WorldSummaryExt::audit`].
+///
+/// The report is logged, and written to [`Self::export_path`] if set.
+#[derive(Clone, Debug, Default)]
+pub struct WorldAuditPlugin {
+    /// Settings for the audit.
+    pub settings: AuditSettings,
+    /// The file to write the report to, if any.
+    pub export_path: Option<PathBuf>,
+}
+
+impl Plugin for WorldAuditPlugin {
+    fn build(&self, app: &mut App) {
+        let plugin = self.clone();
+        app.add_systems(PostStartup, move |world: &mut World| {
+            let audit = world.audit(plugin.settings.clone());
+            log_audit(&audit);
+            if let Some(path) = &plugin.export_path
+                && let Err(error) = std::fs::write(path, audit.to_string())
+            {
+                warn!(
+                    "Failed to export the world audit to {}: {}",
+                    path.display(),
+                    error
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::entity_disabling::Disabled;
+
+    use super::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Target(Entity);
+
+    #[derive(Component)]
+    struct Unreflected;
+
+    #[test]
+    fn audit_finds_dangling_references_and_unreflected_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Target>();
+        let gone = world.spawn_empty().id();
+        world.despawn(gone);
+        let alive = world.spawn(Name::new("Alive")).id();
+        let holder = world.spawn((Name::new("Holder"), Target(gone))).id();
+        world.spawn((Name::new("Fine"), Target(alive)));
+        world.spawn(Unreflected);
+        world.spawn(Unreflected);
+        // Observers, disabled entities and hidden entities aren't expected to be named
+        world.add_observer(|_: On<Add, Target>| {});
+        world.spawn((Unreflected, Disabled));
+        world.init_resource::<EntityExclusions>();
+        world.spawn((Unreflected, Window::default()));
+
+        let audit = world.audit(AuditSettings {
+            unnamed_entity_threshold: 1,
+            ..default()
+        });
+        let dangling: Vec<_> = audit
+            .dangling_references
+            .iter()
+            .map(|reference| (reference.entity, reference.target))
+            .collect();
+        assert_eq!(dangling, [(holder, gone)]);
+        assert_eq!(audit.unnamed_entities, Some(2));
+        assert!(audit.unreflected_components.iter().any(|component| {
+            component.name.shortname().to_string() == "Unreflected" && component.entity_count == 4
+        }));
+        assert!(audit.huge_components.is_empty());
+        assert!(!audit.is_clean());
+    }
 }